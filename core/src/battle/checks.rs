@@ -1,6 +1,12 @@
 use rand::rngs::StdRng;
 use rand::Rng;
 use crate::models::{MoveData, PokemonInstance, StatusCondition, MoveMeta};
+use super::events::{BattleEvent, DamageCause};
+use super::systems::damage_system::calculate_damage;
+use super::systems::field_system::Screens;
+
+/// Probabilidad de que un Pokémon confundido se golpee a sí mismo
+const CONFUSION_SELF_HIT_CHANCE: f64 = 1.0 / 3.0;
 
 /// Verifica si un estado alterado se aplica exitosamente
 /// Si chance es 0:
@@ -90,56 +96,66 @@ pub fn create_struggle_move() -> MoveData {
     }
 }
 
+/// Crea el MoveData del golpe que se da un Pokémon confundido
+/// Físico, sin tipo (neutro contra todo, sin STAB) y de potencia 40
+pub fn create_confusion_move() -> MoveData {
+    MoveData {
+        id: "confusion-self-hit".to_string(),
+        name: "Confusión".to_string(),
+        r#type: "typeless".to_string(),
+        power: Some(40),
+        accuracy: None,
+        priority: 0,
+        pp: 0,
+        damage_class: "physical".to_string(),
+        meta: MoveMeta::default(),
+        stat_changes: Vec::new(),
+        target: "user".to_string(),
+    }
+}
+
 /// Verifica si un Pokémon puede moverse basándose en su condición de estado
-/// Retorna (puede_moverse, logs)
-pub(crate) fn can_pokemon_move(pokemon: &mut PokemonInstance, rng: &mut StdRng) -> (bool, Vec<String>) {
-    let mut logs = Vec::new();
+/// Retorna (puede_moverse, eventos)
+pub(crate) fn can_pokemon_move(pokemon: &mut PokemonInstance, rng: &mut StdRng) -> (bool, Vec<BattleEvent>) {
+    let mut events = Vec::new();
+    let name = pokemon.species.display_name.clone();
 
     // Primero verificar status conditions permanentes
-    if let Some(status) = &pokemon.status_condition {
+    if let Some(status) = pokemon.status_condition {
         match status {
             StatusCondition::Sleep => {
                 // 33% de probabilidad de despertar
                 if rng.gen_bool(0.33) {
                     pokemon.status_condition = None;
-                    logs.push(format!(
-                        "¡{} se despertó!",
-                        pokemon.species.display_name
-                    ));
-                    return (true, logs);
+                    events.push(BattleEvent::StatusCured { target: name, status });
+                    return (true, events);
                 } else {
-                    logs.push(format!(
-                        "¡{} está dormido!",
-                        pokemon.species.display_name
-                    ));
-                    return (false, logs);
+                    events.push(BattleEvent::message("battle.status.is_asleep", [
+                        ("pokemon", name.to_string()),
+                    ]));
+                    return (false, events);
                 }
             }
             StatusCondition::Freeze => {
                 // 20% de probabilidad de descongelarse
                 if rng.gen_bool(0.2) {
                     pokemon.status_condition = None;
-                    logs.push(format!(
-                        "¡{} se descongeló!",
-                        pokemon.species.display_name
-                    ));
-                    return (true, logs);
+                    events.push(BattleEvent::StatusCured { target: name, status });
+                    return (true, events);
                 } else {
-                    logs.push(format!(
-                        "¡{} está congelado!",
-                        pokemon.species.display_name
-                    ));
-                    return (false, logs);
+                    events.push(BattleEvent::message("battle.status.is_frozen", [
+                        ("pokemon", name.to_string()),
+                    ]));
+                    return (false, events);
                 }
             }
             StatusCondition::Paralysis => {
                 // 25% de probabilidad de NO moverse
                 if rng.gen_bool(0.25) {
-                    logs.push(format!(
-                        "¡{} está paralizado y no se puede mover!",
-                        pokemon.species.display_name
-                    ));
-                    return (false, logs);
+                    events.push(BattleEvent::message("battle.status.fully_paralyzed", [
+                        ("pokemon", name.to_string()),
+                    ]));
+                    return (false, events);
                 }
                 // Si pasa el 75%, puede moverse (no se añade log)
             }
@@ -151,75 +167,128 @@ pub(crate) fn can_pokemon_move(pokemon: &mut PokemonInstance, rng: &mut StdRng)
     }
 
     // Luego verificar confusion (volatile status)
-    if let Some(ref mut volatile) = pokemon.volatile_status {
-        if volatile.confused {
-            logs.push(format!("¡{} está confundido!", pokemon.species.display_name));
+    let confused = pokemon.volatile_status.as_ref().is_some_and(|v| v.confused);
+    if confused {
+        let snapped_out = pokemon.volatile_status.as_mut().is_some_and(|volatile| {
+            volatile.confusion_turns = volatile.confusion_turns.saturating_sub(1);
+            if volatile.confusion_turns == 0 {
+                volatile.confused = false;
+            }
+            !volatile.confused
+        });
 
-            // 50% de probabilidad de golpearse a sí mismo
-            if rng.gen_bool(0.5) {
-                // Aplicar daño por confusión (40 de power, físico, sin STAB)
-                let confusion_damage = calculate_confusion_damage(pokemon);
-                pokemon.current_hp = pokemon.current_hp.saturating_sub(confusion_damage);
-
-                logs.push(format!(
-                    "¡{} se golpeó a sí mismo en confusión y perdió {} HP!",
-                    pokemon.species.display_name,
-                    confusion_damage
-                ));
-                return (false, logs);
+        if snapped_out {
+            events.push(BattleEvent::message("battle.status.confusion_ended", [
+                ("pokemon", name.to_string()),
+            ]));
+        } else {
+            events.push(BattleEvent::message("battle.status.is_confused", [
+                ("pokemon", name.to_string()),
+            ]));
+
+            // 33% de probabilidad de golpearse a sí mismo
+            if rng.gen_bool(CONFUSION_SELF_HIT_CHANCE) {
+                // Golpe físico sin tipo de potencia 40 contra sí mismo (sin crítico ni pantallas)
+                let confusion_move = create_confusion_move();
+                let (confusion_damage, _, _) = calculate_damage(
+                    pokemon,
+                    pokemon,
+                    &confusion_move,
+                    false,
+                    rng,
+                    None,
+                    None,
+                    None,
+                    Screens::default(),
+                );
+                let confusion_damage = confusion_damage.min(pokemon.current_hp);
+                pokemon.current_hp -= confusion_damage;
+
+                events.push(BattleEvent::Damage {
+                    target: name.clone(),
+                    amount: confusion_damage,
+                    remaining_hp: pokemon.current_hp,
+                    cause: DamageCause::Confusion,
+                });
+                if pokemon.current_hp == 0 {
+                    events.push(BattleEvent::Fainted { target: name });
+                }
+                return (false, events);
             } else {
-                logs.push(format!("¡{} superó la confusión este turno!", pokemon.species.display_name));
+                events.push(BattleEvent::message("battle.status.confusion_resisted", [
+                    ("pokemon", name.to_string()),
+                ]));
             }
         }
+    }
 
-        // Verificar infatuation (Attract)
+    // Verificar infatuation (Attract)
+    if let Some(ref volatile) = pokemon.volatile_status {
         if volatile.infatuated_by.is_some() {
-            logs.push(format!("¡{} está enamorado!", pokemon.species.display_name));
+            events.push(BattleEvent::message("battle.status.is_infatuated", [
+                ("pokemon", name.to_string()),
+            ]));
 
             // 50% de probabilidad de no poder atacar
             if rng.gen_bool(0.5) {
-                logs.push(format!(
-                    "¡{} está inmobilizado por el amor!",
-                    pokemon.species.display_name
-                ));
-                return (false, logs);
+                events.push(BattleEvent::message("battle.status.immobilized_by_love", [
+                    ("pokemon", name.to_string()),
+                ]));
+                return (false, events);
             } else {
-                logs.push(format!("¡{} superó la infatuación este turno!", pokemon.species.display_name));
+                events.push(BattleEvent::message("battle.status.infatuation_resisted", [
+                    ("pokemon", name.to_string()),
+                ]));
             }
         }
     }
 
-    (true, logs)
+    (true, events)
 }
 
-/// Calcula el daño que un Pokémon se inflige a sí mismo por confusión
-/// Usa power 40, tipo físico, sin STAB ni efectividad
-fn calculate_confusion_damage(pokemon: &PokemonInstance) -> u16 {
-    let level = pokemon.level;
-    let attack = pokemon.base_computed_stats.attack;
-    let defense = pokemon.base_computed_stats.defense;
-    let power = 40;
-
-    // Fórmula simplificada: ((2 * Level / 5 + 2) * Power * A / D) / 50 + 2
-    let base_damage = ((2 * level as u32 / 5 + 2) * power * attack as u32 / defense as u32) / 50 + 2;
-
-    // Aplicar stages si existen
-    let mut final_damage = base_damage as f32;
-    if let Some(stages) = &pokemon.battle_stages {
-        let attack_multiplier = get_stat_multiplier(stages.attack);
-        let defense_multiplier = get_stat_multiplier(stages.defense);
-        final_damage *= attack_multiplier / defense_multiplier;
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use crate::battle::test_fixtures::{is_confused, moves, pokemon};
 
-    final_damage as u16
-}
+    #[test]
+    fn test_confused_pokemon_can_hit_itself_and_snaps_out() {
+        let moves = moves();
+        let confused = |turns: u8| {
+            let mut target = pokemon("target", "run-away", &moves);
+            target.init_battle_stages();
+            let volatile = target.volatile_status.as_mut().unwrap();
+            volatile.confused = true;
+            volatile.confusion_turns = turns;
+            target
+        };
 
-/// Obtiene el multiplicador de stat basado en el stage (-6 a +6)
-fn get_stat_multiplier(stage: i8) -> f32 {
-    if stage >= 0 {
-        (2.0 + stage as f32) / 2.0
-    } else {
-        2.0 / (2.0 - stage as f32)
+        // Con varios turnos por delante, alguna semilla produce el golpe a sí mismo
+        let self_hit = (0..20).find_map(|seed| {
+            let mut target = confused(3);
+            let mut rng = StdRng::seed_from_u64(seed);
+            let (can_move, events) = can_pokemon_move(&mut target, &mut rng);
+            events.iter().find_map(|event| match event {
+                BattleEvent::Damage { amount, remaining_hp, cause: DamageCause::Confusion, .. } => {
+                    Some((can_move, *amount, *remaining_hp, target.current_hp))
+                }
+                _ => None,
+            })
+        });
+        let (can_move, amount, remaining_hp, current_hp) = self_hit.expect("ninguna semilla produjo el golpe");
+        assert!(!can_move);
+        assert!(amount > 0);
+        assert_eq!(remaining_hp, current_hp);
+
+        // En el último turno se le pasa y puede atacar
+        let mut target = confused(1);
+        let mut rng = StdRng::seed_from_u64(0);
+        let (can_move, events) = can_pokemon_move(&mut target, &mut rng);
+        assert!(can_move);
+        assert!(!is_confused(&target));
+        assert!(events.contains(&BattleEvent::message("battle.status.confusion_ended", [
+            ("pokemon", "target".to_string()),
+        ])));
     }
 }
-
@@ -0,0 +1,477 @@
+//! Eventos de batalla tipados
+//!
+//! Cada subsistema (daño, efectos, items, habilidades) emite `BattleEvent`s
+//! en lugar de strings sueltos. Los logs que ve el jugador se renderizan a
+//! partir de estos eventos en el locale de la partida (ver `crate::i18n`), de
+//! modo que el frontend y las herramientas de análisis pueden consumir el
+//! flujo estructurado sin tener que parsear texto.
+
+use serde::{Deserialize, Serialize};
+use crate::i18n::{tr, Locale, LocalizedText};
+use crate::battle::systems::ability_system::ability_display_name;
+use crate::models::{BattleSide, EntryHazard, PokemonType, SideCondition, StatusCondition, TerrainType, WeatherType};
+
+/// Evento estructurado ocurrido durante un turno de batalla
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum BattleEvent {
+    /// Un Pokémon usó un movimiento
+    MoveUsed {
+        user: String,
+        move_id: String,
+        move_name: String,
+    },
+    /// Un Pokémon perdió HP
+    Damage {
+        target: String,
+        amount: u16,
+        remaining_hp: u16,
+        cause: DamageCause,
+    },
+    /// Un Pokémon recuperó HP
+    Heal {
+        target: String,
+        amount: u16,
+        remaining_hp: u16,
+        cause: HealCause,
+    },
+    /// Cambió el stage de una stat (ya aplicado y recortado a -6..=6)
+    StatStageChanged {
+        target: String,
+        stat: String,
+        stages: i8,
+    },
+    /// Se aplicó un estado alterado permanente
+    StatusApplied {
+        target: String,
+        status: StatusCondition,
+    },
+    /// Se curó un estado alterado permanente
+    StatusCured {
+        target: String,
+        status: StatusCondition,
+    },
+    /// Cambió el clima (`WeatherType::None` = el clima terminó)
+    WeatherSet { weather: WeatherType },
+    /// Cambió el terreno (`None` = el terreno terminó)
+    TerrainSet { terrain: Option<TerrainType> },
+    /// Golpe crítico sobre el objetivo
+    CriticalHit { target: String },
+    /// Efectividad de tipo notable (distinta de x1)
+    Effectiveness { target: String, multiplier: f32 },
+    /// Se activó una habilidad
+    AbilityActivated { pokemon: String, ability: String },
+    /// Se activó un objeto equipado
+    ItemActivated { pokemon: String, item: String },
+    /// Un Pokémon se debilitó
+    Fainted { target: String },
+    /// Se colocó un hazard de entrada en un lado del campo
+    HazardSet { side: BattleSide, hazard: EntryHazard },
+    /// Se retiraron los hazards de un lado del campo (Rapid Spin, Defog)
+    HazardsCleared { side: BattleSide },
+    /// Se activó una condición temporal en un lado (Reflect, Tailwind...)
+    SideConditionStarted { side: BattleSide, condition: SideCondition },
+    /// Terminó una condición temporal de un lado
+    SideConditionEnded { side: BattleSide, condition: SideCondition },
+    /// Se rompieron las pantallas de un lado (Brick Break, Defog)
+    ScreensBroken { side: BattleSide },
+    /// Un Pokémon creó un sustituto
+    SubstituteCreated { pokemon: String },
+    /// El sustituto de un Pokémon recibió un golpe sin romperse
+    SubstituteDamaged { pokemon: String },
+    /// El sustituto de un Pokémon se rompió
+    SubstituteBroken { pokemon: String },
+    /// Un Pokémon se teracristalizó y pasó a ser de su teratipo
+    Terastallized { pokemon: String, tera_type: PokemonType },
+    /// Un Pokémon megaevolucionó con su megapiedra
+    MegaEvolved { pokemon: String, form: String },
+    /// Un Pokémon entró al campo (`trainer = None` para el jugador)
+    Switched {
+        trainer: Option<String>,
+        pokemon: String,
+    },
+    /// Narración sin efecto mecánico tipado (fallos, cargas, protecciones...)
+    Message(LocalizedText),
+}
+
+/// Origen de un evento de daño
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "kind")]
+pub enum DamageCause {
+    /// Daño directo de un movimiento (acumulado si es multi-hit)
+    Move { hits: u8 },
+    /// Retroceso del propio atacante (Double-Edge, Forcejeo)
+    Recoil,
+    /// Daño residual del clima
+    Weather { weather: WeatherType },
+    /// Daño residual de un estado alterado
+    Status { status: StatusCondition },
+    /// Golpe a sí mismo por confusión
+    Confusion,
+    /// Drenadoras (Leech Seed)
+    LeechSeed,
+    /// Canto Mortal (Perish Song)
+    PerishSong,
+    /// Hazard de entrada (Stealth Rock, Spikes)
+    Hazard { hazard: EntryHazard },
+    /// Habilidad del Pokémon `holder` (Rough Skin, Iron Barbs)
+    Ability { ability: String, holder: String },
+    /// Objeto del Pokémon `holder` (Rocky Helmet, Life Orb)
+    Item { item: String, holder: String },
+}
+
+/// Origen de un evento de curación
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "kind")]
+pub enum HealCause {
+    /// Movimiento de curación o de drenaje
+    Move,
+    /// Terreno (Grassy Terrain)
+    Terrain { terrain: TerrainType },
+    /// Absorción de Drenadoras
+    LeechSeed,
+    /// Habilidad (Rain Dish, Ice Body, Regenerator)
+    Ability { ability: String },
+    /// Objeto (Sitrus Berry, Leftovers)
+    Item { item: String },
+}
+
+impl BattleEvent {
+    /// Crea un evento de narración libre a partir de una clave del catálogo
+    pub fn message<const N: usize>(key: &str, params: [(&str, String); N]) -> Self {
+        BattleEvent::Message(LocalizedText::new(key, params))
+    }
+
+    /// Convierte el evento en un mensaje localizable (clave + parámetros)
+    pub fn to_text(&self, locale: Locale) -> LocalizedText {
+        match self {
+            BattleEvent::MoveUsed { user, move_name, .. } => LocalizedText::new("battle.move_used", [
+                ("user", user.clone()),
+                ("move", move_name.clone()),
+            ]),
+            BattleEvent::Damage { target, amount, cause, .. } => damage_text(target, *amount, cause),
+            BattleEvent::Heal { target, amount, cause, .. } => {
+                let (key, extra) = match cause {
+                    HealCause::Move => ("battle.heal.move", None),
+                    HealCause::Terrain { terrain } => (
+                        "battle.heal.terrain",
+                        Some(("terrain", tr(locale, terrain_key(*terrain)))),
+                    ),
+                    HealCause::LeechSeed => ("battle.heal.leech_seed", None),
+                    HealCause::Ability { ability } => (
+                        "battle.heal.ability",
+                        Some(("ability", ability_display_name(ability))),
+                    ),
+                    HealCause::Item { item } => (
+                        "battle.heal.item",
+                        Some(("item", display_name_from_id(item))),
+                    ),
+                };
+                let mut text = LocalizedText::new(key, [
+                    ("target", target.clone()),
+                    ("amount", amount.to_string()),
+                ]);
+                if let Some((name, value)) = extra {
+                    text.params.insert(name.to_string(), value);
+                }
+                text
+            }
+            BattleEvent::StatStageChanged { target, stat, stages } => {
+                let key = match *stages {
+                    s if s >= 2 => "battle.stat.rose_sharply",
+                    s if s > 0 => "battle.stat.rose",
+                    s if s <= -2 => "battle.stat.fell_harshly",
+                    _ => "battle.stat.fell",
+                };
+                LocalizedText::new(key, [
+                    ("stat", stat_display_name(stat, locale)),
+                    ("target", target.clone()),
+                ])
+            }
+            BattleEvent::StatusApplied { target, status } => {
+                let key = match status {
+                    StatusCondition::Burn => "battle.status.burned",
+                    StatusCondition::Paralysis => "battle.status.paralyzed",
+                    StatusCondition::Poison => "battle.status.poisoned",
+                    StatusCondition::BadPoison => "battle.status.badly_poisoned",
+                    StatusCondition::Sleep => "battle.status.asleep",
+                    StatusCondition::Freeze => "battle.status.frozen",
+                };
+                LocalizedText::new(key, [("target", target.clone())])
+            }
+            BattleEvent::StatusCured { target, status } => {
+                let key = match status {
+                    StatusCondition::Sleep => "battle.status.woke_up",
+                    StatusCondition::Freeze => "battle.status.thawed",
+                    StatusCondition::Paralysis => "battle.status.paralysis_cured",
+                    StatusCondition::Burn => "battle.status.burn_cured",
+                    StatusCondition::Poison | StatusCondition::BadPoison => "battle.status.poison_cured",
+                };
+                LocalizedText::new(key, [("target", target.clone())])
+            }
+            BattleEvent::WeatherSet { weather } => {
+                let key = match weather {
+                    WeatherType::Sun => "battle.weather.sun",
+                    WeatherType::Rain => "battle.weather.rain",
+                    WeatherType::Sandstorm => "battle.weather.sandstorm",
+                    WeatherType::Hail => "battle.weather.hail",
+                    WeatherType::None => "battle.weather.ended",
+                };
+                LocalizedText::new(key, [])
+            }
+            BattleEvent::TerrainSet { terrain } => {
+                let key = match terrain {
+                    Some(TerrainType::Electric) => "battle.terrain.electric",
+                    Some(TerrainType::Grassy) => "battle.terrain.grassy",
+                    Some(TerrainType::Misty) => "battle.terrain.misty",
+                    Some(TerrainType::Psychic) => "battle.terrain.psychic",
+                    None => "battle.terrain.ended",
+                };
+                LocalizedText::new(key, [])
+            }
+            BattleEvent::CriticalHit { .. } => LocalizedText::new("battle.critical_hit", []),
+            BattleEvent::Effectiveness { target, multiplier } => {
+                if *multiplier == 0.0 {
+                    LocalizedText::new("battle.no_effect", [("target", target.clone())])
+                } else if *multiplier >= 2.0 {
+                    LocalizedText::new("battle.super_effective", [])
+                } else {
+                    LocalizedText::new("battle.not_very_effective", [])
+                }
+            }
+            BattleEvent::AbilityActivated { pokemon, ability } => LocalizedText::new("battle.ability_activated", [
+                ("ability", ability_display_name(ability)),
+                ("pokemon", pokemon.clone()),
+            ]),
+            BattleEvent::ItemActivated { pokemon, item } => LocalizedText::new("battle.item_activated", [
+                ("item", display_name_from_id(item)),
+                ("pokemon", pokemon.clone()),
+            ]),
+            BattleEvent::Fainted { target } => LocalizedText::new("battle.fainted", [("target", target.clone())]),
+            BattleEvent::HazardSet { side, hazard } => {
+                let key = match hazard {
+                    EntryHazard::StealthRock => "battle.hazard.stealth_rock",
+                    EntryHazard::Spikes => "battle.hazard.spikes",
+                    EntryHazard::ToxicSpikes => "battle.hazard.toxic_spikes",
+                    EntryHazard::StickyWeb => "battle.hazard.sticky_web",
+                };
+                LocalizedText::new(key, [("side", tr(locale, side_key(*side)))])
+            }
+            BattleEvent::HazardsCleared { side } => {
+                LocalizedText::new("battle.hazard.cleared", [("side", tr(locale, side_key(*side)))])
+            }
+            BattleEvent::SideConditionStarted { side, condition } => {
+                let key = match condition {
+                    SideCondition::Reflect => "battle.side_condition.reflect",
+                    SideCondition::LightScreen => "battle.side_condition.light_screen",
+                    SideCondition::AuroraVeil => "battle.side_condition.aurora_veil",
+                    SideCondition::Tailwind => "battle.side_condition.tailwind",
+                };
+                LocalizedText::new(key, [("side", tr(locale, side_key(*side)))])
+            }
+            BattleEvent::SideConditionEnded { side, condition } => {
+                let key = match condition {
+                    SideCondition::Reflect => "battle.side_condition.reflect_ended",
+                    SideCondition::LightScreen => "battle.side_condition.light_screen_ended",
+                    SideCondition::AuroraVeil => "battle.side_condition.aurora_veil_ended",
+                    SideCondition::Tailwind => "battle.side_condition.tailwind_ended",
+                };
+                LocalizedText::new(key, [("side", tr(locale, side_key(*side)))])
+            }
+            BattleEvent::ScreensBroken { side } => {
+                LocalizedText::new("battle.side_condition.screens_broken", [("side", tr(locale, side_key(*side)))])
+            }
+            BattleEvent::SubstituteCreated { pokemon } => {
+                LocalizedText::new("battle.substitute.created", [("pokemon", pokemon.clone())])
+            }
+            BattleEvent::SubstituteDamaged { pokemon } => {
+                LocalizedText::new("battle.substitute.damaged", [("pokemon", pokemon.clone())])
+            }
+            BattleEvent::SubstituteBroken { pokemon } => {
+                LocalizedText::new("battle.substitute.broken", [("pokemon", pokemon.clone())])
+            }
+            BattleEvent::Terastallized { pokemon, tera_type } => LocalizedText::new("battle.terastallized", [
+                ("pokemon", pokemon.clone()),
+                ("type", format!("{:?}", tera_type)),
+            ]),
+            BattleEvent::MegaEvolved { pokemon, form } => LocalizedText::new("battle.mega_evolved", [
+                ("pokemon", pokemon.clone()),
+                ("form", form.clone()),
+            ]),
+            BattleEvent::Switched { trainer, pokemon } => match trainer {
+                Some(trainer) => LocalizedText::new("battle.switched.trainer", [
+                    ("trainer", trainer.clone()),
+                    ("pokemon", pokemon.clone()),
+                ]),
+                None => LocalizedText::new("battle.switched.player", [("pokemon", pokemon.clone())]),
+            },
+            BattleEvent::Message(text) => text.clone(),
+        }
+    }
+
+    /// Renderiza el evento como línea de log en el locale indicado
+    pub fn render(&self, locale: Locale) -> String {
+        self.to_text(locale).resolve(locale)
+    }
+}
+
+/// Renderiza una secuencia de eventos como líneas de log
+pub fn render_logs(events: &[BattleEvent], locale: Locale) -> Vec<String> {
+    events.iter().map(|event| event.render(locale)).collect()
+}
+
+fn damage_text(target: &str, amount: u16, cause: &DamageCause) -> LocalizedText {
+    let key = match cause {
+        DamageCause::Move { hits } if *hits > 1 => "battle.damage.multi_hit",
+        DamageCause::Move { .. } => "battle.damage.move",
+        DamageCause::Recoil => "battle.damage.recoil",
+        DamageCause::Weather { weather } => match weather {
+            WeatherType::Sandstorm => "battle.damage.sandstorm",
+            WeatherType::Hail => "battle.damage.hail",
+            _ => "battle.damage.weather",
+        },
+        DamageCause::Status { status } => match status {
+            StatusCondition::Burn => "battle.damage.burn",
+            StatusCondition::Poison => "battle.damage.poison",
+            StatusCondition::BadPoison => "battle.damage.bad_poison",
+            _ => "battle.damage.status",
+        },
+        DamageCause::Confusion => "battle.damage.confusion",
+        DamageCause::LeechSeed => "battle.damage.leech_seed",
+        DamageCause::PerishSong => "battle.damage.perish_song",
+        DamageCause::Hazard { hazard: EntryHazard::StealthRock } => "battle.damage.stealth_rock",
+        DamageCause::Hazard { .. } => "battle.damage.spikes",
+        DamageCause::Ability { .. } => "battle.damage.ability",
+        DamageCause::Item { holder, .. } if holder == target => "battle.damage.own_item",
+        DamageCause::Item { .. } => "battle.damage.item",
+    };
+
+    let mut text = LocalizedText::new(key, [
+        ("target", target.to_string()),
+        ("amount", amount.to_string()),
+    ]);
+    match cause {
+        DamageCause::Move { hits } => {
+            text.params.insert("hits".to_string(), hits.to_string());
+        }
+        DamageCause::Ability { ability, holder } => {
+            text.params.insert("ability".to_string(), ability_display_name(ability));
+            text.params.insert("holder".to_string(), holder.clone());
+        }
+        DamageCause::Item { item, holder } => {
+            text.params.insert("item".to_string(), display_name_from_id(item));
+            text.params.insert("holder".to_string(), holder.clone());
+        }
+        _ => {}
+    }
+    text
+}
+
+/// Nombre localizado de una stat de batalla
+pub fn stat_display_name(stat: &str, locale: Locale) -> String {
+    let key = format!("stat.{}", stat.replace('-', "_"));
+    let name = tr(locale, &key);
+    if name == key {
+        stat.to_string()
+    } else {
+        name
+    }
+}
+
+fn side_key(side: BattleSide) -> &'static str {
+    match side {
+        BattleSide::Player => "side.player",
+        BattleSide::Opponent => "side.opponent",
+    }
+}
+
+fn terrain_key(terrain: TerrainType) -> &'static str {
+    match terrain {
+        TerrainType::Electric => "terrain.electric",
+        TerrainType::Grassy => "terrain.grassy",
+        TerrainType::Misty => "terrain.misty",
+        TerrainType::Psychic => "terrain.psychic",
+    }
+}
+
+/// Convierte un id en kebab-case ("rocky-helmet") a un nombre legible ("Rocky Helmet")
+pub fn display_name_from_id(id: &str) -> String {
+    id.split('-')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_damage_renders_multi_hit_total() {
+        let event = BattleEvent::Damage {
+            target: "Pikachu".to_string(),
+            amount: 42,
+            remaining_hp: 10,
+            cause: DamageCause::Move { hits: 3 },
+        };
+        assert_eq!(event.render(Locale::Es), "¡Golpeó 3 veces! Pikachu recibió 42 de daño total");
+    }
+
+    #[test]
+    fn test_stat_stage_change_uses_spanish_names() {
+        let event = BattleEvent::StatStageChanged {
+            target: "Gyarados".to_string(),
+            stat: "special_attack".to_string(),
+            stages: -2,
+        };
+        assert_eq!(event.render(Locale::Es), "¡El ataque especial de Gyarados bajó mucho!");
+        assert_eq!(event.render(Locale::En), "Gyarados's Sp. Atk harshly fell!");
+    }
+
+    #[test]
+    fn test_render_logs_preserves_order() {
+        let events = vec![
+            BattleEvent::MoveUsed {
+                user: "Charmander".to_string(),
+                move_id: "ember".to_string(),
+                move_name: "Ember".to_string(),
+            },
+            BattleEvent::Fainted { target: "Bulbasaur".to_string() },
+        ];
+        assert_eq!(
+            render_logs(&events, Locale::Es),
+            vec!["Charmander usó Ember".to_string(), "¡Bulbasaur se debilitó!".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_event_serializes_with_type_tag() {
+        let event = BattleEvent::WeatherSet { weather: WeatherType::Rain };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "WeatherSet");
+        assert_eq!(json["weather"], "Rain");
+    }
+
+    #[test]
+    fn test_message_serializes_key_and_params() {
+        let event = BattleEvent::message("battle.protected", [("pokemon", "Pikachu".to_string())]);
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "Message");
+        assert_eq!(json["key"], "battle.protected");
+        assert_eq!(json["params"]["pokemon"], "Pikachu");
+        assert_eq!(event.render(Locale::En), "Pikachu protected itself!");
+    }
+
+    #[test]
+    fn test_display_name_from_id() {
+        assert_eq!(display_name_from_id("rocky-helmet"), "Rocky Helmet");
+        assert_eq!(display_name_from_id("levitate"), "Levitate");
+    }
+}
@@ -38,6 +38,7 @@ pub fn resolve_move_data(move_id: &str, pool: Option<&HashMap<String, MoveData>>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::MoveMeta;
 
     #[test]
     fn test_resolve_struggle() {
@@ -60,9 +61,15 @@ mod tests {
         let tackle = MoveData {
             id: "tackle".to_string(),
             name: "Tackle".to_string(),
+            r#type: "Normal".to_string(),
             power: Some(40),
+            accuracy: Some(100),
+            priority: 0,
             pp: 35,
-            ..Default::default()
+            damage_class: "physical".to_string(),
+            meta: MoveMeta::default(),
+            stat_changes: Vec::new(),
+            target: "selected-pokemon".to_string(),
         };
         pool.insert("tackle".to_string(), tackle.clone());
 
@@ -17,6 +17,12 @@
 //! ## Capa de Infraestructura (infrastructure/)
 //! - Utilidades y helpers de soporte
 //!
+//! ## Eventos (events)
+//! - `BattleEvent`: flujo tipado de lo ocurrido en el turno; los logs se renderizan desde aquí
+//!
+//! ## Replays (replay)
+//! - `BattleReplay`: grabación de una batalla y su verificación re-simulando los turnos
+//!
 //! ## Módulos Legacy (en proceso de migración)
 //! - `context`: Contexto de batalla para procesar ataques individuales
 //! - `targeting`: Resolución de objetivos en combates Singles/Doubles
@@ -29,6 +35,8 @@
 pub mod systems;
 pub mod orchestration;
 pub mod infrastructure;
+pub mod events;
+pub mod replay;
 
 // Módulos legacy (se migrarán gradualmente)
 pub mod context;
@@ -39,8 +47,14 @@ pub mod pipeline;
 pub mod checks;
 pub mod ability_logic;
 
+// Fixtures de tests compartidos entre sistemas
+#[cfg(test)]
+pub(crate) mod test_fixtures;
+
 // Re-exportar tipos principales para compatibilidad
 pub use context::BattleContext;
+pub use events::{BattleEvent, DamageCause, HealCause, render_logs};
+pub use replay::{BattleReplay, ReplayMismatch, ReplayReplacement, ReplayStep, ReplayTurn, verify_replay};
 pub use targeting::resolve_targets;
 pub use mechanics::{
     check_critical_hit,
@@ -57,17 +71,22 @@ pub use effects::{
 // Re-exportar funciones de checks (estado)
 pub use checks::check_ailment_success;
 
-// Re-exportar funciones de validation_system (PP)
+// Re-exportar funciones de validation_system (PP y bloqueo de Choice)
 pub use systems::validation_system::{
     initialize_move_pp,
     consume_move_pp,
     has_moves_with_pp,
     create_struggle_move,
+    enforce_choice_lock,
 };
 // Re-exportar execute_turn desde orchestration (que por ahora usa pipeline)
-pub use orchestration::execute_turn;
+pub use orchestration::{
+    execute_turn, execute_pivot, prepare_turn, run_turn, resume_turn, finish_turn, send_in_replacement, replace_fainted,
+};
 
 use serde::{Deserialize, Serialize};
+use crate::game::PendingPlayerAction;
+use crate::i18n::Locale;
 
 /// Resultado de la batalla después de un turno
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -82,13 +101,23 @@ pub enum BattleOutcome {
     PlayerMustSwitch,
     /// El enemigo murió y sacó uno nuevo (útil para logs/animación)
     EnemySwitched,
+    /// Un Pokémon del jugador sale con un movimiento de relevo (o un Eject
+    /// Button/Pack): el turno queda en `BattleState::pending_pivot` hasta que
+    /// elija quién entra con `resume_turn`
+    PlayerMustPivot,
 }
 
 /// Resultado de ejecutar un turno de batalla
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TurnResult {
-    /// Narración de lo que ocurrió en el turno
+    /// Narración de lo que ocurrió en el turno (renderizada desde `events`)
     pub logs: Vec<String>,
+    /// Eventos tipados del turno, en orden
+    #[serde(default)]
+    pub events: Vec<BattleEvent>,
+    /// Movimientos elegidos por la IA del oponente (`user_index` = slot del oponente)
+    #[serde(default)]
+    pub opponent_actions: Vec<PendingPlayerAction>,
     /// Daño infligido por el jugador
     pub player_damage_dealt: u16,
     /// Daño infligido por el enemigo
@@ -102,11 +131,18 @@ impl TurnResult {
     pub fn new() -> Self {
         Self {
             logs: Vec::new(),
+            events: Vec::new(),
+            opponent_actions: Vec::new(),
             player_damage_dealt: 0,
             enemy_damage_dealt: 0,
             outcome: BattleOutcome::Continue,
         }
     }
+
+    /// Vuelve a renderizar `logs` a partir de `events` en el locale indicado
+    pub fn render_logs(&mut self, locale: Locale) {
+        self.logs = render_logs(&self.events, locale);
+    }
 }
 
 impl Default for TurnResult {
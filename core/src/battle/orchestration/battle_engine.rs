@@ -8,12 +8,240 @@
 //! 5. Resolución de fin de turno
 //! 6. Verificación de victorias/derrotas
 
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+
 use crate::game::{BattleState, PlayerTeam};
-use super::super::BattleOutcome;
+use crate::models::{MoveData, PokemonInstance};
+use super::super::{BattleOutcome, TurnResult};
+use super::super::events::BattleEvent;
+use super::super::systems::validation_system::initialize_move_pp;
 
 // Re-exportar la función principal del pipeline por ahora
 // TODO: Migrar gradualmente la lógica de pipeline.rs aquí
-pub use super::super::pipeline::execute_turn;
+pub use super::super::pipeline::{execute_pivot, execute_turn};
+use super::super::pipeline::send_in;
+
+/// Prepara a los combatientes activos antes de ejecutar un turno
+///
+/// Inicializa el PP de los movimientos elegidos por el jugador y de todos los
+/// movimientos de los oponentes activos, y las etapas de stats de quien no las tenga.
+pub fn prepare_turn(
+    player_team: &mut PlayerTeam,
+    battle_state: &mut BattleState,
+    moves: &HashMap<String, MoveData>,
+) {
+    // PP de los movimientos elegidos por el jugador
+    for action in &battle_state.pending_player_actions {
+        let player_index = battle_state.player_active_indices[action.user_index];
+        if let Some(player_mon) = player_team.active_members.get_mut(player_index) {
+            if action.move_id != "struggle" {
+                if let Some(move_data) = moves.get(&action.move_id) {
+                    initialize_move_pp(player_mon, &action.move_id, move_data);
+                }
+            }
+        }
+    }
+
+    // PP de los oponentes activos
+    if battle_state.is_trainer_battle {
+        for &idx in &battle_state.opponent_active_indices.clone() {
+            if let Some(opponent_mon) = battle_state.opponent_team.get_mut(idx) {
+                let move_ids: Vec<String> = opponent_mon.get_active_learned_moves()
+                    .iter()
+                    .map(|m| m.move_id.clone())
+                    .collect();
+                for move_id in move_ids {
+                    if let Some(move_data) = moves.get(&move_id) {
+                        initialize_move_pp(opponent_mon, &move_id, move_data);
+                    }
+                }
+            }
+        }
+    } else {
+        // Batalla salvaje
+        let move_ids: Vec<String> = battle_state.opponent_instance.get_active_learned_moves()
+            .iter()
+            .map(|m| m.move_id.clone())
+            .collect();
+        for move_id in move_ids {
+            if let Some(move_data) = moves.get(&move_id) {
+                initialize_move_pp(&mut battle_state.opponent_instance, &move_id, move_data);
+            }
+        }
+    }
+
+    // Etapas de stats de los activos
+    for &idx in &battle_state.player_active_indices {
+        if let Some(pokemon) = player_team.active_members.get_mut(idx) {
+            if pokemon.battle_stages.is_none() {
+                pokemon.init_battle_stages();
+            }
+        }
+    }
+
+    if battle_state.is_trainer_battle {
+        for &idx in &battle_state.opponent_active_indices.clone() {
+            if let Some(pokemon) = battle_state.opponent_team.get_mut(idx) {
+                if pokemon.battle_stages.is_none() {
+                    pokemon.init_battle_stages();
+                }
+            }
+        }
+    } else if battle_state.opponent_instance.battle_stages.is_none() {
+        battle_state.opponent_instance.init_battle_stages();
+    }
+
+    // Teracristalización: solo el Pokémon registrado por cada bando sigue
+    // teracristalizado (los de batallas anteriores vuelven a su tipo)
+    for (i, pokemon) in player_team.active_members.iter_mut().enumerate() {
+        pokemon.terastallized = battle_state.player_terastallized == Some(i);
+    }
+    let opponent_terastallized = battle_state.opponent_terastallized;
+    if battle_state.is_trainer_battle {
+        for (i, pokemon) in battle_state.opponent_team.iter_mut().enumerate() {
+            pokemon.terastallized = opponent_terastallized == Some(i);
+        }
+    } else {
+        battle_state.opponent_instance.terastallized = opponent_terastallized == Some(0);
+    }
+}
+
+/// Ejecuta un turno completo sobre el estado guardado de la batalla
+///
+/// En batallas de entrenador el pipeline opera sobre `opponent_team`; en las
+/// salvajes opera sobre `opponent_instance` y luego se sincroniza `opponent_team[0]`.
+pub fn run_turn(
+    player_team: &mut PlayerTeam,
+    battle_state: &mut BattleState,
+    rng: &mut StdRng,
+    moves: Option<&HashMap<String, MoveData>>,
+) -> TurnResult {
+    let result = with_opponents(battle_state, |battle_state, opponent_team| {
+        execute_turn(player_team, opponent_team, battle_state, rng, moves)
+    });
+    end_battle_forms(player_team, &result);
+    result
+}
+
+/// Continúa un turno detenido por un relevo del jugador (`BattleOutcome::PlayerMustPivot`)
+///
+/// `new_index` es el Pokémon del equipo que entra en el slot que sale.
+pub fn resume_turn(
+    player_team: &mut PlayerTeam,
+    battle_state: &mut BattleState,
+    rng: &mut StdRng,
+    new_index: usize,
+) -> TurnResult {
+    let result = with_opponents(battle_state, |battle_state, opponent_team| {
+        execute_pivot(player_team, opponent_team, battle_state, rng, new_index)
+    });
+    end_battle_forms(player_team, &result);
+    result
+}
+
+/// Si la batalla terminó, revierte las formas de batalla del equipo del
+/// jugador (Megaevolución, Teracristalización)
+fn end_battle_forms(player_team: &mut PlayerTeam, result: &TurnResult) {
+    if matches!(result.outcome, BattleOutcome::PlayerWon | BattleOutcome::PlayerLost) {
+        player_team.revert_battle_forms();
+    }
+}
+
+/// Manda al campo un reemplazo del jugador tras un debilitamiento
+///
+/// El Pokémon entra fuera del pipeline de turnos pero recibe los mismos efectos
+/// de entrada (hazards y habilidades). Retorna los eventos producidos, o
+/// ninguno si el Pokémon no puede entrar.
+pub fn send_in_replacement(
+    player_team: &mut PlayerTeam,
+    battle_state: &mut BattleState,
+    slot: usize,
+    new_index: usize,
+) -> Vec<BattleEvent> {
+    let mut events = Vec::new();
+    with_opponents(battle_state, |battle_state, opponent_team| {
+        send_in(true, slot, new_index, battle_state, player_team, opponent_team, &mut events);
+    });
+    if battle_state.is_trainer_battle {
+        battle_state.sync_opponent_instance();
+    }
+    events
+}
+
+/// Resuelve el cambio forzado del jugador tras un debilitamiento
+///
+/// Manda el reemplazo con `send_in_replacement`, renderiza sus eventos y los
+/// añade al log de la batalla. Si los hazards debilitan al reemplazo, el
+/// outcome indica si el jugador debe volver a cambiar o perdió (y el equipo
+/// vuelve a su forma normal); si no, es `Continue`. No usa RNG, así que el replay lo re-simula con los mismos datos.
+pub fn replace_fainted(
+    player_team: &mut PlayerTeam,
+    battle_state: &mut BattleState,
+    slot: usize,
+    new_index: usize,
+) -> TurnResult {
+    let mut result = TurnResult::new();
+    result.events = send_in_replacement(player_team, battle_state, slot, new_index);
+    result.render_logs(battle_state.locale);
+    for log in &result.logs {
+        battle_state.add_log(log.clone());
+    }
+
+    if player_team.active_members.get(new_index).is_some_and(|p| p.current_hp == 0) {
+        result.outcome = determine_player_outcome(player_team, battle_state);
+    }
+    end_battle_forms(player_team, &result);
+    result
+}
+
+/// Presta al pipeline el equipo oponente sobre el que debe operar
+///
+/// En batallas de entrenador es `opponent_team` (extraído temporalmente para
+/// evitar problemas de borrow); en las salvajes es una copia de
+/// `opponent_instance` que luego se sincroniza también en `opponent_team[0]`.
+fn with_opponents<T>(
+    battle_state: &mut BattleState,
+    f: impl FnOnce(&mut BattleState, &mut Vec<PokemonInstance>) -> T,
+) -> T {
+    if battle_state.is_trainer_battle {
+        let mut opponent_team = std::mem::take(&mut battle_state.opponent_team);
+        let result = f(battle_state, &mut opponent_team);
+        battle_state.opponent_team = opponent_team;
+        result
+    } else {
+        let mut opponent_vec = vec![battle_state.opponent_instance.clone()];
+        let result = f(battle_state, &mut opponent_vec);
+        if let Some(opponent) = opponent_vec.first() {
+            battle_state.opponent_instance = opponent.clone();
+            // También sincronizar opponent_team[0] para que get_opponent_active() retorne el valor correcto
+            battle_state.opponent_team[0] = opponent.clone();
+        }
+        result
+    }
+}
+
+/// Cierra el turno: limpia las acciones pendientes, avanza el contador de
+/// turnos, guarda los logs y, si el oponente cambió de Pokémon (por
+/// debilitarse o porque la IA lo retiró), sincroniza `opponent_instance`
+///
+/// Un turno detenido por un relevo (`PlayerMustPivot`) no avanza el contador:
+/// lo hace al terminar en `resume_turn`.
+pub fn finish_turn(battle_state: &mut BattleState, turn_result: &TurnResult) {
+    battle_state.pending_player_actions.clear();
+    if turn_result.outcome != BattleOutcome::PlayerMustPivot {
+        battle_state.turn_counter = battle_state.turn_counter.saturating_add(1);
+    }
+
+    for log in &turn_result.logs {
+        battle_state.add_log(log.clone());
+    }
+
+    if turn_result.outcome == BattleOutcome::EnemySwitched || battle_state.is_trainer_battle {
+        battle_state.sync_opponent_instance();
+    }
+}
 
 /// Determina el resultado cuando el jugador se debilita
 ///
@@ -33,22 +261,35 @@ pub fn determine_player_outcome(player_team: &PlayerTeam, battle_state: &BattleS
 
 /// Determina el resultado cuando el enemigo se debilita
 ///
-/// Verifica si hay más enemigos disponibles en el equipo.
-/// Si hay más enemigos, cambia al siguiente y retorna EnemySwitched.
-/// Si no hay más, retorna PlayerWon.
-pub fn determine_enemy_outcome(battle_state: &mut BattleState, logs: &mut Vec<String>) -> BattleOutcome {
+/// Verifica si hay más enemigos disponibles en `opponent_team` (el equipo que
+/// está modificando el pipeline, no `battle_state.opponent_team`, que durante
+/// `run_turn` está vacío). Si hay más enemigos, cambia al siguiente y retorna
+/// EnemySwitched. Si no hay más, retorna PlayerWon.
+pub fn determine_enemy_outcome(
+    battle_state: &mut BattleState,
+    opponent_team: &[PokemonInstance],
+    events: &mut Vec<BattleEvent>,
+) -> BattleOutcome {
     eprintln!("[BATTLE_END] determine_enemy_outcome - Verificando si hay más oponentes...");
-    // Buscar si hay otro Pokémon enemigo disponible
-    if battle_state.switch_to_next_opponent() {
+    // Buscar el siguiente Pokémon con HP > 0, empezando después del actual
+    let current_index = battle_state.opponent_active_indices.first().copied().unwrap_or(0);
+    let next_index = (current_index + 1..opponent_team.len())
+        .chain(0..current_index)
+        .find(|&i| opponent_team[i].current_hp > 0 && !battle_state.opponent_active_indices.contains(&i));
+
+    if let Some(next_index) = next_index {
         eprintln!("[BATTLE_END] Se encontró otro oponente, cambiando...");
-        // El enemigo cambió de Pokémon
-        let next_opponent = battle_state.get_opponent_active().clone();
+        // Actualizar el primer slot (en Single solo hay uno)
+        if let Some(first) = battle_state.opponent_active_indices.first_mut() {
+            *first = next_index;
+        } else {
+            battle_state.opponent_active_indices.push(next_index);
+        }
         let opponent_name = battle_state.opponent_name.clone().unwrap_or_else(|| "El entrenador".to_string());
-        logs.push(format!(
-            "{} envió a {}!",
-            opponent_name,
-            next_opponent.species.display_name
-        ));
+        events.push(BattleEvent::Switched {
+            trainer: Some(opponent_name),
+            pokemon: opponent_team[next_index].species.display_name.clone(),
+        });
         BattleOutcome::EnemySwitched
     } else {
         eprintln!("[BATTLE_END] No hay más oponentes, jugador ganó!");
@@ -63,8 +304,8 @@ pub fn determine_enemy_outcome(battle_state: &mut BattleState, logs: &mut Vec<St
 pub fn check_battle_state(
     battle_state: &mut BattleState,
     player_team: &PlayerTeam,
-    opponent_team: &Vec<crate::models::PokemonInstance>,
-    logs: &mut Vec<String>,
+    opponent_team: &Vec<PokemonInstance>,
+    events: &mut Vec<BattleEvent>,
 ) -> BattleOutcome {
     eprintln!("[BATTLE_END] check_battle_state - Verificando estado de batalla");
 
@@ -90,7 +331,7 @@ pub fn check_battle_state(
     if all_opponent_active_fainted {
         eprintln!("[BATTLE_END] Todos los oponentes están debilitados, determinando resultado...");
         // Intentar cambiar a un nuevo oponente
-        return determine_enemy_outcome(battle_state, logs);
+        return determine_enemy_outcome(battle_state, opponent_team, events);
     }
 
     // Si todos los jugadores activos están debilitados
@@ -118,7 +359,192 @@ pub fn check_battle_state(
 #[cfg(test)]
 mod tests {
     use super::*;
-
+    use crate::battle::systems::effect_system::EXTENDED_FIELD_TURNS;
+    use crate::battle::test_fixtures::{mega_form, moves, moves_with_finisher, play_turn, play_wild_turn, pokemon, status_move, use_move};
+    use crate::models::{BattleFormat, Gender, TerrainState, TerrainType, WeatherState, WeatherType};
     // TODO: Agregar tests unitarios para determine_player_outcome y determine_enemy_outcome
     // Una vez que se migren más funciones de pipeline.rs aquí
+
+    #[test]
+    fn test_ai_difficulty_depends_on_the_opponent() {
+        use crate::battle::systems::ai_system::AiDifficulty;
+        use crate::game::GameConfig;
+
+        let moves = moves();
+        let wild = BattleState::new(0, vec![pokemon("wild", "run-away", &moves)], String::new(), BattleFormat::Single, false);
+        assert_eq!(wild.ai_difficulty, AiDifficulty::Random);
+
+        let trainer = BattleState::new(0, vec![pokemon("rival", "run-away", &moves)], "Rival".to_string(), BattleFormat::Single, true);
+        assert_eq!(trainer.ai_difficulty, AiDifficulty::Greedy);
+
+        // Los líderes de gimnasio usan la dificultad de la partida (Smart en sesiones anteriores al campo)
+        let config: GameConfig = serde_json::from_str(r#"{"gym_interval": 5, "total_encounters": 20}"#).unwrap();
+        assert_eq!(config.gym_ai_difficulty, AiDifficulty::Smart);
+        assert_eq!(GameConfig::default().gym_ai_difficulty, AiDifficulty::Smart);
+    }
+
+    #[test]
+    fn test_trainer_ai_switches_out_before_perish_song_ends() {
+        let moves = moves();
+        let mut team = PlayerTeam::new();
+        team.add_member(pokemon("player", "run-away", &moves));
+
+        let mut doomed = pokemon("doomed", "run-away", &moves);
+        doomed.init_battle_stages();
+        doomed.volatile_status.as_mut().unwrap().perish_count = Some(1);
+        let relief = pokemon("relief", "intimidate", &moves);
+
+        let mut battle_state = BattleState::new(
+            0,
+            vec![doomed, relief],
+            "Rival".to_string(),
+            BattleFormat::Single,
+            true,
+        );
+        battle_state.ai_difficulty = crate::battle::systems::ai_system::AiDifficulty::Smart;
+        battle_state.turn_counter = 2;
+        battle_state.pending_player_actions = vec![use_move(0, "tackle")];
+
+        let result = play_turn(&mut team, &mut battle_state, &moves, 3);
+
+        // El reemplazo entró, activó Intimidate y recibió el ataque en lugar del saliente
+        assert_eq!(battle_state.opponent_active_indices, vec![1]);
+        assert_eq!(battle_state.opponent_instance.species.species_id, "relief");
+        assert!(result.events.contains(&BattleEvent::Switched {
+            trainer: Some("Rival".to_string()),
+            pokemon: "relief".to_string(),
+        }));
+        assert_eq!(team.active_members[0].battle_stages.as_ref().unwrap().attack, -1);
+        assert!(battle_state.opponent_team[1].current_hp < battle_state.opponent_team[1].base_computed_stats.hp);
+
+        // El saliente no se debilitó: retirarse limpia el contador
+        assert!(battle_state.opponent_team[0].current_hp > 0);
+        assert!(battle_state.opponent_team[0].volatile_status.is_none());
+        assert_eq!(result.opponent_actions, vec![crate::game::PendingPlayerAction::switch(0, 1)]);
+    }
+
+    #[test]
+    fn test_knockout_awards_effort_values_to_the_active_pokemon() {
+        let moves = moves_with_finisher();
+        let mut team = PlayerTeam::new();
+        team.add_member(pokemon("player", "run-away", &moves));
+        team.add_member(pokemon("bench", "run-away", &moves));
+        team.active_members[0].learn_new_move("finisher".to_string(), 5);
+        team.active_members[0].base_computed_stats.speed = 500;
+
+        let mut wild = pokemon("wild", "run-away", &moves);
+        wild.species.ev_yield.speed = 2;
+        let mut battle_state = BattleState::new(0, vec![wild], "wild".to_string(), BattleFormat::Single, false);
+        battle_state.pending_player_actions = vec![use_move(0, "finisher")];
+
+        let result = play_turn(&mut team, &mut battle_state, &moves, 5);
+
+        assert_eq!(result.outcome, BattleOutcome::PlayerWon);
+        assert_eq!(team.active_members[0].effort_values.speed, 2);
+        assert_eq!(battle_state.ev_awarded_opponents, vec![0]);
+        // Solo gana EVs quien está en el campo
+        assert_eq!(team.active_members[1].effort_values.total(), 0);
+    }
+
+    #[test]
+    fn test_losing_to_hazards_on_a_forced_switch_reverts_battle_forms() {
+        let moves = moves();
+        let mut team = PlayerTeam::new();
+        let mut fainted = pokemon("player", "run-away", &moves);
+        fainted.mega_evolve(&mega_form());
+        fainted.terastallized = true;
+        fainted.current_hp = 0;
+        team.add_member(fainted);
+        let mut replacement = pokemon("replacement", "run-away", &moves);
+        replacement.current_hp = 1;
+        team.add_member(replacement);
+
+        let wild = pokemon("wild", "run-away", &moves);
+        let mut battle_state = BattleState::new(0, vec![wild], "wild".to_string(), BattleFormat::Single, false);
+        battle_state.player_side.spikes = 1;
+
+        let result = replace_fainted(&mut team, &mut battle_state, 0, 1);
+
+        assert_eq!(result.outcome, BattleOutcome::PlayerLost);
+        let reverted = &team.active_members[0];
+        assert_eq!(reverted.mega_evolution, None);
+        assert_eq!(reverted.ability, "run-away");
+        assert!(!reverted.terastallized);
+    }
+
+    #[test]
+    fn test_attract_is_reflected_by_destiny_knot() {
+        let mut moves = moves();
+        let mut attract = status_move("attract", "selected-pokemon");
+        attract.meta.ailment = "infatuation".to_string();
+        moves.insert("attract".to_string(), attract);
+
+        let mut team = PlayerTeam::new();
+        let mut player = pokemon("player", "run-away", &moves);
+        player.id = "player".to_string();
+        player.gender = Gender::Male;
+        player.base_computed_stats.speed = 500;
+        player.learn_new_move("attract".to_string(), 15);
+        team.add_member(player);
+
+        let mut wild = pokemon("wild", "run-away", &moves);
+        wild.id = "wild".to_string();
+        wild.gender = Gender::Female;
+        wild.held_item = Some("destiny-knot".to_string());
+        let mut battle_state = BattleState::new(0, vec![wild], "wild".to_string(), BattleFormat::Single, false);
+        battle_state.pending_player_actions = vec![use_move(0, "attract")];
+
+        let result = play_turn(&mut team, &mut battle_state, &moves, 3);
+
+        let infatuated_by = |p: &PokemonInstance| p.volatile_status.as_ref().and_then(|v| v.infatuated_by.clone());
+        assert_eq!(infatuated_by(&battle_state.opponent_instance), Some("player".to_string()));
+        assert_eq!(infatuated_by(&team.active_members[0]), Some("wild".to_string()));
+        assert!(result.events.contains(&BattleEvent::ItemActivated {
+            pokemon: "wild".to_string(),
+            item: "destiny-knot".to_string(),
+        }));
+    }
+
+    #[test]
+    fn test_weather_move_sets_weather_extended_by_rock() {
+        let mut moves = moves();
+        moves.insert("sunny-day".to_string(), status_move("sunny-day", "entire-field"));
+
+        let (_, battle_state, result) = play_wild_turn("sunny-day", &moves, |_, team| {
+            team.active_members[0].held_item = Some("heat-rock".to_string());
+        });
+
+        assert!(result.events.contains(&BattleEvent::WeatherSet { weather: WeatherType::Sun }));
+        // 8 turnos con Heat Rock, menos el que ya terminó
+        assert_eq!(
+            battle_state.weather,
+            Some(WeatherState::with_duration(WeatherType::Sun, EXTENDED_FIELD_TURNS - 1))
+        );
+    }
+
+    #[test]
+    fn test_terrain_move_sets_terrain() {
+        let mut moves = moves();
+        moves.insert("grassy-terrain".to_string(), status_move("grassy-terrain", "entire-field"));
+
+        let (_, battle_state, _) = play_wild_turn("grassy-terrain", &moves, |_, _| {});
+
+        assert_eq!(
+            battle_state.terrain,
+            Some(TerrainState::with_duration(TerrainType::Grassy, 4))
+        );
+    }
+
+    #[test]
+    fn test_weather_move_fails_when_already_active() {
+        let mut moves = moves();
+        moves.insert("rain-dance".to_string(), status_move("rain-dance", "entire-field"));
+
+        let (_, _, result) = play_wild_turn("rain-dance", &moves, |state, _| {
+            state.weather = Some(WeatherState::new(WeatherType::Rain));
+        });
+
+        assert!(!result.events.contains(&BattleEvent::WeatherSet { weather: WeatherType::Rain }));
+        assert!(result.events.contains(&BattleEvent::message("battle.move_failed", [])));
+    }
 }
@@ -8,6 +8,13 @@ pub mod battle_engine;
 // Re-exportar funciones principales
 pub use battle_engine::{
     execute_turn,
+    execute_pivot,
+    prepare_turn,
+    run_turn,
+    resume_turn,
+    finish_turn,
+    send_in_replacement,
+    replace_fainted,
     determine_player_outcome,
     determine_enemy_outcome,
     check_battle_state,
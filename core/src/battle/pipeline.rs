@@ -1,24 +1,38 @@
 use rand::rngs::StdRng;
 use rand::Rng;
-use std::collections::HashMap;
-use crate::models::{MoveData, PokemonInstance, FieldPosition, BattleFormat, WeatherType, TerrainType, WeatherState, TerrainState};
-use crate::game::{BattleState, PlayerTeam};
+use std::collections::{HashMap, VecDeque};
+use crate::models::{
+    MoveData, PokemonInstance, FieldPosition, BattleFormat, BattleSide, EntryHazard, SideCondition, WeatherType,
+    StatStages, TerrainType, VolatileStatus,
+};
+use crate::game::{BattleState, PendingPivot, PendingPlayerAction, PlayerTeam};
+use crate::experience::award_effort_values;
 use super::context::BattleContext;
 use super::targeting::resolve_targets;
-use super::effects::{apply_weather_residuals, apply_residual_effects};
-use super::systems::validation_system::consume_move_pp;
+use super::effects::{
+    apply_weather_residuals, apply_residual_effects, terrain_for_move, terrain_state_for, weather_for_move,
+    weather_state_for,
+};
+use super::systems::validation_system::{consume_move_pp, lock_choice_move};
 use super::ability_logic::{get_ability_hooks, AbilityTrigger, AbilityEffect, StatChangeTarget};
 use super::systems::ability_system::{get_speed_with_abilities, get_priority_with_abilities};
-use super::systems::item_system::ItemProcessor;
+use super::systems::item_system::{item_display_name, ItemProcessor};
 use super::{BattleOutcome, TurnResult};
+use super::events::{BattleEvent, DamageCause, HealCause};
 
 // Importar desde los nuevos módulos de infraestructura y sistemas
 use super::infrastructure::{
     get_pokemon, get_pokemon_mut, get_team_index, is_pokemon_alive, resolve_move_data
 };
-use super::systems::ai_system::select_ai_move;
+use super::systems::ai_system::{planned_damage, AiContext};
 use super::systems::validation_system::reset_turn_flags;
-use super::systems::action_system::ActionCandidate;
+use super::systems::action_system::{ActionCandidate, BattleAction, SwitchCandidate};
+use super::systems::field_system::{
+    apply_entry_hazards, break_screens, breaks_screens, clear_hazards, hazard_for_move, lay_hazard,
+    side_condition_for_move, start_side_condition, tick_side_conditions, Screens,
+};
+use super::systems::protection_system::{bypasses_substitute, check_advanced_protections, has_substitute};
+use super::systems::effect_system::end_infatuation_without_source;
 use super::orchestration::battle_engine::check_battle_state;
 
 // MIGRADO: ActionCandidate ahora está en systems/action_system/models.rs
@@ -32,14 +46,19 @@ use super::orchestration::battle_engine::check_battle_state;
 /// Flujo:
 /// 1. Hook de entrada (solo turno 1)
 /// 2. Resetea estados volátiles de inicio de turno
-/// 3. Recopila todos los combatientes en el campo
-/// 4. Ordena por prioridad -> velocidad -> RNG
+/// 3. Recopila las acciones de todos los combatientes (movimientos y cambios)
+/// 4. Ordena: cambios primero, luego prioridad -> velocidad -> RNG
 /// 5. Ejecuta acciones en orden, verificando si están vivos antes de cada acción
 /// 6. Aplica spread damage penalty (0.75x) para movimientos de área
 /// 7. Aplica efectos residuales al final
 /// 8. Determina resultado de la batalla
 ///
-/// Retorna un TurnResult con los logs y el resultado de la batalla.
+/// Si un Pokémon del jugador sale con un movimiento de relevo (U-turn, Volt
+/// Switch, Flip Turn, Parting Shot, Baton Pass) o un Eject Button/Pack, el
+/// turno se detiene con `BattleOutcome::PlayerMustPivot` y continúa con
+/// `execute_pivot` cuando el jugador elige el reemplazo.
+///
+/// Retorna un TurnResult con los eventos, sus logs renderizados y el resultado de la batalla.
 pub fn execute_turn(
     player_team: &mut PlayerTeam,
     opponent_team: &mut Vec<PokemonInstance>,
@@ -51,29 +70,130 @@ pub fn execute_turn(
 
     // 1. Hook de Entrada (Solo turno 1)
     if battle_state.turn_counter == 1 {
-        handle_entry_hazards(battle_state, player_team, opponent_team, &mut result.logs);
+        handle_entry_hazards(battle_state, player_team, opponent_team, &mut result.events);
     }
 
     // 2. Resetear estados volátiles de inicio de turno (Flinch, Protect counter)
     reset_turn_flags(player_team, opponent_team, battle_state);
 
     // 3. Fase de Recolección y Ordenamiento
-    let mut candidates = collect_action_candidates(
+    let mut actions = collect_action_candidates(
         battle_state,
         player_team,
         opponent_team,
         move_pool,
-        &mut result.logs,
+        &mut result.opponent_actions,
+        rng,
     );
-    sort_candidates(&mut candidates, battle_state, rng);
+    sort_candidates(&mut actions, battle_state, rng);
+
+    // 4. Megaevolución y Teracristalización (antes de cualquier movimiento, en
+    // orden de acción; la nueva velocidad cuenta desde el turno siguiente)
+    apply_mega_evolution(&actions, battle_state, player_team, opponent_team, &mut result.events);
+    apply_terastallization(&actions, battle_state, player_team, opponent_team, &mut result.events);
+
+    // 5. Fase de Ejecución y Limpieza
+    run_actions(actions.into(), true, battle_state, player_team, opponent_team, rng, &mut result);
+    result.render_logs(battle_state.locale);
 
-    // 4. Fase de Ejecución (Bucle Principal)
-    for candidate in candidates {
-        // Verificar si el usuario sigue vivo antes de ejecutar
-        if !is_pokemon_alive(candidate.position, candidate.team_index, battle_state, player_team, opponent_team) {
+    result
+}
+
+/// Continúa un turno detenido por un relevo del jugador
+///
+/// `new_index` es el Pokémon del equipo que entra en el primer slot pendiente.
+/// Si el índice no es válido el turno sigue detenido; si quedan más slots por
+/// relevar (dobles) se vuelve a pedir `PlayerMustPivot`. Al completar los
+/// relevos se ejecutan las acciones que quedaban en la cola.
+pub fn execute_pivot(
+    player_team: &mut PlayerTeam,
+    opponent_team: &mut Vec<PokemonInstance>,
+    battle_state: &mut BattleState,
+    rng: &mut StdRng,
+    new_index: usize,
+) -> TurnResult {
+    let mut result = TurnResult::new();
+    let Some(mut pivot) = battle_state.pending_pivot.take() else {
+        result.outcome = check_outcome(battle_state, player_team, opponent_team, &mut result.events);
+        result.render_logs(battle_state.locale);
+        return result;
+    };
+
+    let valid = player_team.active_members.get(new_index).is_some_and(|p| p.current_hp > 0)
+        && !battle_state.player_active_indices.contains(&new_index);
+    if !valid || pivot.slots.is_empty() {
+        battle_state.pending_pivot = Some(pivot);
+        result.outcome = BattleOutcome::PlayerMustPivot;
+        result.render_logs(battle_state.locale);
+        return result;
+    }
+
+    let slot = pivot.slots.remove(0);
+    switch_in(
+        true,
+        slot,
+        new_index,
+        pivot.baton_pass,
+        battle_state,
+        player_team,
+        opponent_team,
+        &mut result.events,
+    );
+
+    if !pivot.slots.is_empty() && has_replacement(true, battle_state, player_team, opponent_team) {
+        battle_state.pending_pivot = Some(pivot);
+        result.outcome = BattleOutcome::PlayerMustPivot;
+    } else {
+        let queue = pivot.remaining_actions.into();
+        run_actions(queue, pivot.end_of_turn, battle_state, player_team, opponent_team, rng, &mut result);
+    }
+    result.render_logs(battle_state.locale);
+
+    result
+}
+
+/// Ejecuta las acciones de la cola en orden y, si `end_of_turn`, los efectos
+/// de final de turno; deja en `result` el resultado de la batalla
+///
+/// Si un Pokémon del jugador debe relevarse, guarda el resto de la cola en
+/// `BattleState::pending_pivot` y se detiene con `PlayerMustPivot`.
+#[allow(clippy::too_many_arguments)]
+fn run_actions(
+    mut queue: VecDeque<BattleAction>,
+    end_of_turn: bool,
+    battle_state: &mut BattleState,
+    player_team: &mut PlayerTeam,
+    opponent_team: &mut Vec<PokemonInstance>,
+    rng: &mut StdRng,
+    result: &mut TurnResult,
+) {
+    while let Some(action) = queue.pop_front() {
+        let candidate = match action {
+            BattleAction::Switch(switch) => {
+                switch_in(
+                    switch.is_player,
+                    switch.slot,
+                    switch.new_index,
+                    false,
+                    battle_state,
+                    player_team,
+                    opponent_team,
+                    &mut result.events,
+                );
+                continue;
+            }
+            BattleAction::Move(candidate) => *candidate,
+        };
+
+        // Verificar si el usuario sigue vivo y en el campo (un relevo pudo sacarlo)
+        if !is_pokemon_alive(candidate.position, candidate.team_index, battle_state, player_team, opponent_team)
+            || get_team_index(candidate.position, battle_state) != Some(candidate.team_index)
+        {
             continue;
         }
 
+        end_stale_infatuations(battle_state, player_team, opponent_team);
+
         // Ejecutar la acción
         let action_result = execute_single_action(
             candidate,
@@ -83,8 +203,8 @@ pub fn execute_turn(
             rng,
         );
 
-        // Acumular logs
-        result.logs.extend(action_result.logs);
+        // Acumular eventos
+        result.events.extend(action_result.events);
 
         // Acumular daño
         if action_result.is_player_action {
@@ -93,51 +213,320 @@ pub fn execute_turn(
             result.enemy_damage_dealt += action_result.damage_dealt;
         }
 
+        // Relevos: la IA elige al momento, el jugador deja el turno pendiente
+        let pivot = resolve_pivots(
+            &action_result.pivots,
+            battle_state,
+            player_team,
+            opponent_team,
+            &mut result.events,
+        );
+
         // Si hubo un knockout, verificar el resultado de la batalla
         if action_result.caused_knockout {
             eprintln!("[BATTLE_END] Knockout detectado, verificando estado de batalla...");
-            let outcome = check_battle_state(battle_state, player_team, opponent_team, &mut result.logs);
+            let outcome = check_outcome(battle_state, player_team, opponent_team, &mut result.events);
             eprintln!("[BATTLE_END] Resultado: {:?}", outcome);
             if outcome != BattleOutcome::Continue {
-                result.outcome = outcome;
-                return result;
+                // El rival ya sacó reemplazo; el relevo del jugador cierra el turno
+                result.outcome = match pivot {
+                    Some(pivot) if outcome == BattleOutcome::EnemySwitched => {
+                        battle_state.pending_pivot = Some(pivot);
+                        BattleOutcome::PlayerMustPivot
+                    }
+                    _ => outcome,
+                };
+                return;
             }
         }
+
+        if let Some(mut pivot) = pivot {
+            pivot.remaining_actions = queue.into();
+            pivot.end_of_turn = end_of_turn;
+            battle_state.pending_pivot = Some(pivot);
+            result.outcome = BattleOutcome::PlayerMustPivot;
+            return;
+        }
     }
 
-    // 5. Fase de Limpieza (End of Turn)
-    process_end_of_turn_residuals(
-        battle_state,
-        player_team,
-        opponent_team,
-        &mut result.logs,
-    );
+    // Fase de Limpieza (End of Turn)
+    if end_of_turn {
+        process_end_of_turn_residuals(
+            battle_state,
+            player_team,
+            opponent_team,
+            &mut result.events,
+        );
+    }
 
-    // 6. Determinar Resultado Final
-    result.outcome = check_battle_state(battle_state, player_team, opponent_team, &mut result.logs);
+    // Determinar Resultado Final
+    result.outcome = check_outcome(battle_state, player_team, opponent_team, &mut result.events);
+}
 
-    result
+/// Resuelve los relevos pedidos por una acción
+///
+/// Los slots de la IA cambian en el acto al primer Pokémon sano del banco. Los
+/// del jugador se devuelven como relevo pendiente (sin cola ni fin de turno,
+/// que completa `run_actions`) si tiene a quién sacar. Baton Pass sin
+/// compañeros disponibles falla.
+fn resolve_pivots(
+    pivots: &[Pivot],
+    battle_state: &mut BattleState,
+    player_team: &mut PlayerTeam,
+    opponent_team: &mut Vec<PokemonInstance>,
+    events: &mut Vec<BattleEvent>,
+) -> Option<PendingPivot> {
+    let mut player_slots = Vec::new();
+    let mut player_baton_pass = false;
+
+    for pivot in pivots {
+        let replacement = first_replacement(pivot.is_player, battle_state, player_team, opponent_team);
+        match replacement {
+            None if pivot.baton_pass => events.push(BattleEvent::message("battle.move_failed", [])),
+            None => {}
+            Some(_) if pivot.is_player => {
+                player_slots.push(pivot.slot);
+                player_baton_pass |= pivot.baton_pass;
+            }
+            Some(new_index) => switch_in(
+                false,
+                pivot.slot,
+                new_index,
+                pivot.baton_pass,
+                battle_state,
+                player_team,
+                opponent_team,
+                events,
+            ),
+        }
+    }
+
+    (!player_slots.is_empty()).then(|| PendingPivot {
+        slots: player_slots,
+        baton_pass: player_baton_pass,
+        remaining_actions: Vec::new(),
+        end_of_turn: false,
+    })
+}
+
+/// Primer Pokémon sano del banco de un lado, si lo hay
+fn first_replacement(
+    is_player: bool,
+    battle_state: &BattleState,
+    player_team: &PlayerTeam,
+    opponent_team: &[PokemonInstance],
+) -> Option<usize> {
+    let (active_indices, team) = if is_player {
+        (&battle_state.player_active_indices, &player_team.active_members[..])
+    } else {
+        (&battle_state.opponent_active_indices, opponent_team)
+    };
+    team.iter()
+        .enumerate()
+        .position(|(idx, p)| p.current_hp > 0 && !active_indices.contains(&idx))
+}
+
+/// Si un lado tiene algún Pokémon sano en el banco
+fn has_replacement(
+    is_player: bool,
+    battle_state: &BattleState,
+    player_team: &PlayerTeam,
+    opponent_team: &[PokemonInstance],
+) -> bool {
+    first_replacement(is_player, battle_state, player_team, opponent_team).is_some()
+}
+
+/// Verifica el resultado de la batalla; si el oponente sacó un reemplazo,
+/// éste entra al campo (hazards y habilidades de entrada)
+fn check_outcome(
+    battle_state: &mut BattleState,
+    player_team: &mut PlayerTeam,
+    opponent_team: &mut Vec<PokemonInstance>,
+    events: &mut Vec<BattleEvent>,
+) -> BattleOutcome {
+    award_knockout_evs(battle_state, player_team, opponent_team);
+    let outcome = check_battle_state(battle_state, player_team, opponent_team, events);
+    if outcome == BattleOutcome::EnemySwitched {
+        if let Some(&idx) = battle_state.opponent_active_indices.first() {
+            enter_field(idx, false, battle_state, player_team, opponent_team, events);
+        }
+    }
+    outcome
+}
+
+/// Reparte los EVs de cada rival recién debilitado entre los Pokémon del
+/// jugador que siguen en pie en el campo (una sola vez por rival)
+fn award_knockout_evs(battle_state: &mut BattleState, player_team: &mut PlayerTeam, opponent_team: &[PokemonInstance]) {
+    for &opponent_idx in &battle_state.opponent_active_indices {
+        let Some(opponent) = opponent_team.get(opponent_idx) else {
+            continue;
+        };
+        if opponent.current_hp > 0 || battle_state.ev_awarded_opponents.contains(&opponent_idx) {
+            continue;
+        }
+        battle_state.ev_awarded_opponents.push(opponent_idx);
+
+        let ev_yield = opponent.species.effective_ev_yield();
+        for &player_idx in &battle_state.player_active_indices {
+            if let Some(participant) = player_team.active_members.get_mut(player_idx) {
+                if participant.current_hp > 0 {
+                    award_effort_values(participant, &ev_yield);
+                }
+            }
+        }
+    }
+}
+
+/// Megaevoluciona a los Pokémon que lo pidieron con su movimiento del turno
+///
+/// Cada bando puede hacerlo una sola vez por batalla y solo con la megapiedra
+/// de la especie. La nueva habilidad se activa como al entrar al campo.
+fn apply_mega_evolution(
+    actions: &[BattleAction],
+    battle_state: &mut BattleState,
+    player_team: &mut PlayerTeam,
+    opponent_team: &mut Vec<PokemonInstance>,
+    events: &mut Vec<BattleEvent>,
+) {
+    for action in actions {
+        let BattleAction::Move(candidate) = action else {
+            continue;
+        };
+        let side = candidate.position.side();
+        if !candidate.mega_evolve
+            || !battle_state.mega_available(side)
+            || get_team_index(candidate.position, battle_state) != Some(candidate.team_index)
+        {
+            continue;
+        }
+        let Some(pokemon) = get_pokemon_mut(candidate.position, candidate.team_index, battle_state, player_team, opponent_team)
+        else {
+            continue;
+        };
+        if !ItemProcessor::can_mega_evolve(pokemon) {
+            continue;
+        }
+        let Some(form) = ItemProcessor::mega_form(pokemon).cloned() else {
+            continue;
+        };
+        pokemon.mega_evolve(&form);
+        events.push(BattleEvent::MegaEvolved {
+            pokemon: pokemon.species.display_name.clone(),
+            form: form.display_name,
+        });
+        *battle_state.mega_evolved_mut(side) = Some(candidate.team_index);
+        trigger_entry_abilities(candidate.team_index, candidate.is_player, battle_state, player_team, opponent_team, events);
+    }
+}
+
+/// Teracristaliza a los Pokémon que lo pidieron con su movimiento del turno
+///
+/// Cada bando puede hacerlo una sola vez por batalla; el Pokémon queda
+/// registrado en `BattleState` para mantener el estado entre turnos.
+fn apply_terastallization(
+    actions: &[BattleAction],
+    battle_state: &mut BattleState,
+    player_team: &mut PlayerTeam,
+    opponent_team: &mut Vec<PokemonInstance>,
+    events: &mut Vec<BattleEvent>,
+) {
+    for action in actions {
+        let BattleAction::Move(candidate) = action else {
+            continue;
+        };
+        let side = candidate.position.side();
+        if !candidate.terastallize
+            || !battle_state.tera_available(side)
+            || get_team_index(candidate.position, battle_state) != Some(candidate.team_index)
+        {
+            continue;
+        }
+        let Some(pokemon) = get_pokemon_mut(candidate.position, candidate.team_index, battle_state, player_team, opponent_team)
+        else {
+            continue;
+        };
+        if !pokemon.can_terastallize() {
+            continue;
+        }
+        pokemon.terastallized = true;
+        events.push(BattleEvent::Terastallized {
+            pokemon: pokemon.species.display_name.clone(),
+            tera_type: pokemon.tera_type,
+        });
+        *battle_state.terastallized_mut(side) = Some(candidate.team_index);
+    }
+}
+
+/// Termina el enamoramiento de los Pokémon cuyo causante ya dejó el campo
+/// (cambió o se debilitó)
+fn end_stale_infatuations(battle_state: &BattleState, player_team: &mut PlayerTeam, opponent_team: &mut [PokemonInstance]) {
+    let on_field = |indices: &[usize], team: &[PokemonInstance]| -> Vec<String> {
+        indices
+            .iter()
+            .filter_map(|&idx| team.get(idx))
+            .filter(|p| p.current_hp > 0)
+            .map(|p| p.id.clone())
+            .collect()
+    };
+    let player_ids = on_field(&battle_state.player_active_indices, &player_team.active_members);
+    let opponent_ids = on_field(&battle_state.opponent_active_indices, opponent_team);
+
+    for &idx in &battle_state.player_active_indices {
+        if let Some(pokemon) = player_team.active_members.get_mut(idx) {
+            end_infatuation_without_source(pokemon, &opponent_ids);
+        }
+    }
+    for &idx in &battle_state.opponent_active_indices {
+        if let Some(pokemon) = opponent_team.get_mut(idx) {
+            end_infatuation_without_source(pokemon, &player_ids);
+        }
+    }
 }
 
 /// Resultado de ejecutar una acción individual
 struct ActionResult {
-    logs: Vec<String>,
+    events: Vec<BattleEvent>,
     damage_dealt: u16,
     caused_knockout: bool,
     is_player_action: bool,
+    /// Slots que deben relevarse tras la acción
+    pivots: Vec<Pivot>,
 }
 
 impl ActionResult {
     fn new(is_player: bool) -> Self {
         Self {
-            logs: Vec::new(),
+            events: Vec::new(),
             damage_dealt: 0,
             caused_knockout: false,
             is_player_action: is_player,
+            pivots: Vec::new(),
         }
     }
 }
 
+/// Slot del campo que sale por un movimiento de relevo o un Eject Button/Pack
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Pivot {
+    is_player: bool,
+    slot: usize,
+    /// El que entra hereda stats y sustituto (Baton Pass)
+    baton_pass: bool,
+}
+
+impl Pivot {
+    fn at(position: FieldPosition, baton_pass: bool) -> Self {
+        let slot = match position {
+            FieldPosition::PlayerLeft | FieldPosition::OpponentLeft => 0,
+            FieldPosition::PlayerRight | FieldPosition::OpponentRight => 1,
+        };
+        Self { is_player: position.side() == BattleSide::Player, slot, baton_pass }
+    }
+}
+
+/// Movimientos que sacan al usuario tras golpear (U-turn, Volt Switch, Flip Turn)
+const PIVOT_MOVES: &[&str] = &["u-turn", "volt-switch", "flip-turn"];
+
 /// Ejecuta una acción individual de un Pokémon
 fn execute_single_action(
     candidate: ActionCandidate,
@@ -148,6 +537,13 @@ fn execute_single_action(
 ) -> ActionResult {
     let mut result = ActionResult::new(candidate.is_player);
 
+    // Movimientos de campo: actúan sobre el campo o un lado, no sobre un Pokémon
+    if let Some(field_move) = FieldMove::for_move(&candidate.move_data.id) {
+        result.events = execute_field_move(&candidate, field_move, battle_state, player_team, opponent_team, rng);
+        record_move_use(&candidate, battle_state, player_team, opponent_team);
+        return result;
+    }
+
     // Obtener el atacante para la resolución de targets (necesario para redirection)
     let attacker = if candidate.is_player {
         &player_team.active_members[candidate.team_index]
@@ -169,11 +565,10 @@ fn execute_single_action(
     );
 
     if targets.is_empty() {
-        result.logs.push(format!(
-            "¡{} usó {}, pero no había objetivo!",
-            candidate.pokemon_name,
-            candidate.move_data.name
-        ));
+        result.events.push(BattleEvent::message("battle.no_target", [
+            ("user", candidate.pokemon_name.clone()),
+            ("move", candidate.move_data.name.clone()),
+        ]));
         return result;
     }
 
@@ -181,10 +576,9 @@ fn execute_single_action(
     let is_spread = targets.len() > 1;
     let spread_factor = if is_spread { 0.75 } else { 1.0 };
 
-    // 3. Consumir PP (solo una vez, antes de ejecutar)
-    let should_consume_pp = candidate.move_template_id != "struggle";
-
     // 4. Iterar sobre los objetivos y ejecutar el movimiento
+    let mut landed = false;
+    let mut ejected_target = false;
     for target_pos in targets {
         // Obtener atacante y defensor mutables
         let hit_result = process_move_hit(
@@ -197,36 +591,195 @@ fn execute_single_action(
             rng,
         );
 
-        result.logs.extend(hit_result.logs);
+        result.events.extend(hit_result.events);
         result.damage_dealt += hit_result.damage;
+        landed |= hit_result.landed;
+        for position in hit_result.ejected {
+            ejected_target |= position != candidate.position;
+            push_pivot(&mut result.pivots, Pivot::at(position, false));
+        }
 
         if hit_result.defender_fainted {
             result.caused_knockout = true;
         }
     }
 
-    // 5. Consumir PP después de ejecutar exitosamente
-    if should_consume_pp {
-        let attacker = get_pokemon_mut(
-            candidate.position,
-            candidate.team_index,
-            battle_state,
-            player_team,
-            opponent_team,
-        );
-        if let Some(mon) = attacker {
-            consume_move_pp(mon, &candidate.move_template_id);
+    // 5. Limpieza de campo: Rapid Spin limpia el lado del usuario, Defog ambos
+    //    (y las pantallas del rival)
+    let user_side = candidate.position.side();
+    match candidate.move_data.id.as_str() {
+        "rapid-spin" if result.damage_dealt > 0 => {
+            clear_side_hazards(battle_state, user_side, &mut result.events);
+        }
+        "defog" if landed => {
+            let target_side = user_side.opposite();
+            if break_screens(battle_state.side_mut(target_side)) {
+                result.events.push(BattleEvent::ScreensBroken { side: target_side });
+            }
+            clear_side_hazards(battle_state, target_side, &mut result.events);
+            clear_side_hazards(battle_state, user_side, &mut result.events);
+        }
+        _ => {}
+    }
+
+    // 6. Consumir PP y recordar el movimiento usado
+    record_move_use(&candidate, battle_state, player_team, opponent_team);
+
+    // 7. Relevo del usuario: no sale si está debilitado o si el Eject Button
+    //    del objetivo ya lo sacó a él
+    let user_alive = is_pokemon_alive(candidate.position, candidate.team_index, battle_state, player_team, opponent_team);
+    let move_id = candidate.move_data.id.as_str();
+    let pivots = PIVOT_MOVES.contains(&move_id) && result.damage_dealt > 0
+        || move_id == "parting-shot" && landed;
+    if user_alive && !ejected_target {
+        if pivots {
+            push_pivot(&mut result.pivots, Pivot::at(candidate.position, false));
+        } else if move_id == "baton-pass" && landed {
+            push_pivot(&mut result.pivots, Pivot::at(candidate.position, true));
         }
     }
 
     result
 }
 
+/// Añade un relevo si ese slot no tenía ya uno pendiente
+fn push_pivot(pivots: &mut Vec<Pivot>, pivot: Pivot) {
+    if !pivots.iter().any(|p| p.is_player == pivot.is_player && p.slot == pivot.slot) {
+        pivots.push(pivot);
+    }
+}
+
+/// Consume el PP del movimiento y lo recuerda como el último usado (la IA lo
+/// consulta al decidir)
+fn record_move_use(
+    candidate: &ActionCandidate,
+    battle_state: &mut BattleState,
+    player_team: &mut PlayerTeam,
+    opponent_team: &mut Vec<PokemonInstance>,
+) {
+    let Some(mon) = get_pokemon_mut(candidate.position, candidate.team_index, battle_state, player_team, opponent_team) else {
+        return;
+    };
+
+    if candidate.move_template_id != "struggle" {
+        consume_move_pp(mon, &candidate.move_template_id);
+    }
+    if let Some(ref mut volatile) = mon.volatile_status {
+        volatile.last_move_used = Some(candidate.move_template_id.clone());
+    }
+    lock_choice_move(mon, &candidate.move_template_id);
+}
+
+/// Movimiento que actúa sobre el campo en vez de sobre un Pokémon
+#[derive(Debug, Clone, Copy)]
+enum FieldMove {
+    /// Coloca un hazard en el lado rival
+    Hazard(EntryHazard),
+    /// Activa una condición temporal en el lado del usuario
+    Condition(SideCondition),
+    /// Cambia el clima
+    Weather(WeatherType),
+    /// Cambia el terreno
+    Terrain(TerrainType),
+}
+
+impl FieldMove {
+    fn for_move(move_id: &str) -> Option<Self> {
+        hazard_for_move(move_id)
+            .map(FieldMove::Hazard)
+            .or_else(|| side_condition_for_move(move_id).map(FieldMove::Condition))
+            .or_else(|| weather_for_move(move_id).map(FieldMove::Weather))
+            .or_else(|| terrain_for_move(move_id).map(FieldMove::Terrain))
+    }
+}
+
+/// Ejecuta un movimiento de campo (hazards, pantallas, Tailwind, clima, terreno)
+///
+/// No apunta a ningún Pokémon, así que no lo detienen Protect ni la falta de
+/// objetivos; solo los estados que impiden moverse al usuario. Falla si el
+/// efecto ya está activo.
+fn execute_field_move(
+    candidate: &ActionCandidate,
+    field_move: FieldMove,
+    battle_state: &mut BattleState,
+    player_team: &mut PlayerTeam,
+    opponent_team: &mut Vec<PokemonInstance>,
+    rng: &mut StdRng,
+) -> Vec<BattleEvent> {
+    let Some(mut user) = get_pokemon(candidate.position, candidate.team_index, battle_state, player_team, opponent_team).cloned() else {
+        return Vec::new();
+    };
+    let mut field = user.clone();
+    let weather = battle_state.weather.clone();
+    let terrain = battle_state.terrain.clone();
+
+    let mut ctx = BattleContext::new(
+        &mut user,
+        &mut field,
+        &candidate.move_data,
+        candidate.pokemon_name.clone(),
+        candidate.pokemon_name.clone(),
+        rng,
+        weather.as_ref(),
+        terrain.as_ref(),
+    );
+    let can_move = ctx.can_execute_move();
+    let mut events = std::mem::take(&mut ctx.events);
+
+    if can_move {
+        let user_side = candidate.position.side();
+        let outcome = match field_move {
+            FieldMove::Hazard(hazard) => {
+                let side = user_side.opposite();
+                lay_hazard(battle_state.side_mut(side), hazard)
+                    .then_some(BattleEvent::HazardSet { side, hazard })
+            }
+            FieldMove::Condition(condition) => {
+                start_side_condition(battle_state.side_mut(user_side), condition, &user, weather.as_ref())
+                    .then_some(BattleEvent::SideConditionStarted { side: user_side, condition })
+            }
+            FieldMove::Weather(new_weather) => {
+                let active = weather.as_ref().is_some_and(|w| w.weather_type == new_weather);
+                (!active).then(|| {
+                    battle_state.weather = Some(weather_state_for(&user, new_weather, 0));
+                    BattleEvent::WeatherSet { weather: new_weather }
+                })
+            }
+            FieldMove::Terrain(new_terrain) => {
+                let active = terrain.as_ref().is_some_and(|t| t.terrain_type == new_terrain);
+                (!active).then(|| {
+                    battle_state.terrain = Some(terrain_state_for(&user, new_terrain, 0));
+                    BattleEvent::TerrainSet { terrain: Some(new_terrain) }
+                })
+            }
+        };
+        events.push(outcome.unwrap_or_else(|| BattleEvent::message("battle.move_failed", [])));
+    }
+
+    // El chequeo de estado puede haber cambiado al usuario (sueño, flinch...)
+    if let Some(mon) = get_pokemon_mut(candidate.position, candidate.team_index, battle_state, player_team, opponent_team) {
+        *mon = user;
+    }
+
+    events
+}
+
+/// Retira los hazards de un lado y lo narra si había alguno
+fn clear_side_hazards(battle_state: &mut BattleState, side: BattleSide, events: &mut Vec<BattleEvent>) {
+    if clear_hazards(battle_state.side_mut(side)) {
+        events.push(BattleEvent::HazardsCleared { side });
+    }
+}
+
 /// Resultado de golpear a un objetivo
 struct HitResult {
-    logs: Vec<String>,
+    events: Vec<BattleEvent>,
     damage: u16,
     defender_fainted: bool,
+    /// El movimiento alcanzó al objetivo (el usuario pudo moverse y no hubo protección)
+    landed: bool,
+    /// Posiciones que salen del campo por su Eject Button o Eject Pack
+    ejected: Vec<FieldPosition>,
 }
 
 /// Procesa un golpe individual a un objetivo específico
@@ -240,9 +793,11 @@ fn process_move_hit(
     rng: &mut StdRng,
 ) -> HitResult {
     let mut result = HitResult {
-        logs: Vec::new(),
+        events: Vec::new(),
         damage: 0,
         defender_fainted: false,
+        landed: false,
+        ejected: Vec::new(),
     };
 
     // Verificar que el defensor sigue vivo
@@ -278,9 +833,20 @@ fn process_move_hit(
 
     // Guardar el nombre del defensor antes de prestarlo mutablemente
     let defender_name = defender_clone.species.display_name.clone();
+    // Stats antes del golpe, para detectar bajadas (Eject Pack)
+    let attacker_stages = attacker_clone.battle_stages.clone().unwrap_or_default();
+    let defender_stages = defender_clone.battle_stages.clone().unwrap_or_default();
+    let defender_protected = defender_clone.volatile_status.as_ref().is_some_and(|v| v.protected)
+        || check_advanced_protections(&defender_clone, &candidate.move_data).is_some();
+    // Un movimiento sobre sí mismo nunca da en el propio sustituto
+    let targets_self = target_pos == candidate.position;
+    let behind_substitute = !targets_self
+        && has_substitute(&defender_clone)
+        && !bypasses_substitute(&attacker_clone, &candidate.move_data);
 
     // Crear el BattleContext para procesar el ataque
     // Usamos las clones del weather y terrain para evitar problemas de borrow
+    let defender_side = target_pos.side();
     let mut ctx = BattleContext::new(
         &mut attacker_clone,
         &mut defender_clone,
@@ -290,11 +856,13 @@ fn process_move_hit(
         rng,
         weather_clone.as_ref(),
         terrain_clone.as_ref(),
-    );
+    )
+    .with_screens(Screens::of(battle_state.side(defender_side), battle_state.format))
+    .with_substitute(behind_substitute);
 
     // Paso 1: Verificar si puede ejecutar el movimiento
     if !ctx.can_execute_move() {
-        result.logs = ctx.logs;
+        result.events = ctx.events;
         // Aplicar cambios del atacante (puede haber cambiado volatile_status)
         if let Some(attacker) = get_pokemon_mut(candidate.position, attacker_index, battle_state, player_team, opponent_team) {
             *attacker = attacker_clone;
@@ -302,6 +870,18 @@ fn process_move_hit(
         return result;
     }
 
+    result.landed = !defender_protected;
+
+    // Brick Break rompe las pantallas del objetivo antes de golpear
+    if result.landed
+        && breaks_screens(&candidate.move_data.id)
+        && defender_side != candidate.position.side()
+        && break_screens(battle_state.side_mut(defender_side))
+    {
+        ctx.events.push(BattleEvent::ScreensBroken { side: defender_side });
+        ctx.screens = Screens::default();
+    }
+
     // Paso 2: Calcular daño
     let mut damage = ctx.calculate_damage();
 
@@ -313,8 +893,8 @@ fn process_move_hit(
     // Paso 3: Aplicar efectos del movimiento
     ctx.apply_move_effects(damage);
 
-    // Obtener logs del contexto (antes de consumirlo)
-    result.logs = ctx.logs;
+    // Obtener eventos del contexto (antes de consumirlo)
+    result.events = ctx.events;
     result.damage = damage;
 
     // Paso 4: Aplicar el daño al defensor
@@ -323,9 +903,12 @@ fn process_move_hit(
     let final_defender = ctx.defender;
 
     // Verificar si el defensor se debilitó
-    if final_defender.current_hp == 0 && damage > 0 {
+    if final_defender.current_hp == 0 && damage > 0 && !targets_self {
         eprintln!("[BATTLE_END] Defensor {} debilitado (HP = 0)", final_defender.species.display_name);
         result.defender_fainted = true;
+        result.events.push(BattleEvent::Fainted {
+            target: final_defender.species.display_name.clone(),
+        });
     }
 
     // Aplicar cambios de vuelta a los Pokémon originales
@@ -334,12 +917,16 @@ fn process_move_hit(
             final_attacker.species.display_name, final_attacker.current_hp, attacker.current_hp);
         *attacker = final_attacker.clone();
     }
-    if let Some(defender) = get_pokemon_mut(target_pos, defender_index, battle_state, player_team, opponent_team) {
-        eprintln!("[BATTLE_END] Sincronizando defensor {} | HP del clon: {} | HP antes: {}",
-            final_defender.species.display_name, final_defender.current_hp, defender.current_hp);
-        *defender = final_defender.clone();
-        eprintln!("[BATTLE_END] Sincronizando defensor {} | HP después de copiar: {}",
-            defender.species.display_name, defender.current_hp);
+    // Si el objetivo es el propio usuario, su estado final es el del atacante
+    // (Swords Dance, Protect, Substitute...)
+    if !targets_self {
+        if let Some(defender) = get_pokemon_mut(target_pos, defender_index, battle_state, player_team, opponent_team) {
+            eprintln!("[BATTLE_END] Sincronizando defensor {} | HP del clon: {} | HP antes: {}",
+                final_defender.species.display_name, final_defender.current_hp, defender.current_hp);
+            *defender = final_defender.clone();
+            eprintln!("[BATTLE_END] Sincronizando defensor {} | HP después de copiar: {}",
+                defender.species.display_name, defender.current_hp);
+        }
     }
 
     // Procesar items después de causar daño (Life Orb recoil)
@@ -351,11 +938,35 @@ fn process_move_hit(
                 damage,
             );
 
-            // Agregar logs del item
-            result.logs.extend(item_result.logs);
+            // Agregar eventos del item
+            result.events.extend(item_result.events);
+            if attacker.current_hp == 0 && item_result.recoil_damage > 0 {
+                result.events.push(BattleEvent::Fainted {
+                    target: attacker.species.display_name.clone(),
+                });
+            }
         }
     }
 
+    // Eject Button: el objetivo sale tras recibir daño (no a través del sustituto)
+    if damage > 0 && !targets_self && !behind_substitute
+        && try_eject(target_pos, defender_index, ItemProcessor::ejects_on_hit, battle_state, player_team, opponent_team, &mut result.events)
+    {
+        result.ejected.push(target_pos);
+    }
+    // Eject Pack: el objetivo o el atacante (Overheat, Close Combat) sale si le bajaron una stat
+    if !targets_self
+        && lowered_since(target_pos, defender_index, &defender_stages, battle_state, player_team, opponent_team)
+        && try_eject(target_pos, defender_index, ItemProcessor::ejects_on_stat_drop, battle_state, player_team, opponent_team, &mut result.events)
+    {
+        result.ejected.push(target_pos);
+    }
+    if lowered_since(candidate.position, attacker_index, &attacker_stages, battle_state, player_team, opponent_team)
+        && try_eject(candidate.position, attacker_index, ItemProcessor::ejects_on_stat_drop, battle_state, player_team, opponent_team, &mut result.events)
+    {
+        result.ejected.push(candidate.position);
+    }
+
     // Log final del HP del defensor antes de retornar
     if let Some(defender) = get_pokemon_mut(target_pos, defender_index, battle_state, player_team, opponent_team) {
         eprintln!("[BATTLE_END] HP FINAL del defensor {} antes de retornar: {}", defender.species.display_name, defender.current_hp);
@@ -364,14 +975,64 @@ fn process_move_hit(
     result
 }
 
+/// Si las stats del Pokémon bajaron respecto a `before`
+fn lowered_since(
+    position: FieldPosition,
+    index: usize,
+    before: &StatStages,
+    battle_state: &BattleState,
+    player_team: &PlayerTeam,
+    opponent_team: &Vec<PokemonInstance>,
+) -> bool {
+    get_pokemon(position, index, battle_state, player_team, opponent_team)
+        .and_then(|p| p.battle_stages.as_ref())
+        .is_some_and(|stages| stages.lowered_since(before))
+}
+
+/// Consume el objeto de eyección del Pokémon si sigue en pie, lo lleva y
+/// tiene a quién dejarle el sitio
+///
+/// Retorna true si el objeto se activó (el Pokémon debe salir del campo).
+fn try_eject(
+    position: FieldPosition,
+    index: usize,
+    holds_item: fn(&PokemonInstance) -> bool,
+    battle_state: &mut BattleState,
+    player_team: &mut PlayerTeam,
+    opponent_team: &mut Vec<PokemonInstance>,
+    events: &mut Vec<BattleEvent>,
+) -> bool {
+    if !has_replacement(position.side() == BattleSide::Player, battle_state, player_team, opponent_team) {
+        return false;
+    }
+    let Some(pokemon) = get_pokemon_mut(position, index, battle_state, player_team, opponent_team) else {
+        return false;
+    };
+    if pokemon.current_hp == 0 || !holds_item(pokemon) {
+        return false;
+    }
+    let Some(item) = pokemon.held_item.take() else {
+        return false;
+    };
+    events.push(BattleEvent::ItemActivated {
+        pokemon: pokemon.species.display_name.clone(),
+        item,
+    });
+    true
+}
+
 /// Recopila todas las acciones pendientes de jugador y oponente
+///
+/// La IA del entrenador decide primero si retira a su Pokémon; si no, elige
+/// movimiento. Los cambios y movimientos de la IA quedan en `opponent_actions`.
 fn collect_action_candidates(
     state: &BattleState,
     p_team: &PlayerTeam,
     o_team: &Vec<PokemonInstance>,
     move_pool: Option<&HashMap<String, MoveData>>,
-    logs: &mut Vec<String>,
-) -> Vec<ActionCandidate> {
+    opponent_actions: &mut Vec<PendingPlayerAction>,
+    rng: &mut StdRng,
+) -> Vec<BattleAction> {
     let mut candidates = Vec::new();
 
     // 1. Recopilar acciones del JUGADOR
@@ -379,6 +1040,16 @@ fn collect_action_candidates(
         if let Some(&team_idx) = state.player_active_indices.get(action.user_index) {
             if let Some(pokemon) = p_team.active_members.get(team_idx) {
                 if pokemon.current_hp > 0 {
+                    if let Some(new_index) = action.switch_to {
+                        candidates.push(BattleAction::Switch(SwitchCandidate {
+                            slot: action.user_index,
+                            new_index,
+                            is_player: true,
+                            speed: get_speed_with_abilities(pokemon, BattleSide::Player, state),
+                        }));
+                        continue;
+                    }
+
                     // Determinar posición
                     let pos = match state.format {
                         BattleFormat::Single => FieldPosition::PlayerLeft,
@@ -395,26 +1066,71 @@ fn collect_action_candidates(
                     let move_data = resolve_move_data(&action.move_id, move_pool);
                     let priority = get_priority_with_abilities(pokemon, &move_data);
 
-                    candidates.push(ActionCandidate {
+                    candidates.push(BattleAction::Move(Box::new(ActionCandidate {
                         position: pos,
                         team_index: team_idx,
                         is_player: true,
-                        speed: get_speed_with_abilities(pokemon, state),
+                        speed: get_speed_with_abilities(pokemon, BattleSide::Player, state),
                         priority,
                         move_data,
                         move_template_id: action.move_id.clone(),
                         selected_target: action.target_position,
                         pokemon_name: pokemon.species.display_name.clone(),
-                    });
+                        terastallize: action.terastallize,
+                        mega_evolve: action.mega_evolve,
+                    })));
                 }
             }
         }
     }
 
-    // 2. Recopilar acciones del OPONENTE (IA Simple)
+    // 2. Recopilar acciones del OPONENTE (IA según la dificultad de la batalla)
+    let strategy = state.ai_difficulty.strategy();
+    let player_targets = ai_player_targets(state, p_team);
+    // Daño que los slots anteriores de la IA ya planearon (para coordinar ataques)
+    let mut planned = Vec::new();
+    // Reemplazos ya elegidos por otro slot (no pueden entrar dos veces)
+    let mut incoming = Vec::new();
+    // Solo un Pokémon por bando puede teracristalizarse o megaevolucionar en la batalla
+    let mut tera_used = !state.tera_available(BattleSide::Opponent);
+    let mut mega_used = !state.mega_available(BattleSide::Opponent);
+
     for (i, &team_idx) in state.opponent_active_indices.iter().enumerate() {
         if let Some(pokemon) = o_team.get(team_idx) {
             if pokemon.current_hp > 0 {
+                // Solo los entrenadores retiran Pokémon; el cambio gasta el turno del slot
+                if state.is_trainer_battle {
+                    let bench = o_team
+                        .iter()
+                        .enumerate()
+                        .filter(|(j, p)| {
+                            p.current_hp > 0
+                                && !state.opponent_active_indices.contains(j)
+                                && !incoming.contains(j)
+                        })
+                        .collect();
+                    let ctx = AiContext {
+                        user: pokemon,
+                        targets: player_targets.clone(),
+                        ally: None,
+                        planned_damage: &[],
+                        bench,
+                        battle_state: state,
+                        move_pool,
+                    };
+                    if let Some(new_index) = strategy.choose_switch(&ctx) {
+                        incoming.push(new_index);
+                        opponent_actions.push(PendingPlayerAction::switch(i, new_index));
+                        candidates.push(BattleAction::Switch(SwitchCandidate {
+                            slot: i,
+                            new_index,
+                            is_player: false,
+                            speed: get_speed_with_abilities(pokemon, BattleSide::Opponent, state),
+                        }));
+                        continue;
+                    }
+                }
+
                 let pos = match state.format {
                     BattleFormat::Single => FieldPosition::OpponentLeft,
                     BattleFormat::Double => {
@@ -426,22 +1142,58 @@ fn collect_action_candidates(
                     }
                 };
 
-                // IA muy básica: Seleccionar movimiento aleatorio con PP o Struggle
-                let move_id = select_ai_move(pokemon, logs);
-                let move_data = resolve_move_data(&move_id, move_pool);
+                let ally = state
+                    .opponent_active_indices
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .filter_map(|(_, &idx)| o_team.get(idx))
+                    .find(|p| p.current_hp > 0);
+                let (decision, hits, terastallize) = {
+                    let ctx = AiContext {
+                        user: pokemon,
+                        targets: player_targets.clone(),
+                        ally,
+                        planned_damage: &planned,
+                        bench: Vec::new(),
+                        battle_state: state,
+                        move_pool,
+                    };
+                    let decision = strategy.choose_action(&ctx, rng);
+                    let hits = planned_damage(&ctx, &decision);
+                    let terastallize = !tera_used && strategy.choose_terastallize(&ctx, &decision);
+                    (decision, hits, terastallize)
+                };
+                planned.extend(hits);
+                tera_used |= terastallize;
+                // Los entrenadores megaevolucionan en cuanto pueden
+                let mega_evolve = state.is_trainer_battle && !mega_used && ItemProcessor::can_mega_evolve(pokemon);
+                mega_used |= mega_evolve;
+
+                opponent_actions.push(PendingPlayerAction {
+                    user_index: i,
+                    move_id: decision.move_id.clone(),
+                    target_position: decision.target,
+                    switch_to: None,
+                    terastallize,
+                    mega_evolve,
+                });
+                let move_data = resolve_move_data(&decision.move_id, move_pool);
                 let priority = get_priority_with_abilities(pokemon, &move_data);
 
-                candidates.push(ActionCandidate {
+                candidates.push(BattleAction::Move(Box::new(ActionCandidate {
                     position: pos,
                     team_index: team_idx,
                     is_player: false,
-                    speed: get_speed_with_abilities(pokemon, state),
+                    speed: get_speed_with_abilities(pokemon, BattleSide::Opponent, state),
                     priority,
                     move_data,
-                    move_template_id: move_id,
-                    selected_target: None, // La IA usa lógica por defecto de targeting
+                    move_template_id: decision.move_id,
+                    selected_target: decision.target,
                     pokemon_name: pokemon.species.display_name.clone(),
-                });
+                    terastallize,
+                    mega_evolve,
+                })));
             }
         }
     }
@@ -449,16 +1201,180 @@ fn collect_action_candidates(
     candidates
 }
 
+/// Rivales vivos del jugador, con su posición, tal como los ve la IA
+fn ai_player_targets<'a>(state: &BattleState, p_team: &'a PlayerTeam) -> Vec<(FieldPosition, &'a PokemonInstance)> {
+    let player_positions = [FieldPosition::PlayerLeft, FieldPosition::PlayerRight];
+    state
+        .player_active_indices
+        .iter()
+        .zip(player_positions)
+        .filter_map(|(&idx, pos)| p_team.active_members.get(idx).map(|p| (pos, p)))
+        .filter(|(_, p)| p.current_hp > 0)
+        .collect()
+}
+
+/// Retira al Pokémon de un slot y hace entrar a `new_index` del mismo equipo
+///
+/// El saliente activa sus habilidades OnSwitch (Regenerator) y pierde sus
+/// cambios de stats y estados volátiles (incluido el contador de Perish Song);
+/// con `baton_pass` el entrante los hereda (ver `VolatileStatus::baton_passed`).
+/// El entrante activa sus habilidades de entrada. Se ignora el cambio si el
+/// entrante está debilitado o ya en el campo.
+#[allow(clippy::too_many_arguments)]
+fn switch_in(
+    is_player: bool,
+    slot: usize,
+    new_index: usize,
+    baton_pass: bool,
+    battle_state: &mut BattleState,
+    player_team: &mut PlayerTeam,
+    opponent_team: &mut Vec<PokemonInstance>,
+    events: &mut Vec<BattleEvent>,
+) {
+    let (active_indices, team) = if is_player {
+        (&battle_state.player_active_indices, &mut player_team.active_members)
+    } else {
+        (&battle_state.opponent_active_indices, &mut *opponent_team)
+    };
+    let Some(old_index) = active_indices.get(slot).copied() else {
+        return;
+    };
+    if team.get(new_index).is_none_or(|p| p.current_hp == 0) || active_indices.contains(&new_index) {
+        return;
+    }
+
+    let mut passed = None;
+    if let Some(outgoing) = team.get_mut(old_index) {
+        if baton_pass {
+            passed = Some((
+                outgoing.battle_stages.clone(),
+                outgoing.volatile_status.as_ref().map(VolatileStatus::baton_passed),
+            ));
+        }
+        apply_switch_out_abilities(outgoing, events);
+        outgoing.reset_battle_stages();
+        events.push(BattleEvent::message("battle.withdrew", [
+            ("pokemon", outgoing.species.display_name.clone()),
+        ]));
+    }
+
+    if let Some((stages, volatile)) = passed {
+        let incoming = &mut team[new_index];
+        incoming.init_battle_stages();
+        if stages.is_some() {
+            incoming.battle_stages = stages;
+        }
+        if volatile.is_some() {
+            incoming.volatile_status = volatile;
+        }
+    }
+
+    send_in(is_player, slot, new_index, battle_state, player_team, opponent_team, events);
+}
+
+/// Manda al campo al Pokémon `new_index` en el slot indicado
+///
+/// No retira al ocupante anterior: se usa tanto en cambios como para reemplazar
+/// a un Pokémon debilitado. Retorna `false` si el Pokémon no puede entrar
+/// (no existe, está debilitado o ya está en el campo).
+pub(crate) fn send_in(
+    is_player: bool,
+    slot: usize,
+    new_index: usize,
+    battle_state: &mut BattleState,
+    player_team: &mut PlayerTeam,
+    opponent_team: &mut Vec<PokemonInstance>,
+    events: &mut Vec<BattleEvent>,
+) -> bool {
+    let (active_indices, team) = if is_player {
+        (&mut battle_state.player_active_indices, &mut player_team.active_members)
+    } else {
+        (&mut battle_state.opponent_active_indices, &mut *opponent_team)
+    };
+    if slot >= active_indices.len() {
+        return false;
+    }
+    if team.get(new_index).is_none_or(|p| p.current_hp == 0) || active_indices.contains(&new_index) {
+        return false;
+    }
+    active_indices[slot] = new_index;
+
+    let trainer = if is_player { None } else { battle_state.opponent_name.clone() };
+    let incoming = &mut team[new_index];
+    if incoming.battle_stages.is_none() {
+        incoming.init_battle_stages();
+    }
+    events.push(BattleEvent::Switched {
+        trainer,
+        pokemon: incoming.species.display_name.clone(),
+    });
+
+    enter_field(new_index, is_player, battle_state, player_team, opponent_team, events);
+    true
+}
+
+/// Efectos de entrar al campo: primero los hazards del lado y, si el Pokémon
+/// sigue en pie, sus habilidades de entrada
+pub(crate) fn enter_field(
+    index: usize,
+    is_player: bool,
+    battle_state: &mut BattleState,
+    player_team: &mut PlayerTeam,
+    opponent_team: &mut Vec<PokemonInstance>,
+    events: &mut Vec<BattleEvent>,
+) {
+    let side = battle_state.side_mut(BattleSide::of(is_player));
+    let team = if is_player { &mut player_team.active_members } else { &mut *opponent_team };
+    let Some(pokemon) = team.get_mut(index) else {
+        return;
+    };
+    apply_entry_hazards(pokemon, side, events);
+    if pokemon.current_hp == 0 {
+        return;
+    }
+
+    trigger_entry_abilities(index, is_player, battle_state, player_team, opponent_team, events);
+}
+
+/// Aplica las habilidades OnSwitch del Pokémon que sale del campo (Regenerator)
+fn apply_switch_out_abilities(pokemon: &mut PokemonInstance, events: &mut Vec<BattleEvent>) {
+    if pokemon.current_hp == 0 {
+        return;
+    }
+
+    for hook in get_ability_hooks(&pokemon.ability) {
+        if !matches!(hook.trigger, AbilityTrigger::OnSwitch) {
+            continue;
+        }
+        if let AbilityEffect::HealOnSwitch { fraction } = hook.effect {
+            let max_hp = pokemon.base_computed_stats.hp;
+            let heal_amount = ((max_hp as f32) * fraction) as u16;
+            let old_hp = pokemon.current_hp;
+            pokemon.current_hp = (pokemon.current_hp + heal_amount).min(max_hp);
+            let actual_heal = pokemon.current_hp - old_hp;
+
+            if actual_heal > 0 {
+                events.push(BattleEvent::Heal {
+                    target: pokemon.species.display_name.clone(),
+                    amount: actual_heal,
+                    remaining_hp: pokemon.current_hp,
+                    cause: HealCause::Ability { ability: pokemon.ability.clone() },
+                });
+            }
+        }
+    }
+}
+
 // MIGRADO: select_ai_move ahora está en systems/ai_system/selector.rs
 
-/// Ordena los candidatos por prioridad -> velocidad -> RNG
+/// Ordena las acciones: cambios primero, luego prioridad -> velocidad -> RNG
 fn sort_candidates(
-    candidates: &mut Vec<ActionCandidate>,
+    candidates: &mut Vec<BattleAction>,
     battle_state: &BattleState,
     rng: &mut StdRng,
 ) {
     // Asignar un número aleatorio a cada candidato para desempatar
-    let mut with_random: Vec<(ActionCandidate, u32)> = candidates
+    let mut with_random: Vec<(BattleAction, u32)> = candidates
         .drain(..)
         .map(|c| {
             let random = rng.gen::<u32>();
@@ -469,18 +1385,20 @@ fn sort_candidates(
     // Determinar si Trick Room está activo
     let trick_room_active = battle_state.trick_room_active;
 
-    // Ordenar por: prioridad (desc) -> velocidad (desc o asc según Trick Room) -> random (asc)
+    // Ordenar por: cambios -> prioridad (desc) -> velocidad (desc o asc según Trick Room) -> random (asc)
     with_random.sort_by(|a, b| {
-        // Primero por prioridad (mayor primero) - SIEMPRE
-        match b.0.priority.cmp(&a.0.priority) {
+        // Los cambios van antes que cualquier movimiento
+        let kind_ordering = b.0.is_switch().cmp(&a.0.is_switch());
+        // Luego por prioridad (mayor primero) - SIEMPRE
+        match kind_ordering.then(b.0.priority().cmp(&a.0.priority())) {
             std::cmp::Ordering::Equal => {
                 // Luego por velocidad
                 // Si Trick Room está activo, invertir el orden (menor velocidad primero)
                 // Si NO está activo, orden normal (mayor velocidad primero)
                 let speed_ordering = if trick_room_active {
-                    a.0.speed.cmp(&b.0.speed) // Invertido: menor primero
+                    a.0.speed().cmp(&b.0.speed()) // Invertido: menor primero
                 } else {
-                    b.0.speed.cmp(&a.0.speed) // Normal: mayor primero
+                    b.0.speed().cmp(&a.0.speed()) // Normal: mayor primero
                 };
 
                 match speed_ordering {
@@ -509,195 +1427,114 @@ fn handle_entry_hazards(
     battle_state: &mut BattleState,
     player_team: &mut PlayerTeam,
     opponent_team: &mut Vec<PokemonInstance>,
-    logs: &mut Vec<String>,
+    events: &mut Vec<BattleEvent>,
 ) {
     // Activar habilidades de entrada para todos los Pokémon activos
-
-    // Jugador
     for &idx in &battle_state.player_active_indices.clone() {
-        if let Some(player_mon) = player_team.active_members.get(idx) {
-            let ability_id = &player_mon.ability;
-            let hooks = get_ability_hooks(ability_id);
-
-            // Obtener el nombre de la habilidad para logs
-            let pokemon_name = player_mon.species.display_name.clone();
-            let ability_name = ability_id.clone(); // TODO: Cargar nombre real de la habilidad
-
-            // Filtrar solo hooks OnEntry
-            for hook in hooks.iter().filter(|h| matches!(h.trigger, AbilityTrigger::OnEntry)) {
-                match &hook.effect {
-                    AbilityEffect::SetWeather { weather, duration } => {
-                        // Solo activar si no es None
-                        if *weather != WeatherType::None {
-                            let weather_state = if *duration == 0 {
-                                WeatherState::new(*weather) // Duración por defecto (5 turnos)
-                            } else {
-                                WeatherState { weather_type: *weather, turns_remaining: *duration }
-                            };
-                            battle_state.weather = Some(weather_state);
-
-                            let weather_name = match weather {
-                                WeatherType::Sun => "harsh sunlight",
-                                WeatherType::Rain => "rain",
-                                WeatherType::Sandstorm => "sandstorm",
-                                WeatherType::Hail => "hail",
-                                WeatherType::None => continue,
-                            };
-                            logs.push(format!("{}'s {} set {}!",
-                                pokemon_name,
-                                ability_name,
-                                weather_name
-                            ));
-                        }
-                    },
-                    AbilityEffect::SetTerrain { terrain, duration } => {
-                        let terrain_state = if *duration == 0 {
-                            TerrainState::new(*terrain) // Duración por defecto (5 turnos)
-                        } else {
-                            TerrainState { terrain_type: *terrain, turns_remaining: *duration }
-                        };
-                        battle_state.terrain = Some(terrain_state);
-
-                        let terrain_name = match terrain {
-                            TerrainType::Electric => "Electric Terrain",
-                            TerrainType::Grassy => "Grassy Terrain",
-                            TerrainType::Misty => "Misty Terrain",
-                            TerrainType::Psychic => "Psychic Terrain",
-                        };
-                        logs.push(format!("{}'s {} set {}!",
-                            pokemon_name,
-                            ability_name,
-                            terrain_name
-                        ));
-                    },
-                    AbilityEffect::ModifyStatOnEntry { stat, stages, target } => {
-                        apply_on_entry_stat_change(
-                            stat,
-                            *stages,
-                            target,
-                            idx,
-                            true,
-                            battle_state,
-                            player_team,
-                            opponent_team,
-                            &pokemon_name,
-                            &ability_name,
-                            logs,
-                        );
-                    },
-                    AbilityEffect::Custom { ability_id: custom_id } => {
-                        if custom_id == "download" {
-                            // Download: Compara Defense vs Sp. Defense de los oponentes
-                            // y boosteaAtaque o Sp. Ataque según corresponda
-                            apply_download_boost(
-                                idx,
-                                true,
-                                battle_state,
-                                player_team,
-                                opponent_team,
-                                &pokemon_name,
-                                logs,
-                            );
-                        }
-                    },
-                    _ => {},
-                }
-            }
-        }
+        trigger_entry_abilities(idx, true, battle_state, player_team, opponent_team, events);
     }
-
-    // Oponente
     for &idx in &battle_state.opponent_active_indices.clone() {
-        if let Some(opponent_mon) = opponent_team.get(idx) {
-            let ability_id = &opponent_mon.ability;
-            let hooks = get_ability_hooks(ability_id);
-
-            // Obtener el nombre de la habilidad para logs
-            let pokemon_name = opponent_mon.species.display_name.clone();
-            let ability_name = ability_id.clone(); // TODO: Cargar nombre real de la habilidad
-
-            // Filtrar solo hooks OnEntry
-            for hook in hooks.iter().filter(|h| matches!(h.trigger, AbilityTrigger::OnEntry)) {
-                match &hook.effect {
-                    AbilityEffect::SetWeather { weather, duration } => {
-                        // Solo activar si no es None
-                        if *weather != WeatherType::None {
-                            let weather_state = if *duration == 0 {
-                                WeatherState::new(*weather) // Duración por defecto (5 turnos)
-                            } else {
-                                WeatherState { weather_type: *weather, turns_remaining: *duration }
-                            };
-                            battle_state.weather = Some(weather_state);
-
-                            let weather_name = match weather {
-                                WeatherType::Sun => "harsh sunlight",
-                                WeatherType::Rain => "rain",
-                                WeatherType::Sandstorm => "sandstorm",
-                                WeatherType::Hail => "hail",
-                                WeatherType::None => continue,
-                            };
-                            logs.push(format!("{}'s {} set {}!",
-                                pokemon_name,
-                                ability_name,
-                                weather_name
-                            ));
-                        }
-                    },
-                    AbilityEffect::SetTerrain { terrain, duration } => {
-                        let terrain_state = if *duration == 0 {
-                            TerrainState::new(*terrain) // Duración por defecto (5 turnos)
-                        } else {
-                            TerrainState { terrain_type: *terrain, turns_remaining: *duration }
-                        };
-                        battle_state.terrain = Some(terrain_state);
-
-                        let terrain_name = match terrain {
-                            TerrainType::Electric => "Electric Terrain",
-                            TerrainType::Grassy => "Grassy Terrain",
-                            TerrainType::Misty => "Misty Terrain",
-                            TerrainType::Psychic => "Psychic Terrain",
-                        };
-                        logs.push(format!("{}'s {} set {}!",
-                            pokemon_name,
-                            ability_name,
-                            terrain_name
-                        ));
-                    },
-                    AbilityEffect::ModifyStatOnEntry { stat, stages, target } => {
-                        apply_on_entry_stat_change(
-                            stat,
-                            *stages,
-                            target,
-                            idx,
-                            false,
-                            battle_state,
-                            player_team,
-                            opponent_team,
-                            &pokemon_name,
-                            &ability_name,
-                            logs,
-                        );
-                    },
-                    AbilityEffect::Custom { ability_id: custom_id } => {
-                        if custom_id == "download" {
-                            // Download: Compara Defense vs Sp. Defense de los oponentes
-                            // y boosteaAtaque o Sp. Ataque según corresponda
-                            apply_download_boost(
-                                idx,
-                                false,
-                                battle_state,
-                                player_team,
-                                opponent_team,
-                                &pokemon_name,
-                                logs,
-                            );
-                        }
-                    },
-                    _ => {},
+        trigger_entry_abilities(idx, false, battle_state, player_team, opponent_team, events);
+    }
+}
+
+/// Activa las habilidades OnEntry (clima, terreno, Intimidate, Download) de
+/// un Pokémon que acaba de entrar al campo, y después sus objetos de entrada
+/// (Booster Energy)
+///
+/// Es el mismo camino para el inicio de la batalla y para cualquier cambio,
+/// sea del jugador o de la IA.
+fn trigger_entry_abilities(
+    idx: usize,
+    is_player: bool,
+    battle_state: &mut BattleState,
+    player_team: &mut PlayerTeam,
+    opponent_team: &mut Vec<PokemonInstance>,
+    events: &mut Vec<BattleEvent>,
+) {
+    let pokemon = if is_player {
+        player_team.active_members.get(idx)
+    } else {
+        opponent_team.get(idx)
+    };
+    let Some(pokemon) = pokemon else {
+        return;
+    };
+
+    let ability_id = &pokemon.ability;
+    let hooks = get_ability_hooks(ability_id);
+
+    // Los eventos llevan el ID de la habilidad; al renderizar los logs se
+    // muestra su nombre real de abilities.json (ver `ability_display_name`)
+    let pokemon_name = pokemon.species.display_name.clone();
+    let ability_name = ability_id.clone();
+    // Copia del Pokémon para consultar su objeto (rocas, Terrain Extender) mientras se modifica el equipo
+    let setter = pokemon.clone();
+
+    // Filtrar solo hooks OnEntry
+    for hook in hooks.iter().filter(|h| matches!(h.trigger, AbilityTrigger::OnEntry)) {
+        match &hook.effect {
+            AbilityEffect::SetWeather { weather, duration } => {
+                // Solo activar si no es None
+                if *weather != WeatherType::None {
+                    // 5 turnos (8 con la roca correspondiente)
+                    battle_state.weather = Some(weather_state_for(&setter, *weather, *duration));
+
+                    events.push(BattleEvent::AbilityActivated {
+                        pokemon: pokemon_name.clone(),
+                        ability: ability_name.clone(),
+                    });
+                    events.push(BattleEvent::WeatherSet { weather: *weather });
                 }
-            }
+            },
+            AbilityEffect::SetTerrain { terrain, duration } => {
+                // 5 turnos (8 con Terrain Extender)
+                battle_state.terrain = Some(terrain_state_for(&setter, *terrain, *duration));
+
+                events.push(BattleEvent::AbilityActivated {
+                    pokemon: pokemon_name.clone(),
+                    ability: ability_name.clone(),
+                });
+                events.push(BattleEvent::TerrainSet { terrain: Some(*terrain) });
+            },
+            AbilityEffect::ModifyStatOnEntry { stat, stages, target } => {
+                apply_on_entry_stat_change(
+                    stat,
+                    *stages,
+                    target,
+                    idx,
+                    is_player,
+                    battle_state,
+                    player_team,
+                    opponent_team,
+                    &pokemon_name,
+                    &ability_name,
+                    events,
+                );
+            },
+            AbilityEffect::Custom { ability_id: custom_id } => {
+                if custom_id == "download" {
+                    // Download: Compara Defense vs Sp. Defense de los oponentes
+                    // y boosteaAtaque o Sp. Ataque según corresponda
+                    apply_download_boost(
+                        idx,
+                        is_player,
+                        battle_state,
+                        player_team,
+                        opponent_team,
+                        &pokemon_name,
+                        events,
+                    );
+                }
+            },
+            _ => {},
         }
     }
+
+    let team = if is_player { &mut player_team.active_members } else { &mut *opponent_team };
+    if let Some(pokemon) = team.get_mut(idx) {
+        events.extend(ItemProcessor::process_on_entry(pokemon).events);
+    }
 }
 
 /// Aplica cambios de stats por habilidades OnEntry (ej: Intimidate, Download)
@@ -712,13 +1549,18 @@ fn apply_on_entry_stat_change(
     opponent_team: &mut Vec<PokemonInstance>,
     pokemon_name: &str,
     ability_name: &str,
-    logs: &mut Vec<String>,
+    events: &mut Vec<BattleEvent>,
 ) {
+    let activation = BattleEvent::AbilityActivated {
+        pokemon: pokemon_name.to_string(),
+        ability: ability_name.to_string(),
+    };
+
     match target {
         StatChangeTarget::AllOpponents => {
             if is_player {
                 // Afectar a todos los oponentes
-                logs.push(format!("{}'s {}!", pokemon_name, ability_name));
+                events.push(activation.clone());
                 for &opp_idx in &battle_state.opponent_active_indices {
                     if let Some(opp) = opponent_team.get_mut(opp_idx) {
                         // Verificar inmunidad antes de aplicar
@@ -731,17 +1573,20 @@ fn apply_on_entry_stat_change(
                         );
 
                         if has_immunity {
-                            logs.push(format!("{}'s ability prevents stat loss!", opp.species.display_name));
+                            events.push(BattleEvent::message("battle.stat.loss_prevented", [
+                                ("pokemon", opp.species.display_name.clone()),
+                            ]));
+                        } else if stages < 0 && ItemProcessor::prevents_stat_loss(opp) {
+                            push_item_stat_loss_prevented(events, opp);
                         } else {
-                            apply_stat_stage_change(opp, stat, stages);
-                            let change_text = if stages > 0 { "rose" } else { "fell" };
-                            logs.push(format!("{}'s {} {}!", opp.species.display_name, stat, change_text));
+                            let applied = apply_stat_stage_change(opp, stat, stages);
+                            push_stat_change(events, opp, stat, applied);
                         }
                     }
                 }
             } else {
                 // Afectar a todos los jugadores
-                logs.push(format!("{}'s {}!", pokemon_name, ability_name));
+                events.push(activation.clone());
                 for &player_idx in &battle_state.player_active_indices {
                     if let Some(player) = player_team.active_members.get_mut(player_idx) {
                         // Verificar inmunidad antes de aplicar
@@ -754,11 +1599,14 @@ fn apply_on_entry_stat_change(
                         );
 
                         if has_immunity {
-                            logs.push(format!("{}'s ability prevents stat loss!", player.species.display_name));
+                            events.push(BattleEvent::message("battle.stat.loss_prevented", [
+                                ("pokemon", player.species.display_name.clone()),
+                            ]));
+                        } else if stages < 0 && ItemProcessor::prevents_stat_loss(player) {
+                            push_item_stat_loss_prevented(events, player);
                         } else {
-                            apply_stat_stage_change(player, stat, stages);
-                            let change_text = if stages > 0 { "rose" } else { "fell" };
-                            logs.push(format!("{}'s {} {}!", player.species.display_name, stat, change_text));
+                            let applied = apply_stat_stage_change(player, stat, stages);
+                            push_stat_change(events, player, stat, applied);
                         }
                     }
                 }
@@ -767,17 +1615,15 @@ fn apply_on_entry_stat_change(
         StatChangeTarget::User => {
             if is_player {
                 if let Some(player) = player_team.active_members.get_mut(user_idx) {
-                    apply_stat_stage_change(player, stat, stages);
-                    let change_text = if stages > 0 { "rose" } else { "fell" };
-                    logs.push(format!("{}'s {}!", pokemon_name, ability_name));
-                    logs.push(format!("{}'s {} {}!", pokemon_name, stat, change_text));
+                    let applied = apply_stat_stage_change(player, stat, stages);
+                    events.push(activation.clone());
+                    push_stat_change(events, player, stat, applied);
                 }
             } else {
                 if let Some(opp) = opponent_team.get_mut(user_idx) {
-                    apply_stat_stage_change(opp, stat, stages);
-                    let change_text = if stages > 0 { "rose" } else { "fell" };
-                    logs.push(format!("{}'s {}!", pokemon_name, ability_name));
-                    logs.push(format!("{}'s {} {}!", pokemon_name, stat, change_text));
+                    let applied = apply_stat_stage_change(opp, stat, stages);
+                    events.push(activation.clone());
+                    push_stat_change(events, opp, stat, applied);
                 }
             }
         },
@@ -795,7 +1641,7 @@ fn apply_download_boost(
     player_team: &mut PlayerTeam,
     opponent_team: &mut Vec<PokemonInstance>,
     pokemon_name: &str,
-    logs: &mut Vec<String>,
+    events: &mut Vec<BattleEvent>,
 ) {
     // Obtener las stats de los oponentes
     let (total_def, total_spdef, count) = if is_player {
@@ -832,58 +1678,79 @@ fn apply_download_boost(
     let avg_spdef = total_spdef / count;
 
     // Determinar qué stat boostear
-    let (stat_to_boost, stat_name) = if avg_def < avg_spdef {
-        ("attack", "Attack")
+    let stat_to_boost = if avg_def < avg_spdef {
+        "attack"
     } else {
-        ("special_attack", "Special Attack")
+        "special_attack"
     };
 
     // Aplicar el boost
     if is_player {
         if let Some(player) = player_team.active_members.get_mut(user_idx) {
-            apply_stat_stage_change(player, stat_to_boost, 1);
-            logs.push(format!("{}'s Download!", pokemon_name));
-            logs.push(format!("{}'s {} rose!", pokemon_name, stat_name));
+            let applied = apply_stat_stage_change(player, stat_to_boost, 1);
+            events.push(BattleEvent::AbilityActivated {
+                pokemon: pokemon_name.to_string(),
+                ability: "download".to_string(),
+            });
+            push_stat_change(events, player, stat_to_boost, applied);
         }
     } else {
         if let Some(opp) = opponent_team.get_mut(user_idx) {
-            apply_stat_stage_change(opp, stat_to_boost, 1);
-            logs.push(format!("{}'s Download!", pokemon_name));
-            logs.push(format!("{}'s {} rose!", pokemon_name, stat_name));
+            let applied = apply_stat_stage_change(opp, stat_to_boost, 1);
+            events.push(BattleEvent::AbilityActivated {
+                pokemon: pokemon_name.to_string(),
+                ability: "download".to_string(),
+            });
+            push_stat_change(events, opp, stat_to_boost, applied);
         }
     }
 }
 
 /// Aplica un cambio de stages a un stat específico
-fn apply_stat_stage_change(pokemon: &mut PokemonInstance, stat: &str, stages: i8) {
+/// Retorna el cambio efectivo (0 si hubo inmunidad o el stage ya estaba al límite)
+fn apply_stat_stage_change(pokemon: &mut PokemonInstance, stat: &str, stages: i8) -> i8 {
     // Verificar inmunidad a bajadas de stats
     if stages < 0 {
         let ability = &pokemon.ability;
         // Clear Body, White Smoke, Full Metal Body: inmunidad a todas las bajadas
         if ability == "clear-body" || ability == "white-smoke" || ability == "full-metal-body" {
-            return;
+            return 0;
         }
         // Hyper Cutter: inmunidad a bajada de Attack
         if ability == "hyper-cutter" && stat == "attack" {
-            return;
+            return 0;
         }
         // Keen Eye: inmunidad a bajada de Accuracy
         if ability == "keen-eye" && stat == "accuracy" {
-            return;
+            return 0;
         }
     }
 
-    if let Some(ref mut battle_stages) = pokemon.battle_stages {
-        match stat {
-            "attack" => battle_stages.attack = (battle_stages.attack + stages).clamp(-6, 6),
-            "defense" => battle_stages.defense = (battle_stages.defense + stages).clamp(-6, 6),
-            "special-attack" | "special_attack" => battle_stages.special_attack = (battle_stages.special_attack + stages).clamp(-6, 6),
-            "special-defense" | "special_defense" => battle_stages.special_defense = (battle_stages.special_defense + stages).clamp(-6, 6),
-            "speed" => battle_stages.speed = (battle_stages.speed + stages).clamp(-6, 6),
-            "accuracy" => battle_stages.accuracy = (battle_stages.accuracy + stages).clamp(-6, 6),
-            "evasion" => battle_stages.evasion = (battle_stages.evasion + stages).clamp(-6, 6),
-            _ => {},
-        }
+    if pokemon.battle_stages.is_none() {
+        pokemon.init_battle_stages();
+    }
+    match pokemon.battle_stages {
+        Some(ref mut battle_stages) => battle_stages.apply_change(&stat.replace('-', "_"), stages),
+        None => 0,
+    }
+}
+
+/// Mensaje de Clear Amulet al evitar una bajada de stats
+fn push_item_stat_loss_prevented(events: &mut Vec<BattleEvent>, pokemon: &PokemonInstance) {
+    events.push(BattleEvent::message("battle.item.stat_loss_prevented", [
+        ("pokemon", pokemon.species.display_name.clone()),
+        ("item", pokemon.held_item.as_deref().map(item_display_name).unwrap_or_default()),
+    ]));
+}
+
+/// Emite un StatStageChanged si el cambio tuvo efecto
+fn push_stat_change(events: &mut Vec<BattleEvent>, pokemon: &PokemonInstance, stat: &str, applied: i8) {
+    if applied != 0 {
+        events.push(BattleEvent::StatStageChanged {
+            target: pokemon.species.display_name.clone(),
+            stat: stat.replace('-', "_"),
+            stages: applied,
+        });
     }
 }
 
@@ -891,7 +1758,7 @@ fn apply_stat_stage_change(pokemon: &mut PokemonInstance, stat: &str, stages: i8
 fn apply_end_of_turn_abilities(
     pokemon: &mut PokemonInstance,
     battle_state: &BattleState,
-    logs: &mut Vec<String>,
+    events: &mut Vec<BattleEvent>,
 ) {
     use super::ability_logic::HealCondition;
 
@@ -904,22 +1771,16 @@ fn apply_end_of_turn_abilities(
             // Boost de stat al final del turno (Speed Boost)
             AbilityEffect::BoostStatEndOfTurn { stat, stages } => {
                 if let Some(ref mut battle_stages) = pokemon.battle_stages {
-                    match stat.as_str() {
-                        "speed" => {
-                            let old_stage = battle_stages.speed;
-                            battle_stages.speed = (battle_stages.speed + stages).clamp(-6, 6);
-                            if battle_stages.speed != old_stage {
-                                logs.push(format!("{}'s {} raised its Speed!", pokemon.species.display_name, ability_id));
-                            }
-                        },
-                        "attack" => {
-                            let old_stage = battle_stages.attack;
-                            battle_stages.attack = (battle_stages.attack + stages).clamp(-6, 6);
-                            if battle_stages.attack != old_stage {
-                                logs.push(format!("{}'s {} raised its Attack!", pokemon.species.display_name, ability_id));
-                            }
-                        },
-                        _ => {},
+                    let applied = match stat.as_str() {
+                        "speed" | "attack" => battle_stages.apply_change(stat, *stages),
+                        _ => 0,
+                    };
+                    if applied != 0 {
+                        events.push(BattleEvent::AbilityActivated {
+                            pokemon: pokemon.species.display_name.clone(),
+                            ability: ability_id.clone(),
+                        });
+                        push_stat_change(events, pokemon, stat, applied);
                     }
                 }
             },
@@ -942,7 +1803,12 @@ fn apply_end_of_turn_abilities(
                     let actual_heal = pokemon.current_hp - old_hp;
 
                     if actual_heal > 0 {
-                        logs.push(format!("{}'s {} restored HP!", pokemon.species.display_name, ability_id));
+                        events.push(BattleEvent::Heal {
+                            target: pokemon.species.display_name.clone(),
+                            amount: actual_heal,
+                            remaining_hp: pokemon.current_hp,
+                            cause: HealCause::Ability { ability: ability_id.clone() },
+                        });
                     }
                 }
             },
@@ -958,14 +1824,14 @@ fn process_end_of_turn_residuals(
     battle_state: &mut BattleState,
     player_team: &mut PlayerTeam,
     opponent_team: &mut Vec<PokemonInstance>,
-    logs: &mut Vec<String>,
+    events: &mut Vec<BattleEvent>,
 ) {
     // 1. Decrementar duración del clima
     if let Some(ref mut weather) = battle_state.weather {
         if weather.turns_remaining > 0 {
             weather.turns_remaining -= 1;
             if weather.turns_remaining == 0 {
-                logs.push("¡El clima volvió a la normalidad!".to_string());
+                events.push(BattleEvent::WeatherSet { weather: WeatherType::None });
                 battle_state.weather = None;
             }
         }
@@ -976,7 +1842,7 @@ fn process_end_of_turn_residuals(
         if terrain.turns_remaining > 0 {
             terrain.turns_remaining -= 1;
             if terrain.turns_remaining == 0 {
-                logs.push("¡El terreno volvió a la normalidad!".to_string());
+                events.push(BattleEvent::TerrainSet { terrain: None });
                 battle_state.terrain = None;
             }
         }
@@ -984,7 +1850,6 @@ fn process_end_of_turn_residuals(
 
     // 2.5. Aplicar curación de Grassy Terrain a Pokémon grounded
     if let Some(ref terrain) = battle_state.terrain {
-        use crate::models::TerrainType;
         use super::effects::is_grounded;
 
         if terrain.terrain_type == TerrainType::Grassy {
@@ -999,11 +1864,12 @@ fn process_end_of_turn_residuals(
                         let actual_heal = pokemon.current_hp - old_hp;
 
                         if actual_heal > 0 {
-                            logs.push(format!(
-                                "¡{} se curó {} HP por Grassy Terrain!",
-                                pokemon.species.display_name,
-                                actual_heal
-                            ));
+                            events.push(BattleEvent::Heal {
+                                target: pokemon.species.display_name.clone(),
+                                amount: actual_heal,
+                                remaining_hp: pokemon.current_hp,
+                                cause: HealCause::Terrain { terrain: TerrainType::Grassy },
+                            });
                         }
                     }
                 }
@@ -1020,11 +1886,12 @@ fn process_end_of_turn_residuals(
                         let actual_heal = pokemon.current_hp - old_hp;
 
                         if actual_heal > 0 {
-                            logs.push(format!(
-                                "¡{} se curó {} HP por Grassy Terrain!",
-                                pokemon.species.display_name,
-                                actual_heal
-                            ));
+                            events.push(BattleEvent::Heal {
+                                target: pokemon.species.display_name.clone(),
+                                amount: actual_heal,
+                                remaining_hp: pokemon.current_hp,
+                                cause: HealCause::Terrain { terrain: TerrainType::Grassy },
+                            });
                         }
                     }
                 }
@@ -1038,10 +1905,10 @@ fn process_end_of_turn_residuals(
         for &idx in &battle_state.player_active_indices.clone() {
             if let Some(pokemon) = player_team.active_members.get_mut(idx) {
                 if pokemon.current_hp > 0 {
-                    let (damage, weather_logs) = apply_weather_residuals(pokemon, battle_state.weather.as_ref());
-                    logs.extend(weather_logs);
+                    let (damage, weather_events) = apply_weather_residuals(pokemon, battle_state.weather.as_ref());
+                    events.extend(weather_events);
                     if damage > 0 && pokemon.current_hp == 0 {
-                        logs.push(format!("¡{} se debilitó!", pokemon.species.display_name));
+                        events.push(BattleEvent::Fainted { target: pokemon.species.display_name.clone() });
                     }
                 }
             }
@@ -1051,10 +1918,10 @@ fn process_end_of_turn_residuals(
         for &idx in &battle_state.opponent_active_indices.clone() {
             if let Some(pokemon) = opponent_team.get_mut(idx) {
                 if pokemon.current_hp > 0 {
-                    let (damage, weather_logs) = apply_weather_residuals(pokemon, battle_state.weather.as_ref());
-                    logs.extend(weather_logs);
+                    let (damage, weather_events) = apply_weather_residuals(pokemon, battle_state.weather.as_ref());
+                    events.extend(weather_events);
                     if damage > 0 && pokemon.current_hp == 0 {
-                        logs.push(format!("¡{} se debilitó!", pokemon.species.display_name));
+                        events.push(BattleEvent::Fainted { target: pokemon.species.display_name.clone() });
                     }
                 }
             }
@@ -1066,10 +1933,10 @@ fn process_end_of_turn_residuals(
     for &idx in &battle_state.player_active_indices.clone() {
         if let Some(pokemon) = player_team.active_members.get_mut(idx) {
             if pokemon.current_hp > 0 {
-                let (damage, status_logs) = apply_residual_effects(pokemon);
-                logs.extend(status_logs);
+                let (damage, status_events) = apply_residual_effects(pokemon);
+                events.extend(status_events);
                 if damage > 0 && pokemon.current_hp == 0 {
-                    logs.push(format!("¡{} se debilitó!", pokemon.species.display_name));
+                    events.push(BattleEvent::Fainted { target: pokemon.species.display_name.clone() });
                 }
             }
         }
@@ -1079,10 +1946,10 @@ fn process_end_of_turn_residuals(
     for &idx in &battle_state.opponent_active_indices.clone() {
         if let Some(pokemon) = opponent_team.get_mut(idx) {
             if pokemon.current_hp > 0 {
-                let (damage, status_logs) = apply_residual_effects(pokemon);
-                logs.extend(status_logs);
+                let (damage, status_events) = apply_residual_effects(pokemon);
+                events.extend(status_events);
                 if damage > 0 && pokemon.current_hp == 0 {
-                    logs.push(format!("¡{} se debilitó!", pokemon.species.display_name));
+                    events.push(BattleEvent::Fainted { target: pokemon.species.display_name.clone() });
                 }
             }
         }
@@ -1093,7 +1960,7 @@ fn process_end_of_turn_residuals(
     for &idx in &battle_state.player_active_indices.clone() {
         if let Some(pokemon) = player_team.active_members.get_mut(idx) {
             if pokemon.current_hp > 0 {
-                apply_end_of_turn_abilities(pokemon, battle_state, logs);
+                apply_end_of_turn_abilities(pokemon, battle_state, events);
             }
         }
     }
@@ -1102,7 +1969,7 @@ fn process_end_of_turn_residuals(
     for &idx in &battle_state.opponent_active_indices.clone() {
         if let Some(pokemon) = opponent_team.get_mut(idx) {
             if pokemon.current_hp > 0 {
-                apply_end_of_turn_abilities(pokemon, battle_state, logs);
+                apply_end_of_turn_abilities(pokemon, battle_state, events);
             }
         }
     }
@@ -1113,7 +1980,7 @@ fn process_end_of_turn_residuals(
         if let Some(pokemon) = player_team.active_members.get_mut(idx) {
             if pokemon.current_hp > 0 {
                 let item_result = ItemProcessor::process_end_of_turn(pokemon);
-                logs.extend(item_result.logs);
+                events.extend(item_result.events);
             }
         }
     }
@@ -1123,7 +1990,7 @@ fn process_end_of_turn_residuals(
         if let Some(pokemon) = opponent_team.get_mut(idx) {
             if pokemon.current_hp > 0 {
                 let item_result = ItemProcessor::process_end_of_turn(pokemon);
-                logs.extend(item_result.logs);
+                events.extend(item_result.events);
             }
         }
     }
@@ -1136,7 +2003,7 @@ fn process_end_of_turn_residuals(
     for &idx in &battle_state.player_active_indices.clone() {
         if let Some(pokemon) = player_team.active_members.get_mut(idx) {
             if pokemon.current_hp > 0 {
-                process_volatile_status_single(pokemon, logs, &mut leech_seed_heals);
+                process_volatile_status_single(pokemon, events, &mut leech_seed_heals);
             }
         }
     }
@@ -1145,7 +2012,7 @@ fn process_end_of_turn_residuals(
     for &idx in &battle_state.opponent_active_indices.clone() {
         if let Some(pokemon) = opponent_team.get_mut(idx) {
             if pokemon.current_hp > 0 {
-                process_volatile_status_single(pokemon, logs, &mut leech_seed_heals);
+                process_volatile_status_single(pokemon, events, &mut leech_seed_heals);
             }
         }
     }
@@ -1159,11 +2026,12 @@ fn process_end_of_turn_residuals(
                 if source_pokemon.id == source_id && source_pokemon.current_hp > 0 {
                     let max_hp = source_pokemon.base_computed_stats.hp;
                     source_pokemon.current_hp = (source_pokemon.current_hp + heal_amount).min(max_hp);
-                    logs.push(format!(
-                        "¡{} absorbe {} HP!",
-                        source_pokemon.species.display_name,
-                        heal_amount
-                    ));
+                    events.push(BattleEvent::Heal {
+                        target: source_pokemon.species.display_name.clone(),
+                        amount: heal_amount,
+                        remaining_hp: source_pokemon.current_hp,
+                        cause: HealCause::LeechSeed,
+                    });
                     healed = true;
                     break;
                 }
@@ -1177,11 +2045,12 @@ fn process_end_of_turn_residuals(
                     if source_pokemon.id == source_id && source_pokemon.current_hp > 0 {
                         let max_hp = source_pokemon.base_computed_stats.hp;
                         source_pokemon.current_hp = (source_pokemon.current_hp + heal_amount).min(max_hp);
-                        logs.push(format!(
-                            "¡{} absorbe {} HP!",
-                            source_pokemon.species.display_name,
-                            heal_amount
-                        ));
+                        events.push(BattleEvent::Heal {
+                            target: source_pokemon.species.display_name.clone(),
+                            amount: heal_amount,
+                            remaining_hp: source_pokemon.current_hp,
+                            cause: HealCause::LeechSeed,
+                        });
                         break;
                     }
                 }
@@ -1195,16 +2064,22 @@ fn process_end_of_turn_residuals(
 
         if battle_state.trick_room_turns_left == 0 {
             battle_state.trick_room_active = false;
-            logs.push("¡Las dimensiones retornaron a la normalidad!".to_string());
+            events.push(BattleEvent::message("battle.trick_room.ended", []));
         } else {
-            logs.push(format!(
-                "Trick Room activo ({} turnos restantes)",
-                battle_state.trick_room_turns_left
-            ));
+            events.push(BattleEvent::message("battle.trick_room.active", [
+                ("turns", battle_state.trick_room_turns_left.to_string()),
+            ]));
         }
     }
 
-    // 9. Limpiar efectos de redirección al final del turno
+    // 9. Decrementar las condiciones de cada lado (pantallas, Tailwind)
+    for side in [BattleSide::Player, BattleSide::Opponent] {
+        for condition in tick_side_conditions(battle_state.side_mut(side)) {
+            events.push(BattleEvent::SideConditionEnded { side, condition });
+        }
+    }
+
+    // 10. Limpiar efectos de redirección al final del turno
     use crate::battle::systems::redirection_system::clear_redirection;
     clear_redirection(battle_state);
 }
@@ -1214,7 +2089,7 @@ fn process_end_of_turn_residuals(
 /// - Perish Song: Decrementar contador y KO si llega a 0
 fn process_volatile_status_single(
     pokemon: &mut PokemonInstance,
-    logs: &mut Vec<String>,
+    events: &mut Vec<BattleEvent>,
     leech_seed_heals: &mut Vec<(String, u16)>,
 ) {
     if let Some(ref mut volatile) = pokemon.volatile_status {
@@ -1226,11 +2101,12 @@ fn process_volatile_status_single(
             pokemon.current_hp = pokemon.current_hp.saturating_sub(damage);
             let actual_damage = old_hp - pokemon.current_hp;
 
-            logs.push(format!(
-                "¡{} pierde {} HP por Leech Seed!",
-                pokemon.species.display_name,
-                actual_damage
-            ));
+            events.push(BattleEvent::Damage {
+                target: pokemon.species.display_name.clone(),
+                amount: actual_damage,
+                remaining_hp: pokemon.current_hp,
+                cause: DamageCause::LeechSeed,
+            });
 
             // Registrar curación para el source
             if let Some(ref source_id) = volatile.leech_seed_source {
@@ -1242,19 +2118,24 @@ fn process_volatile_status_single(
         if let Some(ref mut count) = volatile.perish_count {
             if *count > 0 {
                 *count -= 1;
-                logs.push(format!(
-                    "¡El contador de Perish Song de {} es {}!",
-                    pokemon.species.display_name,
-                    count
-                ));
+                events.push(BattleEvent::message("battle.perish_count", [
+                    ("pokemon", pokemon.species.display_name.clone()),
+                    ("count", count.to_string()),
+                ]));
 
                 // Si llega a 0, debilitar al Pokémon
                 if *count == 0 {
+                    let lost_hp = pokemon.current_hp;
                     pokemon.current_hp = 0;
-                    logs.push(format!(
-                        "¡{} fue debilitado por Perish Song!",
-                        pokemon.species.display_name
-                    ));
+                    events.push(BattleEvent::Damage {
+                        target: pokemon.species.display_name.clone(),
+                        amount: lost_hp,
+                        remaining_hp: 0,
+                        cause: DamageCause::PerishSong,
+                    });
+                    events.push(BattleEvent::Fainted {
+                        target: pokemon.species.display_name.clone(),
+                    });
                 }
             }
         }
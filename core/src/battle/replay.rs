@@ -0,0 +1,424 @@
+//! Grabación y reproducción de batallas
+//!
+//! Un `BattleReplay` guarda la foto inicial de la batalla (estado y equipo del
+//! jugador) y, por cada turno, la semilla del RNG, las acciones del jugador, lo
+//! que eligió la IA y el resultado observado (logs, HP y outcome). Con eso
+//! `verify_replay` vuelve a ejecutar cada turno por `execute_turn` y comprueba
+//! que todo coincide, lo que permite reproducir exactamente un reporte de bug.
+//!
+//! El reemplazo de un Pokémon debilitado ocurre fuera de los turnos: se graba
+//! como un paso `Replacement` que se re-simula con `replace_fainted` (hazards
+//! y habilidades de entrada incluidos) y se verifica igual que un turno. La
+//! elección del relevo tras un U-turn o similar pasa por el pipeline: se graba
+//! como un turno cuya única acción es el cambio y se reproduce con
+//! `resume_turn`.
+
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+use crate::game::{BattleState, PendingPlayerAction, PlayerTeam};
+use crate::models::MoveData;
+use super::orchestration::{finish_turn, prepare_turn, replace_fainted, resume_turn, run_turn};
+use super::{BattleOutcome, TurnResult};
+
+/// Grabación completa de una batalla
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BattleReplay {
+    /// Estado de la batalla antes del primer turno
+    pub initial_state: BattleState,
+    /// Equipo del jugador antes del primer turno
+    pub initial_team: PlayerTeam,
+    /// Pasos grabados, en orden
+    #[serde(default)]
+    pub steps: Vec<ReplayStep>,
+}
+
+/// Paso de un replay
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum ReplayStep {
+    /// Turno ejecutado por el pipeline
+    Turn(ReplayTurn),
+    /// Cambio forzado del jugador tras un debilitamiento
+    Replacement(ReplayReplacement),
+    /// Foto del estado tras un cambio hecho fuera del pipeline
+    ///
+    /// Ya no se graba (los cambios forzados son `Replacement`), pero los
+    /// replays guardados por versiones anteriores aún pueden traerlo.
+    Checkpoint {
+        battle_state: Box<BattleState>,
+        team: PlayerTeam,
+    },
+}
+
+/// Entradas y resultado observado de un turno
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReplayTurn {
+    /// Semilla del RNG usado en el turno
+    pub rng_seed: u64,
+    /// Acciones del jugador
+    pub player_actions: Vec<PendingPlayerAction>,
+    /// Movimientos elegidos por la IA del oponente
+    #[serde(default)]
+    pub opponent_actions: Vec<PendingPlayerAction>,
+    /// Logs producidos por el turno
+    pub logs: Vec<String>,
+    /// HP del equipo activo del jugador al terminar el turno
+    pub player_hp: Vec<u16>,
+    /// HP del equipo oponente al terminar el turno
+    pub opponent_hp: Vec<u16>,
+    /// Resultado del turno
+    pub outcome: BattleOutcome,
+}
+
+/// Entradas y resultado observado de un cambio forzado
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReplayReplacement {
+    /// Slot del campo que se rellena
+    pub slot: usize,
+    /// Índice en el equipo del Pokémon que entra
+    pub new_index: usize,
+    /// Logs producidos al entrar
+    pub logs: Vec<String>,
+    /// HP del equipo activo del jugador tras el cambio
+    pub player_hp: Vec<u16>,
+    /// HP del equipo oponente tras el cambio
+    pub opponent_hp: Vec<u16>,
+    /// Resultado del cambio (`Continue` salvo que los hazards debiliten al reemplazo)
+    pub outcome: BattleOutcome,
+}
+
+/// Primera diferencia encontrada al reproducir un replay
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayMismatch {
+    /// Índice del paso en `BattleReplay::steps`
+    pub step: usize,
+    /// Qué dato difiere ("logs", "player_hp", ...)
+    pub field: &'static str,
+    /// Valor grabado
+    pub expected: String,
+    /// Valor obtenido al re-simular
+    pub actual: String,
+}
+
+impl std::fmt::Display for ReplayMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "replay diverged at step {} ({}): expected {}, got {}",
+            self.step, self.field, self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for ReplayMismatch {}
+
+impl BattleReplay {
+    /// Empieza a grabar una batalla a partir de su estado inicial
+    pub fn new(battle_state: &BattleState, team: &PlayerTeam) -> Self {
+        Self {
+            initial_state: battle_state.clone(),
+            initial_team: team.clone(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Graba un turno ya ejecutado (llamar después de `finish_turn`)
+    pub fn record_turn(
+        &mut self,
+        rng_seed: u64,
+        player_actions: Vec<PendingPlayerAction>,
+        turn_result: &TurnResult,
+        team: &PlayerTeam,
+        battle_state: &BattleState,
+    ) {
+        self.steps.push(ReplayStep::Turn(ReplayTurn {
+            rng_seed,
+            player_actions,
+            opponent_actions: turn_result.opponent_actions.clone(),
+            logs: turn_result.logs.clone(),
+            player_hp: team_hp(team),
+            opponent_hp: opponent_hp(battle_state),
+            outcome: turn_result.outcome.clone(),
+        }));
+    }
+
+    /// Graba un cambio forzado ya resuelto (llamar después de `replace_fainted`)
+    pub fn record_replacement(
+        &mut self,
+        slot: usize,
+        new_index: usize,
+        result: &TurnResult,
+        team: &PlayerTeam,
+        battle_state: &BattleState,
+    ) {
+        self.steps.push(ReplayStep::Replacement(ReplayReplacement {
+            slot,
+            new_index,
+            logs: result.logs.clone(),
+            player_hp: team_hp(team),
+            opponent_hp: opponent_hp(battle_state),
+            outcome: result.outcome.clone(),
+        }));
+    }
+}
+
+/// Re-simula un replay turno a turno y verifica logs, HP y decisiones de la IA
+///
+/// Cada turno se ejecuta igual que en el servidor (`prepare_turn` → `run_turn`
+/// → `finish_turn`, o `resume_turn` → `finish_turn` si había un relevo
+/// pendiente) con el RNG sembrado con la semilla grabada. Los cambios forzados
+/// se re-simulan con `replace_fainted`.
+///
+/// # Errors
+///
+/// Retorna la primera diferencia entre lo grabado y lo re-simulado.
+pub fn verify_replay(
+    replay: &BattleReplay,
+    moves: &HashMap<String, MoveData>,
+) -> Result<(), ReplayMismatch> {
+    let mut battle_state = replay.initial_state.clone();
+    let mut team = replay.initial_team.clone();
+
+    for (step, entry) in replay.steps.iter().enumerate() {
+        match entry {
+            ReplayStep::Checkpoint { battle_state: snapshot, team: snapshot_team } => {
+                battle_state = snapshot.as_ref().clone();
+                team = snapshot_team.clone();
+            }
+            ReplayStep::Replacement(replacement) => {
+                let result = replace_fainted(&mut team, &mut battle_state, replacement.slot, replacement.new_index);
+
+                check(step, "logs", &replacement.logs, &result.logs)?;
+                check(step, "player_hp", &replacement.player_hp, &team_hp(&team))?;
+                check(step, "opponent_hp", &replacement.opponent_hp, &opponent_hp(&battle_state))?;
+                check(step, "outcome", &replacement.outcome, &result.outcome)?;
+            }
+            ReplayStep::Turn(turn) => {
+                let mut rng = StdRng::seed_from_u64(turn.rng_seed);
+                let result = if battle_state.pending_pivot.is_some() {
+                    let new_index = turn.player_actions.first().and_then(|a| a.switch_to).unwrap_or(usize::MAX);
+                    resume_turn(&mut team, &mut battle_state, &mut rng, new_index)
+                } else {
+                    battle_state.pending_player_actions = turn.player_actions.clone();
+                    prepare_turn(&mut team, &mut battle_state, moves);
+                    run_turn(&mut team, &mut battle_state, &mut rng, Some(moves))
+                };
+                finish_turn(&mut battle_state, &result);
+
+                check(step, "opponent_actions", &turn.opponent_actions, &result.opponent_actions)?;
+                check(step, "logs", &turn.logs, &result.logs)?;
+                check(step, "player_hp", &turn.player_hp, &team_hp(&team))?;
+                check(step, "opponent_hp", &turn.opponent_hp, &opponent_hp(&battle_state))?;
+                check(step, "outcome", &turn.outcome, &result.outcome)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn check<T: PartialEq + std::fmt::Debug>(
+    step: usize,
+    field: &'static str,
+    expected: &T,
+    actual: &T,
+) -> Result<(), ReplayMismatch> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(ReplayMismatch {
+            step,
+            field,
+            expected: format!("{:?}", expected),
+            actual: format!("{:?}", actual),
+        })
+    }
+}
+
+fn team_hp(team: &PlayerTeam) -> Vec<u16> {
+    team.active_members.iter().map(|p| p.current_hp).collect()
+}
+
+fn opponent_hp(battle_state: &BattleState) -> Vec<u16> {
+    battle_state.opponent_team.iter().map(|p| p.current_hp).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::create_pokemon_instance;
+    use crate::models::{BattleFormat, MoveMeta, PokemonSpecies, PokemonType, Stats};
+
+    fn species(id: &str, name: &str) -> PokemonSpecies {
+        PokemonSpecies {
+            species_id: id.to_string(),
+            display_name: name.to_string(),
+            generation: 1,
+            primary_type: PokemonType::Normal,
+            secondary_type: None,
+            base_stats: Stats {
+                hp: 80,
+                attack: 80,
+                defense: 80,
+                special_attack: 80,
+                special_defense: 80,
+                speed: 80,
+            },
+            move_pool: vec!["tackle".to_string()],
+            possible_abilities: vec!["run-away".to_string()],
+            is_starter_candidate: false,
+            evolutions: Vec::new(),
+            ev_yield: Stats::zero(),
+            gender_rate: 4,
+            mega_forms: Vec::new(),
+        }
+    }
+
+    fn moves() -> HashMap<String, MoveData> {
+        let tackle = MoveData {
+            id: "tackle".to_string(),
+            name: "Tackle".to_string(),
+            r#type: "Normal".to_string(),
+            power: Some(40),
+            accuracy: Some(95),
+            priority: 0,
+            pp: 35,
+            damage_class: "physical".to_string(),
+            meta: MoveMeta::default(),
+            stat_changes: vec![],
+            target: "selected-pokemon".to_string(),
+        };
+        HashMap::from([("tackle".to_string(), tackle)])
+    }
+
+    /// Juega `turns` turnos de una batalla salvaje grabándolos como lo hace el servidor
+    fn record_battle(turns: usize) -> BattleReplay {
+        record_battle_with_levels(&[30], turns)
+    }
+
+    /// Como `record_battle`, con un Pokémon del jugador por cada nivel de
+    /// `levels`: cuando el activo se debilita entra el siguiente con `replace_fainted`
+    fn record_battle_with_levels(levels: &[u8], turns: usize) -> BattleReplay {
+        let moves = moves();
+        let mut team = PlayerTeam::new();
+        for (seed, level) in levels.iter().enumerate() {
+            team.add_member(create_pokemon_instance(&species("rattata", "Rattata"), *level, 1 + seed as u64, false, &[], Some(&moves)));
+        }
+        let wild = create_pokemon_instance(&species("pidgey", "Pidgey"), 30, 2, false, &[], Some(&moves));
+
+        let mut battle_state = BattleState::new(0, vec![wild], "Pidgey".to_string(), BattleFormat::Single, false);
+        battle_state.rng_seed = 99;
+        battle_state.player_side.stealth_rock = true;
+        let mut replay = BattleReplay::new(&battle_state, &team);
+
+        for _ in 0..turns {
+            battle_state.pending_player_actions = vec![PendingPlayerAction {
+                user_index: 0,
+                move_id: "tackle".to_string(),
+                target_position: None,
+                switch_to: None,
+                terastallize: false,
+                mega_evolve: false,
+            }];
+            let player_actions = battle_state.pending_player_actions.clone();
+            let turn_seed = battle_state.next_turn_seed();
+
+            prepare_turn(&mut team, &mut battle_state, &moves);
+            let mut rng = StdRng::seed_from_u64(turn_seed);
+            let result = run_turn(&mut team, &mut battle_state, &mut rng, Some(&moves));
+            finish_turn(&mut battle_state, &result);
+            replay.record_turn(turn_seed, player_actions, &result, &team, &battle_state);
+
+            if result.outcome == BattleOutcome::PlayerMustSwitch {
+                let next = team.active_members.iter().position(|p| p.current_hp > 0).unwrap();
+                let replacement = replace_fainted(&mut team, &mut battle_state, 0, next);
+                replay.record_replacement(0, next, &replacement, &team, &battle_state);
+            } else if result.outcome != BattleOutcome::Continue {
+                break;
+            }
+        }
+
+        replay
+    }
+
+    fn replacement_step(replay: &BattleReplay) -> usize {
+        replay
+            .steps
+            .iter()
+            .position(|step| matches!(step, ReplayStep::Replacement(_)))
+            .expect("the lead should have fainted")
+    }
+
+    #[test]
+    fn test_recorded_battle_replays_exactly() {
+        let replay = record_battle(3);
+        assert!(!replay.steps.is_empty());
+        assert_eq!(verify_replay(&replay, &moves()), Ok(()));
+    }
+
+    #[test]
+    fn test_records_ai_choice() {
+        let replay = record_battle(1);
+        match &replay.steps[0] {
+            ReplayStep::Turn(turn) => {
+                assert_eq!(turn.opponent_actions.len(), 1);
+                assert_eq!(turn.opponent_actions[0].move_id, "tackle");
+            }
+            other => panic!("expected a turn, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_detects_tampered_hp() {
+        let mut replay = record_battle(1);
+        if let ReplayStep::Turn(turn) = &mut replay.steps[0] {
+            turn.player_hp[0] += 1;
+        }
+
+        let mismatch = verify_replay(&replay, &moves()).unwrap_err();
+        assert_eq!(mismatch.step, 0);
+        assert_eq!(mismatch.field, "player_hp");
+    }
+
+    #[test]
+    fn test_replays_through_a_faint_and_a_forced_switch() {
+        let replay = record_battle_with_levels(&[3, 30], 6);
+        let step = replacement_step(&replay);
+        assert!(replay.steps.len() > step + 1, "the battle should go on after the switch");
+
+        // El reemplazo recibe Stealth Rock al entrar y eso queda grabado
+        let ReplayStep::Replacement(replacement) = &replay.steps[step] else {
+            unreachable!();
+        };
+        assert_eq!(replacement.outcome, BattleOutcome::Continue);
+        assert!(!replacement.logs.is_empty());
+
+        assert_eq!(verify_replay(&replay, &moves()), Ok(()));
+    }
+
+    #[test]
+    fn test_detects_tampered_replacement() {
+        let mut replay = record_battle_with_levels(&[3, 30], 6);
+        let step = replacement_step(&replay);
+        if let ReplayStep::Replacement(replacement) = &mut replay.steps[step] {
+            // Como si el reemplazo no hubiera recibido el daño de Stealth Rock
+            replacement.player_hp[1] += 10;
+        }
+
+        let mismatch = verify_replay(&replay, &moves()).unwrap_err();
+        assert_eq!(mismatch.step, step);
+        assert_eq!(mismatch.field, "player_hp");
+    }
+
+    #[test]
+    fn test_replay_survives_serde_round_trip() {
+        let replay = record_battle(2);
+        let json = serde_json::to_string(&replay).unwrap();
+        let decoded: BattleReplay = serde_json::from_str(&json).unwrap();
+        assert_eq!(verify_replay(&decoded, &moves()), Ok(()));
+    }
+}
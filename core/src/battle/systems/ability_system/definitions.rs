@@ -0,0 +1,141 @@
+//! Definiciones de habilidades cargadas desde `abilities.json`
+//!
+//! Cada entrada aporta el nombre visible y la descripción de una habilidad, y
+//! opcionalmente sus hooks. Así los diseñadores pueden ajustar valores o añadir
+//! habilidades puramente de datos sin recompilar. Las habilidades sin hooks en
+//! el JSON (o que no aparecen en él) usan los hooks escritos en código.
+//!
+//! El servidor registra las definiciones una sola vez al arrancar; si nunca se
+//! registran, todo el sistema funciona con el registry en código.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use serde::{Deserialize, Serialize};
+use crate::battle::events::display_name_from_id;
+use super::registry::AbilityHook;
+
+/// Definición de una habilidad en `abilities.json`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AbilityDefinition {
+    /// ID de la habilidad en kebab-case (ej: "intimidate")
+    pub id: String,
+    /// Nombre visible (ej: "Intimidate")
+    pub name: String,
+    /// Descripción corta del efecto
+    #[serde(default)]
+    pub description: String,
+    /// Hooks de la habilidad; vacío = usar los hooks escritos en código
+    #[serde(default)]
+    pub hooks: Vec<AbilityHook>,
+}
+
+/// Definiciones registradas al arrancar, indexadas por ID
+static ABILITY_DEFINITIONS: OnceLock<HashMap<String, AbilityDefinition>> = OnceLock::new();
+
+/// Parsea el contenido de `abilities.json` y lo indexa por ID
+pub fn parse_ability_definitions(json: &str) -> Result<HashMap<String, AbilityDefinition>, serde_json::Error> {
+    let definitions: Vec<AbilityDefinition> = serde_json::from_str(json)?;
+    Ok(definitions
+        .into_iter()
+        .map(|definition| (definition.id.clone(), definition))
+        .collect())
+}
+
+/// Registra las definiciones de habilidades para todo el proceso
+///
+/// Solo la primera llamada tiene efecto; retorna false si ya había definiciones.
+pub fn register_ability_definitions(definitions: HashMap<String, AbilityDefinition>) -> bool {
+    ABILITY_DEFINITIONS.set(definitions).is_ok()
+}
+
+/// Definición registrada de una habilidad, si existe
+pub fn ability_definition(ability_id: &str) -> Option<&'static AbilityDefinition> {
+    ABILITY_DEFINITIONS.get()?.get(ability_id)
+}
+
+/// Nombre visible de una habilidad
+///
+/// Usa el nombre de `abilities.json`; si la habilidad no está definida, lo
+/// deriva del ID ("sand-veil" -> "Sand Veil").
+pub fn ability_display_name(ability_id: &str) -> String {
+    ability_definition(ability_id)
+        .map(|definition| definition.name.clone())
+        .unwrap_or_else(|| display_name_from_id(ability_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::registry::{builtin_ability_hooks, AbilityEffect, AbilityTrigger};
+    use crate::models::WeatherType;
+
+    #[test]
+    fn test_parse_definition_with_hooks() {
+        let json = r#"[
+            {
+                "id": "drought",
+                "name": "Drought",
+                "description": "Turns the sunlight harsh.",
+                "hooks": [
+                    { "trigger": "OnEntry", "effect": { "type": "SetWeather", "weather": "Sun", "duration": 8 } }
+                ]
+            },
+            { "id": "download", "name": "Download" }
+        ]"#;
+        let definitions = parse_ability_definitions(json).expect("JSON válido");
+
+        let drought = &definitions["drought"];
+        assert_eq!(drought.name, "Drought");
+        assert_eq!(drought.hooks.len(), 1);
+        assert_eq!(drought.hooks[0].trigger, AbilityTrigger::OnEntry);
+        assert!(matches!(
+            drought.hooks[0].effect,
+            AbilityEffect::SetWeather { weather: WeatherType::Sun, duration: 8 }
+        ));
+
+        // Sin hooks ni descripción: se usan los valores por defecto
+        let download = &definitions["download"];
+        assert!(download.description.is_empty());
+        assert!(download.hooks.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_effect_is_rejected() {
+        let json = r#"[{ "id": "x", "name": "X", "hooks": [
+            { "trigger": "OnEntry", "effect": { "type": "DoesNotExist" } }
+        ]}]"#;
+        assert!(parse_ability_definitions(json).is_err());
+    }
+
+    #[test]
+    fn test_shipped_abilities_json_matches_builtin_hooks() {
+        let json = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../server/data/abilities.json"));
+        let definitions = parse_ability_definitions(json).expect("abilities.json debe ser válido");
+
+        for definition in definitions.values() {
+            assert!(!definition.name.is_empty(), "{} debe tener nombre", definition.id);
+            if definition.hooks.is_empty() {
+                continue;
+            }
+            // Los valores pueden ajustarse, pero los triggers y efectos deben
+            // coincidir con los que el motor sabe aplicar en código
+            let builtin = builtin_ability_hooks(&definition.id);
+            assert_eq!(definition.hooks.len(), builtin.len(), "{}", definition.id);
+            for (hook, expected) in definition.hooks.iter().zip(&builtin) {
+                assert_eq!(hook.trigger, expected.trigger, "{}", definition.id);
+                assert_eq!(
+                    std::mem::discriminant(&hook.effect),
+                    std::mem::discriminant(&expected.effect),
+                    "{}",
+                    definition.id
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_display_name_falls_back_to_id() {
+        // Sin definiciones registradas, el nombre se deriva del ID
+        assert_eq!(ability_display_name("sand-veil"), "Sand Veil");
+    }
+}
@@ -2,10 +2,12 @@
 //!
 //! Este sistema es responsable de:
 //! - Procesar hooks de habilidades
+//! - Cargar nombres, descripciones y hooks desde `abilities.json`
 //! - Activar habilidades según triggers
 //! - Modificar stats, prioridad, velocidad según habilidades
 
 pub mod registry;
+pub mod definitions;
 pub mod triggers;
 pub mod processor;
 
@@ -15,13 +17,27 @@ mod tests;
 // Re-exportar tipos y funciones principales del registry
 pub use registry::{
     AbilityTrigger, AbilityEffect, AbilityHook, StatChangeTarget, HealCondition,
-    get_ability_hooks,
+    get_ability_hooks, builtin_ability_hooks,
+};
+
+// Re-exportar las definiciones cargadas de abilities.json
+pub use definitions::{
+    AbilityDefinition,
+    parse_ability_definitions,
+    register_ability_definitions,
+    ability_definition,
+    ability_display_name,
 };
 
 // Re-exportar funciones del processor
 pub use processor::{
     get_speed_with_abilities,
     get_priority_with_abilities,
+    prevents_confusion,
+    prevents_infatuation,
+    gender_damage_multiplier,
+    accuracy_multiplier,
+    evasion_multiplier,
 };
 
 // NOTA: handle_entry_hazards, apply_end_of_turn_abilities
@@ -2,18 +2,20 @@
 //!
 //! Este módulo procesa los hooks de habilidades en diferentes momentos de la batalla.
 
-use crate::models::{PokemonInstance, MoveData};
+use crate::models::{BattleSide, Gender, PokemonInstance, MoveData, WeatherState};
 use crate::game::BattleState;
 use super::{get_ability_hooks, AbilityTrigger, AbilityEffect};
 use super::super::damage_system::get_effective_speed;
 use super::super::item_system::ItemProcessor;
+use super::super::field_system::has_tailwind;
 
 /// Calcula la velocidad de un Pokémon incluyendo modificadores de habilidades e items
 ///
-/// Aplica multiplicadores basados en clima/terreno (Chlorophyll, Swift Swim, etc.)
-/// y items (Choice Scarf)
+/// Aplica multiplicadores basados en clima/terreno (Chlorophyll, Swift Swim, etc.),
+/// items (Choice Scarf) y el Tailwind del lado `side` en el que está el Pokémon
 pub fn get_speed_with_abilities(
     pokemon: &PokemonInstance,
+    side: BattleSide,
     battle_state: &BattleState,
 ) -> u16 {
     let base_speed = get_effective_speed(pokemon) as u16;
@@ -49,6 +51,11 @@ pub fn get_speed_with_abilities(
     let item_speed_multiplier = ItemProcessor::get_speed_multiplier(pokemon);
     final_speed *= item_speed_multiplier;
 
+    // Tailwind duplica la velocidad de todo el lado
+    if has_tailwind(battle_state.side(side)) {
+        final_speed *= 2.0;
+    }
+
     final_speed as u16
 }
 
@@ -106,6 +113,64 @@ pub fn get_priority_with_abilities(
     base_priority
 }
 
+/// Si la habilidad del Pokémon impide que se confunda (Own Tempo)
+pub fn prevents_confusion(pokemon: &PokemonInstance) -> bool {
+    get_ability_hooks(&pokemon.ability)
+        .iter()
+        .any(|hook| matches!(hook.effect, AbilityEffect::PreventConfusion))
+}
+
+/// Si la habilidad del Pokémon impide que se enamore (Oblivious)
+pub fn prevents_infatuation(pokemon: &PokemonInstance) -> bool {
+    get_ability_hooks(&pokemon.ability)
+        .iter()
+        .any(|hook| matches!(hook.effect, AbilityEffect::PreventInfatuation))
+}
+
+/// Multiplicador de daño por el género del objetivo (Rivalry)
+///
+/// No cambia nada si alguno de los dos no tiene género.
+pub fn gender_damage_multiplier(attacker: &PokemonInstance, defender: &PokemonInstance) -> f32 {
+    if attacker.gender == Gender::Genderless || defender.gender == Gender::Genderless {
+        return 1.0;
+    }
+    get_ability_hooks(&attacker.ability)
+        .iter()
+        .map(|hook| match hook.effect {
+            AbilityEffect::GenderDamageModifier { same_gender, .. } if attacker.gender == defender.gender => same_gender,
+            AbilityEffect::GenderDamageModifier { opposite_gender, .. } => opposite_gender,
+            _ => 1.0,
+        })
+        .product()
+}
+
+/// Multiplicador de precisión por la habilidad del atacante (Compound Eyes, Hustle)
+pub fn accuracy_multiplier(attacker: &PokemonInstance, move_data: &MoveData) -> f32 {
+    get_ability_hooks(&attacker.ability)
+        .iter()
+        .map(|hook| match hook.effect {
+            AbilityEffect::ModifyAccuracy { multiplier, physical_only }
+                if !physical_only || move_data.damage_class == "physical" => multiplier,
+            _ => 1.0,
+        })
+        .product()
+}
+
+/// Multiplicador de precisión por la habilidad evasiva del defensor (Sand Veil, Snow Cloak)
+pub fn evasion_multiplier(defender: &PokemonInstance, weather: Option<&WeatherState>) -> f32 {
+    let Some(weather) = weather else {
+        return 1.0;
+    };
+    get_ability_hooks(&defender.ability)
+        .iter()
+        .map(|hook| match hook.effect {
+            AbilityEffect::EvasionInWeather { weather: active, multiplier }
+                if active == weather.weather_type => multiplier,
+            _ => 1.0,
+        })
+        .product()
+}
+
 // NOTA: handle_entry_hazards, apply_on_entry_stat_change y apply_end_of_turn_abilities
 // permanecen en pipeline.rs por ahora debido a su complejidad y dependencias.
 // Se migrarán en una fase posterior si es necesario.
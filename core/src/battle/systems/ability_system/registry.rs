@@ -9,10 +9,11 @@
 /// - **AbilityTrigger**: Define CUÁNDO se activa una habilidad
 /// - **AbilityEffect**: Define QUÉ hace la habilidad cuando se activa
 /// - **get_ability_hooks**: Registry central que mapea ability_id -> lista de hooks
+/// - **AbilityDefinition**: Nombre, descripción y hooks cargados de `abilities.json`
 ///
 /// # Ejemplo de uso
 ///
-/// ```rust
+/// ```ignore
 /// let hooks = get_ability_hooks("intimidate");
 /// for hook in hooks {
 ///     if hook.trigger == AbilityTrigger::OnEntry {
@@ -21,10 +22,12 @@
 /// }
 /// ```
 
+use serde::{Deserialize, Serialize};
 use crate::models::{PokemonType, WeatherType, TerrainType, StatusCondition};
+use super::definitions::ability_definition;
 
 /// Momento en el que se activa una habilidad
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AbilityTrigger {
     /// Al entrar al campo (ej: Intimidate, Drought)
     OnEntry,
@@ -47,7 +50,7 @@ pub enum AbilityTrigger {
 }
 
 /// Tipo de objetivo para efectos de stat change
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StatChangeTarget {
     /// Afecta al usuario de la habilidad
     User,
@@ -60,7 +63,11 @@ pub enum StatChangeTarget {
 }
 
 /// Efecto de una habilidad
-#[derive(Debug, Clone)]
+///
+/// En `abilities.json` se escribe con el nombre de la variante en `type`, por
+/// ejemplo `{ "type": "BoostContactMoves", "multiplier": 1.3 }`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
 pub enum AbilityEffect {
     /// Establece un clima al entrar (Drought, Drizzle, Sand Stream, Snow Warning)
     SetWeather {
@@ -125,9 +132,16 @@ pub enum AbilityEffect {
         condition: Option<PriorityCondition>, // Para Gale Wings: HP completo
     },
 
-    /// Aumenta precisión de movimientos (Compound Eyes, Victory Star)
+    /// Modifica la precisión de los movimientos del usuario (Compound Eyes, Hustle)
     ModifyAccuracy {
-        multiplier: f32, // 1.3 para Compound Eyes
+        multiplier: f32, // 1.3 para Compound Eyes, 0.8 para Hustle
+        physical_only: bool, // Hustle solo penaliza los movimientos físicos
+    },
+
+    /// Reduce la precisión de los ataques rivales bajo un clima (Sand Veil, Snow Cloak)
+    EvasionInWeather {
+        weather: WeatherType,
+        multiplier: f32, // 0.8
     },
 
     /// Aumenta tasa de crítico (Super Luck)
@@ -167,6 +181,23 @@ pub enum AbilityEffect {
         statuses: Vec<StatusCondition>, // Vec vacío = todos los estados
     },
 
+    /// Previene la confusión (Own Tempo)
+    PreventConfusion,
+
+    /// Previene el enamoramiento (Oblivious)
+    PreventInfatuation,
+
+    /// Enamora al atacante al contacto si es del género opuesto (Cute Charm 30%)
+    InfatuateOnContact {
+        chance: f32, // 0.3 = 30%
+    },
+
+    /// Modifica el daño según el género del objetivo (Rivalry)
+    GenderDamageModifier {
+        same_gender: f32,     // 1.25 para Rivalry
+        opposite_gender: f32, // 0.75 para Rivalry
+    },
+
     /// Regenera HP al cambiar de Pokémon (Regenerator)
     HealOnSwitch {
         fraction: f32, // 1/3 = 0.33
@@ -209,7 +240,7 @@ pub enum AbilityEffect {
 }
 
 /// Condición para modificar prioridad
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PriorityCondition {
     /// Solo funciona con HP completo (Gale Wings)
     FullHP,
@@ -218,7 +249,7 @@ pub enum PriorityCondition {
 }
 
 /// Condición para curación
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HealCondition {
     /// Solo bajo lluvia (Rain Dish)
     Weather(WeatherType),
@@ -227,7 +258,7 @@ pub enum HealCondition {
 }
 
 /// Un "hook" de habilidad: cuándo se activa + qué hace
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AbilityHook {
     pub trigger: AbilityTrigger,
     pub effect: AbilityEffect,
@@ -241,12 +272,24 @@ impl AbilityHook {
 
 /// Registry central: Mapea ability_id -> lista de hooks
 ///
-/// Esta función es el corazón del sistema. Para añadir una nueva habilidad,
-/// simplemente agrega un nuevo case aquí con sus hooks correspondientes.
+/// Si `abilities.json` define hooks para la habilidad, se usan esos; si no, se
+/// recurre a los hooks escritos en código (`builtin_ability_hooks`).
+pub fn get_ability_hooks(ability_id: &str) -> Vec<AbilityHook> {
+    match ability_definition(ability_id) {
+        Some(definition) if !definition.hooks.is_empty() => definition.hooks.clone(),
+        _ => builtin_ability_hooks(ability_id),
+    }
+}
+
+/// Hooks escritos en código: fallback para las habilidades que no están en
+/// `abilities.json` y para las que necesitan lógica propia (`Custom`)
+///
+/// Para añadir una habilidad que requiera código, agrega un nuevo case aquí
+/// con sus hooks correspondientes.
 ///
 /// # Ejemplo
 ///
-/// ```rust
+/// ```ignore
 /// "intimidate" => vec![
 ///     AbilityHook::new(
 ///         AbilityTrigger::OnEntry,
@@ -258,7 +301,7 @@ impl AbilityHook {
 ///     ),
 /// ],
 /// ```
-pub fn get_ability_hooks(ability_id: &str) -> Vec<AbilityHook> {
+pub fn builtin_ability_hooks(ability_id: &str) -> Vec<AbilityHook> {
     match ability_id {
         // ============================================================
         // CLIMA Y TERRENO (Weather/Terrain Summon)
@@ -533,7 +576,37 @@ pub fn get_ability_hooks(ability_id: &str) -> Vec<AbilityHook> {
         // ============================================================
         "compound-eyes" => vec![AbilityHook::new(
             AbilityTrigger::BeforeDamage,
-            AbilityEffect::ModifyAccuracy { multiplier: 1.3 },
+            AbilityEffect::ModifyAccuracy { multiplier: 1.3, physical_only: false },
+        )],
+
+        "hustle" => vec![
+            AbilityHook::new(
+                AbilityTrigger::BeforeDamage,
+                AbilityEffect::MultiplyBaseStat {
+                    stat: "attack".to_string(),
+                    multiplier: 1.5,
+                },
+            ),
+            AbilityHook::new(
+                AbilityTrigger::BeforeDamage,
+                AbilityEffect::ModifyAccuracy { multiplier: 0.8, physical_only: true },
+            ),
+        ],
+
+        "sand-veil" => vec![AbilityHook::new(
+            AbilityTrigger::BeforeDamage,
+            AbilityEffect::EvasionInWeather {
+                weather: WeatherType::Sandstorm,
+                multiplier: 0.8,
+            },
+        )],
+
+        "snow-cloak" => vec![AbilityHook::new(
+            AbilityTrigger::BeforeDamage,
+            AbilityEffect::EvasionInWeather {
+                weather: WeatherType::Hail,
+                multiplier: 0.8,
+            },
         )],
 
         "super-luck" => vec![AbilityHook::new(
@@ -580,6 +653,11 @@ pub fn get_ability_hooks(ability_id: &str) -> Vec<AbilityHook> {
             },
         )],
 
+        "cute-charm" => vec![AbilityHook::new(
+            AbilityTrigger::OnContact,
+            AbilityEffect::InfatuateOnContact { chance: 0.3 },
+        )],
+
         "rough-skin" | "iron-barbs" => vec![AbilityHook::new(
             AbilityTrigger::OnContact,
             AbilityEffect::DamageAttackerOnContact {
@@ -637,6 +715,16 @@ pub fn get_ability_hooks(ability_id: &str) -> Vec<AbilityHook> {
             },
         )],
 
+        "own-tempo" => vec![AbilityHook::new(
+            AbilityTrigger::BeforeDamage,
+            AbilityEffect::PreventConfusion,
+        )],
+
+        "oblivious" => vec![AbilityHook::new(
+            AbilityTrigger::BeforeDamage,
+            AbilityEffect::PreventInfatuation,
+        )],
+
         // ============================================================
         // ON-SWITCH EFFECTS
         // ============================================================
@@ -676,6 +764,15 @@ pub fn get_ability_hooks(ability_id: &str) -> Vec<AbilityHook> {
             },
         )],
 
+        // Rivalry: x1.25 contra su mismo género, x0.75 contra el opuesto
+        "rivalry" => vec![AbilityHook::new(
+            AbilityTrigger::BeforeDamage,
+            AbilityEffect::GenderDamageModifier {
+                same_gender: 1.25,
+                opposite_gender: 0.75,
+            },
+        )],
+
         // Sheer Force: Elimina efectos secundarios y aumenta daño en 30%
         "sheer-force" => vec![AbilityHook::new(
             AbilityTrigger::BeforeDamage,
@@ -4,7 +4,7 @@
 //! en las Fases 1 y 2, validando triggers, efectos y edge cases.
 
 use crate::models::{PokemonInstance, PokemonSpecies, Stats, RandomizedProfile, PokemonType, StatModifiers, VolatileStatus, StatStages, MoveData, MoveMeta};
-use super::registry::{get_ability_hooks, AbilityTrigger, AbilityEffect};
+use super::registry::{get_ability_hooks, AbilityTrigger, AbilityEffect, StatChangeTarget};
 
 /// Helper para crear Pokémon de prueba con ability específica
 fn create_test_pokemon(ability: &str, hp: u16, max_hp: u16) -> PokemonInstance {
@@ -28,6 +28,9 @@ fn create_test_pokemon(ability: &str, hp: u16, max_hp: u16) -> PokemonInstance {
             possible_abilities: vec![ability.to_string()],
             is_starter_candidate: false,
             evolutions: Vec::new(),
+            ev_yield: Stats::zero(),
+            gender_rate: 4,
+            mega_forms: Vec::new(),
         },
         level: 50,
         current_hp: hp,
@@ -38,6 +41,11 @@ fn create_test_pokemon(ability: &str, hp: u16, max_hp: u16) -> PokemonInstance {
         volatile_status: Some(VolatileStatus::new()),
         individual_values: Stats::default(),
         effort_values: Stats::default(),
+        nature: Default::default(),
+        gender: Default::default(),
+        tera_type: Default::default(),
+        terastallized: false,
+        mega_evolution: None,
         randomized_profile: RandomizedProfile {
             rolled_primary_type: PokemonType::Normal,
             rolled_secondary_type: None,
@@ -274,11 +282,12 @@ mod intimidate {
             .find(|h| matches!(h.trigger, AbilityTrigger::OnEntry))
             .expect("Intimidate debe tener hook OnEntry");
 
-        if let AbilityEffect::LowerOpponentsStat { stat, stages } = &on_entry_hook.effect {
+        if let AbilityEffect::ModifyStatOnEntry { stat, stages, target } = &on_entry_hook.effect {
             assert_eq!(stat, "attack", "Intimidate debe bajar Attack");
             assert_eq!(*stages, -1, "Intimidate debe bajar 1 stage");
+            assert_eq!(*target, StatChangeTarget::AllOpponents, "Intimidate afecta a todos los rivales");
         } else {
-            panic!("Intimidate debe tener efecto LowerOpponentsStat");
+            panic!("Intimidate debe tener efecto ModifyStatOnEntry");
         }
     }
 }
@@ -368,8 +377,8 @@ mod contact_abilities {
             .find(|h| matches!(h.trigger, AbilityTrigger::OnContact))
             .expect("Rough Skin debe tener hook OnContact");
 
-        if let AbilityEffect::DamageAttackerOnContact { percent } = on_contact_hook.effect {
-            assert_eq!(percent, 0.125, "Rough Skin debe causar 1/8 (12.5%) de daño");
+        if let AbilityEffect::DamageAttackerOnContact { damage_fraction } = on_contact_hook.effect {
+            assert_eq!(damage_fraction, 0.125, "Rough Skin debe causar 1/8 (12.5%) de daño");
         } else {
             panic!("Rough Skin debe tener efecto DamageAttackerOnContact");
         }
@@ -400,10 +409,50 @@ mod contact_abilities {
 mod type_boost_abilities {
     use super::*;
 
+    /// Daño de un movimiento de 80 de poder con la misma tirada aleatoria
+    fn stab_damage(attacker: &PokemonInstance, move_type: &str) -> u16 {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+        use crate::battle::systems::damage_system::calculate_damage;
+        use crate::battle::systems::field_system::Screens;
+
+        let defender = create_test_pokemon("run-away", 200, 200);
+        let move_data = create_test_move(Some(80), move_type, "physical");
+        let mut rng = StdRng::seed_from_u64(1);
+        calculate_damage(attacker, &defender, &move_data, false, &mut rng, None, None, None, Screens::default()).0
+    }
+
+    fn terastallized(ability: &str, tera_type: PokemonType) -> PokemonInstance {
+        let mut pokemon = create_test_pokemon(ability, 200, 200);
+        pokemon.tera_type = tera_type;
+        pokemon.terastallized = true;
+        pokemon
+    }
+
+    #[test]
+    fn test_adaptability_boosts_stab() {
+        // Adaptability no tiene hooks: el cálculo de daño sube su STAB de 1.5x a 2.0x
+        let adaptability = create_test_pokemon("adaptability", 200, 200);
+        let plain = create_test_pokemon("run-away", 200, 200);
+
+        assert!(stab_damage(&adaptability, "Normal") > stab_damage(&plain, "Normal"));
+        assert_eq!(stab_damage(&adaptability, "Fire"), stab_damage(&plain, "Fire"), "Sin STAB no cambia nada");
+    }
+
     #[test]
-    fn test_adaptability_exists() {
-        let hooks = get_ability_hooks("adaptability");
-        assert!(!hooks.is_empty(), "Adaptability debe estar definido");
+    fn test_adaptability_boosts_tera_stab() {
+        // Tera Type igual a un tipo original: 2.0x, o 2.25x con Adaptability
+        let plain = create_test_pokemon("run-away", 200, 200);
+        let tera_normal = terastallized("run-away", PokemonType::Normal);
+        let tera_normal_adaptability = terastallized("adaptability", PokemonType::Normal);
+        assert!(stab_damage(&tera_normal, "Normal") > stab_damage(&plain, "Normal"));
+        assert!(stab_damage(&tera_normal_adaptability, "Normal") > stab_damage(&tera_normal, "Normal"));
+
+        // Tera Type nuevo: 1.5x, o 2.0x con Adaptability
+        let tera_fire = terastallized("run-away", PokemonType::Fire);
+        let tera_fire_adaptability = terastallized("adaptability", PokemonType::Fire);
+        assert!(stab_damage(&tera_fire, "Fire") > stab_damage(&plain, "Fire"));
+        assert!(stab_damage(&tera_fire_adaptability, "Fire") > stab_damage(&tera_fire, "Fire"));
     }
 
     #[test]
@@ -7,8 +7,11 @@
 
 pub mod models;
 
-// Re-exportar modelo principal
-pub use models::ActionCandidate;
+#[cfg(test)]
+mod tests;
+
+// Re-exportar modelos principales
+pub use models::{ActionCandidate, BattleAction, SwitchCandidate};
 
 // NOTA: collect_action_candidates, sort_candidates y execute_single_action
 // permanecen en pipeline.rs por ahora debido a dependencias con funciones
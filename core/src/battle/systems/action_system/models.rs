@@ -3,13 +3,14 @@
 //! Define las estructuras de datos utilizadas para representar
 //! acciones de batalla en la cola de prioridad.
 
+use serde::{Deserialize, Serialize};
 use crate::models::{FieldPosition, MoveData};
 
 /// Candidato de acción para la cola de prioridad global
 ///
 /// Representa un Pokémon que va a ejecutar una acción en este turno.
 /// Se utiliza para ordenar las acciones por prioridad y velocidad.
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct ActionCandidate {
     /// Posición del Pokémon en el campo
     pub position: FieldPosition,
@@ -37,6 +38,14 @@ pub struct ActionCandidate {
 
     /// Nombre del Pokémon para logs
     pub pokemon_name: String,
+
+    /// Si el Pokémon se teracristaliza antes de moverse este turno
+    #[serde(default)]
+    pub terastallize: bool,
+
+    /// Si el Pokémon megaevoluciona antes de moverse este turno
+    #[serde(default)]
+    pub mega_evolve: bool,
 }
 
 impl ActionCandidate {
@@ -63,6 +72,61 @@ impl ActionCandidate {
             move_template_id,
             selected_target,
             pokemon_name,
+            terastallize: false,
+            mega_evolve: false,
+        }
+    }
+}
+
+/// Cambio de Pokémon pedido para un slot del campo
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SwitchCandidate {
+    /// Slot del campo que cambia (índice en player_active_indices u opponent_active_indices)
+    pub slot: usize,
+
+    /// Índice en el equipo del Pokémon que entra
+    pub new_index: usize,
+
+    /// Si es del jugador (true) o del oponente (false)
+    pub is_player: bool,
+
+    /// Velocidad efectiva del Pokémon que sale (ordena los cambios entre sí)
+    pub speed: u16,
+}
+
+/// Acción de un combatiente en la cola del turno
+///
+/// Los cambios se ejecutan antes que cualquier movimiento, sin importar su
+/// prioridad; entre acciones del mismo tipo se ordena por prioridad y velocidad.
+/// Es serializable porque el resto de la cola se guarda en `PendingPivot`
+/// mientras el jugador elige el relevo.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum BattleAction {
+    /// Usar un movimiento
+    Move(Box<ActionCandidate>),
+    /// Cambiar al Pokémon del slot por otro del equipo
+    Switch(SwitchCandidate),
+}
+
+impl BattleAction {
+    /// Si la acción es un cambio de Pokémon
+    pub fn is_switch(&self) -> bool {
+        matches!(self, BattleAction::Switch(_))
+    }
+
+    /// Prioridad de la acción (los cambios no tienen prioridad propia)
+    pub fn priority(&self) -> i8 {
+        match self {
+            BattleAction::Move(candidate) => candidate.priority,
+            BattleAction::Switch(_) => 0,
+        }
+    }
+
+    /// Velocidad efectiva del Pokémon que actúa
+    pub fn speed(&self) -> u16 {
+        match self {
+            BattleAction::Move(candidate) => candidate.speed,
+            BattleAction::Switch(switch) => switch.speed,
         }
     }
 }
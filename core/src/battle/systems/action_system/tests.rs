@@ -0,0 +1,185 @@
+//! Tests del Action System: orden de las acciones dentro del turno
+
+use crate::battle::events::{BattleEvent, HealCause};
+use crate::battle::test_fixtures::{mega_form, moves, moves_with_finisher, play_turn, pokemon, use_move};
+use crate::battle::BattleOutcome;
+use crate::game::{BattleState, PendingPlayerAction, PlayerTeam};
+use crate::models::{BattleFormat, FieldPosition, PokemonType, WeatherType};
+
+#[test]
+fn test_player_switch_resolves_before_moves() {
+    let moves = moves();
+    let mut team = PlayerTeam::new();
+    team.add_member(pokemon("player", "run-away", &moves));
+    team.add_member(pokemon("relief", "intimidate", &moves));
+    let wild = pokemon("wild", "run-away", &moves);
+
+    let mut battle_state = BattleState::new(0, vec![wild], "wild".to_string(), BattleFormat::Single, false);
+    battle_state.turn_counter = 2;
+    battle_state.pending_player_actions = vec![PendingPlayerAction::switch(0, 1)];
+
+    let result = play_turn(&mut team, &mut battle_state, &moves, 5);
+
+    assert_eq!(battle_state.player_active_indices, vec![1]);
+    assert_eq!(battle_state.turn_counter, 3);
+
+    // El cambio ocurre antes del ataque salvaje, que recae sobre el entrante
+    let switched = result.events.iter().position(|e| matches!(e, BattleEvent::Switched { trainer: None, .. }));
+    let attacked = result.events.iter().position(|e| matches!(e, BattleEvent::MoveUsed { .. }));
+    assert!(switched.unwrap() < attacked.unwrap());
+    assert_eq!(team.active_members[0].current_hp, team.active_members[0].base_computed_stats.hp);
+    assert!(team.active_members[1].current_hp < team.active_members[1].base_computed_stats.hp);
+
+    // Intimidate del entrante
+    assert_eq!(battle_state.opponent_instance.battle_stages.as_ref().unwrap().attack, -1);
+}
+
+#[test]
+fn test_player_switches_right_slot_in_doubles_with_regenerator() {
+    let moves = moves();
+    let mut team = PlayerTeam::new();
+    team.add_member(pokemon("left", "run-away", &moves));
+    let mut right = pokemon("right", "regenerator", &moves);
+    right.current_hp = right.base_computed_stats.hp / 2;
+    let wounded_hp = right.current_hp;
+    team.add_member(right);
+    team.add_member(pokemon("bench", "run-away", &moves));
+
+    let mut battle_state = BattleState::new(
+        0,
+        vec![pokemon("foe-a", "run-away", &moves), pokemon("foe-b", "run-away", &moves)],
+        "Rival".to_string(),
+        BattleFormat::Double,
+        true,
+    );
+    battle_state.turn_counter = 2;
+    battle_state.pending_player_actions = vec![
+        PendingPlayerAction {
+            target_position: Some(FieldPosition::OpponentLeft),
+            ..use_move(0, "tackle")
+        },
+        PendingPlayerAction::switch(1, 2),
+    ];
+
+    let result = play_turn(&mut team, &mut battle_state, &moves, 8);
+
+    assert_eq!(battle_state.player_active_indices, vec![0, 2]);
+
+    // Regenerator cura al saliente, que ya no recibe ataques
+    let healed = &team.active_members[1];
+    assert!(healed.current_hp > wounded_hp);
+    assert!(result.events.iter().any(|e| matches!(
+        e,
+        BattleEvent::Heal { cause: HealCause::Ability { ability }, .. } if ability == "regenerator"
+    )));
+}
+
+#[test]
+fn test_terastallization_changes_typing_once_per_battle() {
+    let moves = moves();
+    let mut team = PlayerTeam::new();
+    let mut player = pokemon("player", "run-away", &moves);
+    player.tera_type = PokemonType::Ghost;
+    team.add_member(player);
+    let max_hp = team.active_members[0].base_computed_stats.hp;
+
+    let wild = pokemon("wild", "run-away", &moves);
+    let mut battle_state = BattleState::new(0, vec![wild], "wild".to_string(), BattleFormat::Single, false);
+    let tera_tackle = PendingPlayerAction { terastallize: true, ..use_move(0, "tackle") };
+    battle_state.pending_player_actions = vec![tera_tackle.clone()];
+
+    let result = play_turn(&mut team, &mut battle_state, &moves, 3);
+
+    assert!(result.events.contains(&BattleEvent::Terastallized {
+        pokemon: "player".to_string(),
+        tera_type: PokemonType::Ghost,
+    }));
+    assert_eq!(battle_state.player_terastallized, Some(0));
+    // Ya es tipo Fantasma: el Tackle del salvaje no le afecta
+    assert_eq!(team.active_members[0].current_hp, max_hp);
+
+    // Solo una vez por batalla: pedirlo de nuevo no hace nada
+    battle_state.pending_player_actions = vec![tera_tackle];
+    let result = play_turn(&mut team, &mut battle_state, &moves, 4);
+
+    assert!(!result.events.iter().any(|e| matches!(e, BattleEvent::Terastallized { .. })));
+    assert!(team.active_members[0].terastallized);
+    assert_eq!(team.active_members[0].current_hp, max_hp);
+}
+
+#[test]
+fn test_mega_evolution_swaps_form_and_reverts_when_the_battle_ends() {
+    let moves = moves_with_finisher();
+    let mut team = PlayerTeam::new();
+    let mut player = pokemon("player", "run-away", &moves);
+    player.species.mega_forms = vec![mega_form()];
+    player.held_item = Some("playerite".to_string());
+    player.learn_new_move("finisher".to_string(), 5);
+    team.add_member(player);
+    let original_stats = team.active_members[0].base_computed_stats.clone();
+
+    let wild = pokemon("wild", "run-away", &moves);
+    let mut battle_state = BattleState::new(0, vec![wild], "wild".to_string(), BattleFormat::Single, false);
+    battle_state.turn_counter = 2;
+    let mega = |move_id: &str| PendingPlayerAction { mega_evolve: true, ..use_move(0, move_id) };
+    battle_state.pending_player_actions = vec![mega("tackle")];
+
+    let result = play_turn(&mut team, &mut battle_state, &moves, 3);
+
+    assert!(result.events.contains(&BattleEvent::MegaEvolved {
+        pokemon: "player".to_string(),
+        form: "Mega Player".to_string(),
+    }));
+    // La nueva habilidad se activa como al entrar al campo
+    assert!(result.events.contains(&BattleEvent::WeatherSet { weather: WeatherType::Sun }));
+    assert_eq!(battle_state.player_mega_evolved, Some(0));
+    let evolved = &team.active_members[0];
+    assert_eq!(evolved.ability, "drought");
+    assert_eq!(evolved.current_types(), vec![PokemonType::Fire, PokemonType::Dragon]);
+    assert!(evolved.base_computed_stats.attack > original_stats.attack);
+    assert_eq!(evolved.base_computed_stats.hp, original_stats.hp);
+
+    // Al ganar la batalla vuelve a su forma normal
+    battle_state.pending_player_actions = vec![mega("finisher")];
+    let result = play_turn(&mut team, &mut battle_state, &moves, 4);
+
+    assert_eq!(result.outcome, BattleOutcome::PlayerWon);
+    assert!(!result.events.iter().any(|e| matches!(e, BattleEvent::MegaEvolved { .. })));
+    let reverted = &team.active_members[0];
+    assert_eq!(reverted.mega_evolution, None);
+    assert_eq!(reverted.ability, "run-away");
+    assert_eq!(reverted.current_types(), vec![PokemonType::Normal]);
+    assert_eq!(reverted.base_computed_stats, original_stats);
+}
+
+#[test]
+fn test_trainer_ai_mega_evolves_with_its_own_stone_only() {
+    let moves = moves();
+    let mut team = PlayerTeam::new();
+    team.add_member(pokemon("player", "run-away", &moves));
+
+    let mut rival = pokemon("rival", "run-away", &moves);
+    rival.species.mega_forms = vec![mega_form()];
+    rival.held_item = Some("playerite".to_string());
+    // La megapiedra de otra especie no sirve
+    let mut partner = pokemon("partner", "run-away", &moves);
+    partner.held_item = Some("playerite".to_string());
+    let mut battle_state = BattleState::new(
+        0,
+        vec![partner, rival],
+        "Rival".to_string(),
+        BattleFormat::Double,
+        true,
+    );
+    team.add_member(pokemon("player-2", "run-away", &moves));
+    battle_state.player_active_indices = vec![0, 1];
+    battle_state.pending_player_actions = vec![];
+
+    let result = play_turn(&mut team, &mut battle_state, &moves, 3);
+
+    assert!(!result.opponent_actions[0].mega_evolve);
+    assert!(result.opponent_actions[1].mega_evolve);
+    assert_eq!(battle_state.opponent_mega_evolved, Some(1));
+    assert_eq!(battle_state.opponent_team[1].ability, "drought");
+    assert_eq!(battle_state.opponent_team[0].mega_evolution, None);
+}
@@ -2,10 +2,12 @@
 //!
 //! Este sistema es responsable de:
 //! - Seleccionar movimientos para oponentes
-//! - Implementar estrategias de IA
+//! - Implementar estrategias de IA por dificultad (`strategy`)
 //! - Tomar decisiones de cambio de Pokémon
 
 pub mod selector;
+pub mod strategy;
 
 // Re-exportar función principal
 pub use selector::select_ai_move;
+pub use strategy::{planned_damage, AiContext, AiDecision, AiDifficulty, AiStrategy, GreedyAi, RandomAi, SmartAi};
@@ -4,7 +4,8 @@
 //! para los oponentes controlados por IA.
 
 use crate::models::PokemonInstance;
-use super::super::validation_system::has_moves_with_pp;
+use super::super::super::events::BattleEvent;
+use super::super::validation_system::{has_moves_with_pp, is_move_allowed_by_choice};
 
 /// Selecciona un movimiento para la IA del oponente
 ///
@@ -13,16 +14,15 @@ use super::super::validation_system::has_moves_with_pp;
 ///
 /// # Argumentos
 /// * `pokemon` - Pokémon controlado por IA
-/// * `_logs` - Vector de logs (reservado para futuras estrategias que generen logs)
+/// * `_events` - Eventos de batalla (reservado para futuras estrategias que narren su decisión)
 ///
 /// # Retorna
 /// ID del movimiento seleccionado (String)
 ///
-/// # TODO
-/// - Implementar estrategias más inteligentes (elegir por tipo, poder, etc.)
-/// - Considerar el tipo del oponente para elegir movimientos efectivos
-/// - Implementar niveles de dificultad de IA
-pub fn select_ai_move(pokemon: &PokemonInstance, _logs: &mut Vec<String>) -> String {
+/// # Nota
+/// El pipeline de batalla ya no usa esta función: elige con la `AiStrategy`
+/// de `BattleState::ai_difficulty` (ver `strategy.rs`).
+pub fn select_ai_move(pokemon: &PokemonInstance, _events: &mut Vec<BattleEvent>) -> String {
     // Verificar si tiene movimientos con PP
     if !has_moves_with_pp(pokemon) {
         return "struggle".to_string();
@@ -32,7 +32,7 @@ pub fn select_ai_move(pokemon: &PokemonInstance, _logs: &mut Vec<String>) -> Str
     let moves_with_pp: Vec<String> = pokemon
         .get_active_learned_moves()
         .iter()
-        .filter(|m| m.current_pp > 0)
+        .filter(|m| m.current_pp > 0 && is_move_allowed_by_choice(pokemon, &m.move_id))
         .map(|m| m.move_id.clone())
         .collect();
 
@@ -138,6 +138,9 @@ mod tests {
                 possible_abilities: vec!["static".to_string()],
                 is_starter_candidate: false,
                 evolutions: Vec::new(),
+                ev_yield: Stats::zero(),
+                gender_rate: 4,
+                mega_forms: Vec::new(),
             },
             level: 50,
             current_hp: 100,
@@ -148,6 +151,11 @@ mod tests {
             held_item: None,
             individual_values: Stats::default(),
             effort_values: Stats::default(),
+            nature: Default::default(),
+            gender: Default::default(),
+            tera_type: Default::default(),
+            terastallized: false,
+            mega_evolution: None,
             randomized_profile: RandomizedProfile {
                 rolled_primary_type: PokemonType::Electric,
                 rolled_secondary_type: None,
@@ -0,0 +1,1181 @@
+//! Estrategias de IA por nivel de dificultad
+//!
+//! Cada estrategia implementa `AiStrategy` y elige movimiento y objetivo para
+//! un Pokémon controlado por la IA a partir de un `AiContext`:
+//! - `RandomAi`: cualquier movimiento con PP (Pokémon salvajes)
+//! - `GreedyAi`: el que más daño esperado hace (daño × precisión × golpes)
+//! - `SmartAi`: además valora estados, Protect, movimientos de mejora y
+//!   curación, y prioriza debilitar al rival antes de que él la debilite
+//!
+//! En dobles cada opción es un par (movimiento, objetivo): los movimientos de
+//! área suman el daño a ambos rivales (con la penalización x0.75) y restan el
+//! que harían al propio aliado. El daño que el compañero de la IA ya planeó
+//! este turno (`planned_damage`) descuenta el HP del rival, de modo que el
+//! segundo slot ve cuándo puede rematar al mismo objetivo.
+//!
+//! `GreedyAi` se teracristaliza para potenciar un ataque de su Tera Type;
+//! `SmartAi` también lo hace para quitarse una debilidad ante un rival.
+//!
+//! Las estimaciones de daño usan `calculate_damage` con un RNG propio de
+//! semilla fija, de modo que evaluar movimientos no consume el RNG del turno
+//! (sólo `RandomAi` y los empates lo usan, y eso queda en el replay).
+
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::game::BattleState;
+use crate::models::{BattleSide, FieldPosition, MoveData, PokemonInstance, PokemonType, StatusCondition};
+use super::super::ability_system::{get_priority_with_abilities, get_speed_with_abilities};
+use super::super::damage_system::calculator::parse_type;
+use super::super::damage_system::{calculate_damage, get_type_effectiveness};
+use super::super::field_system::Screens;
+use super::super::validation_system::is_move_allowed_by_choice;
+
+/// Semilla del RNG auxiliar usado para estimar daño
+const ESTIMATE_SEED: u64 = 0;
+
+/// Nivel de dificultad de la IA de una batalla
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AiDifficulty {
+    /// Movimiento aleatorio con PP
+    #[default]
+    Random,
+    /// Maximiza el daño esperado
+    Greedy,
+    /// Evalúa daño, estados, protección, mejoras y la carrera de KOs
+    Smart,
+}
+
+impl AiDifficulty {
+    /// Estrategia que implementa este nivel de dificultad
+    pub fn strategy(self) -> &'static dyn AiStrategy {
+        match self {
+            AiDifficulty::Random => &RandomAi,
+            AiDifficulty::Greedy => &GreedyAi,
+            AiDifficulty::Smart => &SmartAi,
+        }
+    }
+}
+
+/// Información que una estrategia necesita para decidir
+pub struct AiContext<'a> {
+    /// Pokémon controlado por la IA
+    pub user: &'a PokemonInstance,
+    /// Rivales vivos en el campo, con su posición
+    pub targets: Vec<(FieldPosition, &'a PokemonInstance)>,
+    /// Aliado vivo del usuario (sólo en dobles)
+    pub ally: Option<&'a PokemonInstance>,
+    /// Daño que el aliado ya planeó hacer este turno, por posición
+    pub planned_damage: &'a [(FieldPosition, u16)],
+    /// Pokémon vivos en el banquillo, con su índice en el equipo (vacío en salvajes)
+    pub bench: Vec<(usize, &'a PokemonInstance)>,
+    /// Estado de la batalla (clima, terreno, Trick Room)
+    pub battle_state: &'a BattleState,
+    /// Datos de movimientos disponibles
+    pub move_pool: Option<&'a HashMap<String, MoveData>>,
+}
+
+/// Movimiento y objetivo elegidos por la IA
+#[derive(Debug, Clone, PartialEq)]
+pub struct AiDecision {
+    /// ID del movimiento ("struggle" si no queda PP)
+    pub move_id: String,
+    /// Objetivo elegido (None si el movimiento no lo necesita)
+    pub target: Option<FieldPosition>,
+}
+
+/// Estrategia de selección de acciones de la IA
+pub trait AiStrategy {
+    /// Elige movimiento y objetivo para `ctx.user`
+    fn choose_action(&self, ctx: &AiContext, rng: &mut StdRng) -> AiDecision;
+
+    /// Decide si retirar a `ctx.user` antes de atacar; retorna el índice del
+    /// reemplazo en el equipo. Por defecto la IA nunca cambia voluntariamente.
+    fn choose_switch(&self, _ctx: &AiContext) -> Option<usize> {
+        None
+    }
+
+    /// Decide si `ctx.user` se teracristaliza antes de usar `decision`. Por
+    /// defecto la IA nunca se teracristaliza.
+    fn choose_terastallize(&self, _ctx: &AiContext, _decision: &AiDecision) -> bool {
+        false
+    }
+}
+
+/// Ventaja mínima de emparejamiento que justifica gastar el turno cambiando
+const SWITCH_MARGIN: f32 = 0.25;
+
+/// Elige un movimiento con PP y un rival al azar
+pub struct RandomAi;
+
+/// Elige el movimiento y objetivo con mayor daño esperado
+pub struct GreedyAi;
+
+/// Elige puntuando daño, KOs, estados, Protect, mejoras y curación
+pub struct SmartAi;
+
+impl AiStrategy for RandomAi {
+    fn choose_action(&self, ctx: &AiContext, rng: &mut StdRng) -> AiDecision {
+        let moves = usable_moves(ctx.user, ctx.move_pool);
+        if moves.is_empty() {
+            return struggle(ctx);
+        }
+        let move_data = &moves[rng.gen_range(0..moves.len())];
+
+        let target = if takes_target(move_data) && ctx.targets.len() > 1 {
+            Some(ctx.targets[rng.gen_range(0..ctx.targets.len())].0)
+        } else {
+            default_target(ctx, move_data)
+        };
+        AiDecision {
+            move_id: move_data.id.clone(),
+            target,
+        }
+    }
+}
+
+impl AiStrategy for GreedyAi {
+    fn choose_action(&self, ctx: &AiContext, rng: &mut StdRng) -> AiDecision {
+        let moves = usable_moves(ctx.user, ctx.move_pool);
+        if ctx.targets.is_empty() {
+            return RandomAi.choose_action(ctx, rng);
+        }
+
+        let options = action_options(ctx, &moves);
+        let scores: Vec<f32> = options
+            .iter()
+            .map(|option| {
+                hits(ctx, option.move_data, option.target)
+                    .iter()
+                    .map(|hit| {
+                        let dealt = hit.damage.min(hit.remaining_hp) as f32 * accuracy_factor(option.move_data);
+                        if hit.is_ally { -dealt } else { dealt }
+                    })
+                    .sum()
+            })
+            .collect();
+        pick_best(&options, &scores).unwrap_or_else(|| RandomAi.choose_action(ctx, rng))
+    }
+
+    fn choose_terastallize(&self, ctx: &AiContext, decision: &AiDecision) -> bool {
+        tera_boosts_attack(ctx, decision)
+    }
+}
+
+impl AiStrategy for SmartAi {
+    fn choose_action(&self, ctx: &AiContext, rng: &mut StdRng) -> AiDecision {
+        let moves = usable_moves(ctx.user, ctx.move_pool);
+        if ctx.targets.is_empty() {
+            return RandomAi.choose_action(ctx, rng);
+        }
+
+        // ¿Qué rival puede hacernos más daño este turno, y con qué movimiento?
+        let threat = ctx
+            .targets
+            .iter()
+            .flat_map(|&(position, foe)| {
+                usable_moves(foe, ctx.move_pool).into_iter().map(move |move_data| {
+                    let damage = raw_damage(foe, ctx.user, &move_data, ctx.battle_state);
+                    (damage, position, foe, move_data)
+                })
+            })
+            .max_by_key(|(damage, ..)| *damage);
+        let threatened = threat
+            .as_ref()
+            .is_some_and(|(damage, ..)| *damage >= ctx.user.current_hp);
+        let threat_position = threat.as_ref().filter(|_| threatened).map(|(_, position, ..)| *position);
+
+        let options = action_options(ctx, &moves);
+        let scores: Vec<f32> = options
+            .iter()
+            .map(|option| {
+                let outspeeds = match &threat {
+                    Some((_, _, foe, threat_move)) => moves_first(
+                        ctx.user,
+                        option.move_data,
+                        foe,
+                        threat_move,
+                        ctx.battle_state,
+                    ),
+                    None => true,
+                };
+                let situation = Situation { threatened, outspeeds, threat_position };
+                score_option(ctx, option, &situation)
+            })
+            .collect();
+        pick_best(&options, &scores).unwrap_or_else(|| GreedyAi.choose_action(ctx, rng))
+    }
+
+    fn choose_switch(&self, ctx: &AiContext) -> Option<usize> {
+        if ctx.targets.is_empty() {
+            return None;
+        }
+        let reason = switch_reason(ctx)?;
+
+        // El mejor reemplazo por emparejamiento (el primero en caso de empate)
+        let (index, best) = ctx
+            .bench
+            .iter()
+            .map(|&(index, pokemon)| (index, matchup(pokemon, ctx)))
+            .fold(None, |best: Option<(usize, f32)>, candidate| match best {
+                Some(b) if b.1 >= candidate.1 => Some(b),
+                _ => Some(candidate),
+            })?;
+
+        match reason {
+            // Quedarse significa debilitarse al final del turno
+            SwitchReason::PerishSong => Some(index),
+            SwitchReason::Weakness | SwitchReason::Threat => {
+                (best > matchup(ctx.user, ctx) + SWITCH_MARGIN).then_some(index)
+            }
+        }
+    }
+
+    fn choose_terastallize(&self, ctx: &AiContext, decision: &AiDecision) -> bool {
+        tera_boosts_attack(ctx, decision) || tera_removes_weakness(ctx)
+    }
+}
+
+/// Si el movimiento elegido es de daño y del Tera Type del usuario
+fn tera_boosts_attack(ctx: &AiContext, decision: &AiDecision) -> bool {
+    let user = ctx.user;
+    user.can_terastallize()
+        && ctx
+            .move_pool
+            .and_then(|pool| pool.get(&decision.move_id))
+            .is_some_and(|m| m.power.is_some() && parse_type(&m.r#type) == user.tera_type)
+}
+
+/// Si un rival tiene un ataque súper eficaz contra el usuario que su Tera
+/// Type recibiría con menos efectividad
+fn tera_removes_weakness(ctx: &AiContext) -> bool {
+    let user = ctx.user;
+    if !user.can_terastallize() {
+        return false;
+    }
+    let current = user.current_types();
+    let tera = [user.tera_type];
+    ctx.targets
+        .iter()
+        .flat_map(|&(_, foe)| usable_moves(foe, ctx.move_pool))
+        .filter(|m| m.power.is_some())
+        .any(|m| {
+            let move_type = parse_type(&m.r#type);
+            let now = get_type_effectiveness(&move_type, &current);
+            now >= 2.0 && get_type_effectiveness(&move_type, &tera) < now
+        })
+}
+
+/// Motivo por el que la IA considera retirar a su Pokémon
+enum SwitchReason {
+    /// El contador de Perish Song llega a 0 este turno
+    PerishSong,
+    /// Un rival tiene un movimiento x4 contra él
+    Weakness,
+    /// Un rival lo debilita este turno y no puede debilitar a nadie antes
+    Threat,
+}
+
+fn switch_reason(ctx: &AiContext) -> Option<SwitchReason> {
+    let user = ctx.user;
+    let perish_count = user.volatile_status.as_ref().and_then(|v| v.perish_count);
+    if perish_count.is_some_and(|count| count <= 1) {
+        return Some(SwitchReason::PerishSong);
+    }
+
+    let user_types = user.current_types();
+    let foe_moves: Vec<(&PokemonInstance, MoveData)> = ctx
+        .targets
+        .iter()
+        .flat_map(|&(_, foe)| usable_moves(foe, ctx.move_pool).into_iter().map(move |m| (foe, m)))
+        .filter(|(_, m)| m.power.is_some())
+        .collect();
+
+    let quad_weak = foe_moves
+        .iter()
+        .any(|(_, m)| get_type_effectiveness(&parse_type(&m.r#type), &user_types) >= 4.0);
+    if quad_weak {
+        return Some(SwitchReason::Weakness);
+    }
+
+    let threat = foe_moves
+        .iter()
+        .find(|(foe, m)| raw_damage(foe, user, m, ctx.battle_state) >= user.current_hp);
+    if let Some((foe, threat_move)) = threat {
+        let can_ko_first = usable_moves(user, ctx.move_pool).iter().any(|m| {
+            moves_first(user, m, foe, threat_move, ctx.battle_state)
+                && ctx
+                    .targets
+                    .iter()
+                    .any(|&(_, target)| raw_damage(user, target, m, ctx.battle_state) >= target.current_hp)
+        });
+        if !can_ko_first {
+            return Some(SwitchReason::Threat);
+        }
+    }
+
+    None
+}
+
+/// Emparejamiento de un Pokémon contra los rivales en el campo
+///
+/// Fracción de vida que puede quitarle al rival más expuesto menos la
+/// fracción de su propia vida que el rival más peligroso puede quitarle.
+fn matchup(pokemon: &PokemonInstance, ctx: &AiContext) -> f32 {
+    let offense = usable_moves(pokemon, ctx.move_pool)
+        .iter()
+        .flat_map(|m| {
+            ctx.targets.iter().map(move |&(_, foe)| {
+                let hp = foe.current_hp.max(1);
+                raw_damage(pokemon, foe, m, ctx.battle_state).min(hp) as f32 * accuracy_factor(m) / hp as f32
+            })
+        })
+        .fold(0.0, f32::max);
+
+    let defense = ctx
+        .targets
+        .iter()
+        .flat_map(|&(_, foe)| {
+            usable_moves(foe, ctx.move_pool)
+                .into_iter()
+                .map(move |m| raw_damage(foe, pokemon, &m, ctx.battle_state))
+        })
+        .map(|damage| (damage as f32 / pokemon.current_hp.max(1) as f32).min(1.0))
+        .fold(0.0, f32::max);
+
+    offense - defense
+}
+
+/// Daño estimado que una decisión hará a cada rival (para coordinar al aliado)
+pub fn planned_damage(ctx: &AiContext, decision: &AiDecision) -> Vec<(FieldPosition, u16)> {
+    let Some(move_data) = ctx.move_pool.and_then(|pool| pool.get(&decision.move_id)) else {
+        return Vec::new();
+    };
+    hits(ctx, move_data, decision.target)
+        .into_iter()
+        .filter(|hit| !hit.is_ally)
+        .filter_map(|hit| hit.position.map(|position| (position, hit.damage)))
+        .collect()
+}
+
+/// Un par (movimiento, objetivo) evaluable
+struct ActionOption<'m> {
+    move_data: &'m MoveData,
+    target: Option<FieldPosition>,
+}
+
+/// Un Pokémon alcanzado por una opción
+struct Hit {
+    position: Option<FieldPosition>,
+    is_ally: bool,
+    /// HP que le queda tras el daño ya planeado por el aliado
+    remaining_hp: u16,
+    /// Daño estimado (con penalización de área)
+    damage: u16,
+}
+
+/// Situación del usuario frente al rival más peligroso
+struct Situation {
+    /// El rival puede debilitarnos este turno
+    threatened: bool,
+    /// Actuamos antes que ese rival
+    outspeeds: bool,
+    /// Posición del rival que puede debilitarnos
+    threat_position: Option<FieldPosition>,
+}
+
+/// Opciones a evaluar: un movimiento con objetivo genera una por rival
+fn action_options<'m>(ctx: &AiContext, moves: &'m [MoveData]) -> Vec<ActionOption<'m>> {
+    let mut options = Vec::new();
+    for move_data in moves {
+        if takes_target(move_data) {
+            for &(position, _) in &ctx.targets {
+                options.push(ActionOption { move_data, target: Some(position) });
+            }
+        } else {
+            options.push(ActionOption { move_data, target: None });
+        }
+    }
+    options
+}
+
+/// Pokémon que alcanzaría un movimiento y daño estimado a cada uno
+fn hits(ctx: &AiContext, move_data: &MoveData, target: Option<FieldPosition>) -> Vec<Hit> {
+    let mut affected: Vec<(Option<FieldPosition>, &PokemonInstance, bool)> = match move_data.target.as_str() {
+        "all-opponents" | "random-opponent" => ctx.targets.iter().map(|&(pos, foe)| (Some(pos), foe, false)).collect(),
+        "all-other-pokemon" => {
+            let mut all: Vec<_> = ctx.targets.iter().map(|&(pos, foe)| (Some(pos), foe, false)).collect();
+            if let Some(ally) = ctx.ally {
+                all.push((None, ally, true));
+            }
+            all
+        }
+        _ => ctx
+            .targets
+            .iter()
+            .filter(|(pos, _)| Some(*pos) == target)
+            .map(|&(pos, foe)| (Some(pos), foe, false))
+            .collect(),
+    };
+
+    // Outrage y similares golpean a uno solo al azar: se promedia
+    let share = if move_data.target == "random-opponent" { affected.len().max(1) as f32 } else { 1.0 };
+    let spread = if affected.len() > 1 && share == 1.0 { 0.75 } else { 1.0 };
+
+    affected
+        .drain(..)
+        .map(|(position, pokemon, is_ally)| {
+            let planned: u16 = ctx
+                .planned_damage
+                .iter()
+                .filter(|(pos, _)| Some(*pos) == position)
+                .map(|(_, damage)| *damage)
+                .sum();
+            let damage = raw_damage(ctx.user, pokemon, move_data, ctx.battle_state) as f32 * spread / share;
+            Hit {
+                position,
+                is_ally,
+                remaining_hp: pokemon.current_hp.saturating_sub(planned),
+                damage: damage as u16,
+            }
+        })
+        .collect()
+}
+
+/// Puntuación de `SmartAi` para una opción
+///
+/// Escala aproximada: 1.0 = quitar toda la vida restante de un rival; cada KO
+/// suma 1.0 más, y otro 1.0 si además golpeamos antes que él (0.5 extra si es
+/// el rival que amenaza con debilitarnos). Dañar al aliado resta.
+fn score_option(ctx: &AiContext, option: &ActionOption, situation: &Situation) -> f32 {
+    let user = ctx.user;
+    let move_data = option.move_data;
+    let accuracy = accuracy_factor(move_data);
+
+    if move_data.power.is_some() {
+        return hits(ctx, move_data, option.target)
+            .iter()
+            .map(|hit| {
+                let remaining = hit.remaining_hp.max(1);
+                let dealt = hit.damage.min(remaining) as f32 * accuracy / remaining as f32;
+                let knocks_out = hit.damage >= remaining;
+                if hit.is_ally {
+                    return if knocks_out { -2.0 } else { -dealt };
+                }
+
+                let mut score = dealt;
+                if knocks_out {
+                    score += accuracy;
+                    // Debilitarlo antes de que nos debilite
+                    if situation.outspeeds || !situation.threatened {
+                        score += accuracy;
+                    }
+                    if hit.position.is_some() && hit.position == situation.threat_position {
+                        score += 0.5 * accuracy;
+                    }
+                }
+                score
+            })
+            .sum();
+    }
+
+    // Si el rival nos debilita antes de actuar, sólo vale atacar
+    if situation.threatened && !situation.outspeeds && !is_protect(move_data) {
+        return 0.0;
+    }
+
+    if is_protect(move_data) {
+        return ctx
+            .targets
+            .iter()
+            .map(|&(_, foe)| protect_score(user, foe))
+            .fold(0.0, f32::max);
+    }
+
+    if let Some(status) = inflicted_status(move_data) {
+        let best = ctx
+            .targets
+            .iter()
+            .filter(|(position, _)| option.target.is_none() || option.target == Some(*position))
+            .map(|&(_, foe)| status_score(user, foe, move_data, status))
+            .fold(0.0, f32::max);
+        return best * accuracy;
+    }
+
+    if move_data.meta.healing > 0 && move_data.target == "user" {
+        let max_hp = user.base_computed_stats.hp.max(1) as f32;
+        let missing = 1.0 - user.current_hp as f32 / max_hp;
+        if missing < 0.5 {
+            return 0.0;
+        }
+        return (move_data.meta.healing as f32 / 100.0).min(missing) * 1.5;
+    }
+
+    if is_setup(move_data) {
+        return setup_score(user, move_data, situation.threatened);
+    }
+
+    0.0
+}
+
+/// Protect vale la pena si el rival está cargando un movimiento o sufre daño
+/// residual; nunca dos turnos seguidos (la probabilidad de éxito se desploma)
+fn protect_score(user: &PokemonInstance, target: &PokemonInstance) -> f32 {
+    let used_last_turn = user
+        .volatile_status
+        .as_ref()
+        .and_then(|v| v.last_move_used.as_deref())
+        .is_some_and(|last| last == "protect" || last == "detect");
+    if used_last_turn {
+        return 0.0;
+    }
+
+    let target_volatile = target.volatile_status.as_ref();
+    if target_volatile.is_some_and(|v| v.charging_move.is_some()) {
+        return 0.8;
+    }
+
+    let residual_status = matches!(
+        target.status_condition,
+        Some(StatusCondition::Burn | StatusCondition::Poison | StatusCondition::BadPoison)
+    );
+    if residual_status || target_volatile.is_some_and(|v| v.leech_seeded) {
+        return 0.4;
+    }
+
+    0.0
+}
+
+/// Valor de infligir un estado alterado (0.0 si no surtiría efecto)
+fn status_score(
+    user: &PokemonInstance,
+    target: &PokemonInstance,
+    move_data: &MoveData,
+    status: StatusCondition,
+) -> f32 {
+    if target.status_condition.is_some() {
+        return 0.0;
+    }
+
+    let types = target.current_types();
+    let immune_by_type = match status {
+        StatusCondition::Burn => types.contains(&PokemonType::Fire),
+        StatusCondition::Paralysis => types.contains(&PokemonType::Electric),
+        StatusCondition::Poison | StatusCondition::BadPoison => {
+            types.contains(&PokemonType::Poison) || types.contains(&PokemonType::Steel)
+        }
+        StatusCondition::Freeze => types.contains(&PokemonType::Ice),
+        StatusCondition::Sleep => false,
+    };
+    // Thunder Wave no afecta a tipos Tierra, etc.
+    let move_type = parse_type(&move_data.r#type);
+    if immune_by_type || get_type_effectiveness(&move_type, &types) == 0.0 {
+        return 0.0;
+    }
+
+    let stats = &target.base_computed_stats;
+    match status {
+        StatusCondition::Sleep | StatusCondition::Freeze => 0.9,
+        StatusCondition::Paralysis => {
+            if stats.speed > user.base_computed_stats.speed { 0.8 } else { 0.6 }
+        }
+        StatusCondition::Burn => {
+            if stats.attack > stats.special_attack { 0.7 } else { 0.4 }
+        }
+        StatusCondition::BadPoison => 0.6,
+        StatusCondition::Poison => 0.5,
+    }
+}
+
+/// Mejorar stats sólo compensa con vida de sobra y sin amenaza de KO
+fn setup_score(user: &PokemonInstance, move_data: &MoveData, threatened: bool) -> f32 {
+    let max_hp = user.base_computed_stats.hp.max(1) as f32;
+    if threatened || (user.current_hp as f32) < max_hp * 0.7 {
+        return 0.0;
+    }
+
+    let stages = user.battle_stages.clone().unwrap_or_default();
+    let boosts: i8 = move_data
+        .stat_changes
+        .iter()
+        .filter(|change| stage_of(&stages, &change.stat) < 2)
+        .map(|change| change.change)
+        .sum();
+
+    (boosts as f32 * 0.3).min(0.9)
+}
+
+/// Movimientos con PP del Pokémon, con sus datos resueltos
+///
+/// Un Pokémon bloqueado por un objeto Choice solo puede usar su movimiento.
+fn usable_moves(pokemon: &PokemonInstance, move_pool: Option<&HashMap<String, MoveData>>) -> Vec<MoveData> {
+    let Some(pool) = move_pool else {
+        return Vec::new();
+    };
+    pokemon
+        .get_active_learned_moves()
+        .iter()
+        .filter(|m| m.current_pp > 0 && is_move_allowed_by_choice(pokemon, &m.move_id))
+        .filter_map(|m| pool.get(&m.move_id).cloned())
+        .collect()
+}
+
+/// Daño estimado de un golpe (sin crítico, sin precisión ni pantallas)
+fn raw_damage(
+    attacker: &PokemonInstance,
+    defender: &PokemonInstance,
+    move_data: &MoveData,
+    battle_state: &BattleState,
+) -> u16 {
+    let mut scratch = StdRng::seed_from_u64(ESTIMATE_SEED);
+    let (damage, _, _) = calculate_damage(
+        attacker,
+        defender,
+        move_data,
+        false,
+        &mut scratch,
+        None,
+        battle_state.weather.as_ref(),
+        battle_state.terrain.as_ref(),
+        Screens::default(),
+    );
+    let hits = match (move_data.meta.min_hits, move_data.meta.max_hits) {
+        (Some(min), Some(max)) => (min as u16 + max as u16) / 2,
+        _ => 1,
+    };
+    damage.saturating_mul(hits.max(1))
+}
+
+fn accuracy_factor(move_data: &MoveData) -> f32 {
+    move_data.accuracy.map_or(1.0, |accuracy| accuracy as f32 / 100.0)
+}
+
+/// Si `user` actuaría antes que `foe` (prioridad → velocidad, con Trick Room)
+fn moves_first(
+    user: &PokemonInstance,
+    user_move: &MoveData,
+    foe: &PokemonInstance,
+    foe_move: &MoveData,
+    battle_state: &BattleState,
+) -> bool {
+    let user_priority = get_priority_with_abilities(user, user_move);
+    let foe_priority = get_priority_with_abilities(foe, foe_move);
+    if user_priority != foe_priority {
+        return user_priority > foe_priority;
+    }
+
+    let user_speed = get_speed_with_abilities(user, BattleSide::Opponent, battle_state);
+    let foe_speed = get_speed_with_abilities(foe, BattleSide::Player, battle_state);
+    if battle_state.trick_room_active {
+        user_speed < foe_speed
+    } else {
+        user_speed > foe_speed
+    }
+}
+
+/// Opción con mayor puntuación (la primera en caso de empate); None si ninguna puntúa
+fn pick_best(options: &[ActionOption], scores: &[f32]) -> Option<AiDecision> {
+    let mut best: Option<(usize, f32)> = None;
+    for (i, &score) in scores.iter().enumerate() {
+        if score > best.map_or(0.0, |(_, s)| s) {
+            best = Some((i, score));
+        }
+    }
+    best.map(|(i, _)| AiDecision {
+        move_id: options[i].move_data.id.clone(),
+        target: options[i].target,
+    })
+}
+
+fn struggle(ctx: &AiContext) -> AiDecision {
+    AiDecision {
+        move_id: "struggle".to_string(),
+        target: ctx.targets.first().map(|(position, _)| *position),
+    }
+}
+
+/// Si el movimiento necesita que se elija un objetivo concreto
+fn takes_target(move_data: &MoveData) -> bool {
+    move_data.target == "selected-pokemon"
+}
+
+/// Objetivo por defecto: el único rival si el movimiento lo necesita
+fn default_target(ctx: &AiContext, move_data: &MoveData) -> Option<FieldPosition> {
+    if takes_target(move_data) {
+        ctx.targets.first().map(|(position, _)| *position)
+    } else {
+        None
+    }
+}
+
+fn is_protect(move_data: &MoveData) -> bool {
+    move_data.id == "protect" || move_data.id == "detect"
+}
+
+fn is_setup(move_data: &MoveData) -> bool {
+    move_data.target == "user"
+        && !move_data.stat_changes.is_empty()
+        && move_data.stat_changes.iter().all(|change| change.change > 0)
+}
+
+/// Estado alterado que inflige un movimiento de estado
+fn inflicted_status(move_data: &MoveData) -> Option<StatusCondition> {
+    if move_data.damage_class != "status" {
+        return None;
+    }
+    match move_data.meta.ailment.as_str() {
+        "burn" => Some(StatusCondition::Burn),
+        "paralysis" => Some(StatusCondition::Paralysis),
+        "sleep" => Some(StatusCondition::Sleep),
+        "freeze" => Some(StatusCondition::Freeze),
+        "poison" if move_data.id == "toxic" => Some(StatusCondition::BadPoison),
+        "poison" => Some(StatusCondition::Poison),
+        _ => None,
+    }
+}
+
+fn stage_of(stages: &crate::models::StatStages, stat: &str) -> i8 {
+    match stat {
+        "attack" => stages.attack,
+        "defense" => stages.defense,
+        "special-attack" | "special_attack" => stages.special_attack,
+        "special-defense" | "special_defense" => stages.special_defense,
+        "speed" => stages.speed,
+        "accuracy" => stages.accuracy,
+        "evasion" => stages.evasion,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::test_fixtures as fixtures;
+    use crate::battle::BattleEvent;
+    use crate::factory::create_pokemon_instance;
+    use crate::game::PlayerTeam;
+    use crate::models::{BattleFormat, LearnedMove, MoveMeta, MoveStatChange, PokemonSpecies, Stats};
+
+    fn species(id: &str) -> PokemonSpecies {
+        PokemonSpecies {
+            species_id: id.to_string(),
+            display_name: id.to_string(),
+            generation: 1,
+            primary_type: PokemonType::Normal,
+            secondary_type: None,
+            base_stats: Stats {
+                hp: 80,
+                attack: 80,
+                defense: 80,
+                special_attack: 80,
+                special_defense: 80,
+                speed: 80,
+            },
+            move_pool: vec!["tackle".to_string()],
+            possible_abilities: vec!["run-away".to_string()],
+            is_starter_candidate: false,
+            evolutions: Vec::new(),
+            ev_yield: Stats::zero(),
+            gender_rate: 4,
+            mega_forms: Vec::new(),
+        }
+    }
+
+    fn damaging(id: &str, move_type: &str, power: u16, accuracy: u8) -> MoveData {
+        MoveData {
+            id: id.to_string(),
+            name: id.to_string(),
+            r#type: move_type.to_string(),
+            power: Some(power),
+            accuracy: Some(accuracy),
+            priority: 0,
+            pp: 10,
+            damage_class: "special".to_string(),
+            meta: MoveMeta::default(),
+            stat_changes: vec![],
+            target: "selected-pokemon".to_string(),
+        }
+    }
+
+    fn status(id: &str, move_type: &str, ailment: &str) -> MoveData {
+        let mut move_data = damaging(id, move_type, 0, 90);
+        move_data.power = None;
+        move_data.damage_class = "status".to_string();
+        move_data.meta.ailment = ailment.to_string();
+        move_data
+    }
+
+    fn pool() -> HashMap<String, MoveData> {
+        let mut swords_dance = status("swords-dance", "Normal", "none");
+        swords_dance.accuracy = None;
+        swords_dance.target = "user".to_string();
+        swords_dance.stat_changes = vec![MoveStatChange { stat: "attack".to_string(), change: 2 }];
+        let mut surf = damaging("surf", "Water", 90, 100);
+        surf.target = "all-opponents".to_string();
+        let mut earthquake = damaging("earthquake", "Ground", 100, 100);
+        earthquake.target = "all-other-pokemon".to_string();
+
+        [
+            damaging("tackle", "Normal", 40, 100),
+            damaging("water-gun", "Water", 40, 100),
+            damaging("ember", "Fire", 40, 100),
+            damaging("hydro-pump", "Water", 110, 80),
+            damaging("zap-cannon", "Electric", 120, 50),
+            status("thunder-wave", "Electric", "paralysis"),
+            swords_dance,
+            surf,
+            earthquake,
+        ]
+        .into_iter()
+        .map(|m| (m.id.clone(), m))
+        .collect()
+    }
+
+    fn pokemon(id: &str, primary: PokemonType, moves: &[&str]) -> PokemonInstance {
+        let mut pokemon = create_pokemon_instance(&species(id), 50, 1, false, &[], None);
+        pokemon.randomized_profile.rolled_primary_type = primary;
+        pokemon.randomized_profile.rolled_secondary_type = None;
+        pokemon.randomized_profile.learned_moves = moves
+            .iter()
+            .map(|id| LearnedMove { move_id: id.to_string(), max_pp: 10, current_pp: 10 })
+            .collect();
+        pokemon.init_battle_stages();
+        pokemon
+    }
+
+    fn choose(
+        difficulty: AiDifficulty,
+        user: &PokemonInstance,
+        target: &PokemonInstance,
+        moves: &HashMap<String, MoveData>,
+    ) -> String {
+        let battle_state = BattleState::new(0, vec![user.clone()], String::new(), BattleFormat::Single, false);
+        let ctx = AiContext {
+            user,
+            targets: vec![(FieldPosition::PlayerLeft, target)],
+            ally: None,
+            planned_damage: &[],
+            bench: Vec::new(),
+            battle_state: &battle_state,
+            move_pool: Some(moves),
+        };
+        difficulty.strategy().choose_action(&ctx, &mut StdRng::seed_from_u64(7)).move_id
+    }
+
+    /// Decide en dobles contra `left` (PlayerLeft) y `right` (PlayerRight)
+    fn choose_doubles(
+        difficulty: AiDifficulty,
+        user: &PokemonInstance,
+        ally: &PokemonInstance,
+        (left, right): (&PokemonInstance, &PokemonInstance),
+        planned_damage: &[(FieldPosition, u16)],
+        moves: &HashMap<String, MoveData>,
+    ) -> AiDecision {
+        let battle_state = BattleState::new(
+            0,
+            vec![user.clone(), ally.clone()],
+            "Rival".to_string(),
+            BattleFormat::Double,
+            true,
+        );
+        let ctx = AiContext {
+            user,
+            targets: vec![(FieldPosition::PlayerLeft, left), (FieldPosition::PlayerRight, right)],
+            ally: Some(ally),
+            planned_damage,
+            bench: Vec::new(),
+            battle_state: &battle_state,
+            move_pool: Some(moves),
+        };
+        difficulty.strategy().choose_action(&ctx, &mut StdRng::seed_from_u64(7))
+    }
+
+    #[test]
+    fn test_random_only_picks_moves_with_pp() {
+        let moves = pool();
+        let mut user = pokemon("user", PokemonType::Normal, &["tackle", "water-gun"]);
+        user.randomized_profile.learned_moves[0].current_pp = 0;
+        let target = pokemon("target", PokemonType::Normal, &["tackle"]);
+
+        for _ in 0..5 {
+            assert_eq!(choose(AiDifficulty::Random, &user, &target, &moves), "water-gun");
+        }
+
+        user.randomized_profile.learned_moves[1].current_pp = 0;
+        assert_eq!(choose(AiDifficulty::Random, &user, &target, &moves), "struggle");
+    }
+
+    #[test]
+    fn test_greedy_prefers_super_effective_move() {
+        let moves = pool();
+        let user = pokemon("user", PokemonType::Normal, &["tackle", "water-gun"]);
+        let target = pokemon("target", PokemonType::Fire, &["tackle"]);
+
+        assert_eq!(choose(AiDifficulty::Greedy, &user, &target, &moves), "water-gun");
+    }
+
+    #[test]
+    fn test_choice_locked_user_repeats_its_move() {
+        let moves = pool();
+        let mut user = pokemon("user", PokemonType::Normal, &["tackle", "water-gun"]);
+        user.held_item = Some("choice-scarf".to_string());
+        user.volatile_status.as_mut().unwrap().choice_locked_move = Some("tackle".to_string());
+        let target = pokemon("target", PokemonType::Fire, &["tackle"]);
+
+        assert_eq!(choose(AiDifficulty::Greedy, &user, &target, &moves), "tackle");
+        assert_eq!(choose(AiDifficulty::Smart, &user, &target, &moves), "tackle");
+
+        // Sin PP en el movimiento bloqueado solo queda Struggle
+        user.randomized_profile.learned_moves[0].current_pp = 0;
+        assert_eq!(choose(AiDifficulty::Random, &user, &target, &moves), "struggle");
+
+        // Sin el objeto el bloqueo deja de aplicar
+        user.held_item = None;
+        assert_eq!(choose(AiDifficulty::Greedy, &user, &target, &moves), "water-gun");
+    }
+
+    #[test]
+    fn test_greedy_weighs_accuracy() {
+        let moves = pool();
+        // Zap Cannon pega más fuerte, pero al 50% de precisión rinde menos que Hydro Pump
+        let user = pokemon("user", PokemonType::Normal, &["zap-cannon", "hydro-pump"]);
+        let target = pokemon("target", PokemonType::Normal, &["tackle"]);
+
+        assert_eq!(choose(AiDifficulty::Greedy, &user, &target, &moves), "hydro-pump");
+    }
+
+    #[test]
+    fn test_smart_takes_the_sure_ko() {
+        let moves = pool();
+        let user = pokemon("user", PokemonType::Normal, &["hydro-pump", "tackle"]);
+        let mut target = pokemon("target", PokemonType::Normal, &["tackle"]);
+        target.current_hp = 5;
+
+        // Ambos debilitan; Tackle nunca falla
+        assert_eq!(choose(AiDifficulty::Smart, &user, &target, &moves), "tackle");
+    }
+
+    #[test]
+    fn test_smart_paralyzes_a_faster_healthy_target() {
+        let moves = pool();
+        let user = pokemon("user", PokemonType::Normal, &["tackle", "thunder-wave"]);
+        let mut target = pokemon("target", PokemonType::Normal, &["tackle"]);
+        target.base_computed_stats.speed = user.base_computed_stats.speed + 50;
+
+        assert_eq!(choose(AiDifficulty::Smart, &user, &target, &moves), "thunder-wave");
+    }
+
+    #[test]
+    fn test_smart_skips_status_on_immune_target() {
+        let moves = pool();
+        let user = pokemon("user", PokemonType::Normal, &["tackle", "thunder-wave"]);
+        let target = pokemon("target", PokemonType::Ground, &["tackle"]);
+
+        assert_eq!(choose(AiDifficulty::Smart, &user, &target, &moves), "tackle");
+    }
+
+    #[test]
+    fn test_smart_sets_up_only_when_safe() {
+        let moves = pool();
+        let user = pokemon("user", PokemonType::Normal, &["tackle", "swords-dance"]);
+        let target = pokemon("target", PokemonType::Normal, &["tackle"]);
+        assert_eq!(choose(AiDifficulty::Smart, &user, &target, &moves), "swords-dance");
+
+        // Con poca vida y el rival amenazando con debilitarla, mejor atacar
+        let mut weakened = user.clone();
+        weakened.current_hp = 1;
+        assert_eq!(choose(AiDifficulty::Smart, &weakened, &target, &moves), "tackle");
+    }
+
+    #[test]
+    fn test_doubles_targets_the_weaker_matchup() {
+        let moves = pool();
+        let user = pokemon("user", PokemonType::Normal, &["water-gun"]);
+        let ally = pokemon("ally", PokemonType::Normal, &["tackle"]);
+        let left = pokemon("left", PokemonType::Grass, &["tackle"]);
+        let right = pokemon("right", PokemonType::Fire, &["tackle"]);
+
+        for difficulty in [AiDifficulty::Greedy, AiDifficulty::Smart] {
+            let decision = choose_doubles(difficulty, &user, &ally, (&left, &right), &[], &moves);
+            assert_eq!(decision.move_id, "water-gun");
+            assert_eq!(decision.target, Some(FieldPosition::PlayerRight));
+        }
+    }
+
+    #[test]
+    fn test_doubles_prefers_spread_move_against_two_targets() {
+        let moves = pool();
+        let user = pokemon("user", PokemonType::Normal, &["hydro-pump", "surf"]);
+        let ally = pokemon("ally", PokemonType::Normal, &["tackle"]);
+        let left = pokemon("left", PokemonType::Normal, &["tackle"]);
+        let right = pokemon("right", PokemonType::Normal, &["tackle"]);
+
+        let decision = choose_doubles(AiDifficulty::Greedy, &user, &ally, (&left, &right), &[], &moves);
+        assert_eq!(decision.move_id, "surf");
+        assert_eq!(decision.target, None);
+    }
+
+    #[test]
+    fn test_doubles_avoids_knocking_out_own_ally() {
+        let moves = pool();
+        let user = pokemon("user", PokemonType::Normal, &["earthquake", "tackle"]);
+        let mut ally = pokemon("ally", PokemonType::Normal, &["tackle"]);
+        ally.current_hp = 10;
+        let left = pokemon("left", PokemonType::Normal, &["tackle"]);
+        let right = pokemon("right", PokemonType::Normal, &["tackle"]);
+
+        let decision = choose_doubles(AiDifficulty::Smart, &user, &ally, (&left, &right), &[], &moves);
+        assert_eq!(decision.move_id, "tackle");
+
+        // Un aliado volador no recibe daño de Earthquake
+        let flying_ally = pokemon("ally", PokemonType::Flying, &["tackle"]);
+        let decision = choose_doubles(AiDifficulty::Smart, &user, &flying_ally, (&left, &right), &[], &moves);
+        assert_eq!(decision.move_id, "earthquake");
+    }
+
+    #[test]
+    fn test_doubles_focuses_target_the_ally_already_weakened() {
+        let moves = pool();
+        let user = pokemon("user", PokemonType::Normal, &["tackle"]);
+        let ally = pokemon("ally", PokemonType::Normal, &["tackle"]);
+        let left = pokemon("left", PokemonType::Normal, &["tackle"]);
+        let right = pokemon("right", PokemonType::Normal, &["tackle"]);
+
+        let alone = choose_doubles(AiDifficulty::Smart, &user, &ally, (&left, &right), &[], &moves);
+        assert_eq!(alone.target, Some(FieldPosition::PlayerLeft));
+
+        // El aliado ya planeó dejar a PlayerRight casi debilitado: rematarlo
+        let planned = [(FieldPosition::PlayerRight, right.current_hp - 5)];
+        let focused = choose_doubles(AiDifficulty::Smart, &user, &ally, (&left, &right), &planned, &moves);
+        assert_eq!(focused.target, Some(FieldPosition::PlayerRight));
+    }
+
+    fn choose_switch(
+        difficulty: AiDifficulty,
+        user: &PokemonInstance,
+        foe: &PokemonInstance,
+        bench: &[&PokemonInstance],
+        moves: &HashMap<String, MoveData>,
+    ) -> Option<usize> {
+        let battle_state = BattleState::new(0, vec![user.clone()], "Rival".to_string(), BattleFormat::Single, true);
+        let ctx = AiContext {
+            user,
+            targets: vec![(FieldPosition::PlayerLeft, foe)],
+            ally: None,
+            planned_damage: &[],
+            bench: bench.iter().enumerate().map(|(i, p)| (i + 1, *p)).collect(),
+            battle_state: &battle_state,
+            move_pool: Some(moves),
+        };
+        difficulty.strategy().choose_switch(&ctx)
+    }
+
+    #[test]
+    fn test_smart_switches_out_of_quad_weakness() {
+        let moves = pool();
+        let mut user = pokemon("user", PokemonType::Grass, &["tackle"]);
+        user.randomized_profile.rolled_secondary_type = Some(PokemonType::Bug);
+        let foe = pokemon("foe", PokemonType::Fire, &["ember"]);
+        let normal = pokemon("normal", PokemonType::Normal, &["tackle"]);
+        let water = pokemon("water", PokemonType::Water, &["water-gun"]);
+
+        // Elige el reemplazo que resiste y amenaza al rival
+        assert_eq!(choose_switch(AiDifficulty::Smart, &user, &foe, &[&normal, &water], &moves), Some(2));
+        // Sin banquillo no hay a quién cambiar
+        assert_eq!(choose_switch(AiDifficulty::Smart, &user, &foe, &[], &moves), None);
+    }
+
+    #[test]
+    fn test_smart_stays_in_a_good_matchup() {
+        let moves = pool();
+        let user = pokemon("user", PokemonType::Water, &["water-gun"]);
+        let foe = pokemon("foe", PokemonType::Fire, &["ember"]);
+        let bench = pokemon("bench", PokemonType::Normal, &["tackle"]);
+
+        assert_eq!(choose_switch(AiDifficulty::Smart, &user, &foe, &[&bench], &moves), None);
+    }
+
+    #[test]
+    fn test_smart_switches_before_perish_song_ends() {
+        let moves = pool();
+        let mut user = pokemon("user", PokemonType::Water, &["water-gun"]);
+        user.volatile_status.as_mut().unwrap().perish_count = Some(1);
+        let foe = pokemon("foe", PokemonType::Fire, &["ember"]);
+        let bench = pokemon("bench", PokemonType::Normal, &["tackle"]);
+
+        assert_eq!(choose_switch(AiDifficulty::Smart, &user, &foe, &[&bench], &moves), Some(1));
+        // Las IAs más simples nunca cambian por voluntad propia
+        assert_eq!(choose_switch(AiDifficulty::Greedy, &user, &foe, &[&bench], &moves), None);
+    }
+
+    /// Si la IA se teracristaliza para usar `move_id` contra `target`
+    fn terastallizes(
+        difficulty: AiDifficulty,
+        user: &PokemonInstance,
+        target: &PokemonInstance,
+        move_id: &str,
+        moves: &HashMap<String, MoveData>,
+    ) -> bool {
+        let battle_state = BattleState::new(0, vec![user.clone()], "Rival".to_string(), BattleFormat::Single, true);
+        let ctx = AiContext {
+            user,
+            targets: vec![(FieldPosition::PlayerLeft, target)],
+            ally: None,
+            planned_damage: &[],
+            bench: Vec::new(),
+            battle_state: &battle_state,
+            move_pool: Some(moves),
+        };
+        let decision = AiDecision { move_id: move_id.to_string(), target: Some(FieldPosition::PlayerLeft) };
+        difficulty.strategy().choose_terastallize(&ctx, &decision)
+    }
+
+    #[test]
+    fn test_terastallize_decisions() {
+        let moves = pool();
+        let mut user = pokemon("user", PokemonType::Fire, &["ember", "water-gun"]);
+        user.tera_type = PokemonType::Water;
+        let neutral = pokemon("target", PokemonType::Normal, &["tackle"]);
+
+        // Ofensiva: el ataque elegido es del Tera Type
+        assert!(terastallizes(AiDifficulty::Greedy, &user, &neutral, "water-gun", &moves));
+        assert!(terastallizes(AiDifficulty::Smart, &user, &neutral, "water-gun", &moves));
+        assert!(!terastallizes(AiDifficulty::Greedy, &user, &neutral, "ember", &moves));
+        assert!(!terastallizes(AiDifficulty::Random, &user, &neutral, "water-gun", &moves));
+
+        // Defensiva: el Tera Type Agua resiste el ataque x2 del rival
+        let threat = pokemon("threat", PokemonType::Normal, &["water-gun"]);
+        assert!(terastallizes(AiDifficulty::Smart, &user, &threat, "ember", &moves));
+        assert!(!terastallizes(AiDifficulty::Greedy, &user, &threat, "ember", &moves));
+
+        // Ya teracristalizado no vuelve a hacerlo
+        user.terastallized = true;
+        assert!(!terastallizes(AiDifficulty::Smart, &user, &threat, "water-gun", &moves));
+    }
+
+    #[test]
+    fn test_trainer_ai_terastallizes_into_its_attack_type() {
+        let moves = fixtures::moves();
+        let mut team = PlayerTeam::new();
+        team.add_member(fixtures::pokemon("player", "run-away", &moves));
+
+        let mut rival = fixtures::pokemon("rival", "run-away", &moves);
+        rival.tera_type = PokemonType::Normal;
+        let mut battle_state = BattleState::new(0, vec![rival], "Rival".to_string(), BattleFormat::Single, true);
+        battle_state.ai_difficulty = AiDifficulty::Greedy;
+        battle_state.pending_player_actions = vec![fixtures::use_move(0, "tackle")];
+
+        let result = fixtures::play_turn(&mut team, &mut battle_state, &moves, 3);
+
+        assert!(result.opponent_actions[0].terastallize);
+        assert!(result.events.contains(&BattleEvent::Terastallized {
+            pokemon: "rival".to_string(),
+            tera_type: PokemonType::Normal,
+        }));
+        assert_eq!(battle_state.opponent_terastallized, Some(0));
+        assert!(battle_state.opponent_team[0].terastallized);
+    }
+}
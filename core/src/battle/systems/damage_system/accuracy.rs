@@ -0,0 +1,234 @@
+//! Precisión y evasión
+//!
+//! Un ataque acierta si una tirada de 1 a 100 no supera su precisión final:
+//! la precisión base del movimiento (que algunos climas cambian) multiplicada
+//! por la tabla de stages de precisión/evasión (3/9 a 9/3) y por los
+//! modificadores de habilidades y objetos. Los movimientos sin precisión nunca
+//! fallan e ignoran la evasión.
+
+use rand::rngs::StdRng;
+use rand::Rng;
+use crate::models::{MoveData, PokemonInstance, WeatherState, WeatherType};
+use super::super::ability_system::{accuracy_multiplier, evasion_multiplier};
+use super::super::item_system::ItemProcessor;
+
+/// Límite de los stages de precisión/evasión combinados
+const MAX_ACCURACY_STAGE: i8 = 6;
+/// Precisión de Thunder y Hurricane bajo sol intenso
+const SUN_ACCURACY: u8 = 50;
+
+/// Multiplicador de precisión para un stage combinado (precisión - evasión)
+///
+/// - Si stage >= 0: (3 + stage) / 3
+/// - Si stage < 0: 3 / (3 - stage)
+pub fn accuracy_stage_multiplier(stage: i8) -> f32 {
+    let stage = stage.clamp(-MAX_ACCURACY_STAGE, MAX_ACCURACY_STAGE);
+    if stage >= 0 {
+        (3.0 + stage as f32) / 3.0
+    } else {
+        3.0 / (3.0 - stage as f32)
+    }
+}
+
+/// Precisión base del movimiento con el clima actual (None = nunca falla)
+///
+/// Thunder y Hurricane no fallan bajo lluvia y bajan al 50% con sol;
+/// Blizzard no falla con granizo.
+pub fn move_accuracy(move_data: &MoveData, weather: Option<&WeatherState>) -> Option<u8> {
+    let weather_type = weather.map_or(WeatherType::None, |w| w.weather_type);
+    match (move_data.id.as_str(), weather_type) {
+        ("thunder" | "hurricane", WeatherType::Rain) => None,
+        ("thunder" | "hurricane", WeatherType::Sun) => Some(SUN_ACCURACY),
+        ("blizzard", WeatherType::Hail) => None,
+        _ => move_data.accuracy,
+    }
+}
+
+/// Precisión final del ataque en porcentaje (None = nunca falla)
+pub fn final_accuracy(
+    attacker: &PokemonInstance,
+    defender: &PokemonInstance,
+    move_data: &MoveData,
+    weather: Option<&WeatherState>,
+) -> Option<f32> {
+    let accuracy = move_accuracy(move_data, weather)?;
+
+    let accuracy_stage = attacker.battle_stages.as_ref().map_or(0, |s| s.accuracy);
+    let mut evasion_stage = defender.battle_stages.as_ref().map_or(0, |s| s.evasion);
+    // Keen Eye ignora las subidas de evasión del objetivo
+    if attacker.ability == "keen-eye" {
+        evasion_stage = evasion_stage.min(0);
+    }
+
+    let modifiers = accuracy_multiplier(attacker, move_data)
+        * ItemProcessor::get_accuracy_multiplier(attacker)
+        * evasion_multiplier(defender, weather)
+        * ItemProcessor::get_evasion_multiplier(defender);
+
+    Some(accuracy as f32 * accuracy_stage_multiplier(accuracy_stage - evasion_stage) * modifiers)
+}
+
+/// Tira la precisión de un golpe
+pub fn check_accuracy(
+    attacker: &PokemonInstance,
+    defender: &PokemonInstance,
+    move_data: &MoveData,
+    weather: Option<&WeatherState>,
+    rng: &mut StdRng,
+) -> bool {
+    match final_accuracy(attacker, defender, move_data, weather) {
+        Some(accuracy) => rng.gen_range(1..=100) as f32 <= accuracy,
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::create_pokemon_instance;
+    use crate::models::{MoveMeta, PokemonSpecies, PokemonType, StatStages, Stats};
+
+    fn pokemon(ability: &str) -> PokemonInstance {
+        let species = PokemonSpecies {
+            species_id: "test".to_string(),
+            display_name: "Test".to_string(),
+            generation: 1,
+            primary_type: PokemonType::Normal,
+            secondary_type: None,
+            base_stats: Stats {
+                hp: 80,
+                attack: 80,
+                defense: 80,
+                special_attack: 80,
+                special_defense: 80,
+                speed: 80,
+            },
+            move_pool: Vec::new(),
+            possible_abilities: vec![ability.to_string()],
+            is_starter_candidate: false,
+            evolutions: Vec::new(),
+            ev_yield: Stats::zero(),
+            gender_rate: 4,
+            mega_forms: Vec::new(),
+        };
+        let mut pokemon = create_pokemon_instance(&species, 50, 1, false, &[], None);
+        pokemon.ability = ability.to_string();
+        pokemon.held_item = None;
+        pokemon.battle_stages = Some(StatStages::new());
+        pokemon
+    }
+
+    fn attack(id: &str, damage_class: &str, accuracy: Option<u8>) -> MoveData {
+        MoveData {
+            id: id.to_string(),
+            name: id.to_string(),
+            r#type: "Normal".to_string(),
+            power: Some(80),
+            accuracy,
+            priority: 0,
+            pp: 10,
+            damage_class: damage_class.to_string(),
+            meta: MoveMeta::default(),
+            stat_changes: vec![],
+            target: "selected-pokemon".to_string(),
+        }
+    }
+
+    fn weather(weather_type: WeatherType) -> WeatherState {
+        WeatherState { weather_type, turns_remaining: 5 }
+    }
+
+    /// Precisión final redondeada al entero
+    fn percent(
+        attacker: &PokemonInstance,
+        defender: &PokemonInstance,
+        move_data: &MoveData,
+        weather: Option<&WeatherState>,
+    ) -> Option<u32> {
+        final_accuracy(attacker, defender, move_data, weather).map(|a| a.round() as u32)
+    }
+
+    #[test]
+    fn test_stage_table() {
+        assert_eq!(accuracy_stage_multiplier(0), 1.0);
+        assert_eq!(accuracy_stage_multiplier(3), 2.0);
+        assert_eq!(accuracy_stage_multiplier(6), 3.0);
+        assert_eq!(accuracy_stage_multiplier(-6), 1.0 / 3.0);
+        // El stage combinado se limita a ±6
+        assert_eq!(accuracy_stage_multiplier(-10), 1.0 / 3.0);
+    }
+
+    #[test]
+    fn test_accuracy_and_evasion_stages_combine() {
+        let mut attacker = pokemon("run-away");
+        let mut defender = pokemon("run-away");
+        let tackle = attack("tackle", "physical", Some(100));
+
+        defender.battle_stages.as_mut().unwrap().evasion = 2;
+        assert_eq!(percent(&attacker, &defender, &tackle, None), Some(60));
+
+        attacker.battle_stages.as_mut().unwrap().accuracy = 2;
+        assert_eq!(percent(&attacker, &defender, &tackle, None), Some(100));
+
+        // Keen Eye ignora la evasión subida
+        attacker.battle_stages.as_mut().unwrap().accuracy = 0;
+        attacker.ability = "keen-eye".to_string();
+        assert_eq!(percent(&attacker, &defender, &tackle, None), Some(100));
+    }
+
+    #[test]
+    fn test_never_miss_moves_ignore_evasion() {
+        let attacker = pokemon("run-away");
+        let mut defender = pokemon("run-away");
+        defender.battle_stages.as_mut().unwrap().evasion = 6;
+
+        let swift = attack("swift", "special", None);
+        assert_eq!(percent(&attacker, &defender, &swift, None), None);
+    }
+
+    #[test]
+    fn test_weather_dependent_accuracy() {
+        let thunder = attack("thunder", "special", Some(70));
+        let blizzard = attack("blizzard", "special", Some(70));
+
+        assert_eq!(move_accuracy(&thunder, None), Some(70));
+        assert_eq!(move_accuracy(&thunder, Some(&weather(WeatherType::Rain))), None);
+        assert_eq!(move_accuracy(&thunder, Some(&weather(WeatherType::Sun))), Some(50));
+        assert_eq!(move_accuracy(&blizzard, Some(&weather(WeatherType::Hail))), None);
+        assert_eq!(move_accuracy(&blizzard, Some(&weather(WeatherType::Rain))), Some(70));
+    }
+
+    #[test]
+    fn test_ability_and_item_modifiers() {
+        let defender = pokemon("run-away");
+        let physical = attack("tackle", "physical", Some(100));
+        let special = attack("psybeam", "special", Some(100));
+
+        let compound_eyes = pokemon("compound-eyes");
+        assert_eq!(percent(&compound_eyes, &defender, &physical, None), Some(130));
+
+        // Hustle solo penaliza los movimientos físicos
+        let hustle = pokemon("hustle");
+        assert_eq!(percent(&hustle, &defender, &physical, None), Some(80));
+        assert_eq!(percent(&hustle, &defender, &special, None), Some(100));
+
+        let mut wide_lens = pokemon("run-away");
+        wide_lens.held_item = Some("wide-lens".to_string());
+        assert_eq!(percent(&wide_lens, &defender, &physical, None), Some(110));
+
+        let attacker = pokemon("run-away");
+        let mut bright_powder = pokemon("run-away");
+        bright_powder.held_item = Some("bright-powder".to_string());
+        assert_eq!(percent(&attacker, &bright_powder, &physical, None), Some(90));
+
+        // Sand Veil solo actúa en tormenta de arena
+        let sand_veil = pokemon("sand-veil");
+        let sandstorm = weather(WeatherType::Sandstorm);
+        assert_eq!(percent(&attacker, &sand_veil, &physical, None), Some(100));
+        assert_eq!(percent(&attacker, &sand_veil, &physical, Some(&sandstorm)), Some(80));
+
+        let snow_cloak = pokemon("snow-cloak");
+        let hail = weather(WeatherType::Hail);
+        assert_eq!(percent(&attacker, &snow_cloak, &physical, Some(&hail)), Some(80));
+    }
+}
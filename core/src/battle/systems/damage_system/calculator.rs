@@ -2,53 +2,58 @@ use rand::rngs::StdRng;
 use rand::Rng;
 use crate::models::{MoveData, PokemonInstance, PokemonType, StatusCondition, WeatherState, TerrainState, WeatherType};
 use super::super::super::effects::{is_grounded, check_ability_immunity, modify_offensive_stat_by_ability};
-use super::super::ability_system::{get_ability_hooks, AbilityTrigger, AbilityEffect};
+use super::super::ability_system::{get_ability_hooks, gender_damage_multiplier, AbilityTrigger, AbilityEffect};
 use super::super::item_system::ItemProcessor;
+use super::super::field_system::Screens;
+use super::super::super::events::BattleEvent;
 
 /// Calcula el daño de un movimiento
-/// Retorna (daño, mensaje de efectividad, es_crítico)
+/// Retorna (daño, efectividad de tipo, es_crítico)
 pub fn calculate_damage(
     attacker: &PokemonInstance,
     defender: &PokemonInstance,
     move_data: &MoveData,
     is_critical: bool,
     rng: &mut StdRng,
-    mut logs: Option<&mut Vec<String>>,
+    mut events: Option<&mut Vec<BattleEvent>>,
     weather: Option<&WeatherState>,
     terrain: Option<&TerrainState>,
-) -> (u16, String, bool) {
+    screens: Screens,
+) -> (u16, f32, bool) {
     // Si el movimiento no tiene poder, retorna 0
     let Some(power) = move_data.power else {
-        return (0, String::new(), false);
+        return (0, 1.0, false);
     };
 
     // Obtener el tipo del movimiento
     let move_type = parse_type(&move_data.r#type);
 
     // Hook: Verificar inmunidades basadas en habilidades (antes de calcular daño)
-    if let Some(ref mut log_vec) = logs {
-        if check_ability_immunity(defender, move_data, log_vec) {
+    if let Some(ref mut event_vec) = events {
+        if check_ability_immunity(defender, move_data, event_vec) {
             // Si es inmune, retornar 0 de daño
-            return (0, String::new(), false);
+            return (0, 0.0, false);
         }
     } else {
-        // Si no hay logs disponibles, usar logs temporales
-        let mut temp_logs = Vec::new();
-        if check_ability_immunity(defender, move_data, &mut temp_logs) {
-            return (0, String::new(), false);
+        // Si no hay eventos disponibles, usar eventos temporales
+        let mut temp_events = Vec::new();
+        if check_ability_immunity(defender, move_data, &mut temp_events) {
+            return (0, 0.0, false);
         }
     }
 
-    // Calcular efectividad de tipo
-    let mut defender_types = vec![defender.randomized_profile.rolled_primary_type];
-    if let Some(secondary) = defender.randomized_profile.rolled_secondary_type {
-        defender_types.push(secondary);
+    // Hook: Air Balloon hace inmune a los movimientos de tipo Tierra
+    if move_type == PokemonType::Ground && ItemProcessor::grants_ground_immunity(defender) {
+        return (0, 0.0, false);
     }
 
+    // Calcular efectividad de tipo (un defensor teracristalizado solo tiene su Tera Type)
+    let defender_types = defender.current_types();
+
     let type_effectiveness = get_type_effectiveness(&move_type, &defender_types);
 
     // Verificar STAB (Same Type Attack Bonus)
-    // Considera la habilidad Adaptability para x2.0 en lugar de x1.5
+    // Considera la habilidad Adaptability y las reglas de la teracristalización
     let stab_multiplier = get_stab_multiplier(attacker, &move_type);
 
     // Determinar el stat de ataque y defensa según la clase de daño
@@ -68,7 +73,7 @@ pub fn calculate_damage(
         )
     } else {
         // "status" - no hace daño
-        return (0, String::new(), false);
+        return (0, 1.0, false);
     };
 
     // Aplicar multiplicadores de stats stages
@@ -83,21 +88,21 @@ pub fn calculate_damage(
     };
 
     // Hook: Modificar stat ofensivo basado en habilidades (Blaze, Overgrow, Torrent)
-    let ability_multiplier = if let Some(ref mut log_vec) = logs {
+    let ability_multiplier = if let Some(ref mut event_vec) = events {
         modify_offensive_stat_by_ability(
             attacker,
             move_data,
             attack_stat_name,
-            log_vec,
+            event_vec,
         )
     } else {
-        // Si no hay logs disponibles, usar logs temporales (se descartan)
-        let mut temp_logs = Vec::new();
+        // Si no hay eventos disponibles, usar eventos temporales (se descartan)
+        let mut temp_events = Vec::new();
         modify_offensive_stat_by_ability(
             attacker,
             move_data,
             attack_stat_name,
-            &mut temp_logs,
+            &mut temp_events,
         )
     };
 
@@ -114,21 +119,18 @@ pub fn calculate_damage(
     // Hook: Modificar stat defensivo basado en clima (Sandstorm para Rock, Hail para Ice)
     let weather_defense_multiplier = modify_defensive_stat_by_weather(weather, defender, defense_stat_name);
 
-    // Hook: Aplicar multiplicador de Assault Vest (+50% Sp. Defense)
-    let item_defense_multiplier = if defense_stat_name == "special_defense" {
-        ItemProcessor::get_sp_defense_multiplier(defender)
-    } else {
-        1.0
-    };
+    // Hook: Multiplicadores de stats por items (Assault Vest, Booster Energy)
+    let item_attack_multiplier = ItemProcessor::get_stat_multiplier(attacker, attack_stat_name);
+    let item_defense_multiplier = ItemProcessor::get_stat_multiplier(defender, defense_stat_name);
 
     // Calcular stats efectivos (aplicar multiplicador de habilidad al ataque y clima a la defensa)
-    let attack = (base_attack_stat as f32) * attack_multiplier * ability_multiplier;
+    let attack = (base_attack_stat as f32) * attack_multiplier * ability_multiplier * item_attack_multiplier;
     let defense = (base_defense_stat as f32) * defense_multiplier * weather_defense_multiplier * item_defense_multiplier;
 
     // Fórmula de daño Gen 3+
     // Damage = ((((2 * Level / 5 + 2) * Power * A / D) / 50) + 2) * Modifiers
     let level = attacker.level as f32;
-    let power = power as f32;
+    let power = tera_power(attacker, move_data, &move_type, power) as f32;
 
     let base_damage = ((2.0 * level / 5.0 + 2.0) * power * attack / defense) / 50.0 + 2.0;
 
@@ -140,17 +142,24 @@ pub fn calculate_damage(
     modifiers *= weather_damage_mod;
 
     // Hook: Aplicar multiplicador de terreno
-    let terrain_damage_mod = apply_terrain_damage_mod(terrain, attacker, defender, move_data, logs);
+    let terrain_damage_mod = apply_terrain_damage_mod(terrain, attacker, defender, move_data, events);
     modifiers *= terrain_damage_mod;
 
     // Hook: Aplicar multiplicadores de habilidades de daño (Tough Claws, Sheer Force, Technician, etc.)
     let ability_damage_mod = apply_ability_damage_multiplier(attacker, move_data, &move_type);
     modifiers *= ability_damage_mod;
 
-    // Hook: Aplicar multiplicadores de items (Choice Band/Specs, Life Orb)
-    let item_damage_mod = ItemProcessor::get_damage_multiplier(attacker, &move_data.damage_class);
+    // Hook: Rivalry según el género del objetivo
+    modifiers *= gender_damage_multiplier(attacker, defender);
+
+    // Hook: Aplicar multiplicadores de items (Choice Band/Specs, Life Orb, potenciadores de tipo)
+    let item_damage_mod = ItemProcessor::get_damage_multiplier(attacker, &move_data.damage_class)
+        * ItemProcessor::get_type_damage_multiplier(attacker, &move_data.damage_class, move_type);
     modifiers *= item_damage_mod;
 
+    // Hook: Bayas que reducen un golpe de su tipo (Occa Berry, Chilan Berry...)
+    modifiers *= ItemProcessor::get_incoming_damage_multiplier(defender, move_type, type_effectiveness);
+
     // Hook: Aplicar habilidades defensivas del defensor (Solid Rock, Filter)
     let defender_ability_mod = apply_defender_ability_reduction(defender, type_effectiveness);
     modifiers *= defender_ability_mod;
@@ -160,6 +169,9 @@ pub fn calculate_damage(
         modifiers *= 0.5;
     }
 
+    // Pantallas del lado del defensor (Reflect, Light Screen, Aurora Veil); los críticos las ignoran
+    modifiers *= screens.damage_multiplier(&move_data.damage_class, is_critical);
+
     // Multiplicador de crítico (Gen 7+: x1.5)
     if is_critical {
         modifiers *= 1.5;
@@ -170,15 +182,6 @@ pub fn calculate_damage(
 
     let final_damage = (base_damage * modifiers * random_factor) as u16;
 
-    // Mensaje de efectividad
-    let effectiveness_msg = if type_effectiveness >= 2.0 {
-        "¡Es súper efectivo!".to_string()
-    } else if type_effectiveness <= 0.5 {
-        "No es muy efectivo...".to_string()
-    } else {
-        String::new()
-    };
-
     // Asegurar que el daño sea al menos 1 si el movimiento tiene poder y no es ineficaz
     let damage = if type_effectiveness == 0.0 {
         0
@@ -186,7 +189,7 @@ pub fn calculate_damage(
         final_damage.max(1)
     };
 
-    (damage, effectiveness_msg, is_critical)
+    (damage, type_effectiveness, is_critical)
 }
 
 /// Convierte un string de tipo a PokemonType enum
@@ -384,13 +387,47 @@ fn has_stab(attacker: &PokemonInstance, move_type: &PokemonType) -> bool {
     species_match || randomized_match
 }
 
+/// Poder mínimo de los movimientos del Tera Type de un Pokémon teracristalizado
+const TERA_MIN_POWER: u16 = 60;
+
+/// Poder del movimiento tras el mínimo de 60 de la teracristalización
+///
+/// Solo aplica a movimientos del Tera Type que no tengan prioridad ni
+/// golpeen varias veces.
+fn tera_power(attacker: &PokemonInstance, move_data: &MoveData, move_type: &PokemonType, power: u16) -> u16 {
+    let applies = attacker.terastallized
+        && attacker.tera_type == *move_type
+        && move_data.priority <= 0
+        && move_data.meta.max_hits.is_none();
+    if applies {
+        power.max(TERA_MIN_POWER)
+    } else {
+        power
+    }
+}
+
 /// Calcula el multiplicador STAB considerando la habilidad del atacante
 /// - STAB normal: 1.5x
 /// - Con Adaptability: 2.0x
+///
+/// Teracristalizado, los tipos originales conservan su STAB de 1.5x y los
+/// movimientos del Tera Type lo reciben: 2.0x si además es un tipo original
+/// (2.25x con Adaptability), 1.5x si no (2.0x con Adaptability).
 fn get_stab_multiplier(attacker: &PokemonInstance, move_type: &PokemonType) -> f32 {
+    let adaptability = attacker.ability == "adaptability";
+    if attacker.terastallized {
+        let original = has_stab(attacker, move_type);
+        return match (attacker.tera_type == *move_type, original, adaptability) {
+            (true, true, true) => 2.25,
+            (true, true, false) => 2.0,
+            (true, false, true) => 2.0,
+            (true, false, false) | (false, true, _) => 1.5,
+            (false, false, _) => 1.0,
+        };
+    }
     if has_stab(attacker, move_type) {
         // Verificar si tiene la habilidad Adaptability
-        if attacker.ability == "adaptability" {
+        if adaptability {
             2.0
         } else {
             1.5
@@ -617,7 +654,7 @@ fn apply_terrain_damage_mod(
     attacker: &PokemonInstance,
     defender: &PokemonInstance,
     move_data: &MoveData,
-    mut logs: Option<&mut Vec<String>>,
+    mut events: Option<&mut Vec<BattleEvent>>,
 ) -> f32 {
     use crate::models::TerrainType;
     let Some(terrain_state) = terrain else {
@@ -632,11 +669,10 @@ fn apply_terrain_damage_mod(
         TerrainType::Electric => {
             // Electric Terrain: Atacante grounded + Move Electric -> x1.3
             if attacker_grounded && move_type == PokemonType::Electric {
-                if let Some(ref mut log_vec) = logs {
-                    log_vec.push(format!(
-                        "¡El Campo Eléctrico potencia el ataque de {}!",
-                        attacker.species.display_name
-                    ));
+                if let Some(ref mut event_vec) = events {
+                    event_vec.push(BattleEvent::message("battle.terrain.electric_boost", [
+                        ("pokemon", attacker.species.display_name.clone()),
+                    ]));
                 }
                 return 1.3;
             }
@@ -644,11 +680,10 @@ fn apply_terrain_damage_mod(
         TerrainType::Psychic => {
             // Psychic Terrain: Atacante grounded + Move Psychic -> x1.3
             if attacker_grounded && move_type == PokemonType::Psychic {
-                if let Some(ref mut log_vec) = logs {
-                    log_vec.push(format!(
-                        "¡El Campo Psíquico potencia el ataque de {}!",
-                        attacker.species.display_name
-                    ));
+                if let Some(ref mut event_vec) = events {
+                    event_vec.push(BattleEvent::message("battle.terrain.psychic_boost", [
+                        ("pokemon", attacker.species.display_name.clone()),
+                    ]));
                 }
                 return 1.3;
             }
@@ -656,11 +691,10 @@ fn apply_terrain_damage_mod(
         TerrainType::Grassy => {
             // Grassy Terrain: Atacante grounded + Move Grass -> x1.3
             if attacker_grounded && move_type == PokemonType::Grass {
-                if let Some(ref mut log_vec) = logs {
-                    log_vec.push(format!(
-                        "¡El Campo de Hierba potencia el ataque de {}!",
-                        attacker.species.display_name
-                    ));
+                if let Some(ref mut event_vec) = events {
+                    event_vec.push(BattleEvent::message("battle.terrain.grassy_boost", [
+                        ("pokemon", attacker.species.display_name.clone()),
+                    ]));
                 }
                 return 1.3;
             }
@@ -668,11 +702,10 @@ fn apply_terrain_damage_mod(
             // Grassy Terrain: Movimientos de área Ground (earthquake, bulldoze, magnitude) -> x0.5
             let ground_area_moves = ["earthquake", "bulldoze", "magnitude"];
             if ground_area_moves.contains(&move_data.id.as_str()) {
-                if let Some(ref mut log_vec) = logs {
-                    log_vec.push(format!(
-                        "¡El Campo de Hierba reduce el daño de {}!",
-                        move_data.name
-                    ));
+                if let Some(ref mut event_vec) = events {
+                    event_vec.push(BattleEvent::message("battle.terrain.grassy_weakens", [
+                        ("move", move_data.name.clone()),
+                    ]));
                 }
                 return 0.5;
             }
@@ -680,11 +713,10 @@ fn apply_terrain_damage_mod(
         TerrainType::Misty => {
             // Misty Terrain: Move Dragon -> x0.5 (Si el defensor toca el suelo)
             if defender_grounded && move_type == PokemonType::Dragon {
-                if let Some(ref mut log_vec) = logs {
-                    log_vec.push(format!(
-                        "¡El Campo de Niebla reduce el daño de {}!",
-                        move_data.name
-                    ));
+                if let Some(ref mut event_vec) = events {
+                    event_vec.push(BattleEvent::message("battle.terrain.misty_weakens", [
+                        ("move", move_data.name.clone()),
+                    ]));
                 }
                 return 0.5;
             }
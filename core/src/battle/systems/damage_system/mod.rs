@@ -5,9 +5,13 @@
 //! - Aplicar modificadores (STAB, weather, terrain, abilities)
 //! - Calcular críticos
 //! - Calcular efectividad de tipos
+//! - Resolver la precisión (stages de precisión/evasión, clima, habilidades, items)
 
+pub mod accuracy;
 pub mod calculator;
 
+pub use accuracy::check_accuracy;
+
 // Re-exportar funciones principales
 pub use calculator::{
     calculate_damage,
@@ -1,5 +1,7 @@
 use crate::models::{MoveData, PokemonInstance, PokemonType, WeatherState, TerrainState, WeatherType, TerrainType};
 use super::super::damage_system::calculator::parse_type;
+use super::super::super::events::{BattleEvent, DamageCause};
+use super::super::item_system::ItemProcessor;
 
 /// Hook: Habilidades que se activan al entrar en batalla
 /// Se llama cuando un Pokémon entra en combate (inicio de batalla o switch)
@@ -7,8 +9,13 @@ use super::super::damage_system::calculator::parse_type;
 pub fn trigger_on_entry_abilities(
     pokemon: &PokemonInstance,
     opponent: &mut PokemonInstance,
-    logs: &mut Vec<String>,
+    events: &mut Vec<BattleEvent>,
 ) -> (Option<WeatherState>, Option<TerrainState>) {
+    let ability_activated = BattleEvent::AbilityActivated {
+        pokemon: pokemon.species.display_name.clone(),
+        ability: pokemon.ability.clone(),
+    };
+
     match pokemon.ability.as_str() {
         "intimidate" => {
             // Inicializar battle_stages si no existen
@@ -18,74 +25,42 @@ pub fn trigger_on_entry_abilities(
             
             if let Some(ref mut stages) = opponent.battle_stages {
                 // Bajar el ataque del oponente 1 nivel
+                let old_stage = stages.attack;
                 stages.attack = (stages.attack - 1).clamp(-6, 6);
-                logs.push(format!(
-                    "¡La Intimidación de {} bajó el ataque de {}!",
-                    pokemon.species.display_name,
-                    opponent.species.display_name
-                ));
+                events.push(ability_activated);
+                if stages.attack != old_stage {
+                    events.push(BattleEvent::StatStageChanged {
+                        target: opponent.species.display_name.clone(),
+                        stat: "attack".to_string(),
+                        stages: stages.attack - old_stage,
+                    });
+                }
             }
             (None, None)
         }
-        "drought" => {
-            // Drought: Activa Sol intenso (5 turnos)
-            logs.push(format!(
-                "¡La Sequía de {} intensificó los rayos del sol!",
-                pokemon.species.display_name
-            ));
-            (Some(WeatherState::new(WeatherType::Sun)), None)
-        }
-        "drizzle" => {
-            // Drizzle: Activa Lluvia (5 turnos)
-            logs.push(format!(
-                "¡La Llovizna de {} provocó un diluvio!",
-                pokemon.species.display_name
-            ));
-            (Some(WeatherState::new(WeatherType::Rain)), None)
-        }
-        "sand-stream" => {
-            // Sand Stream: Activa Tormenta de arena (5 turnos)
-            logs.push(format!(
-                "¡La Corriente de Arena de {} desató una tormenta de arena!",
-                pokemon.species.display_name
-            ));
-            (Some(WeatherState::new(WeatherType::Sandstorm)), None)
-        }
-        "snow-warning" => {
-            // Snow Warning: Activa Granizo (5 turnos)
-            logs.push(format!(
-                "¡La Alerta de Nieve de {} provocó una granizada!",
-                pokemon.species.display_name
-            ));
-            (Some(WeatherState::new(WeatherType::Hail)), None)
-        }
-        "electric-surge" => {
-            // Electric Surge: Activa Campo Eléctrico (5 turnos)
-            logs.push(format!(
-                "¡Una corriente eléctrica recorre el campo!",
-            ));
-            (None, Some(TerrainState::new(TerrainType::Electric)))
+        "drought" | "drizzle" | "sand-stream" | "snow-warning" => {
+            // Drought/Drizzle/Sand Stream/Snow Warning: Activan clima (5 turnos)
+            let weather = match pokemon.ability.as_str() {
+                "drought" => WeatherType::Sun,
+                "drizzle" => WeatherType::Rain,
+                "sand-stream" => WeatherType::Sandstorm,
+                _ => WeatherType::Hail,
+            };
+            events.push(ability_activated);
+            events.push(BattleEvent::WeatherSet { weather });
+            (Some(WeatherState::new(weather)), None)
         }
-        "grassy-surge" => {
-            // Grassy Surge: Activa Campo de Hierba (5 turnos)
-            logs.push(format!(
-                "¡Hierba crece en el combate!",
-            ));
-            (None, Some(TerrainState::new(TerrainType::Grassy)))
-        }
-        "misty-surge" => {
-            // Misty Surge: Activa Campo de Niebla (5 turnos)
-            logs.push(format!(
-                "¡Una niebla mística envuelve el campo!",
-            ));
-            (None, Some(TerrainState::new(TerrainType::Misty)))
-        }
-        "psychic-surge" => {
-            // Psychic Surge: Activa Campo Psíquico (5 turnos)
-            logs.push(format!(
-                "¡El campo se llena de energía psíquica!",
-            ));
-            (None, Some(TerrainState::new(TerrainType::Psychic)))
+        "electric-surge" | "grassy-surge" | "misty-surge" | "psychic-surge" => {
+            // Surges: Activan terreno (5 turnos)
+            let terrain = match pokemon.ability.as_str() {
+                "electric-surge" => TerrainType::Electric,
+                "grassy-surge" => TerrainType::Grassy,
+                "misty-surge" => TerrainType::Misty,
+                _ => TerrainType::Psychic,
+            };
+            events.push(ability_activated);
+            events.push(BattleEvent::TerrainSet { terrain: Some(terrain) });
+            (None, Some(TerrainState::new(terrain)))
         }
         _ => {
             // Otras habilidades de entrada se pueden añadir aquí
@@ -94,22 +69,73 @@ pub fn trigger_on_entry_abilities(
     }
 }
 
+/// Duración del clima o del terreno con el objeto que los alarga
+pub const EXTENDED_FIELD_TURNS: u8 = 8;
+
+/// Clima que establece un movimiento (None si no es un movimiento de clima)
+pub fn weather_for_move(move_id: &str) -> Option<WeatherType> {
+    match move_id {
+        "sunny-day" => Some(WeatherType::Sun),
+        "rain-dance" => Some(WeatherType::Rain),
+        "sandstorm" => Some(WeatherType::Sandstorm),
+        "hail" | "snowscape" => Some(WeatherType::Hail),
+        _ => None,
+    }
+}
+
+/// Terreno que establece un movimiento (None si no es un movimiento de terreno)
+pub fn terrain_for_move(move_id: &str) -> Option<TerrainType> {
+    match move_id {
+        "electric-terrain" => Some(TerrainType::Electric),
+        "grassy-terrain" => Some(TerrainType::Grassy),
+        "misty-terrain" => Some(TerrainType::Misty),
+        "psychic-terrain" => Some(TerrainType::Psychic),
+        _ => None,
+    }
+}
+
+/// Clima que establece `setter` (por movimiento o habilidad)
+///
+/// Dura `duration` turnos (0 = duración por defecto), u 8 si el Pokémon lleva
+/// la roca correspondiente.
+pub fn weather_state_for(setter: &PokemonInstance, weather: WeatherType, duration: u8) -> WeatherState {
+    if ItemProcessor::extends_weather(setter, weather) {
+        WeatherState::with_duration(weather, EXTENDED_FIELD_TURNS)
+    } else if duration == 0 {
+        WeatherState::new(weather)
+    } else {
+        WeatherState::with_duration(weather, duration)
+    }
+}
+
+/// Terreno que establece `setter` (por movimiento o habilidad)
+///
+/// Dura `duration` turnos (0 = duración por defecto), u 8 con Terrain Extender.
+pub fn terrain_state_for(setter: &PokemonInstance, terrain: TerrainType, duration: u8) -> TerrainState {
+    if ItemProcessor::extends_terrain(setter) {
+        TerrainState::with_duration(terrain, EXTENDED_FIELD_TURNS)
+    } else if duration == 0 {
+        TerrainState::new(terrain)
+    } else {
+        TerrainState::with_duration(terrain, duration)
+    }
+}
+
 /// Hook: Verifica inmunidades basadas en habilidades
 /// Retorna true si el defensor es inmune al movimiento
 pub fn check_ability_immunity(
     defender: &PokemonInstance,
     move_data: &MoveData,
-    logs: &mut Vec<String>,
+    events: &mut Vec<BattleEvent>,
 ) -> bool {
     let move_type = parse_type(&move_data.r#type);
     
     match defender.ability.as_str() {
         "levitate" => {
             if move_type == PokemonType::Ground {
-                logs.push(format!(
-                    "¡{} levitó sobre el ataque!",
-                    defender.species.display_name
-                ));
+                events.push(BattleEvent::message("battle.levitate", [
+                    ("pokemon", defender.species.display_name.clone()),
+                ]));
                 return true;
             }
         }
@@ -125,32 +151,24 @@ pub fn check_ability_immunity(
 /// Retorna `false` si el Pokémon no toca el suelo, `true` si está grounded
 /// 
 /// Un Pokémon NO está grounded (no toca el suelo) si:
-/// - Es de tipo Flying (primary o secondary)
+/// - Es de tipo Flying (tipos actuales: con teracristalización, solo su Tera Type)
 /// - Tiene la habilidad "levitate"
 /// - Tiene el item "air-balloon"
 /// - (Opcional) Tiene estados volátiles que lo levantan (magnet-rise, telekinesis)
 pub fn is_grounded(pokemon: &PokemonInstance) -> bool {
     // Verificar si es tipo Flying
-    if pokemon.randomized_profile.rolled_primary_type == PokemonType::Flying {
+    if pokemon.current_types().contains(&PokemonType::Flying) {
         return false;
     }
     
-    if let Some(secondary_type) = pokemon.randomized_profile.rolled_secondary_type {
-        if secondary_type == PokemonType::Flying {
-            return false;
-        }
-    }
-    
     // Verificar habilidad Levitate
     if pokemon.ability.as_str() == "levitate" {
         return false;
     }
     
     // Verificar item Air Balloon
-    if let Some(ref item) = pokemon.held_item {
-        if item.as_str() == "air-balloon" {
-            return false;
-        }
+    if ItemProcessor::grants_ground_immunity(pokemon) {
+        return false;
     }
     
     // Nota: Los estados volátiles magnet-rise y telekinesis no están implementados
@@ -171,7 +189,7 @@ pub fn modify_offensive_stat_by_ability(
     attacker: &PokemonInstance,
     move_data: &MoveData,
     _attack_stat_name: &str,
-    logs: &mut Vec<String>,
+    events: &mut Vec<BattleEvent>,
 ) -> f32 {
     let move_type = parse_type(&move_data.r#type);
     let max_hp = attacker.base_computed_stats.hp;
@@ -182,33 +200,37 @@ pub fn modify_offensive_stat_by_ability(
         "blaze" => {
             // Blaze: Aumenta el poder de movimientos de tipo Fuego en 50% cuando HP < 33%
             if current_hp < hp_threshold && move_type == PokemonType::Fire {
-                logs.push(format!(
-                    "¡Arde la habilidad de {}!",
-                    attacker.species.display_name
-                ));
+                events.push(BattleEvent::AbilityActivated {
+                    pokemon: attacker.species.display_name.clone(),
+                    ability: attacker.ability.clone(),
+                });
                 return 1.5;
             }
         }
         "overgrow" => {
             // Overgrow: Aumenta el poder de movimientos de tipo Planta en 50% cuando HP < 33%
             if current_hp < hp_threshold && move_type == PokemonType::Grass {
-                logs.push(format!(
-                    "¡La habilidad de {} se intensifica!",
-                    attacker.species.display_name
-                ));
+                events.push(BattleEvent::AbilityActivated {
+                    pokemon: attacker.species.display_name.clone(),
+                    ability: attacker.ability.clone(),
+                });
                 return 1.5;
             }
         }
         "torrent" => {
             // Torrent: Aumenta el poder de movimientos de tipo Agua en 50% cuando HP < 33%
             if current_hp < hp_threshold && move_type == PokemonType::Water {
-                logs.push(format!(
-                    "¡La habilidad de {} se intensifica!",
-                    attacker.species.display_name
-                ));
+                events.push(BattleEvent::AbilityActivated {
+                    pokemon: attacker.species.display_name.clone(),
+                    ability: attacker.ability.clone(),
+                });
                 return 1.5;
             }
         }
+        "gorilla-tactics" if move_data.damage_class == "physical" => {
+            // Gorilla Tactics: +50% Ataque, a cambio de quedar bloqueado como con Choice Band
+            return 1.5;
+        }
         _ => {
             // Otras habilidades que modifican stats se pueden añadir aquí
         }
@@ -218,13 +240,13 @@ pub fn modify_offensive_stat_by_ability(
 }
 
 /// Aplica efectos residuales del clima a un Pokémon
-/// Retorna (daño total, logs)
-pub fn apply_weather_residuals(pokemon: &mut PokemonInstance, weather: Option<&WeatherState>) -> (u16, Vec<String>) {
-    let mut logs = Vec::new();
+/// Retorna (daño total, eventos)
+pub fn apply_weather_residuals(pokemon: &mut PokemonInstance, weather: Option<&WeatherState>) -> (u16, Vec<BattleEvent>) {
+    let mut events = Vec::new();
     let mut total_damage = 0u16;
     
     let Some(weather_state) = weather else {
-        return (0, logs);
+        return (0, events);
     };
     
     // Aplicar daño residual según el tipo de clima
@@ -244,10 +266,12 @@ pub fn apply_weather_residuals(pokemon: &mut PokemonInstance, weather: Option<&W
                 let actual_damage = damage.min(pokemon.current_hp);
                 pokemon.current_hp = pokemon.current_hp.saturating_sub(damage);
                 total_damage = actual_damage;
-                logs.push(format!(
-                    "¡La tormenta de arena golpea a {}!",
-                    pokemon.species.display_name
-                ));
+                events.push(BattleEvent::Damage {
+                    target: pokemon.species.display_name.clone(),
+                    amount: actual_damage,
+                    remaining_hp: pokemon.current_hp,
+                    cause: DamageCause::Weather { weather: WeatherType::Sandstorm },
+                });
             }
         }
         WeatherType::Hail => {
@@ -261,10 +285,12 @@ pub fn apply_weather_residuals(pokemon: &mut PokemonInstance, weather: Option<&W
                 let actual_damage = damage.min(pokemon.current_hp);
                 pokemon.current_hp = pokemon.current_hp.saturating_sub(damage);
                 total_damage = actual_damage;
-                logs.push(format!(
-                    "¡El granizo golpea a {}!",
-                    pokemon.species.display_name
-                ));
+                events.push(BattleEvent::Damage {
+                    target: pokemon.species.display_name.clone(),
+                    amount: actual_damage,
+                    remaining_hp: pokemon.current_hp,
+                    cause: DamageCause::Weather { weather: WeatherType::Hail },
+                });
             }
         }
         _ => {
@@ -272,17 +298,17 @@ pub fn apply_weather_residuals(pokemon: &mut PokemonInstance, weather: Option<&W
         }
     }
     
-    (total_damage, logs)
+    (total_damage, events)
 }
 
 /// Aplica efectos residuales de condiciones de estado (quemadura, veneno, etc.)
-/// Retorna (daño_recibido, logs)
-pub fn apply_residual_effects(pokemon: &mut PokemonInstance) -> (u16, Vec<String>) {
+/// Retorna (daño_recibido, eventos)
+pub fn apply_residual_effects(pokemon: &mut PokemonInstance) -> (u16, Vec<BattleEvent>) {
     use crate::models::StatusCondition;
-    let mut logs = Vec::new();
+    let mut events = Vec::new();
     let mut total_damage = 0u16;
     
-    if let Some(status) = &pokemon.status_condition {
+    if let Some(status) = pokemon.status_condition {
         match status {
             StatusCondition::Burn => {
                 let max_hp = pokemon.base_computed_stats.hp;
@@ -290,10 +316,12 @@ pub fn apply_residual_effects(pokemon: &mut PokemonInstance) -> (u16, Vec<String
                 let actual_damage = damage.min(pokemon.current_hp);
                 pokemon.current_hp = pokemon.current_hp.saturating_sub(damage);
                 total_damage = actual_damage;
-                logs.push(format!(
-                    "¡{} se lastima por la quemadura!",
-                    pokemon.species.display_name
-                ));
+                events.push(BattleEvent::Damage {
+                    target: pokemon.species.display_name.clone(),
+                    amount: actual_damage,
+                    remaining_hp: pokemon.current_hp,
+                    cause: DamageCause::Status { status },
+                });
             }
             StatusCondition::Poison => {
                 let max_hp = pokemon.base_computed_stats.hp;
@@ -301,10 +329,12 @@ pub fn apply_residual_effects(pokemon: &mut PokemonInstance) -> (u16, Vec<String
                 let actual_damage = damage.min(pokemon.current_hp);
                 pokemon.current_hp = pokemon.current_hp.saturating_sub(damage);
                 total_damage = actual_damage;
-                logs.push(format!(
-                    "¡{} sufre por el veneno!",
-                    pokemon.species.display_name
-                ));
+                events.push(BattleEvent::Damage {
+                    target: pokemon.species.display_name.clone(),
+                    amount: actual_damage,
+                    remaining_hp: pokemon.current_hp,
+                    cause: DamageCause::Status { status },
+                });
             }
             StatusCondition::BadPoison => {
                 // Toxic: aumenta el daño cada turno (1/16, 2/16, 3/16, etc.)
@@ -321,21 +351,24 @@ pub fn apply_residual_effects(pokemon: &mut PokemonInstance) -> (u16, Vec<String
                     pokemon.current_hp = pokemon.current_hp.saturating_sub(damage);
                     total_damage = actual_damage;
 
-                    logs.push(format!(
-                        "¡{} sufre gravemente por el veneno! (turno {})",
-                        pokemon.species.display_name,
-                        turns
-                    ));
+                    events.push(BattleEvent::Damage {
+                        target: pokemon.species.display_name.clone(),
+                        amount: actual_damage,
+                        remaining_hp: pokemon.current_hp,
+                        cause: DamageCause::Status { status },
+                    });
                 } else {
                     // Fallback si no tiene volatile_status (no debería pasar)
                     let damage = max_hp / 8;
                     let actual_damage = damage.min(pokemon.current_hp);
                     pokemon.current_hp = pokemon.current_hp.saturating_sub(damage);
                     total_damage = actual_damage;
-                    logs.push(format!(
-                        "¡{} sufre gravemente por el veneno!",
-                        pokemon.species.display_name
-                    ));
+                    events.push(BattleEvent::Damage {
+                        target: pokemon.species.display_name.clone(),
+                        amount: actual_damage,
+                        remaining_hp: pokemon.current_hp,
+                        cause: DamageCause::Status { status },
+                    });
                 }
             }
             _ => {
@@ -344,6 +377,6 @@ pub fn apply_residual_effects(pokemon: &mut PokemonInstance) -> (u16, Vec<String
         }
     }
     
-    (total_damage, logs)
+    (total_damage, events)
 }
 
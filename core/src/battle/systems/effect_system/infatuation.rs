@@ -0,0 +1,142 @@
+//! Enamoramiento (Attract, Cute Charm)
+//!
+//! Solo se enamoran Pokémon de géneros opuestos y Oblivious lo impide. Si el
+//! enamorado lleva Destiny Knot, quien lo enamoró también se enamora. Dura
+//! hasta que el causante deja el campo: `VolatileStatus::infatuated_by` guarda
+//! su id.
+
+use crate::models::{PokemonInstance, VolatileStatus};
+use super::super::ability_system::prevents_infatuation;
+use super::super::item_system::ItemProcessor;
+
+/// Motivo por el que un Pokémon no puede enamorarse
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InfatuationFailure {
+    /// Ya está enamorado
+    AlreadyInfatuated,
+    /// No son de géneros opuestos (o alguno no tiene género)
+    NotOppositeGender,
+    /// Su habilidad lo impide (Oblivious)
+    Ability,
+}
+
+/// Si el Pokémon está enamorado
+pub fn is_infatuated(pokemon: &PokemonInstance) -> bool {
+    pokemon.volatile_status.as_ref().is_some_and(|v| v.infatuated_by.is_some())
+}
+
+/// Comprueba si `source` puede enamorar a `target`
+pub fn can_infatuate(target: &PokemonInstance, source: &PokemonInstance) -> Result<(), InfatuationFailure> {
+    if is_infatuated(target) {
+        Err(InfatuationFailure::AlreadyInfatuated)
+    } else if !target.gender.is_opposite(source.gender) {
+        Err(InfatuationFailure::NotOppositeGender)
+    } else if prevents_infatuation(target) {
+        Err(InfatuationFailure::Ability)
+    } else {
+        Ok(())
+    }
+}
+
+/// Enamora a `target` de `source` si es posible
+pub fn infatuate(target: &mut PokemonInstance, source: &PokemonInstance) -> Result<(), InfatuationFailure> {
+    can_infatuate(target, source)?;
+    target.volatile_status.get_or_insert_with(VolatileStatus::new).infatuated_by = Some(source.id.clone());
+    Ok(())
+}
+
+/// Si el Pokémon devuelve el enamoramiento a quien lo causó (Destiny Knot)
+pub fn reflects_infatuation(pokemon: &PokemonInstance) -> bool {
+    ItemProcessor::reflects_infatuation(pokemon)
+}
+
+/// Termina el enamoramiento si su causante ya no está en el campo
+///
+/// `rivals_on_field` son los ids de los rivales en pie en el campo.
+pub fn end_infatuation_without_source(pokemon: &mut PokemonInstance, rivals_on_field: &[String]) {
+    if let Some(volatile) = pokemon.volatile_status.as_mut() {
+        if volatile.infatuated_by.as_ref().is_some_and(|source| !rivals_on_field.contains(source)) {
+            volatile.infatuated_by = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::super::ability_system::gender_damage_multiplier;
+    use crate::factory::create_pokemon_instance;
+    use crate::models::{Gender, PokemonSpecies, PokemonType, Stats};
+
+    fn pokemon(id: &str, gender: Gender, ability: &str) -> PokemonInstance {
+        let species = PokemonSpecies {
+            species_id: "test".to_string(),
+            display_name: "Test".to_string(),
+            generation: 1,
+            primary_type: PokemonType::Normal,
+            secondary_type: None,
+            base_stats: Stats {
+                hp: 80,
+                attack: 80,
+                defense: 80,
+                special_attack: 80,
+                special_defense: 80,
+                speed: 80,
+            },
+            move_pool: Vec::new(),
+            possible_abilities: vec![ability.to_string()],
+            is_starter_candidate: false,
+            evolutions: Vec::new(),
+            ev_yield: Stats::zero(),
+            gender_rate: 4,
+            mega_forms: Vec::new(),
+        };
+        let mut pokemon = create_pokemon_instance(&species, 50, 1, false, &[], None);
+        pokemon.id = id.to_string();
+        pokemon.gender = gender;
+        pokemon.ability = ability.to_string();
+        pokemon
+    }
+
+    #[test]
+    fn test_only_opposite_genders_fall_in_love() {
+        let male = pokemon("male", Gender::Male, "run-away");
+        let mut female = pokemon("female", Gender::Female, "run-away");
+        let mut other_male = pokemon("other", Gender::Male, "run-away");
+        let mut genderless = pokemon("genderless", Gender::Genderless, "run-away");
+
+        assert_eq!(infatuate(&mut other_male, &male), Err(InfatuationFailure::NotOppositeGender));
+        assert_eq!(infatuate(&mut genderless, &male), Err(InfatuationFailure::NotOppositeGender));
+        assert_eq!(infatuate(&mut female, &male), Ok(()));
+        assert_eq!(female.volatile_status.as_ref().unwrap().infatuated_by.as_deref(), Some("male"));
+        assert_eq!(infatuate(&mut female, &male), Err(InfatuationFailure::AlreadyInfatuated));
+    }
+
+    #[test]
+    fn test_oblivious_prevents_infatuation() {
+        let male = pokemon("male", Gender::Male, "run-away");
+        let mut oblivious = pokemon("female", Gender::Female, "oblivious");
+        assert_eq!(infatuate(&mut oblivious, &male), Err(InfatuationFailure::Ability));
+        assert!(!is_infatuated(&oblivious));
+    }
+
+    #[test]
+    fn test_infatuation_ends_when_source_leaves() {
+        let male = pokemon("male", Gender::Male, "run-away");
+        let mut female = pokemon("female", Gender::Female, "run-away");
+        infatuate(&mut female, &male).unwrap();
+
+        end_infatuation_without_source(&mut female, &["male".to_string()]);
+        assert!(is_infatuated(&female));
+        end_infatuation_without_source(&mut female, &["replacement".to_string()]);
+        assert!(!is_infatuated(&female));
+    }
+
+    #[test]
+    fn test_rivalry_depends_on_gender() {
+        let rivalry = pokemon("rivalry", Gender::Male, "rivalry");
+        assert_eq!(gender_damage_multiplier(&rivalry, &pokemon("a", Gender::Male, "run-away")), 1.25);
+        assert_eq!(gender_damage_multiplier(&rivalry, &pokemon("b", Gender::Female, "run-away")), 0.75);
+        assert_eq!(gender_damage_multiplier(&rivalry, &pokemon("c", Gender::Genderless, "run-away")), 1.0);
+    }
+}
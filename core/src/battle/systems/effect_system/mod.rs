@@ -4,9 +4,11 @@
 //! - Gestionar efectos de clima (weather)
 //! - Gestionar efectos de terreno (terrain)
 //! - Aplicar efectos de estados alterados
+//! - Gestionar el enamoramiento (Attract, Cute Charm)
 //! - Procesar efectos residuales
 
 pub mod effects_handler;
+pub mod infatuation;
 
 // Re-exportar funciones principales
 pub use effects_handler::{
@@ -16,6 +18,18 @@ pub use effects_handler::{
     check_ability_immunity,
     modify_offensive_stat_by_ability,
     trigger_on_entry_abilities,
+    weather_for_move,
+    terrain_for_move,
+    weather_state_for,
+    terrain_state_for,
+    EXTENDED_FIELD_TURNS,
+};
+pub use infatuation::{
+    can_infatuate,
+    end_infatuation_without_source,
+    infatuate,
+    reflects_infatuation,
+    InfatuationFailure,
 };
 
 // NOTA: process_end_of_turn_residuals permanece en pipeline.rs por ahora
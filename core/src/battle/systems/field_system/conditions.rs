@@ -0,0 +1,155 @@
+//! Condiciones temporales de un lado del campo
+//!
+//! Reflect, Light Screen y Aurora Veil reducen el daño que recibe el lado;
+//! Tailwind duplica su velocidad. Todas se activan con un movimiento del propio
+//! lado y duran un número de turnos que se descuenta al final de cada turno.
+
+use crate::game::SideConditions;
+use crate::models::{BattleFormat, PokemonInstance, SideCondition, WeatherState, WeatherType};
+use super::super::item_system::ItemProcessor;
+
+/// Duración de las pantallas
+pub const SCREEN_TURNS: u8 = 5;
+/// Duración de las pantallas con Light Clay
+pub const EXTENDED_SCREEN_TURNS: u8 = 8;
+/// Duración de Tailwind (incluye el turno en que se usa)
+pub const TAILWIND_TURNS: u8 = 4;
+
+/// Multiplicador de daño de una pantalla en combates individuales
+const SCREEN_MULTIPLIER_SINGLES: f32 = 0.5;
+/// Multiplicador de daño de una pantalla en dobles (2732/4096)
+const SCREEN_MULTIPLIER_DOUBLES: f32 = 2732.0 / 4096.0;
+
+/// Condición que activa un movimiento (None si no es un movimiento de condición)
+pub fn side_condition_for_move(move_id: &str) -> Option<SideCondition> {
+    match move_id {
+        "reflect" => Some(SideCondition::Reflect),
+        "light-screen" => Some(SideCondition::LightScreen),
+        "aurora-veil" => Some(SideCondition::AuroraVeil),
+        "tailwind" => Some(SideCondition::Tailwind),
+        _ => None,
+    }
+}
+
+/// Si el movimiento rompe las pantallas del lado objetivo antes de golpear
+pub fn breaks_screens(move_id: &str) -> bool {
+    matches!(move_id, "brick-break" | "psychic-fangs")
+}
+
+fn turns_mut(side: &mut SideConditions, condition: SideCondition) -> &mut u8 {
+    match condition {
+        SideCondition::Reflect => &mut side.reflect_turns,
+        SideCondition::LightScreen => &mut side.light_screen_turns,
+        SideCondition::AuroraVeil => &mut side.aurora_veil_turns,
+        SideCondition::Tailwind => &mut side.tailwind_turns,
+    }
+}
+
+/// Activa una condición en el lado
+///
+/// Retorna false si ya estaba activa o si es Aurora Veil sin granizo (el
+/// movimiento falla). Las pantallas duran más si el usuario lleva Light Clay.
+pub fn start_side_condition(
+    side: &mut SideConditions,
+    condition: SideCondition,
+    user: &PokemonInstance,
+    weather: Option<&WeatherState>,
+) -> bool {
+    if condition == SideCondition::AuroraVeil
+        && weather.is_none_or(|w| w.weather_type != WeatherType::Hail)
+    {
+        return false;
+    }
+
+    let duration = match condition {
+        SideCondition::Tailwind => TAILWIND_TURNS,
+        _ if ItemProcessor::extends_screens(user) => EXTENDED_SCREEN_TURNS,
+        _ => SCREEN_TURNS,
+    };
+
+    let turns = turns_mut(side, condition);
+    if *turns > 0 {
+        return false;
+    }
+    *turns = duration;
+    true
+}
+
+/// Retira Reflect, Light Screen y Aurora Veil del lado (Brick Break, Defog)
+///
+/// Retorna true si había alguna.
+pub fn break_screens(side: &mut SideConditions) -> bool {
+    let had_screens = side.reflect_turns > 0 || side.light_screen_turns > 0 || side.aurora_veil_turns > 0;
+    side.reflect_turns = 0;
+    side.light_screen_turns = 0;
+    side.aurora_veil_turns = 0;
+    had_screens
+}
+
+/// Descuenta un turno de cada condición activa del lado
+///
+/// Retorna las condiciones que terminaron este turno.
+pub fn tick_side_conditions(side: &mut SideConditions) -> Vec<SideCondition> {
+    let mut ended = Vec::new();
+    for condition in [
+        SideCondition::Reflect,
+        SideCondition::LightScreen,
+        SideCondition::AuroraVeil,
+        SideCondition::Tailwind,
+    ] {
+        let turns = turns_mut(side, condition);
+        if *turns > 0 {
+            *turns -= 1;
+            if *turns == 0 {
+                ended.push(condition);
+            }
+        }
+    }
+    ended
+}
+
+/// Si el lado tiene Tailwind activo
+pub fn has_tailwind(side: &SideConditions) -> bool {
+    side.tailwind_turns > 0
+}
+
+/// Pantallas activas en el lado del defensor, tal como las usa el cálculo de daño
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Screens {
+    pub reflect: bool,
+    pub light_screen: bool,
+    pub aurora_veil: bool,
+    /// En dobles las pantallas reducen menos el daño
+    pub doubles: bool,
+}
+
+impl Screens {
+    /// Pantallas de un lado del campo en el formato de la batalla
+    pub fn of(side: &SideConditions, format: BattleFormat) -> Self {
+        Self {
+            reflect: side.reflect_turns > 0,
+            light_screen: side.light_screen_turns > 0,
+            aurora_veil: side.aurora_veil_turns > 0,
+            doubles: format == BattleFormat::Double,
+        }
+    }
+
+    /// Multiplicador de daño para un movimiento de la clase indicada
+    ///
+    /// Los golpes críticos ignoran las pantallas. Aurora Veil no se acumula
+    /// con Reflect ni Light Screen.
+    pub fn damage_multiplier(&self, damage_class: &str, is_critical: bool) -> f32 {
+        if is_critical {
+            return 1.0;
+        }
+
+        let screened = self.aurora_veil
+            || (damage_class == "physical" && self.reflect)
+            || (damage_class == "special" && self.light_screen);
+        match (screened, self.doubles) {
+            (false, _) => 1.0,
+            (true, false) => SCREEN_MULTIPLIER_SINGLES,
+            (true, true) => SCREEN_MULTIPLIER_DOUBLES,
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Hazards de entrada
+//!
+//! Se colocan con movimientos de campo sobre el lado rival y se activan cada
+//! vez que un Pokémon entra a ese lado. El estado vive en `SideConditions`.
+
+use crate::game::SideConditions;
+use crate::models::{EntryHazard, PokemonInstance, PokemonType, StatusCondition};
+use super::super::super::events::{BattleEvent, DamageCause};
+use super::super::damage_system::get_type_effectiveness;
+use super::super::effect_system::is_grounded;
+
+/// Capas máximas de Spikes
+pub const MAX_SPIKES_LAYERS: u8 = 3;
+/// Capas máximas de Toxic Spikes
+pub const MAX_TOXIC_SPIKES_LAYERS: u8 = 2;
+
+/// Hazard que coloca un movimiento (None si no es un movimiento de hazard)
+pub fn hazard_for_move(move_id: &str) -> Option<EntryHazard> {
+    match move_id {
+        "stealth-rock" => Some(EntryHazard::StealthRock),
+        "spikes" => Some(EntryHazard::Spikes),
+        "toxic-spikes" => Some(EntryHazard::ToxicSpikes),
+        "sticky-web" => Some(EntryHazard::StickyWeb),
+        _ => None,
+    }
+}
+
+/// Coloca una capa del hazard en el lado
+///
+/// Retorna false si el hazard ya estaba al máximo de capas (el movimiento falla).
+pub fn lay_hazard(side: &mut SideConditions, hazard: EntryHazard) -> bool {
+    match hazard {
+        EntryHazard::StealthRock => !std::mem::replace(&mut side.stealth_rock, true),
+        EntryHazard::Spikes => add_layer(&mut side.spikes, MAX_SPIKES_LAYERS),
+        EntryHazard::ToxicSpikes => add_layer(&mut side.toxic_spikes, MAX_TOXIC_SPIKES_LAYERS),
+        EntryHazard::StickyWeb => !std::mem::replace(&mut side.sticky_web, true),
+    }
+}
+
+fn add_layer(layers: &mut u8, max: u8) -> bool {
+    if *layers >= max {
+        return false;
+    }
+    *layers += 1;
+    true
+}
+
+/// Si el lado tiene algún hazard colocado
+pub fn has_hazards(side: &SideConditions) -> bool {
+    side.stealth_rock || side.spikes > 0 || side.toxic_spikes > 0 || side.sticky_web
+}
+
+/// Retira todos los hazards del lado (Rapid Spin, Defog)
+///
+/// Retorna true si había alguno.
+pub fn clear_hazards(side: &mut SideConditions) -> bool {
+    let had_hazards = has_hazards(side);
+    side.stealth_rock = false;
+    side.spikes = 0;
+    side.toxic_spikes = 0;
+    side.sticky_web = false;
+    had_hazards
+}
+
+/// Aplica los hazards del lado a un Pokémon que acaba de entrar
+///
+/// Stealth Rock afecta a todos; Spikes, Toxic Spikes y Sticky Web solo a los
+/// que están en el suelo (`is_grounded`). Un tipo Veneno en el suelo absorbe
+/// las Toxic Spikes y las retira del lado.
+pub fn apply_entry_hazards(
+    pokemon: &mut PokemonInstance,
+    side: &mut SideConditions,
+    events: &mut Vec<BattleEvent>,
+) {
+    if pokemon.current_hp == 0 {
+        return;
+    }
+
+    let types = pokemon.current_types();
+
+    // Stealth Rock: 1/8 del HP máximo escalado por la efectividad de Roca
+    if side.stealth_rock {
+        let effectiveness = get_type_effectiveness(&PokemonType::Rock, &types);
+        deal_hazard_damage(pokemon, effectiveness / 8.0, EntryHazard::StealthRock, events);
+    }
+
+    if pokemon.current_hp == 0 || !is_grounded(pokemon) {
+        return;
+    }
+
+    // Spikes: 1/8, 1/6 o 1/4 del HP máximo según las capas
+    if side.spikes > 0 {
+        let fraction = match side.spikes {
+            1 => 1.0 / 8.0,
+            2 => 1.0 / 6.0,
+            _ => 1.0 / 4.0,
+        };
+        deal_hazard_damage(pokemon, fraction, EntryHazard::Spikes, events);
+        if pokemon.current_hp == 0 {
+            return;
+        }
+    }
+
+    // Toxic Spikes: veneno con 1 capa, veneno grave con 2
+    if side.toxic_spikes > 0 {
+        if types.contains(&PokemonType::Poison) {
+            side.toxic_spikes = 0;
+            events.push(BattleEvent::message("battle.hazard.toxic_spikes_absorbed", [
+                ("pokemon", pokemon.species.display_name.clone()),
+            ]));
+        } else if !types.contains(&PokemonType::Steel) && pokemon.status_condition.is_none() {
+            let status = if side.toxic_spikes >= MAX_TOXIC_SPIKES_LAYERS {
+                StatusCondition::BadPoison
+            } else {
+                StatusCondition::Poison
+            };
+            pokemon.status_condition = Some(status);
+            events.push(BattleEvent::StatusApplied {
+                target: pokemon.species.display_name.clone(),
+                status,
+            });
+        }
+    }
+
+    // Sticky Web: -1 de velocidad
+    if side.sticky_web {
+        if pokemon.battle_stages.is_none() {
+            pokemon.init_battle_stages();
+        }
+        if let Some(ref mut stages) = pokemon.battle_stages {
+            let applied = stages.apply_change("speed", -1);
+            if applied != 0 {
+                events.push(BattleEvent::StatStageChanged {
+                    target: pokemon.species.display_name.clone(),
+                    stat: "speed".to_string(),
+                    stages: applied,
+                });
+            }
+        }
+    }
+}
+
+/// Quita una fracción del HP máximo (mínimo 1 si la fracción es positiva)
+fn deal_hazard_damage(
+    pokemon: &mut PokemonInstance,
+    fraction: f32,
+    hazard: EntryHazard,
+    events: &mut Vec<BattleEvent>,
+) {
+    if fraction <= 0.0 {
+        return;
+    }
+
+    let damage = ((pokemon.base_computed_stats.hp as f32 * fraction) as u16).max(1);
+    pokemon.current_hp = pokemon.current_hp.saturating_sub(damage);
+    events.push(BattleEvent::Damage {
+        target: pokemon.species.display_name.clone(),
+        amount: damage,
+        remaining_hp: pokemon.current_hp,
+        cause: DamageCause::Hazard { hazard },
+    });
+
+    if pokemon.current_hp == 0 {
+        events.push(BattleEvent::Fainted {
+            target: pokemon.species.display_name.clone(),
+        });
+    }
+}
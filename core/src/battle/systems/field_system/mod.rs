@@ -0,0 +1,23 @@
+//! Sistema de condiciones de campo por lado
+//!
+//! Este módulo implementa los efectos que viven en un lado del campo y no en
+//! un Pokémon concreto:
+//! - Stealth Rock: daño según la efectividad de Roca al entrar
+//! - Spikes: hasta 3 capas de daño a los Pokémon en el suelo
+//! - Toxic Spikes: hasta 2 capas de veneno / veneno grave
+//! - Sticky Web: baja la velocidad al entrar
+//!
+//! Rapid Spin limpia el lado del usuario y Defog ambos lados.
+//!
+//! También las condiciones temporales de cada lado:
+//! - Reflect / Light Screen / Aurora Veil: reducen el daño recibido
+//! - Tailwind: duplica la velocidad del lado
+
+pub mod conditions;
+pub mod hazards;
+
+#[cfg(test)]
+mod tests;
+
+pub use conditions::*;
+pub use hazards::*;
@@ -0,0 +1,323 @@
+//! Tests del Field System: hazards y condiciones temporales de cada lado
+
+use super::*;
+use crate::battle::events::BattleEvent;
+use crate::battle::orchestration::send_in_replacement;
+use crate::battle::test_fixtures::{self as fixtures, moves, moves_with_finisher, play_turn, play_wild_turn, status_move, use_move};
+use crate::battle::BattleOutcome;
+use crate::factory::create_pokemon_instance;
+use crate::game::{BattleState, PlayerTeam, SideConditions};
+use crate::models::{
+    BattleFormat, BattleSide, EntryHazard, PokemonInstance, PokemonSpecies, PokemonType, SideCondition, StatusCondition,
+    Stats, WeatherState, WeatherType,
+};
+
+/// Pokémon de prueba con los tipos indicados y 160 HP máximos
+fn pokemon(primary: PokemonType, secondary: Option<PokemonType>) -> PokemonInstance {
+    let species = PokemonSpecies {
+        species_id: "test".to_string(),
+        display_name: "Test".to_string(),
+        generation: 1,
+        primary_type: primary,
+        secondary_type: secondary,
+        base_stats: Stats {
+            hp: 80,
+            attack: 80,
+            defense: 80,
+            special_attack: 80,
+            special_defense: 80,
+            speed: 80,
+        },
+        move_pool: Vec::new(),
+        possible_abilities: vec!["run-away".to_string()],
+        is_starter_candidate: false,
+        evolutions: Vec::new(),
+        ev_yield: Stats::zero(),
+        gender_rate: 4,
+        mega_forms: Vec::new(),
+    };
+    let mut pokemon = create_pokemon_instance(&species, 50, 1, false, &[], None);
+    pokemon.randomized_profile.rolled_primary_type = primary;
+    pokemon.randomized_profile.rolled_secondary_type = secondary;
+    pokemon.ability = "run-away".to_string();
+    pokemon.held_item = None;
+    pokemon.base_computed_stats.hp = 160;
+    pokemon.current_hp = 160;
+    pokemon
+}
+
+fn side_with(hazards: &[EntryHazard]) -> SideConditions {
+    let mut side = SideConditions::default();
+    for &hazard in hazards {
+        lay_hazard(&mut side, hazard);
+    }
+    side
+}
+
+#[test]
+fn test_hazard_moves_are_recognized() {
+    assert_eq!(hazard_for_move("stealth-rock"), Some(EntryHazard::StealthRock));
+    assert_eq!(hazard_for_move("spikes"), Some(EntryHazard::Spikes));
+    assert_eq!(hazard_for_move("toxic-spikes"), Some(EntryHazard::ToxicSpikes));
+    assert_eq!(hazard_for_move("sticky-web"), Some(EntryHazard::StickyWeb));
+    assert_eq!(hazard_for_move("tackle"), None);
+}
+
+#[test]
+fn test_layers_are_capped() {
+    let mut side = SideConditions::default();
+    for _ in 0..MAX_SPIKES_LAYERS {
+        assert!(lay_hazard(&mut side, EntryHazard::Spikes));
+    }
+    assert!(!lay_hazard(&mut side, EntryHazard::Spikes));
+    assert_eq!(side.spikes, MAX_SPIKES_LAYERS);
+
+    assert!(lay_hazard(&mut side, EntryHazard::StealthRock));
+    assert!(!lay_hazard(&mut side, EntryHazard::StealthRock));
+}
+
+#[test]
+fn test_stealth_rock_scales_with_type_effectiveness() {
+    let side = side_with(&[EntryHazard::StealthRock]);
+
+    let mut neutral = pokemon(PokemonType::Normal, None);
+    apply_entry_hazards(&mut neutral, &mut side.clone(), &mut Vec::new());
+    assert_eq!(neutral.current_hp, 160 - 20);
+
+    // Fuego/Volador: x4 contra Roca -> la mitad del HP
+    let mut weak = pokemon(PokemonType::Fire, Some(PokemonType::Flying));
+    apply_entry_hazards(&mut weak, &mut side.clone(), &mut Vec::new());
+    assert_eq!(weak.current_hp, 160 - 80);
+
+    // Acero: x0.5 -> 1/16
+    let mut resistant = pokemon(PokemonType::Steel, None);
+    apply_entry_hazards(&mut resistant, &mut side.clone(), &mut Vec::new());
+    assert_eq!(resistant.current_hp, 160 - 10);
+}
+
+#[test]
+fn test_spikes_damage_grows_with_layers_and_skips_flying() {
+    let mut side = side_with(&[EntryHazard::Spikes, EntryHazard::Spikes, EntryHazard::Spikes]);
+
+    let mut grounded = pokemon(PokemonType::Normal, None);
+    apply_entry_hazards(&mut grounded, &mut side, &mut Vec::new());
+    assert_eq!(grounded.current_hp, 160 - 40);
+
+    let mut flying = pokemon(PokemonType::Normal, Some(PokemonType::Flying));
+    let mut events = Vec::new();
+    apply_entry_hazards(&mut flying, &mut side, &mut events);
+    assert_eq!(flying.current_hp, 160);
+    assert!(events.is_empty());
+}
+
+#[test]
+fn test_spikes_follow_the_tera_type() {
+    let mut side = side_with(&[EntryHazard::Spikes]);
+
+    // Volador teracristalizado en Normal: ya toca el suelo
+    let mut grounded = pokemon(PokemonType::Flying, None);
+    grounded.tera_type = PokemonType::Normal;
+    grounded.terastallized = true;
+    apply_entry_hazards(&mut grounded, &mut side, &mut Vec::new());
+    assert_eq!(grounded.current_hp, 160 - 20);
+
+    // Normal teracristalizado en Volador: las púas no le afectan
+    let mut airborne = pokemon(PokemonType::Normal, None);
+    airborne.tera_type = PokemonType::Flying;
+    airborne.terastallized = true;
+    apply_entry_hazards(&mut airborne, &mut side, &mut Vec::new());
+    assert_eq!(airborne.current_hp, 160);
+}
+
+#[test]
+fn test_toxic_spikes_poison_or_get_absorbed() {
+    let mut side = side_with(&[EntryHazard::ToxicSpikes, EntryHazard::ToxicSpikes]);
+
+    let mut target = pokemon(PokemonType::Normal, None);
+    apply_entry_hazards(&mut target, &mut side, &mut Vec::new());
+    assert_eq!(target.status_condition, Some(StatusCondition::BadPoison));
+
+    let mut steel = pokemon(PokemonType::Steel, None);
+    apply_entry_hazards(&mut steel, &mut side, &mut Vec::new());
+    assert_eq!(steel.status_condition, None);
+
+    // Un tipo Veneno en el suelo las retira del lado
+    let mut poison = pokemon(PokemonType::Poison, None);
+    apply_entry_hazards(&mut poison, &mut side, &mut Vec::new());
+    assert_eq!(poison.status_condition, None);
+    assert_eq!(side.toxic_spikes, 0);
+}
+
+#[test]
+fn test_sticky_web_lowers_speed() {
+    let mut side = side_with(&[EntryHazard::StickyWeb]);
+    let mut target = pokemon(PokemonType::Normal, None);
+    let mut events = Vec::new();
+    apply_entry_hazards(&mut target, &mut side, &mut events);
+
+    assert_eq!(target.battle_stages.as_ref().unwrap().speed, -1);
+    assert!(events.contains(&BattleEvent::StatStageChanged {
+        target: "Test".to_string(),
+        stat: "speed".to_string(),
+        stages: -1,
+    }));
+}
+
+#[test]
+fn test_clear_hazards() {
+    let mut side = side_with(&[EntryHazard::StealthRock, EntryHazard::Spikes, EntryHazard::StickyWeb]);
+    assert!(has_hazards(&side));
+    assert!(clear_hazards(&mut side));
+    assert_eq!(side, SideConditions::default());
+    assert!(!clear_hazards(&mut side));
+}
+
+#[test]
+fn test_trainer_replacement_after_ko_takes_stealth_rock() {
+    let moves = moves_with_finisher();
+    let mut team = PlayerTeam::new();
+    team.add_member(fixtures::pokemon("player", "run-away", &moves));
+    // Un golpe que siempre debilita, para que la IA no tenga motivo para retirarse antes
+    team.active_members[0].learn_new_move("finisher".to_string(), 5);
+
+    let fainting = fixtures::pokemon("fainting", "run-away", &moves);
+    let replacement = fixtures::pokemon("replacement", "run-away", &moves);
+    let max_hp = replacement.base_computed_stats.hp;
+
+    let mut battle_state = BattleState::new(
+        0,
+        vec![fainting, replacement],
+        "Rival".to_string(),
+        BattleFormat::Single,
+        true,
+    );
+    battle_state.opponent_side.stealth_rock = true;
+    battle_state.pending_player_actions = vec![use_move(0, "finisher")];
+
+    let result = play_turn(&mut team, &mut battle_state, &moves, 5);
+
+    assert_eq!(result.outcome, BattleOutcome::EnemySwitched);
+    assert_eq!(battle_state.opponent_active_indices, vec![1]);
+    assert_eq!(battle_state.opponent_instance.current_hp, max_hp - max_hp / 8);
+}
+
+#[test]
+fn test_player_replacement_takes_spikes() {
+    let moves = moves();
+    let mut team = PlayerTeam::new();
+    let mut fainted = fixtures::pokemon("fainted", "run-away", &moves);
+    fainted.current_hp = 0;
+    team.add_member(fainted);
+    team.add_member(fixtures::pokemon("replacement", "run-away", &moves));
+    let max_hp = team.active_members[1].base_computed_stats.hp;
+
+    let wild = fixtures::pokemon("wild", "run-away", &moves);
+    let mut battle_state = BattleState::new(0, vec![wild], "wild".to_string(), BattleFormat::Single, false);
+    battle_state.player_side.spikes = 1;
+
+    let events = send_in_replacement(&mut team, &mut battle_state, 0, 1);
+
+    assert_eq!(battle_state.player_active_indices, vec![1]);
+    assert_eq!(team.active_members[1].current_hp, max_hp - max_hp / 8);
+    assert!(events.contains(&BattleEvent::Switched {
+        trainer: None,
+        pokemon: "replacement".to_string(),
+    }));
+}
+
+#[test]
+fn test_screens_last_longer_with_light_clay() {
+    let user = pokemon(PokemonType::Psychic, None);
+    let mut side = SideConditions::default();
+    assert!(start_side_condition(&mut side, SideCondition::Reflect, &user, None));
+    assert_eq!(side.reflect_turns, SCREEN_TURNS);
+    // Ya activo: el movimiento falla
+    assert!(!start_side_condition(&mut side, SideCondition::Reflect, &user, None));
+
+    let mut holder = pokemon(PokemonType::Psychic, None);
+    holder.held_item = Some("light-clay".to_string());
+    assert!(start_side_condition(&mut side, SideCondition::LightScreen, &holder, None));
+    assert_eq!(side.light_screen_turns, EXTENDED_SCREEN_TURNS);
+
+    // Light Clay no alarga Tailwind
+    assert!(start_side_condition(&mut side, SideCondition::Tailwind, &holder, None));
+    assert_eq!(side.tailwind_turns, TAILWIND_TURNS);
+}
+
+#[test]
+fn test_aurora_veil_requires_hail() {
+    let user = pokemon(PokemonType::Ice, None);
+    let mut side = SideConditions::default();
+    assert!(!start_side_condition(&mut side, SideCondition::AuroraVeil, &user, None));
+
+    let hail = WeatherState { weather_type: WeatherType::Hail, turns_remaining: 5 };
+    assert!(start_side_condition(&mut side, SideCondition::AuroraVeil, &user, Some(&hail)));
+    assert_eq!(side.aurora_veil_turns, SCREEN_TURNS);
+}
+
+#[test]
+fn test_side_conditions_tick_down_and_end() {
+    let mut side = SideConditions { reflect_turns: 1, tailwind_turns: 2, ..Default::default() };
+
+    assert_eq!(tick_side_conditions(&mut side), vec![SideCondition::Reflect]);
+    assert!(has_tailwind(&side));
+    assert_eq!(tick_side_conditions(&mut side), vec![SideCondition::Tailwind]);
+    assert!(!has_tailwind(&side));
+    assert!(tick_side_conditions(&mut side).is_empty());
+}
+
+#[test]
+fn test_screen_damage_multiplier() {
+    let side = SideConditions { reflect_turns: 5, ..Default::default() };
+
+    let singles = Screens::of(&side, BattleFormat::Single);
+    assert_eq!(singles.damage_multiplier("physical", false), 0.5);
+    assert_eq!(singles.damage_multiplier("special", false), 1.0);
+    // Los críticos ignoran las pantallas
+    assert_eq!(singles.damage_multiplier("physical", true), 1.0);
+
+    let doubles = Screens::of(&side, BattleFormat::Double);
+    assert_eq!(doubles.damage_multiplier("physical", false), 2732.0 / 4096.0);
+
+    let veil = Screens::of(&SideConditions { aurora_veil_turns: 5, ..Default::default() }, BattleFormat::Single);
+    assert_eq!(veil.damage_multiplier("special", false), 0.5);
+}
+
+#[test]
+fn test_break_screens() {
+    let mut side = SideConditions { reflect_turns: 3, aurora_veil_turns: 2, tailwind_turns: 2, ..Default::default() };
+    assert!(break_screens(&mut side));
+    assert_eq!(side, SideConditions { tailwind_turns: 2, ..Default::default() });
+    assert!(!break_screens(&mut side));
+}
+
+#[test]
+fn test_reflect_is_set_and_ticks_at_end_of_turn() {
+    let mut moves = moves();
+    moves.insert("reflect".to_string(), status_move("reflect", "users-field"));
+
+    let (_, battle_state, result) = play_wild_turn("reflect", &moves, |_, _| {});
+
+    assert!(result.events.contains(&BattleEvent::SideConditionStarted {
+        side: BattleSide::Player,
+        condition: SideCondition::Reflect,
+    }));
+    assert_eq!(battle_state.player_side.reflect_turns, SCREEN_TURNS - 1);
+}
+
+#[test]
+fn test_brick_break_shatters_screens() {
+    let mut moves = moves();
+    let mut brick_break = moves["tackle"].clone();
+    brick_break.id = "brick-break".to_string();
+    brick_break.r#type = "Fighting".to_string();
+    moves.insert("brick-break".to_string(), brick_break);
+
+    let (_, battle_state, result) = play_wild_turn("brick-break", &moves, |state, _| {
+        state.opponent_side.reflect_turns = 3;
+        state.opponent_side.light_screen_turns = 3;
+    });
+
+    assert!(result.events.contains(&BattleEvent::ScreensBroken { side: BattleSide::Opponent }));
+    assert_eq!(battle_state.opponent_side, SideConditions::default());
+}
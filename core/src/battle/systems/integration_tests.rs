@@ -42,6 +42,9 @@ fn create_test_pokemon(
             possible_abilities: vec![ability.to_string()],
             is_starter_candidate: false,
             evolutions: Vec::new(),
+            ev_yield: Stats::zero(),
+            gender_rate: 4,
+            mega_forms: Vec::new(),
         },
         level: 50,
         current_hp: hp,
@@ -52,6 +55,11 @@ fn create_test_pokemon(
         volatile_status: Some(VolatileStatus::new()),
         individual_values: Stats::default(),
         effort_values: Stats::default(),
+        nature: Default::default(),
+        gender: Default::default(),
+        tera_type: Default::default(),
+        terastallized: false,
+        mega_evolution: None,
         randomized_profile: RandomizedProfile {
             rolled_primary_type: primary_type,
             rolled_secondary_type: None,
@@ -428,6 +436,7 @@ mod protection_redirection_combos {
 mod vgc_scenarios {
     use super::*;
     use crate::battle::systems::redirection_system::processor::*;
+    use crate::battle::systems::protection_system::{activate_quick_guard, is_blocked_by_quick_guard};
 
     #[test]
     fn test_doubles_spread_move_with_redirection() {
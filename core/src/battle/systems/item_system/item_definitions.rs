@@ -0,0 +1,404 @@
+//! Catálogo de items
+//!
+//! Cada `ItemDefinition` describe un objeto: su categoría, cuándo se activa,
+//! qué efectos tiene, si se consume y su potencia para Fling. El sistema de
+//! items lee estas definiciones en lugar de comparar IDs, así que una baya o
+//! un potenciador nuevo es solo una entrada más en `items.json`.
+//!
+//! El servidor registra el catálogo de `items.json` al arrancar. Los items que
+//! no están registrados (o todos, si nunca se registra) usan el catálogo
+//! integrado de `builtin_item_definitions`.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use serde::{Deserialize, Serialize};
+use crate::battle::events::display_name_from_id;
+use crate::models::{PokemonType, WeatherType};
+use super::item_effects::ItemEffect;
+
+/// Categoría de un item
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ItemCategory {
+    /// Objeto equipable con efecto en combate
+    #[default]
+    HeldItem,
+    /// Baya de un solo uso en combate
+    Berry,
+    /// Megapiedra (ver `ItemProcessor::mega_form`)
+    MegaStone,
+    /// Menta: cambia la naturaleza (fuera de combate)
+    Mint,
+    /// Vitamina: sube EVs (fuera de combate)
+    Vitamin,
+    /// Baya que baja EVs (fuera de combate)
+    EvReducingBerry,
+}
+
+/// Condición que activa los efectos de un item
+///
+/// Se contrasta con el `ItemTrigger` que genera el combate en cada momento.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub enum ItemTriggerCondition {
+    /// Nunca se activa: sus efectos se consultan de forma pasiva
+    #[default]
+    Passive,
+    /// Antes de calcular el daño de un movimiento del portador
+    BeforeDamageDealt,
+    /// Después de que el portador cause daño
+    AfterDamageDealt,
+    /// Al recibir cualquier golpe
+    OnDamageTaken,
+    /// Al recibir un golpe super efectivo (Weakness Policy)
+    OnSuperEffectiveHit,
+    /// Al recibir un golpe, antes de aplicar el daño (bayas que lo reducen)
+    BeforeDamageTaken,
+    /// Al recibir un golpe que lo debilitaría (Focus Sash)
+    OnLethalHit,
+    /// Al recibir un ataque de contacto (Rocky Helmet)
+    OnContact,
+    /// Al sufrir un estado; lista vacía = cualquiera ("confusion" incluido)
+    OnStatusApplied {
+        #[serde(default)]
+        statuses: Vec<String>,
+    },
+    /// Al intentar usar un movimiento de estado (Assault Vest)
+    OnStatusMoveAttempt,
+    /// Cuando el HP queda en o por debajo de una fracción del máximo (Sitrus Berry)
+    OnHPThreshold { threshold: f32 },
+    /// Al final de cada turno (Leftovers)
+    EndOfTurn,
+    /// Al entrar al campo (Booster Energy)
+    OnEntry,
+    /// Se usa desde el botín, fuera de combate (mentas, vitaminas)
+    OnUse,
+}
+
+/// Definición de un item en `items.json`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ItemDefinition {
+    /// ID del item en kebab-case (ej: "life-orb")
+    pub id: String,
+    /// Nombre visible
+    pub name: String,
+    /// URL del sprite
+    #[serde(default)]
+    pub sprite_url: String,
+    /// Descripción corta del efecto
+    #[serde(default)]
+    pub effect_text: String,
+    #[serde(default)]
+    pub category: ItemCategory,
+    #[serde(default)]
+    pub trigger: ItemTriggerCondition,
+    /// Efectos con sus parámetros
+    #[serde(default)]
+    pub effects: Vec<ItemEffect>,
+    /// Si el item se pierde al activarse
+    #[serde(default)]
+    pub consumable: bool,
+    /// Potencia de Fling al lanzar el item (None = no se puede lanzar)
+    #[serde(default)]
+    pub fling_power: Option<u8>,
+}
+
+impl ItemDefinition {
+    /// Crea una definición con nombre y sprite derivados del ID
+    pub fn new(id: &str, category: ItemCategory, trigger: ItemTriggerCondition, effects: Vec<ItemEffect>) -> Self {
+        Self {
+            id: id.to_string(),
+            name: display_name_from_id(id),
+            sprite_url: format!("https://raw.githubusercontent.com/PokeAPI/sprites/master/sprites/items/{}.png", id),
+            effect_text: String::new(),
+            category,
+            trigger,
+            effects,
+            consumable: false,
+            fling_power: None,
+        }
+    }
+
+    /// Marca el item como consumible
+    pub fn consumable(mut self) -> Self {
+        self.consumable = true;
+        self
+    }
+
+    /// Fija la potencia de Fling
+    pub fn fling_power(mut self, power: u8) -> Self {
+        self.fling_power = Some(power);
+        self
+    }
+}
+
+/// Catálogo registrado al arrancar, indexado por ID
+static ITEM_DEFINITIONS: OnceLock<HashMap<String, ItemDefinition>> = OnceLock::new();
+/// Catálogo integrado, construido la primera vez que se consulta
+static BUILTIN_ITEM_DEFINITIONS: OnceLock<HashMap<String, ItemDefinition>> = OnceLock::new();
+
+/// Parsea el contenido de `items.json` y lo indexa por ID
+pub fn parse_item_definitions(json: &str) -> Result<HashMap<String, ItemDefinition>, serde_json::Error> {
+    let definitions: Vec<ItemDefinition> = serde_json::from_str(json)?;
+    Ok(definitions
+        .into_iter()
+        .map(|definition| (definition.id.clone(), definition))
+        .collect())
+}
+
+/// Registra el catálogo de items para todo el proceso
+///
+/// Solo la primera llamada tiene efecto; retorna false si ya había catálogo.
+pub fn register_item_definitions(definitions: HashMap<String, ItemDefinition>) -> bool {
+    ITEM_DEFINITIONS.set(definitions).is_ok()
+}
+
+/// Definición de un item: la registrada o, si no existe, la integrada
+pub fn item_definition(item_id: &str) -> Option<&'static ItemDefinition> {
+    ITEM_DEFINITIONS
+        .get()
+        .and_then(|definitions| definitions.get(item_id))
+        .or_else(|| {
+            BUILTIN_ITEM_DEFINITIONS
+                .get_or_init(|| {
+                    builtin_item_definitions()
+                        .into_iter()
+                        .map(|definition| (definition.id.clone(), definition))
+                        .collect()
+                })
+                .get(item_id)
+        })
+}
+
+/// Nombre visible de un item
+///
+/// Usa el nombre del catálogo; si el item no está definido, lo deriva del ID.
+pub fn item_display_name(item_id: &str) -> String {
+    item_definition(item_id)
+        .map(|definition| definition.name.clone())
+        .unwrap_or_else(|| display_name_from_id(item_id))
+}
+
+/// Catálogo integrado: fallback de los items que no están en `items.json`
+pub fn builtin_item_definitions() -> Vec<ItemDefinition> {
+    use ItemCategory::*;
+    use ItemEffect::*;
+    use ItemTriggerCondition as Trigger;
+
+    let boost = |multiplier: f32, damage_class: Option<&str>| BoostDamage {
+        multiplier,
+        damage_class: damage_class.map(str::to_string),
+        move_type: None,
+    };
+    let stat = |stat: &str, multiplier: f32| MultiplyStat { stat: stat.to_string(), multiplier };
+    let extend_weather = |id: &str, weather: WeatherType, fling: u8| {
+        ItemDefinition::new(id, HeldItem, Trigger::Passive, vec![ExtendWeather { weather }]).fling_power(fling)
+    };
+
+    let mut items = vec![
+        // ============================================================
+        // POTENCIADORES
+        // ============================================================
+        ItemDefinition::new("choice-band", HeldItem, Trigger::BeforeDamageDealt, vec![boost(1.5, Some("physical")), LockMove])
+            .fling_power(10),
+        ItemDefinition::new("choice-specs", HeldItem, Trigger::BeforeDamageDealt, vec![boost(1.5, Some("special")), LockMove])
+            .fling_power(10),
+        ItemDefinition::new("choice-scarf", HeldItem, Trigger::BeforeDamageDealt, vec![stat("speed", 1.5), LockMove])
+            .fling_power(10),
+        ItemDefinition::new("life-orb", HeldItem, Trigger::AfterDamageDealt, vec![boost(1.3, None), RecoilDamage { percent: 0.1 }])
+            .fling_power(30),
+        ItemDefinition::new("wide-lens", HeldItem, Trigger::Passive, vec![ModifyAccuracy { multiplier: 1.1 }])
+            .fling_power(10),
+
+        // ============================================================
+        // DEFENSIVOS
+        // ============================================================
+        ItemDefinition::new("assault-vest", HeldItem, Trigger::OnStatusMoveAttempt, vec![stat("special_defense", 1.5), BlockStatusMoves])
+            .fling_power(80),
+        ItemDefinition::new("rocky-helmet", HeldItem, Trigger::OnContact, vec![DamageAttackerOnContact { percent: 1.0 / 6.0 }])
+            .fling_power(60),
+        ItemDefinition::new("leftovers", HeldItem, Trigger::EndOfTurn, vec![RestoreHP { percent: 0.0625 }])
+            .fling_power(10),
+        ItemDefinition::new("weakness-policy", HeldItem, Trigger::OnSuperEffectiveHit, vec![
+            BoostStat { stat: "attack".to_string(), stages: 2 },
+            BoostStat { stat: "special_attack".to_string(), stages: 2 },
+        ])
+            .consumable()
+            .fling_power(80),
+        ItemDefinition::new("bright-powder", HeldItem, Trigger::Passive, vec![ModifyIncomingAccuracy { multiplier: 0.9 }])
+            .fling_power(10),
+        ItemDefinition::new("lax-incense", HeldItem, Trigger::Passive, vec![ModifyIncomingAccuracy { multiplier: 0.9 }])
+            .fling_power(10),
+        ItemDefinition::new("destiny-knot", HeldItem, Trigger::Passive, vec![ReflectInfatuation])
+            .fling_power(10),
+        ItemDefinition::new("focus-sash", HeldItem, Trigger::OnLethalHit, vec![SurviveLethalHit { chance: 1.0, full_hp_only: true }])
+            .consumable()
+            .fling_power(10),
+        ItemDefinition::new("focus-band", HeldItem, Trigger::OnLethalHit, vec![SurviveLethalHit { chance: 0.1, full_hp_only: false }])
+            .fling_power(10),
+        ItemDefinition::new("air-balloon", HeldItem, Trigger::OnDamageTaken, vec![GroundImmunity])
+            .consumable()
+            .fling_power(10),
+        ItemDefinition::new("mental-herb", HeldItem, Trigger::OnStatusApplied { statuses: vec!["infatuation".to_string()] }, vec![CureInfatuation])
+            .consumable()
+            .fling_power(10),
+        ItemDefinition::new("clear-amulet", HeldItem, Trigger::Passive, vec![PreventStatLoss])
+            .fling_power(30),
+        ItemDefinition::new("covert-cloak", HeldItem, Trigger::Passive, vec![BlockSecondaryEffects])
+            .fling_power(30),
+        ItemDefinition::new("booster-energy", HeldItem, Trigger::OnEntry, vec![ActivateParadoxAbility])
+            .consumable()
+            .fling_power(30),
+
+        // ============================================================
+        // EYECCIÓN
+        // ============================================================
+        ItemDefinition::new("eject-button", HeldItem, Trigger::OnDamageTaken, vec![EjectOnHit])
+            .consumable()
+            .fling_power(30),
+        ItemDefinition::new("eject-pack", HeldItem, Trigger::Passive, vec![EjectOnStatDrop])
+            .consumable()
+            .fling_power(50),
+
+        // ============================================================
+        // CLIMA, TERRENO Y PANTALLAS
+        // ============================================================
+        extend_weather("heat-rock", WeatherType::Sun, 60),
+        extend_weather("damp-rock", WeatherType::Rain, 60),
+        extend_weather("smooth-rock", WeatherType::Sandstorm, 10),
+        extend_weather("icy-rock", WeatherType::Hail, 40),
+        ItemDefinition::new("light-clay", HeldItem, Trigger::Passive, vec![ExtendScreens])
+            .fling_power(30),
+        ItemDefinition::new("terrain-extender", HeldItem, Trigger::Passive, vec![ExtendTerrain])
+            .fling_power(60),
+
+        // ============================================================
+        // BAYAS
+        // ============================================================
+        ItemDefinition::new("sitrus-berry", Berry, Trigger::OnHPThreshold { threshold: 0.5 }, vec![RestoreHP { percent: 0.25 }])
+            .consumable()
+            .fling_power(10),
+        ItemDefinition::new("lum-berry", Berry, Trigger::OnStatusApplied { statuses: vec![] }, vec![CureStatus, CureConfusion])
+            .consumable()
+            .fling_power(10),
+        ItemDefinition::new("persim-berry", Berry, Trigger::OnStatusApplied { statuses: vec!["confusion".to_string()] }, vec![CureConfusion])
+            .consumable()
+            .fling_power(10),
+    ];
+
+    // Bayas que reducen a la mitad un golpe super efectivo de su tipo
+    // (Chilan Berry: cualquier golpe de tipo Normal)
+    for (berry, move_type) in [
+        ("occa-berry", PokemonType::Fire),
+        ("passho-berry", PokemonType::Water),
+        ("wacan-berry", PokemonType::Electric),
+        ("rindo-berry", PokemonType::Grass),
+        ("yache-berry", PokemonType::Ice),
+        ("chople-berry", PokemonType::Fighting),
+        ("kebia-berry", PokemonType::Poison),
+        ("shuca-berry", PokemonType::Ground),
+        ("coba-berry", PokemonType::Flying),
+        ("payapa-berry", PokemonType::Psychic),
+        ("tanga-berry", PokemonType::Bug),
+        ("charti-berry", PokemonType::Rock),
+        ("kasib-berry", PokemonType::Ghost),
+        ("haban-berry", PokemonType::Dragon),
+        ("colbur-berry", PokemonType::Dark),
+        ("babiri-berry", PokemonType::Steel),
+        ("roseli-berry", PokemonType::Fairy),
+        ("chilan-berry", PokemonType::Normal),
+    ] {
+        let super_effective_only = move_type != PokemonType::Normal;
+        items.push(
+            ItemDefinition::new(berry, Berry, Trigger::BeforeDamageTaken, vec![
+                ResistTypeHit { move_type, multiplier: 0.5, super_effective_only },
+            ])
+                .consumable()
+                .fling_power(10),
+        );
+    }
+
+    // Objetos de uso fuera de combate (botín de gimnasio)
+    for mint in ["adamant-mint", "modest-mint", "jolly-mint", "timid-mint"] {
+        items.push(ItemDefinition::new(mint, Mint, Trigger::OnUse, vec![]).consumable().fling_power(10));
+    }
+    for vitamin in ["hp-up", "protein", "iron", "calcium", "zinc", "carbos"] {
+        items.push(ItemDefinition::new(vitamin, Vitamin, Trigger::OnUse, vec![]).consumable().fling_power(30));
+    }
+    for berry in ["pomeg-berry", "kelpsy-berry", "qualot-berry", "hondew-berry", "grepa-berry", "tamato-berry"] {
+        items.push(ItemDefinition::new(berry, EvReducingBerry, Trigger::OnUse, vec![]).consumable().fling_power(10));
+    }
+    for stone in [
+        "venusaurite", "charizardite-x", "charizardite-y", "blastoisinite",
+        "sceptilite", "blazikenite", "swampertite",
+    ] {
+        items.push(ItemDefinition::new(stone, MegaStone, Trigger::Passive, vec![]).fling_power(80));
+    }
+
+    items
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_data_only_booster() {
+        let json = r#"[
+            {
+                "id": "charcoal",
+                "name": "Charcoal",
+                "trigger": "Passive",
+                "effects": [{ "type": "BoostDamage", "multiplier": 1.2, "move_type": "Fire" }],
+                "fling_power": 30
+            },
+            {
+                "id": "oran-berry",
+                "name": "Oran Berry",
+                "category": "Berry",
+                "trigger": { "OnHPThreshold": { "threshold": 0.5 } },
+                "effects": [{ "type": "RestoreHP", "percent": 0.1 }],
+                "consumable": true
+            }
+        ]"#;
+        let definitions = parse_item_definitions(json).expect("JSON válido");
+
+        let charcoal = &definitions["charcoal"];
+        assert_eq!(charcoal.category, ItemCategory::HeldItem);
+        assert!(!charcoal.consumable);
+        assert_eq!(charcoal.fling_power, Some(30));
+        assert_eq!(
+            charcoal.effects,
+            vec![ItemEffect::BoostDamage { multiplier: 1.2, damage_class: None, move_type: Some(PokemonType::Fire) }]
+        );
+
+        let oran = &definitions["oran-berry"];
+        assert_eq!(oran.trigger, ItemTriggerCondition::OnHPThreshold { threshold: 0.5 });
+        assert!(oran.consumable);
+        assert_eq!(oran.fling_power, None);
+    }
+
+    #[test]
+    fn test_builtin_catalogue_lookup() {
+        let life_orb = item_definition("life-orb").expect("Life Orb está en el catálogo");
+        assert_eq!(life_orb.trigger, ItemTriggerCondition::AfterDamageDealt);
+        assert_eq!(life_orb.fling_power, Some(30));
+        assert!(item_definition("unknown-item").is_none());
+    }
+
+    #[test]
+    fn test_shipped_items_json_matches_builtin_catalogue() {
+        let json = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../server/data/items.json"));
+        let definitions = parse_item_definitions(json).expect("items.json debe ser válido");
+
+        // Todo item con mecánica en código debe estar en el catálogo publicado
+        for builtin in builtin_item_definitions() {
+            let shipped = definitions
+                .get(&builtin.id)
+                .unwrap_or_else(|| panic!("{} falta en items.json", builtin.id));
+            assert_eq!(shipped.category, builtin.category, "{}", builtin.id);
+            assert_eq!(shipped.trigger, builtin.trigger, "{}", builtin.id);
+            assert_eq!(shipped.effects, builtin.effects, "{}", builtin.id);
+            assert_eq!(shipped.consumable, builtin.consumable, "{}", builtin.id);
+        }
+    }
+}
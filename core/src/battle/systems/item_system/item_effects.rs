@@ -1,14 +1,19 @@
 //! Efectos que producen los items cuando se activan
 //!
-//! Define qué hace cada item cuando su trigger se cumple
+//! Define qué hace cada item cuando su trigger se cumple. Los efectos de cada
+//! item vienen de su `ItemDefinition`.
 
-use crate::models::{PokemonInstance, StatusCondition};
+use serde::{Deserialize, Serialize};
+use crate::models::{PokemonInstance, PokemonType, VolatileStatus, WeatherType};
+use super::super::super::events::{BattleEvent, DamageCause, HealCause};
+use super::super::ability_system::ability_display_name;
+use super::item_definitions::{item_definition, ItemTriggerCondition};
 
 /// Resultado de aplicar un efecto de item
 #[derive(Debug, Clone)]
 pub struct ItemEffectResult {
-    /// Logs generados por el efecto
-    pub logs: Vec<String>,
+    /// Eventos generados por el efecto
+    pub events: Vec<BattleEvent>,
 
     /// Si el item se consumió (berries de un solo uso)
     pub consumed: bool,
@@ -20,12 +25,14 @@ pub struct ItemEffectResult {
     pub recoil_damage: u16,
     pub status_cured: bool,
     pub move_locked: Option<String>, // Para Choice items
+    /// Si el Pokémon aguanta con 1 HP un golpe que lo debilitaría (Focus Sash)
+    pub survived_lethal_hit: bool,
 }
 
 impl Default for ItemEffectResult {
     fn default() -> Self {
         Self {
-            logs: Vec::new(),
+            events: Vec::new(),
             consumed: false,
             damage_multiplier: 1.0,
             stat_boosts: Vec::new(),
@@ -33,36 +40,122 @@ impl Default for ItemEffectResult {
             recoil_damage: 0,
             status_cured: false,
             move_locked: None,
+            survived_lethal_hit: false,
         }
     }
 }
 
-/// Define los efectos que puede producir un item
-#[derive(Debug, Clone, PartialEq)]
+/// Efecto de un item
+///
+/// Los efectos activos se aplican en `apply_item_effect` cuando se cumple el
+/// trigger del item; los pasivos (multiplicadores, objetos de eyección...) los
+/// consulta `ItemProcessor` en el punto del combate que corresponde. En
+/// `items.json` se escriben con el nombre de la variante en `type`, por
+/// ejemplo `{ "type": "RestoreHP", "percent": 0.25 }`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
 pub enum ItemEffect {
-    /// Multiplica el daño de un movimiento
-    BoostDamage { multiplier: f32 },
-
-    /// Aumenta una stat en stages
+    /// Multiplica el daño de los movimientos del portador (Choice Band, Life Orb, Charcoal)
+    BoostDamage {
+        multiplier: f32,
+        /// Solo movimientos de esta categoría ("physical" / "special")
+        #[serde(default)]
+        damage_class: Option<String>,
+        /// Solo movimientos de este tipo
+        #[serde(default)]
+        move_type: Option<PokemonType>,
+    },
+
+    /// Aumenta una stat en stages (Weakness Policy)
     BoostStat { stat: String, stages: i8 },
 
-    /// Bloquea al Pokémon a usar solo un movimiento
-    LockMove { move_id: String },
+    /// Multiplica una stat mientras se lleva el objeto (Choice Scarf, Assault Vest)
+    MultiplyStat { stat: String, multiplier: f32 },
+
+    /// Bloquea al Pokémon en el primer movimiento que usa (objetos Choice)
+    LockMove,
 
-    /// Cura status conditions
+    /// Cura los problemas de estado (Lum Berry)
     CureStatus,
 
+    /// Cura la confusión (Persim Berry, Lum Berry)
+    CureConfusion,
+
     /// Restaura HP (porcentaje del HP máximo)
     RestoreHP { percent: f32 },
 
     /// Causa recoil damage (porcentaje del HP máximo)
     RecoilDamage { percent: f32 },
 
+    /// Daña al atacante que hace contacto (porcentaje de su HP máximo; Rocky Helmet)
+    DamageAttackerOnContact { percent: f32 },
+
     /// Bloquea movimientos de status
     BlockStatusMoves,
+
+    /// Alarga el clima que establece el portador (Heat Rock, Damp Rock...)
+    ExtendWeather { weather: WeatherType },
+
+    /// Alarga las pantallas del portador (Light Clay)
+    ExtendScreens,
+
+    /// Alarga el terreno que establece el portador (Terrain Extender)
+    ExtendTerrain,
+
+    /// Multiplica la precisión de los ataques del portador (Wide Lens)
+    ModifyAccuracy { multiplier: f32 },
+
+    /// Multiplica la precisión de los ataques que recibe el portador (Bright Powder)
+    ModifyIncomingAccuracy { multiplier: f32 },
+
+    /// Saca al portador del campo al recibir un golpe (Eject Button)
+    EjectOnHit,
+
+    /// Saca al portador del campo al bajarle una stat (Eject Pack)
+    EjectOnStatDrop,
+
+    /// Devuelve el enamoramiento a quien lo causó (Destiny Knot)
+    ReflectInfatuation,
+
+    /// Aguanta con 1 HP un golpe que lo debilitaría (Focus Sash, Focus Band)
+    SurviveLethalHit {
+        /// Probabilidad de aguantar (1.0 = siempre)
+        chance: f32,
+        /// Solo funciona con el HP al máximo
+        full_hp_only: bool,
+    },
+
+    /// Inmunidad a los movimientos de tipo Tierra; explota al recibir un golpe (Air Balloon)
+    GroundImmunity,
+
+    /// Cura el enamoramiento (Mental Herb)
+    CureInfatuation,
+
+    /// Multiplica el daño de un golpe recibido de este tipo (Occa Berry, Chilan Berry...)
+    ResistTypeHit {
+        move_type: PokemonType,
+        multiplier: f32,
+        /// Solo si el golpe es super efectivo
+        super_effective_only: bool,
+    },
+
+    /// Activa Protosynthesis o Quark Drive del portador al entrar (Booster Energy)
+    ActivateParadoxAbility,
+
+    /// Evita las bajadas de stats que causan otros Pokémon (Clear Amulet)
+    PreventStatLoss,
+
+    /// Bloquea los efectos secundarios de los ataques que recibe (Covert Cloak)
+    BlockSecondaryEffects,
 }
 
+/// Habilidades que Booster Energy puede activar
+const PARADOX_ABILITIES: [&str; 2] = ["protosynthesis", "quark-drive"];
+
 /// Aplica el efecto de un item al Pokémon
+///
+/// Recorre los efectos de la definición del item. Si alguno se activa y el
+/// item es consumible, el Pokémon lo pierde.
 pub fn apply_item_effect(
     item_id: &str,
     pokemon: &mut PokemonInstance,
@@ -70,168 +163,261 @@ pub fn apply_item_effect(
     damage_dealt: Option<u16>,
 ) -> ItemEffectResult {
     let mut result = ItemEffectResult::default();
+    // Un item ya consumido no vuelve a activarse
+    if pokemon.held_item.as_deref() != Some(item_id) {
+        return result;
+    }
+    // Item no reconocido o no implementado
+    let Some(definition) = item_definition(item_id) else {
+        return result;
+    };
+
+    // Los items que actúan al atacar necesitan el movimiento o el daño causado
+    let has_context = match definition.trigger {
+        ItemTriggerCondition::BeforeDamageDealt => move_id.is_some(),
+        ItemTriggerCondition::AfterDamageDealt => move_id.is_some() && damage_dealt.is_some(),
+        _ => true,
+    };
+    if !has_context {
+        return result;
+    }
 
-    match item_id {
-        // Choice Band: +50% Attack en movimientos físicos
-        "choice-band" => {
-            if let Some(mv) = move_id {
-                result.damage_multiplier = 1.5;
-                result.move_locked = Some(mv.to_string());
-                result.logs.push(format!(
-                    "{}'s Choice Band boosted its attack!",
-                    pokemon.species.display_name
-                ));
-            }
-        }
-
-        // Choice Specs: +50% Sp. Attack en movimientos especiales
-        "choice-specs" => {
-            if let Some(mv) = move_id {
-                result.damage_multiplier = 1.5;
-                result.move_locked = Some(mv.to_string());
-                result.logs.push(format!(
-                    "{}'s Choice Specs boosted its special attack!",
-                    pokemon.species.display_name
-                ));
+    let pokemon_name = pokemon.species.display_name.clone();
+    let max_hp = pokemon.base_computed_stats.hp;
+    let mut activated = false;
+    // Curación y recoil ya muestran el objeto en su propio evento
+    let mut announced = false;
+    let mut effect_events = Vec::new();
+
+    for effect in &definition.effects {
+        match effect {
+            ItemEffect::BoostDamage { multiplier, .. } => {
+                result.damage_multiplier *= multiplier;
+                activated = true;
             }
-        }
-
-        // Choice Scarf: +50% Speed (aplicado como stat boost permanente mientras esté equipado)
-        "choice-scarf" => {
-            if let Some(mv) = move_id {
-                result.move_locked = Some(mv.to_string());
-                // El boost de speed se maneja en el cálculo de velocidad directamente
-                result.logs.push(format!(
-                    "{}'s Choice Scarf boosted its speed!",
-                    pokemon.species.display_name
-                ));
+            ItemEffect::LockMove => {
+                result.move_locked = move_id.map(str::to_string);
+                activated = true;
             }
-        }
-
-        // Life Orb: +30% damage, -10% HP de recoil
-        "life-orb" => {
-            if damage_dealt.is_some() {
-                result.damage_multiplier = 1.3;
-                let max_hp = pokemon.base_computed_stats.hp;
-                result.recoil_damage = max_hp / 10;
-
-                // Aplicar recoil inmediatamente
+            ItemEffect::RecoilDamage { percent } => {
+                result.recoil_damage = (max_hp as f32 * percent) as u16;
                 pokemon.current_hp = pokemon.current_hp.saturating_sub(result.recoil_damage);
-
-                result.logs.push(format!(
-                    "{} lost some HP due to Life Orb!",
-                    pokemon.species.display_name
-                ));
+                effect_events.push(BattleEvent::Damage {
+                    target: pokemon_name.clone(),
+                    amount: result.recoil_damage,
+                    remaining_hp: pokemon.current_hp,
+                    cause: DamageCause::Item {
+                        item: item_id.to_string(),
+                        holder: pokemon_name.clone(),
+                    },
+                });
+                activated = true;
+                announced = true;
             }
-        }
-
-        // Assault Vest: +50% Sp. Defense (manejado en cálculo de daño)
-        // El bloqueo de status moves se maneja en el validation system
-        "assault-vest" => {
-            // Este efecto es pasivo, no genera resultado aquí
-        }
-
-        // Sitrus Berry: Cura 25% HP
-        "sitrus-berry" => {
-            let max_hp = pokemon.base_computed_stats.hp;
-            let heal_amount = max_hp / 4;
-            let old_hp = pokemon.current_hp;
-            pokemon.current_hp = (pokemon.current_hp + heal_amount).min(max_hp);
-            result.healed_hp = pokemon.current_hp - old_hp;
-            result.consumed = true;
-
-            result.logs.push(format!(
-                "{} restored HP using its Sitrus Berry!",
-                pokemon.species.display_name
-            ));
-
-            // Consumir el item
-            pokemon.held_item = None;
-        }
-
-        // Lum Berry: Cura todos los status conditions
-        "lum-berry" => {
-            if pokemon.status_condition.is_some() {
-                let status_name = match pokemon.status_condition.as_ref().unwrap() {
-                    StatusCondition::Burn => "burn",
-                    StatusCondition::Freeze => "freeze",
-                    StatusCondition::Paralysis => "paralysis",
-                    StatusCondition::Poison => "poison",
-                    StatusCondition::BadPoison => "bad poison",
-                    StatusCondition::Sleep { .. } => "sleep",
-                };
-
-                pokemon.status_condition = None;
-                result.status_cured = true;
-                result.consumed = true;
-
-                result.logs.push(format!(
-                    "{}'s Lum Berry cured its {}!",
-                    pokemon.species.display_name,
-                    status_name
-                ));
-
-                // Consumir el item
-                pokemon.held_item = None;
+            ItemEffect::RestoreHP { percent } => {
+                let heal_amount = (max_hp as f32 * percent) as u16;
+                let old_hp = pokemon.current_hp;
+                pokemon.current_hp = (pokemon.current_hp + heal_amount).min(max_hp);
+                result.healed_hp = pokemon.current_hp - old_hp;
+                if result.healed_hp > 0 {
+                    effect_events.push(BattleEvent::Heal {
+                        target: pokemon_name.clone(),
+                        amount: result.healed_hp,
+                        remaining_hp: pokemon.current_hp,
+                        cause: HealCause::Item { item: item_id.to_string() },
+                    });
+                }
+                activated = true;
+                announced = true;
+            }
+            ItemEffect::CureStatus => {
+                if let Some(status) = pokemon.status_condition.take() {
+                    effect_events.push(BattleEvent::StatusCured {
+                        target: pokemon_name.clone(),
+                        status,
+                    });
+                    result.status_cured = true;
+                    activated = true;
+                }
+            }
+            ItemEffect::CureConfusion => {
+                if cure_confusion(pokemon) {
+                    effect_events.push(confusion_ended(pokemon));
+                    result.status_cured = true;
+                    activated = true;
+                }
+            }
+            // Los StatStageChanged los emite quien aplica los stat_boosts
+            ItemEffect::BoostStat { stat, stages } => {
+                result.stat_boosts.push((stat.clone(), *stages));
+                activated = true;
+            }
+            ItemEffect::SurviveLethalHit { .. } => {
+                result.survived_lethal_hit = true;
+                effect_events.push(BattleEvent::message("battle.item.endured", [
+                    ("pokemon", pokemon_name.clone()),
+                    ("item", definition.name.clone()),
+                ]));
+                activated = true;
+                announced = true;
+            }
+            // El globo explota con el primer golpe que recibe
+            ItemEffect::GroundImmunity => {
+                effect_events.push(BattleEvent::message("battle.item.air_balloon_popped", [
+                    ("pokemon", pokemon_name.clone()),
+                    ("item", definition.name.clone()),
+                ]));
+                activated = true;
+                announced = true;
+            }
+            ItemEffect::CureInfatuation => {
+                if let Some(volatile) = pokemon.volatile_status.as_mut().filter(|v| v.infatuated_by.is_some()) {
+                    volatile.infatuated_by = None;
+                    effect_events.push(BattleEvent::message("battle.status.infatuation_cured", [
+                        ("pokemon", pokemon_name.clone()),
+                    ]));
+                    result.status_cured = true;
+                    activated = true;
+                }
+            }
+            // El multiplicador ya lo aplicó calculate_damage; aquí se consume la baya
+            ItemEffect::ResistTypeHit { multiplier, .. } => {
+                result.damage_multiplier *= multiplier;
+                effect_events.push(BattleEvent::message("battle.item.damage_weakened", [
+                    ("pokemon", pokemon_name.clone()),
+                    ("item", definition.name.clone()),
+                ]));
+                activated = true;
+                announced = true;
+            }
+            ItemEffect::ActivateParadoxAbility if PARADOX_ABILITIES.contains(&pokemon.ability.as_str()) => {
+                let stat = highest_stat(pokemon);
+                let volatile = pokemon.volatile_status.get_or_insert_with(VolatileStatus::new);
+                if volatile.paradox_boost.is_none() {
+                    volatile.paradox_boost = Some(stat.to_string());
+                    effect_events.push(BattleEvent::message("battle.item.booster_energy", [
+                        ("pokemon", pokemon_name.clone()),
+                        ("item", definition.name.clone()),
+                        ("ability", ability_display_name(&pokemon.ability)),
+                    ]));
+                    activated = true;
+                    announced = true;
+                }
             }
+            // Efectos pasivos: los consulta ItemProcessor
+            _ => {}
         }
+    }
 
-        // Weakness Policy: +2 Attack y Sp. Attack cuando recibe golpe super efectivo
-        "weakness-policy" => {
-            result.stat_boosts.push(("attack".to_string(), 2));
-            result.stat_boosts.push(("sp_attack".to_string(), 2));
-            result.consumed = true;
+    if !activated {
+        return result;
+    }
+    if !announced {
+        result.events.push(BattleEvent::ItemActivated {
+            pokemon: pokemon_name,
+            item: item_id.to_string(),
+        });
+    }
+    result.events.extend(effect_events);
 
-            result.logs.push(format!(
-                "{}'s Weakness Policy sharply raised its offenses!",
-                pokemon.species.display_name
-            ));
+    if definition.consumable {
+        result.consumed = true;
+        pokemon.held_item = None;
+    }
+
+    result
+}
 
-            // Consumir el item
-            pokemon.held_item = None;
+/// Stat más alta del Pokémon sin contar el HP (en empate, la primera de la lista)
+fn highest_stat(pokemon: &PokemonInstance) -> &'static str {
+    let stats = &pokemon.base_computed_stats;
+    let mut best = ("attack", stats.attack);
+    for candidate in [
+        ("defense", stats.defense),
+        ("special_attack", stats.special_attack),
+        ("special_defense", stats.special_defense),
+        ("speed", stats.speed),
+    ] {
+        if candidate.1 > best.1 {
+            best = candidate;
         }
+    }
+    best.0
+}
 
-        _ => {
-            // Item no reconocido o no implementado
+/// Quita la confusión del Pokémon. Retorna true si estaba confundido
+fn cure_confusion(pokemon: &mut PokemonInstance) -> bool {
+    match pokemon.volatile_status.as_mut() {
+        Some(volatile) if volatile.confused => {
+            volatile.confused = false;
+            volatile.confusion_turns = 0;
+            true
         }
+        _ => false,
     }
+}
 
-    result
+fn confusion_ended(pokemon: &PokemonInstance) -> BattleEvent {
+    BattleEvent::message("battle.status.confusion_ended", [
+        ("pokemon", pokemon.species.display_name.clone()),
+    ])
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{PokemonSpecies, Stats, StatStages};
+    use crate::models::{
+        Nature, PokemonSpecies, PokemonType, RandomizedProfile, StatModifiers, Stats, StatStages, StatusCondition,
+    };
 
     fn create_test_pokemon(item: Option<String>, hp: u16) -> PokemonInstance {
         PokemonInstance {
             id: "test-1".to_string(),
             species: PokemonSpecies {
-                id: "pikachu".to_string(),
+                species_id: "pikachu".to_string(),
                 display_name: "Pikachu".to_string(),
-                types: vec!["Electric".to_string()],
-                base_stats: Default::default(),
+                generation: 1,
+                primary_type: PokemonType::Electric,
+                secondary_type: None,
+                base_stats: Stats::default(),
+                move_pool: Vec::new(),
+                possible_abilities: vec!["static".to_string()],
+                is_starter_candidate: false,
+                evolutions: Vec::new(),
+                ev_yield: Stats::zero(),
+                gender_rate: 4,
+                mega_forms: Vec::new(),
             },
             level: 50,
             current_hp: hp,
             status_condition: None,
             held_item: item,
             ability: "static".to_string(),
-            nature: "adamant".to_string(),
-            evs: Default::default(),
-            ivs: Default::default(),
-            learned_moves: vec![],
+            battle_stages: Some(StatStages::new()),
+            volatile_status: None,
+            individual_values: Stats::default(),
+            effort_values: Stats::default(),
+            nature: Nature::Adamant,
+            gender: Default::default(),
+            tera_type: Default::default(),
+            terastallized: false,
+            mega_evolution: None,
+            randomized_profile: RandomizedProfile {
+                rolled_primary_type: PokemonType::Electric,
+                rolled_secondary_type: None,
+                rolled_ability_id: "static".to_string(),
+                stat_modifiers: StatModifiers::default(),
+                learned_moves: Vec::new(),
+                moves: Vec::new(),
+            },
             base_computed_stats: Stats {
                 hp: 200,
                 attack: 100,
                 defense: 100,
-                sp_attack: 100,
-                sp_defense: 100,
+                special_attack: 100,
+                special_defense: 100,
                 speed: 100,
             },
-            stat_stages: Some(StatStages::default()),
-            volatile_status: None,
         }
     }
 
@@ -287,7 +473,7 @@ mod tests {
 
         assert_eq!(result.stat_boosts.len(), 2);
         assert!(result.stat_boosts.contains(&("attack".to_string(), 2)));
-        assert!(result.stat_boosts.contains(&("sp_attack".to_string(), 2)));
+        assert!(result.stat_boosts.contains(&("special_attack".to_string(), 2)));
         assert!(result.consumed);
         assert!(pokemon.held_item.is_none()); // Policy consumed
     }
@@ -1,16 +1,34 @@
 //! Procesador de items que integra con el pipeline de batalla
 //!
 //! Este módulo provee funciones de alto nivel para procesar items
-//! en diferentes puntos del pipeline de batalla
-
-use crate::models::PokemonInstance;
-use super::item_effects::{apply_item_effect, ItemEffectResult};
+//! en diferentes puntos del pipeline de batalla. Los efectos pasivos se leen
+//! de la `ItemDefinition` del objeto equipado.
+
+use rand::rngs::StdRng;
+use rand::Rng;
+use crate::models::{MegaForm, PokemonInstance, PokemonType, WeatherType};
+use super::item_definitions::item_definition;
+use super::item_effects::{apply_item_effect, ItemEffect, ItemEffectResult};
 use super::item_triggers::{check_item_trigger, ItemTrigger};
 
+/// Multiplicador de Protosynthesis/Quark Drive sobre la Velocidad
+const PARADOX_SPEED_MULTIPLIER: f32 = 1.5;
+/// Multiplicador de Protosynthesis/Quark Drive sobre el resto de stats
+const PARADOX_STAT_MULTIPLIER: f32 = 1.3;
+
 /// Procesador principal de items
 pub struct ItemProcessor;
 
 impl ItemProcessor {
+    /// Efectos del item equipado (vacío si no lleva item o no está en el catálogo)
+    fn held_effects(pokemon: &PokemonInstance) -> &'static [ItemEffect] {
+        pokemon
+            .held_item
+            .as_deref()
+            .and_then(item_definition)
+            .map_or(&[], |definition| definition.effects.as_slice())
+    }
+
     /// Procesa items antes de calcular daño
     pub fn process_before_damage(
         pokemon: &mut PokemonInstance,
@@ -73,6 +91,69 @@ impl ItemProcessor {
         ItemEffectResult::default()
     }
 
+    /// Procesa las bayas que reducen un golpe recibido (Occa Berry, Chilan Berry...)
+    ///
+    /// `calculate_damage` ya aplicó la reducción; si la baya actuó, se consume.
+    pub fn process_before_damage_taken(
+        pokemon: &mut PokemonInstance,
+        move_type: PokemonType,
+        type_effectiveness: f32,
+    ) -> ItemEffectResult {
+        if let Some(ref item_id) = pokemon.held_item.clone() {
+            let trigger = ItemTrigger::BeforeDamageTaken { move_type, type_effectiveness };
+
+            if check_item_trigger(item_id, &trigger, pokemon)
+                && Self::get_incoming_damage_multiplier(pokemon, move_type, type_effectiveness) != 1.0
+            {
+                return apply_item_effect(item_id, pokemon, None, None);
+            }
+        }
+
+        ItemEffectResult::default()
+    }
+
+    /// Procesa los items que aguantan un golpe letal (Focus Sash, Focus Band)
+    ///
+    /// Si `survived_lethal_hit` es true, el Pokémon debe quedar con 1 HP.
+    pub fn process_lethal_hit(
+        pokemon: &mut PokemonInstance,
+        damage: u16,
+        rng: &mut StdRng,
+    ) -> ItemEffectResult {
+        if let Some(ref item_id) = pokemon.held_item.clone() {
+            let trigger = ItemTrigger::OnLethalHit {
+                damage,
+                current_hp: pokemon.current_hp,
+            };
+
+            if check_item_trigger(item_id, &trigger, pokemon) {
+                let full_hp = pokemon.current_hp == pokemon.base_computed_stats.hp;
+                let holds = Self::held_effects(pokemon).iter().any(|effect| match effect {
+                    ItemEffect::SurviveLethalHit { chance, full_hp_only } => {
+                        (full_hp || !full_hp_only) && rng.gen::<f32>() < *chance
+                    }
+                    _ => false,
+                });
+                if holds {
+                    return apply_item_effect(item_id, pokemon, None, None);
+                }
+            }
+        }
+
+        ItemEffectResult::default()
+    }
+
+    /// Procesa items al entrar al campo (Booster Energy)
+    pub fn process_on_entry(pokemon: &mut PokemonInstance) -> ItemEffectResult {
+        if let Some(ref item_id) = pokemon.held_item.clone() {
+            if check_item_trigger(item_id, &ItemTrigger::OnEntry, pokemon) {
+                return apply_item_effect(item_id, pokemon, None, None);
+            }
+        }
+
+        ItemEffectResult::default()
+    }
+
     /// Procesa items cuando se aplica un status
     pub fn process_on_status_applied(
         pokemon: &mut PokemonInstance,
@@ -92,21 +173,25 @@ impl ItemProcessor {
     }
 
     /// Procesa items al final del turno
+    ///
+    /// Berries con umbral de HP (Sitrus Berry) y objetos de fin de turno (Leftovers).
     pub fn process_end_of_turn(
         pokemon: &mut PokemonInstance,
     ) -> ItemEffectResult {
-        // Verificar threshold de HP para berries
         let current_hp = pokemon.current_hp;
         let max_hp = pokemon.base_computed_stats.hp;
 
         if let Some(ref item_id) = pokemon.held_item.clone() {
-            let trigger = ItemTrigger::OnHPThreshold {
-                current_hp,
-                max_hp,
-                threshold: 0.5,
-            };
-
-            if check_item_trigger(item_id, &trigger, pokemon) {
+            let triggers = [
+                ItemTrigger::OnHPThreshold {
+                    current_hp,
+                    max_hp,
+                    threshold: 0.5,
+                },
+                ItemTrigger::EndOfTurn,
+            ];
+
+            if triggers.iter().any(|trigger| check_item_trigger(item_id, trigger, pokemon)) {
                 return apply_item_effect(item_id, pokemon, None, None);
             }
         }
@@ -119,51 +204,199 @@ impl ItemProcessor {
         pokemon: &PokemonInstance,
         _move_id: &str,
     ) -> bool {
-        if let Some(ref item_id) = pokemon.held_item {
-            if item_id == "assault-vest" {
-                // TODO: Verificar que el movimiento sea de status consultando MoveData
-                // Por ahora asumimos que se verificará en el validation system
-                return true;
-            }
-        }
-        false
+        // TODO: Verificar que el movimiento sea de status consultando MoveData
+        // Por ahora asumimos que se verificará en el validation system
+        Self::held_effects(pokemon).contains(&ItemEffect::BlockStatusMoves)
     }
 
     /// Obtiene el multiplicador de daño de un item (para Choice items y Life Orb)
+    ///
+    /// Solo cuenta los potenciadores sin tipo; los de tipo se aplican en
+    /// `get_type_damage_multiplier`.
     pub fn get_damage_multiplier(
         pokemon: &PokemonInstance,
         move_category: &str, // "physical" o "special"
     ) -> f32 {
-        if let Some(ref item_id) = pokemon.held_item {
-            match item_id.as_str() {
-                "choice-band" if move_category == "physical" => 1.5,
-                "choice-specs" if move_category == "special" => 1.5,
-                "life-orb" => 1.3,
-                _ => 1.0,
-            }
-        } else {
-            1.0
-        }
+        Self::held_effects(pokemon)
+            .iter()
+            .filter_map(|effect| match effect {
+                ItemEffect::BoostDamage { multiplier, damage_class, move_type: None }
+                    if damage_class.as_deref().is_none_or(|class| class == move_category) =>
+                {
+                    Some(*multiplier)
+                }
+                _ => None,
+            })
+            .product()
+    }
+
+    /// Obtiene el multiplicador de daño de los potenciadores de tipo (Charcoal, Mystic Water...)
+    pub fn get_type_damage_multiplier(
+        pokemon: &PokemonInstance,
+        move_category: &str,
+        move_type: PokemonType,
+    ) -> f32 {
+        Self::held_effects(pokemon)
+            .iter()
+            .filter_map(|effect| match effect {
+                ItemEffect::BoostDamage { multiplier, damage_class, move_type: Some(boosted) }
+                    if *boosted == move_type
+                        && damage_class.as_deref().is_none_or(|class| class == move_category) =>
+                {
+                    Some(*multiplier)
+                }
+                _ => None,
+            })
+            .product()
+    }
+
+    /// Verifica si el item es un objeto Choice (bloquea el primer movimiento usado)
+    pub fn is_choice_item(pokemon: &PokemonInstance) -> bool {
+        Self::held_effects(pokemon).contains(&ItemEffect::LockMove)
+    }
+
+    /// Multiplicador que el item aplica a una stat ("speed", "special_defense"...)
+    ///
+    /// Incluye la stat que potenció Booster Energy, que sigue activa después
+    /// de consumirse el objeto.
+    pub fn get_stat_multiplier(pokemon: &PokemonInstance, stat: &str) -> f32 {
+        let item_multiplier: f32 = Self::held_effects(pokemon)
+            .iter()
+            .filter_map(|effect| match effect {
+                ItemEffect::MultiplyStat { stat: boosted, multiplier } if boosted == stat => Some(*multiplier),
+                _ => None,
+            })
+            .product();
+
+        let paradox_boost = pokemon.volatile_status.as_ref().and_then(|v| v.paradox_boost.as_deref());
+        let paradox_multiplier = match paradox_boost {
+            Some(boosted) if boosted == stat && stat == "speed" => PARADOX_SPEED_MULTIPLIER,
+            Some(boosted) if boosted == stat => PARADOX_STAT_MULTIPLIER,
+            _ => 1.0,
+        };
+
+        item_multiplier * paradox_multiplier
     }
 
     /// Obtiene el multiplicador de Speed de Choice Scarf
     pub fn get_speed_multiplier(pokemon: &PokemonInstance) -> f32 {
-        if let Some(ref item_id) = pokemon.held_item {
-            if item_id == "choice-scarf" {
-                return 1.5;
-            }
-        }
-        1.0
+        Self::get_stat_multiplier(pokemon, "speed")
+    }
+
+    /// Verifica si el item alarga las pantallas del usuario (Light Clay)
+    pub fn extends_screens(pokemon: &PokemonInstance) -> bool {
+        Self::held_effects(pokemon).contains(&ItemEffect::ExtendScreens)
+    }
+
+    /// Verifica si el item alarga el clima que establece el portador
+    /// (Heat Rock, Damp Rock, Smooth Rock, Icy Rock)
+    pub fn extends_weather(pokemon: &PokemonInstance, weather: WeatherType) -> bool {
+        Self::held_effects(pokemon).contains(&ItemEffect::ExtendWeather { weather })
+    }
+
+    /// Verifica si el item alarga el terreno que establece el portador (Terrain Extender)
+    pub fn extends_terrain(pokemon: &PokemonInstance) -> bool {
+        Self::held_effects(pokemon).contains(&ItemEffect::ExtendTerrain)
+    }
+
+    /// Multiplicador de precisión de los ataques del portador (Wide Lens)
+    pub fn get_accuracy_multiplier(pokemon: &PokemonInstance) -> f32 {
+        Self::held_effects(pokemon)
+            .iter()
+            .filter_map(|effect| match effect {
+                ItemEffect::ModifyAccuracy { multiplier } => Some(*multiplier),
+                _ => None,
+            })
+            .product()
+    }
+
+    /// Multiplicador de precisión de los ataques que recibe el portador
+    /// (Bright Powder, Lax Incense)
+    pub fn get_evasion_multiplier(pokemon: &PokemonInstance) -> f32 {
+        Self::held_effects(pokemon)
+            .iter()
+            .filter_map(|effect| match effect {
+                ItemEffect::ModifyIncomingAccuracy { multiplier } => Some(*multiplier),
+                _ => None,
+            })
+            .product()
+    }
+
+    /// Fracción del HP máximo del atacante que pierde al hacer contacto con el portador (Rocky Helmet)
+    pub fn contact_damage_percent(pokemon: &PokemonInstance) -> Option<f32> {
+        Self::held_effects(pokemon).iter().find_map(|effect| match effect {
+            ItemEffect::DamageAttackerOnContact { percent } => Some(*percent),
+            _ => None,
+        })
+    }
+
+    /// Verifica si el item saca al portador del campo al recibir un golpe (Eject Button)
+    pub fn ejects_on_hit(pokemon: &PokemonInstance) -> bool {
+        Self::held_effects(pokemon).contains(&ItemEffect::EjectOnHit)
+    }
+
+    /// Verifica si el item saca al portador del campo al bajarle una stat (Eject Pack)
+    pub fn ejects_on_stat_drop(pokemon: &PokemonInstance) -> bool {
+        Self::held_effects(pokemon).contains(&ItemEffect::EjectOnStatDrop)
+    }
+
+    /// Verifica si el item devuelve el enamoramiento a quien lo causó (Destiny Knot)
+    pub fn reflects_infatuation(pokemon: &PokemonInstance) -> bool {
+        Self::held_effects(pokemon).contains(&ItemEffect::ReflectInfatuation)
+    }
+
+    /// Verifica si el item hace inmune a los movimientos de tipo Tierra (Air Balloon)
+    pub fn grants_ground_immunity(pokemon: &PokemonInstance) -> bool {
+        Self::held_effects(pokemon).contains(&ItemEffect::GroundImmunity)
+    }
+
+    /// Multiplicador que el item del defensor aplica a un golpe recibido
+    /// (bayas que reducen el daño de un tipo)
+    pub fn get_incoming_damage_multiplier(
+        pokemon: &PokemonInstance,
+        move_type: PokemonType,
+        type_effectiveness: f32,
+    ) -> f32 {
+        Self::held_effects(pokemon)
+            .iter()
+            .filter_map(|effect| match effect {
+                ItemEffect::ResistTypeHit { move_type: resisted, multiplier, super_effective_only }
+                    if *resisted == move_type && (!super_effective_only || type_effectiveness >= 2.0) =>
+                {
+                    Some(*multiplier)
+                }
+                _ => None,
+            })
+            .product()
+    }
+
+    /// Verifica si el item evita las bajadas de stats que causan otros Pokémon (Clear Amulet)
+    pub fn prevents_stat_loss(pokemon: &PokemonInstance) -> bool {
+        Self::held_effects(pokemon).contains(&ItemEffect::PreventStatLoss)
+    }
+
+    /// Verifica si el item bloquea los efectos secundarios de los ataques recibidos (Covert Cloak)
+    pub fn blocks_secondary_effects(pokemon: &PokemonInstance) -> bool {
+        Self::held_effects(pokemon).contains(&ItemEffect::BlockSecondaryEffects)
+    }
+
+    /// Forma Mega que activa la megapiedra equipada
+    ///
+    /// Una megapiedra solo sirve a la especie que la tiene entre sus formas
+    /// Mega (Charizardite X en Charizard); en cualquier otro no hace nada.
+    pub fn mega_form(pokemon: &PokemonInstance) -> Option<&MegaForm> {
+        let item = pokemon.held_item.as_deref()?;
+        pokemon.species.mega_forms.iter().find(|form| form.mega_stone == item)
+    }
+
+    /// Verifica si el Pokémon puede megaevolucionar (megapiedra propia y aún no lo hizo)
+    pub fn can_mega_evolve(pokemon: &PokemonInstance) -> bool {
+        pokemon.mega_evolution.is_none() && pokemon.current_hp > 0 && Self::mega_form(pokemon).is_some()
     }
 
     /// Obtiene el multiplicador de Sp. Defense de Assault Vest
     pub fn get_sp_defense_multiplier(pokemon: &PokemonInstance) -> f32 {
-        if let Some(ref item_id) = pokemon.held_item {
-            if item_id == "assault-vest" {
-                return 1.5;
-            }
-        }
-        1.0
+        Self::get_stat_multiplier(pokemon, "special_defense")
     }
 }
 
@@ -188,36 +421,58 @@ pub fn process_items_after_damage(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{PokemonSpecies, Stats, StatStages};
+    use crate::models::{
+        Nature, PokemonSpecies, PokemonType, RandomizedProfile, StatModifiers, Stats, StatStages,
+    };
 
     fn create_test_pokemon(item: Option<String>) -> PokemonInstance {
         PokemonInstance {
             id: "test-1".to_string(),
             species: PokemonSpecies {
-                id: "pikachu".to_string(),
+                species_id: "pikachu".to_string(),
                 display_name: "Pikachu".to_string(),
-                types: vec!["Electric".to_string()],
-                base_stats: Default::default(),
+                generation: 1,
+                primary_type: PokemonType::Electric,
+                secondary_type: None,
+                base_stats: Stats::default(),
+                move_pool: Vec::new(),
+                possible_abilities: vec!["static".to_string()],
+                is_starter_candidate: false,
+                evolutions: Vec::new(),
+                ev_yield: Stats::zero(),
+                gender_rate: 4,
+                mega_forms: Vec::new(),
             },
             level: 50,
             current_hp: 200,
             status_condition: None,
             held_item: item,
             ability: "static".to_string(),
-            nature: "adamant".to_string(),
-            evs: Default::default(),
-            ivs: Default::default(),
-            learned_moves: vec![],
+            battle_stages: Some(StatStages::new()),
+            volatile_status: None,
+            individual_values: Stats::default(),
+            effort_values: Stats::default(),
+            nature: Nature::Adamant,
+            gender: Default::default(),
+            tera_type: Default::default(),
+            terastallized: false,
+            mega_evolution: None,
+            randomized_profile: RandomizedProfile {
+                rolled_primary_type: PokemonType::Electric,
+                rolled_secondary_type: None,
+                rolled_ability_id: "static".to_string(),
+                stat_modifiers: StatModifiers::default(),
+                learned_moves: Vec::new(),
+                moves: Vec::new(),
+            },
             base_computed_stats: Stats {
                 hp: 200,
                 attack: 100,
                 defense: 100,
-                sp_attack: 100,
-                sp_defense: 100,
+                special_attack: 100,
+                special_defense: 100,
                 speed: 100,
             },
-            stat_stages: Some(StatStages::default()),
-            volatile_status: None,
         }
     }
 
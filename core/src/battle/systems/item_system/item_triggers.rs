@@ -1,8 +1,11 @@
 //! Triggers que activan los efectos de items
 //!
-//! Define cuándo y bajo qué condiciones se activan los items
+//! Define cuándo y bajo qué condiciones se activan los items: cada momento del
+//! combate genera un `ItemTrigger` que se contrasta con la condición de la
+//! definición del item
 
-use crate::models::PokemonInstance;
+use crate::models::{PokemonInstance, PokemonType};
+use super::item_definitions::{item_definition, ItemTriggerCondition};
 
 /// Define los diferentes momentos/condiciones en que un item puede activarse
 #[derive(Debug, Clone, PartialEq)]
@@ -26,6 +29,21 @@ pub enum ItemTrigger {
         is_super_effective: bool,
     },
 
+    /// Cuando este Pokémon recibe un golpe, antes de aplicar el daño
+    BeforeDamageTaken {
+        move_type: PokemonType,
+        type_effectiveness: f32,
+    },
+
+    /// Cuando este Pokémon recibe un golpe de al menos su HP actual
+    OnLethalHit {
+        damage: u16,
+        current_hp: u16,
+    },
+
+    /// Cuando este Pokémon entra al campo
+    OnEntry,
+
     /// Cuando se aplica un status condition a este Pokémon
     OnStatusApplied {
         status: String,
@@ -62,41 +80,33 @@ pub fn check_item_trigger(
         return false;
     }
 
-    match item_id {
-        // Choice Items: se activan antes de calcular daño
-        "choice-band" | "choice-specs" | "choice-scarf" => {
-            matches!(trigger, ItemTrigger::BeforeDamageDealt { .. })
-        }
+    let Some(definition) = item_definition(item_id) else {
+        return false;
+    };
 
-        // Life Orb: se activa después de causar daño
-        "life-orb" => {
-            matches!(trigger, ItemTrigger::AfterDamageDealt { damage_dealt, .. } if *damage_dealt > 0)
+    match (&definition.trigger, trigger) {
+        (ItemTriggerCondition::BeforeDamageDealt, ItemTrigger::BeforeDamageDealt { .. }) => true,
+        (ItemTriggerCondition::AfterDamageDealt, ItemTrigger::AfterDamageDealt { damage_dealt, .. }) => {
+            *damage_dealt > 0
         }
-
-        // Assault Vest: se activa cuando se intenta usar un status move
-        "assault-vest" => {
-            matches!(trigger, ItemTrigger::OnStatusMoveAttempt { .. })
+        (ItemTriggerCondition::OnDamageTaken, ItemTrigger::OnDamageTaken { .. }) => true,
+        (ItemTriggerCondition::OnSuperEffectiveHit, ItemTrigger::OnDamageTaken { is_super_effective, .. }) => {
+            *is_super_effective
         }
-
-        // Sitrus Berry: se activa cuando HP < 50%
-        "sitrus-berry" => {
-            if let ItemTrigger::OnHPThreshold { current_hp, max_hp, .. } = trigger {
-                (*current_hp as f32 / *max_hp as f32) <= 0.5
-            } else {
-                false
-            }
+        (ItemTriggerCondition::BeforeDamageTaken, ItemTrigger::BeforeDamageTaken { .. }) => true,
+        (ItemTriggerCondition::OnLethalHit, ItemTrigger::OnLethalHit { damage, current_hp }) => {
+            *current_hp > 0 && *damage >= *current_hp
         }
-
-        // Lum Berry: se activa cuando se aplica un status
-        "lum-berry" => {
-            matches!(trigger, ItemTrigger::OnStatusApplied { .. })
+        (ItemTriggerCondition::OnEntry, ItemTrigger::OnEntry) => true,
+        (ItemTriggerCondition::OnStatusApplied { statuses }, ItemTrigger::OnStatusApplied { status }) => {
+            statuses.is_empty() || statuses.contains(status)
         }
-
-        // Weakness Policy: se activa al recibir golpe super efectivo
-        "weakness-policy" => {
-            matches!(trigger, ItemTrigger::OnDamageTaken { is_super_effective: true, .. })
+        (ItemTriggerCondition::OnStatusMoveAttempt, ItemTrigger::OnStatusMoveAttempt { .. }) => true,
+        // El umbral lo fija la definición del item (Sitrus Berry: 50%)
+        (ItemTriggerCondition::OnHPThreshold { threshold }, ItemTrigger::OnHPThreshold { current_hp, max_hp, .. }) => {
+            (*current_hp as f32 / *max_hp as f32) <= *threshold
         }
-
+        (ItemTriggerCondition::EndOfTurn, ItemTrigger::EndOfTurn) => true,
         _ => false,
     }
 }
@@ -104,36 +114,58 @@ pub fn check_item_trigger(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{PokemonSpecies, Stats, StatStages};
+    use crate::models::{
+        Nature, PokemonSpecies, PokemonType, RandomizedProfile, StatModifiers, Stats, StatStages,
+    };
 
     fn create_test_pokemon(item: Option<String>) -> PokemonInstance {
         PokemonInstance {
             id: "test-1".to_string(),
             species: PokemonSpecies {
-                id: "pikachu".to_string(),
+                species_id: "pikachu".to_string(),
                 display_name: "Pikachu".to_string(),
-                types: vec!["Electric".to_string()],
-                base_stats: Default::default(),
+                generation: 1,
+                primary_type: PokemonType::Electric,
+                secondary_type: None,
+                base_stats: Stats::default(),
+                move_pool: Vec::new(),
+                possible_abilities: vec!["static".to_string()],
+                is_starter_candidate: false,
+                evolutions: Vec::new(),
+                ev_yield: Stats::zero(),
+                gender_rate: 4,
+                mega_forms: Vec::new(),
             },
             level: 50,
             current_hp: 100,
             status_condition: None,
             held_item: item,
             ability: "static".to_string(),
-            nature: "adamant".to_string(),
-            evs: Default::default(),
-            ivs: Default::default(),
-            learned_moves: vec![],
+            battle_stages: Some(StatStages::new()),
+            volatile_status: None,
+            individual_values: Stats::default(),
+            effort_values: Stats::default(),
+            nature: Nature::Adamant,
+            gender: Default::default(),
+            tera_type: Default::default(),
+            terastallized: false,
+            mega_evolution: None,
+            randomized_profile: RandomizedProfile {
+                rolled_primary_type: PokemonType::Electric,
+                rolled_secondary_type: None,
+                rolled_ability_id: "static".to_string(),
+                stat_modifiers: StatModifiers::default(),
+                learned_moves: Vec::new(),
+                moves: Vec::new(),
+            },
             base_computed_stats: Stats {
                 hp: 200,
                 attack: 100,
                 defense: 100,
-                sp_attack: 100,
-                sp_defense: 100,
+                special_attack: 100,
+                special_defense: 100,
                 speed: 100,
             },
-            stat_stages: Some(StatStages::default()),
-            volatile_status: None,
         }
     }
 
@@ -1,8 +1,10 @@
 //! Sistema de Items
 //!
 //! Este módulo maneja todos los efectos de items equipados en batalla.
-//! Incluye items como Choice Band, Life Orb, berries, etc.
+//! Incluye items como Choice Band, Life Orb, berries, etc. Cada item se
+//! describe con una `ItemDefinition` del catálogo (`items.json`).
 
+pub mod item_definitions;
 pub mod item_effects;
 pub mod item_triggers;
 pub mod item_processor;
@@ -11,6 +13,10 @@ pub mod item_processor;
 mod tests;
 
 // Re-exportar tipos principales
+pub use item_definitions::{
+    ItemCategory, ItemDefinition, ItemTriggerCondition,
+    builtin_item_definitions, item_definition, item_display_name, parse_item_definitions, register_item_definitions,
+};
 pub use item_effects::{ItemEffect, apply_item_effect};
 pub use item_triggers::{ItemTrigger, check_item_trigger};
 pub use item_processor::{ItemProcessor, process_items_before_damage, process_items_after_damage};
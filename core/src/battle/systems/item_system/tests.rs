@@ -33,6 +33,9 @@ fn create_test_pokemon(
             possible_abilities: vec!["static".to_string()],
             is_starter_candidate: false,
             evolutions: Vec::new(),
+            ev_yield: Stats::zero(),
+            gender_rate: 4,
+            mega_forms: Vec::new(),
         },
         level: 50,
         current_hp: hp,
@@ -43,6 +46,11 @@ fn create_test_pokemon(
         volatile_status: Some(VolatileStatus::new()),
         individual_values: Stats::default(),
         effort_values: Stats::default(),
+        nature: Default::default(),
+        gender: Default::default(),
+        tera_type: Default::default(),
+        terastallized: false,
+        mega_evolution: None,
         randomized_profile: RandomizedProfile {
             rolled_primary_type: PokemonType::Electric,
             rolled_secondary_type: None,
@@ -333,7 +341,7 @@ mod weakness_policy {
             "Debe incluir +2 Attack"
         );
         assert!(
-            result.stat_boosts.contains(&("sp_attack".to_string(), 2)),
+            result.stat_boosts.contains(&("special_attack".to_string(), 2)),
             "Debe incluir +2 Sp. Attack"
         );
         assert!(result.consumed, "Weakness Policy debe consumirse");
@@ -355,6 +363,335 @@ mod weakness_policy {
     }
 }
 
+// ==================== LEFTOVERS TESTS ====================
+
+#[cfg(test)]
+mod leftovers {
+    use super::*;
+    use super::super::item_processor::ItemProcessor;
+
+    #[test]
+    fn test_leftovers_heals_one_sixteenth_each_turn() {
+        let mut pokemon = create_test_pokemon(Some("leftovers".to_string()), 100, 160, None);
+        let result = ItemProcessor::process_end_of_turn(&mut pokemon);
+
+        assert_eq!(result.healed_hp, 10, "Debe curar 1/16 de 160 HP = 10");
+        assert_eq!(pokemon.current_hp, 110);
+        assert!(!result.consumed, "Leftovers no es consumible");
+        assert_eq!(pokemon.held_item, Some("leftovers".to_string()));
+    }
+
+    #[test]
+    fn test_leftovers_is_silent_at_full_hp() {
+        let mut pokemon = create_test_pokemon(Some("leftovers".to_string()), 160, 160, None);
+        let result = ItemProcessor::process_end_of_turn(&mut pokemon);
+
+        assert_eq!(result.healed_hp, 0);
+        assert!(result.events.is_empty(), "Sin curación no debe haber eventos");
+    }
+}
+
+// ==================== FOCUS SASH / FOCUS BAND TESTS ====================
+
+#[cfg(test)]
+mod focus_items {
+    use super::*;
+    use super::super::item_processor::ItemProcessor;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_focus_sash_survives_lethal_hit_at_full_hp() {
+        let mut pokemon = create_test_pokemon(Some("focus-sash".to_string()), 200, 200, None);
+        let mut rng = StdRng::seed_from_u64(1);
+        let result = ItemProcessor::process_lethal_hit(&mut pokemon, 250, &mut rng);
+
+        assert!(result.survived_lethal_hit);
+        assert!(result.consumed, "Focus Sash se consume");
+        assert!(pokemon.held_item.is_none());
+    }
+
+    #[test]
+    fn test_focus_sash_fails_below_full_hp() {
+        let mut pokemon = create_test_pokemon(Some("focus-sash".to_string()), 199, 200, None);
+        let mut rng = StdRng::seed_from_u64(1);
+        let result = ItemProcessor::process_lethal_hit(&mut pokemon, 250, &mut rng);
+
+        assert!(!result.survived_lethal_hit);
+        assert_eq!(pokemon.held_item, Some("focus-sash".to_string()));
+    }
+
+    #[test]
+    fn test_focus_sash_ignores_non_lethal_hits() {
+        let mut pokemon = create_test_pokemon(Some("focus-sash".to_string()), 200, 200, None);
+        let mut rng = StdRng::seed_from_u64(1);
+        let result = ItemProcessor::process_lethal_hit(&mut pokemon, 150, &mut rng);
+
+        assert!(!result.survived_lethal_hit);
+        assert_eq!(pokemon.held_item, Some("focus-sash".to_string()));
+    }
+
+    #[test]
+    fn test_focus_band_is_a_chance_and_is_not_consumed() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut survived = 0;
+        for _ in 0..1000 {
+            let mut pokemon = create_test_pokemon(Some("focus-band".to_string()), 50, 200, None);
+            let result = ItemProcessor::process_lethal_hit(&mut pokemon, 100, &mut rng);
+            if result.survived_lethal_hit {
+                survived += 1;
+                assert_eq!(pokemon.held_item, Some("focus-band".to_string()), "Focus Band no se consume");
+            }
+        }
+        assert!((50..=150).contains(&survived), "~10% de activaciones, obtuvo {}", survived);
+    }
+}
+
+// ==================== AIR BALLOON TESTS ====================
+
+#[cfg(test)]
+mod air_balloon {
+    use super::*;
+    use super::super::item_processor::ItemProcessor;
+    use super::super::super::effect_system::is_grounded;
+
+    #[test]
+    fn test_air_balloon_lifts_holder() {
+        let pokemon = create_test_pokemon(Some("air-balloon".to_string()), 200, 200, None);
+        assert!(ItemProcessor::grants_ground_immunity(&pokemon));
+        assert!(!is_grounded(&pokemon));
+        assert!(is_grounded(&create_test_pokemon(None, 200, 200, None)));
+    }
+
+    #[test]
+    fn test_air_balloon_pops_when_hit() {
+        let mut pokemon = create_test_pokemon(Some("air-balloon".to_string()), 200, 200, None);
+        let result = ItemProcessor::process_on_damage_taken(&mut pokemon, 30, "attacker", false);
+
+        assert!(result.consumed);
+        assert!(pokemon.held_item.is_none());
+        assert!(is_grounded(&pokemon), "Sin el globo vuelve a tocar el suelo");
+    }
+}
+
+// ==================== MENTAL HERB TESTS ====================
+
+#[cfg(test)]
+mod mental_herb {
+    use super::*;
+    use super::super::item_processor::ItemProcessor;
+
+    #[test]
+    fn test_mental_herb_cures_infatuation() {
+        let mut pokemon = create_test_pokemon(Some("mental-herb".to_string()), 200, 200, None);
+        pokemon.volatile_status.as_mut().unwrap().infatuated_by = Some("rival".to_string());
+
+        let result = ItemProcessor::process_on_status_applied(&mut pokemon, "infatuation");
+
+        assert!(result.status_cured);
+        assert!(result.consumed);
+        assert!(pokemon.volatile_status.as_ref().unwrap().infatuated_by.is_none());
+    }
+
+    #[test]
+    fn test_mental_herb_ignores_other_statuses() {
+        let mut pokemon = create_test_pokemon(Some("mental-herb".to_string()), 200, 200, Some(StatusCondition::Burn));
+        let result = ItemProcessor::process_on_status_applied(&mut pokemon, "burn");
+
+        assert!(!result.consumed);
+        assert_eq!(pokemon.held_item, Some("mental-herb".to_string()));
+    }
+}
+
+// ==================== TYPE-RESIST BERRIES TESTS ====================
+
+#[cfg(test)]
+mod resist_berries {
+    use super::*;
+    use super::super::item_processor::ItemProcessor;
+
+    #[test]
+    fn test_resist_berry_halves_super_effective_hits_of_its_type() {
+        let pokemon = create_test_pokemon(Some("occa-berry".to_string()), 200, 200, None);
+
+        assert_eq!(ItemProcessor::get_incoming_damage_multiplier(&pokemon, PokemonType::Fire, 2.0), 0.5);
+        assert_eq!(ItemProcessor::get_incoming_damage_multiplier(&pokemon, PokemonType::Fire, 1.0), 1.0);
+        assert_eq!(ItemProcessor::get_incoming_damage_multiplier(&pokemon, PokemonType::Water, 2.0), 1.0);
+    }
+
+    #[test]
+    fn test_chilan_berry_works_on_any_normal_hit() {
+        let pokemon = create_test_pokemon(Some("chilan-berry".to_string()), 200, 200, None);
+        assert_eq!(ItemProcessor::get_incoming_damage_multiplier(&pokemon, PokemonType::Normal, 1.0), 0.5);
+    }
+
+    #[test]
+    fn test_resist_berry_is_consumed_only_when_it_acts() {
+        let mut pokemon = create_test_pokemon(Some("chople-berry".to_string()), 200, 200, None);
+
+        let result = ItemProcessor::process_before_damage_taken(&mut pokemon, PokemonType::Fighting, 1.0);
+        assert!(!result.consumed, "Un golpe neutro no activa la baya");
+
+        let result = ItemProcessor::process_before_damage_taken(&mut pokemon, PokemonType::Fighting, 2.0);
+        assert!(result.consumed);
+        assert!(pokemon.held_item.is_none());
+    }
+}
+
+// ==================== BOOSTER ENERGY TESTS ====================
+
+#[cfg(test)]
+mod booster_energy {
+    use super::*;
+    use super::super::item_processor::ItemProcessor;
+
+    #[test]
+    fn test_booster_energy_boosts_highest_stat_of_paradox_pokemon() {
+        let mut pokemon = create_test_pokemon(Some("booster-energy".to_string()), 200, 200, None);
+        pokemon.ability = "quark-drive".to_string();
+        pokemon.base_computed_stats.speed = 150;
+
+        let result = ItemProcessor::process_on_entry(&mut pokemon);
+
+        assert!(result.consumed);
+        assert_eq!(pokemon.volatile_status.as_ref().unwrap().paradox_boost.as_deref(), Some("speed"));
+        // El boost sigue activo sin el objeto: x1.5 en Velocidad
+        assert_eq!(ItemProcessor::get_speed_multiplier(&pokemon), 1.5);
+        assert_eq!(ItemProcessor::get_stat_multiplier(&pokemon, "attack"), 1.0);
+    }
+
+    #[test]
+    fn test_booster_energy_needs_paradox_ability() {
+        let mut pokemon = create_test_pokemon(Some("booster-energy".to_string()), 200, 200, None);
+        let result = ItemProcessor::process_on_entry(&mut pokemon);
+
+        assert!(!result.consumed);
+        assert_eq!(pokemon.held_item, Some("booster-energy".to_string()));
+    }
+}
+
+// ==================== CLEAR AMULET / COVERT CLOAK TESTS ====================
+
+#[cfg(test)]
+mod protective_items {
+    use super::*;
+    use super::super::item_processor::ItemProcessor;
+
+    #[test]
+    fn test_clear_amulet_and_covert_cloak_are_passive() {
+        let amulet = create_test_pokemon(Some("clear-amulet".to_string()), 200, 200, None);
+        let cloak = create_test_pokemon(Some("covert-cloak".to_string()), 200, 200, None);
+
+        assert!(ItemProcessor::prevents_stat_loss(&amulet));
+        assert!(!ItemProcessor::blocks_secondary_effects(&amulet));
+        assert!(ItemProcessor::blocks_secondary_effects(&cloak));
+        assert!(!ItemProcessor::prevents_stat_loss(&cloak));
+    }
+}
+
+// ==================== PERSIM BERRY TESTS ====================
+
+#[cfg(test)]
+mod persim_berry {
+    use crate::battle::events::BattleEvent;
+    use crate::battle::test_fixtures::{confusion_move, is_confused, moves, play_wild_turn};
+
+    #[test]
+    fn test_persim_berry_cures_confusion() {
+        let mut moves = moves();
+        moves.insert("confuse-ray".to_string(), confusion_move("confuse-ray"));
+
+        let (_, battle_state, result) = play_wild_turn("confuse-ray", &moves, |state, _| {
+            state.opponent_instance.held_item = Some("persim-berry".to_string());
+        });
+
+        assert!(result.events.contains(&BattleEvent::ItemActivated {
+            pokemon: "wild".to_string(),
+            item: "persim-berry".to_string(),
+        }));
+        assert!(!is_confused(&battle_state.opponent_instance));
+        assert_eq!(battle_state.opponent_instance.held_item, None);
+    }
+}
+
+// ==================== EJECT BUTTON / EJECT PACK TESTS ====================
+
+#[cfg(test)]
+mod eject_items {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use crate::battle::events::BattleEvent;
+    use crate::battle::orchestration::{finish_turn, resume_turn};
+    use crate::battle::test_fixtures::{moves, play_turn, play_wild_turn, pokemon, status_move, use_move};
+    use crate::battle::BattleOutcome;
+    use crate::game::{BattleState, PlayerTeam};
+    use crate::models::{BattleFormat, MoveStatChange};
+
+    #[test]
+    fn test_eject_button_makes_the_player_pick_a_replacement() {
+        let moves = moves();
+        let (mut team, mut battle_state, result) = play_wild_turn("tackle", &moves, |state, team| {
+            state.opponent_instance.base_computed_stats.speed = 500;
+            team.active_members[0].base_computed_stats.speed = 1;
+            team.active_members[0].held_item = Some("eject-button".to_string());
+            team.add_member(pokemon("bench", "run-away", &moves));
+        });
+
+        assert_eq!(result.outcome, BattleOutcome::PlayerMustPivot);
+        assert!(result.events.contains(&BattleEvent::ItemActivated {
+            pokemon: "player".to_string(),
+            item: "eject-button".to_string(),
+        }));
+        assert_eq!(team.active_members[0].held_item, None);
+
+        let mut rng = StdRng::seed_from_u64(12);
+        let result = resume_turn(&mut team, &mut battle_state, &mut rng, 1);
+        finish_turn(&mut battle_state, &result);
+
+        // El saliente ya no ataca: su Tackle se pierde
+        assert_eq!(result.outcome, BattleOutcome::Continue);
+        assert_eq!(battle_state.player_active_indices, vec![1]);
+        assert_eq!(battle_state.opponent_instance.current_hp, battle_state.opponent_instance.base_computed_stats.hp);
+        assert_eq!(team.active_members[1].current_hp, team.active_members[1].base_computed_stats.hp);
+    }
+
+    #[test]
+    fn test_eject_pack_switches_out_the_lowered_pokemon() {
+        let mut moves = moves();
+        let mut growl = status_move("growl", "all-opponents");
+        growl.stat_changes = vec![MoveStatChange { stat: "attack".to_string(), change: -1 }];
+        moves.insert("growl".to_string(), growl);
+
+        let mut team = PlayerTeam::new();
+        team.add_member(pokemon("player", "run-away", &moves));
+        team.active_members[0].learn_new_move("growl".to_string(), 10);
+        team.active_members[0].base_computed_stats.speed = 500;
+
+        let mut holder = pokemon("holder", "run-away", &moves);
+        holder.held_item = Some("eject-pack".to_string());
+        let mut battle_state = BattleState::new(
+            0,
+            vec![holder, pokemon("replacement", "run-away", &moves)],
+            "Rival".to_string(),
+            BattleFormat::Single,
+            true,
+        );
+        battle_state.turn_counter = 2;
+        battle_state.pending_player_actions = vec![use_move(0, "growl")];
+
+        let result = play_turn(&mut team, &mut battle_state, &moves, 4);
+
+        assert!(result.events.contains(&BattleEvent::ItemActivated {
+            pokemon: "holder".to_string(),
+            item: "eject-pack".to_string(),
+        }));
+        assert_eq!(battle_state.opponent_active_indices, vec![1]);
+        assert_eq!(battle_state.opponent_team[0].held_item, None);
+        // El reemplazo no usa la acción del saliente
+        assert_eq!(team.active_members[0].current_hp, team.active_members[0].base_computed_stats.hp);
+    }
+}
+
 // ==================== INTEGRATION TESTS ====================
 
 #[cfg(test)]
@@ -13,6 +13,7 @@ pub mod validation_system;
 pub mod item_system;
 pub mod redirection_system;
 pub mod protection_system;
+pub mod field_system;
 
 #[cfg(test)]
 mod integration_tests;
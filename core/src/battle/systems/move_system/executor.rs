@@ -1,11 +1,20 @@
 use rand::rngs::StdRng;
 use rand::Rng;
-use crate::models::{MoveData, PokemonInstance, WeatherState, TerrainState, PokemonType, StatusCondition, TerrainType};
+use crate::models::{MoveData, PokemonInstance, WeatherState, WeatherType, TerrainState, PokemonType, StatusCondition, TerrainType, VolatileStatus};
 use super::super::super::checks::{can_pokemon_move, check_ailment_success};
-use super::super::damage_system::{calculate_damage, check_critical_hit, calculate_hit_count};
-use super::super::effect_system::is_grounded;
-use super::super::ability_system::{get_ability_hooks, AbilityTrigger, AbilityEffect};
-use super::super::item_system::ItemProcessor;
+use super::super::damage_system::{calculate_damage, check_accuracy, check_critical_hit, calculate_hit_count};
+use super::super::damage_system::calculator::parse_type;
+use super::super::effect_system::{can_infatuate, infatuate, is_grounded, reflects_infatuation, InfatuationFailure};
+use super::super::ability_system::{get_ability_hooks, prevents_confusion, AbilityTrigger, AbilityEffect};
+use super::super::item_system::{item_display_name, ItemProcessor};
+use super::super::field_system::Screens;
+use super::super::protection_system::{absorb_substitute_damage, create_substitute, has_substitute};
+use super::super::super::events::{BattleEvent, DamageCause, HealCause};
+
+/// Duración mínima de la confusión si el movimiento no la indica
+const CONFUSION_MIN_TURNS: u8 = 2;
+/// Duración máxima de la confusión si el movimiento no la indica
+const CONFUSION_MAX_TURNS: u8 = 5;
 
 /// Contexto de batalla para procesar un ataque individual
 /// Implementa el patrón Pipeline para organizar la lógica de batalla
@@ -16,9 +25,13 @@ pub struct BattleContext<'a> {
     pub attacker_name: String,
     pub defender_name: String,
     pub rng: &'a mut StdRng,
-    pub logs: Vec<String>,
+    pub events: Vec<BattleEvent>,
     pub weather: Option<&'a WeatherState>,
     pub terrain: Option<&'a TerrainState>,
+    /// Pantallas activas en el lado del defensor
+    pub screens: Screens,
+    /// Si el ataque da en el sustituto del defensor en vez de en el Pokémon
+    pub behind_substitute: bool,
 }
 
 impl<'a> BattleContext<'a> {
@@ -40,32 +53,44 @@ impl<'a> BattleContext<'a> {
             attacker_name,
             defender_name,
             rng,
-            logs: Vec::new(),
+            events: Vec::new(),
             weather,
             terrain,
+            screens: Screens::default(),
+            behind_substitute: false,
         }
     }
 
+    /// Indica las pantallas activas en el lado del defensor
+    pub fn with_screens(mut self, screens: Screens) -> Self {
+        self.screens = screens;
+        self
+    }
+
+    /// Indica si el defensor está tras un sustituto que este ataque no atraviesa
+    pub fn with_substitute(mut self, behind_substitute: bool) -> Self {
+        self.behind_substitute = behind_substitute;
+        self
+    }
+
     /// Paso 1: Chequeo de Estado (Dormido, Congelado, Flinch, Paralysis, Recarga, Carga)
     /// Retorna true si el atacante puede ejecutar el movimiento
     pub fn can_execute_move(&mut self) -> bool {
         // Check de Flinch
         if let Some(ref mut volatile) = self.attacker.volatile_status {
             if volatile.flinched {
-                self.logs.push(format!(
-                    "¡{} retrocedió y no pudo atacar!",
-                    self.attacker_name
-                ));
+                self.events.push(BattleEvent::message("battle.flinched_cant_move", [
+                    ("pokemon", self.attacker_name.clone()),
+                ]));
                 volatile.flinched = false; // Resetear flinch
                 return false; // Terminar turno de este Pokémon
             }
             
             // Check de Recarga (Hyper Beam)
             if volatile.must_recharge {
-                self.logs.push(format!(
-                    "¡{} debe recargar energía!",
-                    self.attacker_name
-                ));
+                self.events.push(BattleEvent::message("battle.must_recharge", [
+                    ("pokemon", self.attacker_name.clone()),
+                ]));
                 volatile.must_recharge = false; // Resetear recarga
                 return false; // Terminar turno de este Pokémon
             }
@@ -75,28 +100,26 @@ impl<'a> BattleContext<'a> {
                 // Ejecutar el movimiento cargado inmediatamente
                 // El movimiento ya está en self.move_data (debe ser el mismo que charging_move_id)
                 if self.move_data.id == *charging_move_id {
-                    self.logs.push(format!(
-                        "¡{} lanzó {}!",
-                        self.attacker_name,
-                        self.move_data.name
-                    ));
+                    self.events.push(BattleEvent::message("battle.charged_move_released", [
+                        ("pokemon", self.attacker_name.clone()),
+                        ("move", self.move_data.name.clone()),
+                    ]));
                     // Resetear charging_move después de ejecutar
                     volatile.charging_move = None;
                     return true; // Continuar con el ataque
                 } else {
                     // Está cargando otro movimiento, no puede usar este
-                    self.logs.push(format!(
-                        "¡{} está cargando energía!",
-                        self.attacker_name
-                    ));
+                    self.events.push(BattleEvent::message("battle.charging", [
+                        ("pokemon", self.attacker_name.clone()),
+                    ]));
                     return false; // No puede ejecutar otro movimiento
                 }
             }
         }
 
         // Check de estados alterados (Sleep, Freeze, Paralysis)
-        let (can_move, status_logs) = can_pokemon_move(self.attacker, self.rng);
-        self.logs.extend(status_logs);
+        let (can_move, status_events) = can_pokemon_move(self.attacker, self.rng);
+        self.events.extend(status_events);
 
         if !can_move {
             return false;
@@ -106,17 +129,20 @@ impl<'a> BattleContext<'a> {
         if let Some(terrain_state) = self.terrain {
             if terrain_state.terrain_type == TerrainType::Psychic {
                 if self.move_data.priority > 0 && is_grounded(self.defender) {
-                    self.logs.push(format!(
-                        "¡El Campo Psíquico protege a {} de ataques rápidos!",
-                        self.defender_name
-                    ));
+                    self.events.push(BattleEvent::message("battle.terrain.psychic_blocks_priority", [
+                        ("pokemon", self.defender_name.clone()),
+                    ]));
                     return false;
                 }
             }
         }
 
         // Puede moverse: añadir log del movimiento
-        self.logs.push(format!("{} usó {}", self.attacker_name, self.move_data.name));
+        self.events.push(BattleEvent::MoveUsed {
+            user: self.attacker_name.clone(),
+            move_id: self.move_data.id.clone(),
+            move_name: self.move_data.name.clone(),
+        });
         true
     }
 
@@ -126,10 +152,9 @@ impl<'a> BattleContext<'a> {
         // Check de Protección (Protect/Detect)
         if let Some(ref volatile) = self.defender.volatile_status {
             if volatile.protected {
-                self.logs.push(format!(
-                    "¡{} se protegió!",
-                    self.defender_name
-                ));
+                self.events.push(BattleEvent::message("battle.protected", [
+                    ("pokemon", self.defender_name.clone()),
+                ]));
                 return 0; // No hace daño y evita efectos secundarios
             }
         }
@@ -137,9 +162,15 @@ impl<'a> BattleContext<'a> {
         // Check de Protecciones Avanzadas (Wide Guard, Quick Guard, Mat Block, Crafty Shield)
         use super::super::protection_system::check_advanced_protections;
         if let Some(protection_msg) = check_advanced_protections(self.defender, self.move_data) {
-            self.logs.push(protection_msg);
+            self.events.push(BattleEvent::Message(protection_msg));
             return 0; // No hace daño y evita efectos secundarios
         }
+
+        // Los movimientos de estado no afectan a un Pokémon tras su sustituto
+        if self.behind_substitute && self.move_data.power.is_none() {
+            self.events.push(BattleEvent::message("battle.move_failed", []));
+            return 0;
+        }
         
         // Cálculo de número de golpes (Multi-Hit)
         let hit_count = calculate_hit_count(
@@ -150,23 +181,25 @@ impl<'a> BattleContext<'a> {
 
         let mut total_damage = 0u16;
         let mut hit_successfully = false;
-        let mut first_effectiveness_msg = String::new();
+        let mut first_effectiveness = 1.0;
+        let mut hits_landed = 0u8;
+        let mut substitute_broken = false;
+        // Mensajes de objetos que se muestran tras el daño (Focus Sash)
+        let mut item_events = Vec::new();
+        let move_type = parse_type(&self.move_data.r#type);
 
         // Bucle de golpes
         for hit_num in 0..hit_count {
             // Check de precisión para cada golpe
-            let move_hits = if let Some(accuracy) = self.move_data.accuracy {
-                let roll = self.rng.gen_range(0..=100);
-                roll <= accuracy as u32
-            } else {
-                true
-            };
+            let move_hits = check_accuracy(self.attacker, self.defender, self.move_data, self.weather, self.rng);
 
             if !move_hits {
                 if hit_count == 1 {
-                    self.logs.push("¡Pero falló!".to_string());
+                    self.events.push(BattleEvent::message("battle.move_failed", []));
                 } else {
-                    self.logs.push(format!("¡Falló el golpe {}!", hit_num + 1));
+                    self.events.push(BattleEvent::message("battle.hit_missed", [
+                        ("hit", (hit_num + 1).to_string()),
+                    ]));
                 }
                 break; // Si falla, no continúa con más golpes
             }
@@ -175,28 +208,57 @@ impl<'a> BattleContext<'a> {
             let is_critical = check_critical_hit(self.move_data.meta.crit_rate, self.rng);
             if is_critical && hit_num == 0 {
                 // Solo mostrar mensaje de crítico en el primer golpe
-                self.logs.push("¡Golpe crítico!".to_string());
+                self.events.push(BattleEvent::CriticalHit {
+                    target: self.defender_name.clone(),
+                });
             }
 
-            // Calcular daño (pasar logs del contexto, weather y terrain)
-            let (damage, effectiveness_msg, _) = calculate_damage(
+            // Calcular daño (pasar eventos del contexto, weather y terrain)
+            let (damage, effectiveness, _) = calculate_damage(
                 self.attacker,
                 self.defender,
                 self.move_data,
                 is_critical,
                 self.rng,
-                Some(&mut self.logs),
+                Some(&mut self.events),
                 self.weather,
                 self.terrain,
+                self.screens,
             );
 
+            // Las bayas que reducen el daño (Occa Berry...) se consumen con el golpe que debilitan
+            if damage > 0 {
+                let item_result = ItemProcessor::process_before_damage_taken(self.defender, move_type, effectiveness);
+                self.events.extend(item_result.events);
+            }
+
+            // Focus Sash / Focus Band: aguanta con 1 HP un golpe que lo debilitaría
+            let mut damage = damage;
+            if !self.behind_substitute && damage > 0 && damage >= self.defender.current_hp {
+                let item_result = ItemProcessor::process_lethal_hit(self.defender, damage, self.rng);
+                if item_result.survived_lethal_hit {
+                    damage = self.defender.current_hp - 1;
+                }
+                item_events.extend(item_result.events);
+            }
+
             // Mostrar mensaje de efectividad solo en el primer golpe
-            if hit_num == 0 && !effectiveness_msg.is_empty() {
-                first_effectiveness_msg = effectiveness_msg;
+            if hit_num == 0 {
+                first_effectiveness = effectiveness;
             }
 
             total_damage += damage;
             hit_successfully = true;
+            hits_landed += 1;
+
+            // El sustituto recibe el golpe; al romperse, los golpes restantes se pierden
+            if self.behind_substitute {
+                if damage > 0 && absorb_substitute_damage(self.defender, damage) {
+                    substitute_broken = true;
+                    break;
+                }
+                continue;
+            }
 
             // Aplicar el daño al defensor
             self.defender.current_hp = self.defender.current_hp.saturating_sub(damage);
@@ -213,22 +275,33 @@ impl<'a> BattleContext<'a> {
         }
 
         // Mostrar mensaje de efectividad
-        if !first_effectiveness_msg.is_empty() {
-            self.logs.push(first_effectiveness_msg);
+        if first_effectiveness != 1.0 {
+            self.events.push(BattleEvent::Effectiveness {
+                target: self.defender_name.clone(),
+                multiplier: first_effectiveness,
+            });
+        }
+
+        // Daño absorbido por el sustituto
+        if self.behind_substitute {
+            if substitute_broken {
+                self.events.push(BattleEvent::SubstituteBroken { pokemon: self.defender_name.clone() });
+            } else if total_damage > 0 {
+                self.events.push(BattleEvent::SubstituteDamaged { pokemon: self.defender_name.clone() });
+            }
+            return total_damage;
         }
 
         // Mostrar mensaje de daño (acumulado para multi-hit)
-        if hit_count > 1 {
-            self.logs.push(format!(
-                "¡Golpeó {} veces! {} recibió {} de daño total",
-                hit_count, self.defender_name, total_damage
-            ));
-        } else {
-            self.logs.push(format!(
-                "{} recibió {} de daño",
-                self.defender_name, total_damage
-            ));
+        if first_effectiveness != 0.0 {
+            self.events.push(BattleEvent::Damage {
+                target: self.defender_name.clone(),
+                amount: total_damage,
+                remaining_hp: self.defender.current_hp,
+                cause: DamageCause::Move { hits: hits_landed },
+            });
         }
+        self.events.extend(item_events);
 
         total_damage
     }
@@ -263,40 +336,49 @@ impl<'a> BattleContext<'a> {
             || !self.move_data.stat_changes.is_empty();
         let sheer_force_active = has_sheer_force && has_secondary_effects;
 
-        // Procesar Weakness Policy (si recibió golpe super efectivo)
-        if damage_dealt > 0 && self.move_data.power.is_some() {
-            use super::super::damage_system::calculator::{parse_type, get_type_effectiveness};
+        // Tras un sustituto, el defensor no sufre efectos secundarios
+        let hits_substitute = self.behind_substitute;
+
+        // Covert Cloak: el defensor no sufre los efectos secundarios de los ataques
+        let covert_cloak_active = self.move_data.power.is_some() && ItemProcessor::blocks_secondary_effects(self.defender);
+
+        // Procesar items que reaccionan al golpe (Weakness Policy si fue super efectivo, Air Balloon)
+        if damage_dealt > 0 && self.move_data.power.is_some() && !hits_substitute {
+            use super::super::damage_system::calculator::get_type_effectiveness;
 
             let move_type = parse_type(&self.move_data.r#type);
-            let mut defender_types = vec![self.defender.randomized_profile.rolled_primary_type];
-            if let Some(secondary) = self.defender.randomized_profile.rolled_secondary_type {
-                defender_types.push(secondary);
-            }
+            let defender_types = self.defender.current_types();
 
             let type_effectiveness = get_type_effectiveness(&move_type, &defender_types);
             let is_super_effective = type_effectiveness >= 2.0;
 
-            if is_super_effective {
-                let item_result = ItemProcessor::process_on_damage_taken(
-                    &mut self.defender,
-                    damage_dealt,
-                    &self.attacker.id,
-                    true
-                );
-
-                // Aplicar stat boosts de Weakness Policy
-                if !item_result.stat_boosts.is_empty() {
-                    if self.defender.battle_stages.is_none() {
-                        self.defender.init_battle_stages();
-                    }
-                    if let Some(ref mut stages) = self.defender.battle_stages {
-                        for (stat, boost) in &item_result.stat_boosts {
-                            stages.apply_change(stat, *boost);
+            let item_result = ItemProcessor::process_on_damage_taken(
+                &mut self.defender,
+                damage_dealt,
+                &self.attacker.id,
+                is_super_effective
+            );
+
+            // Aplicar stat boosts de Weakness Policy
+            if !item_result.stat_boosts.is_empty() {
+                if self.defender.battle_stages.is_none() {
+                    self.defender.init_battle_stages();
+                }
+                self.events.extend(item_result.events);
+                if let Some(ref mut stages) = self.defender.battle_stages {
+                    for (stat, boost) in &item_result.stat_boosts {
+                        let applied = stages.apply_change(stat, *boost);
+                        if applied != 0 {
+                            self.events.push(BattleEvent::StatStageChanged {
+                                target: self.defender_name.clone(),
+                                stat: stat.clone(),
+                                stages: applied,
+                            });
                         }
                     }
                 }
-
-                self.logs.extend(item_result.logs);
+            } else {
+                self.events.extend(item_result.events);
             }
         }
 
@@ -313,93 +395,41 @@ impl<'a> BattleContext<'a> {
             if roll <= stat_chance as u32 {
                 let attacker_name = self.attacker.species.display_name.clone();
                 let defender_name = self.defender.species.display_name.clone();
-                
+                let mut stat_loss_prevented = false;
+
                 for stat_change in &self.move_data.stat_changes {
                     let apply_to_user = self.move_data.target == "user";
-                    
-                    if apply_to_user {
-                        if self.attacker.battle_stages.is_none() {
-                            self.attacker.init_battle_stages();
-                        }
-                        if let Some(ref mut stages) = self.attacker.battle_stages {
-                            let old_stage = match stat_change.stat.as_str() {
-                                "attack" => stages.attack,
-                                "defense" => stages.defense,
-                                "special_attack" => stages.special_attack,
-                                "special_defense" => stages.special_defense,
-                                "speed" => stages.speed,
-                                "accuracy" => stages.accuracy,
-                                "evasion" => stages.evasion,
-                                _ => continue,
-                            };
-                            stages.apply_change(&stat_change.stat, stat_change.change);
-                            let new_stage = match stat_change.stat.as_str() {
-                                "attack" => stages.attack,
-                                "defense" => stages.defense,
-                                "special_attack" => stages.special_attack,
-                                "special_defense" => stages.special_defense,
-                                "speed" => stages.speed,
-                                "accuracy" => stages.accuracy,
-                                "evasion" => stages.evasion,
-                                _ => continue,
-                            };
-                            let stat_name = match stat_change.stat.as_str() {
-                                "attack" => "ataque",
-                                "defense" => "defensa",
-                                "special_attack" => "ataque especial",
-                                "special_defense" => "defensa especial",
-                                "speed" => "velocidad",
-                                "accuracy" => "precisión",
-                                "evasion" => "evasión",
-                                _ => continue,
-                            };
-                            if new_stage > old_stage {
-                                self.logs.push(format!("¡El {} de {} subió!", stat_name, attacker_name));
-                            } else if new_stage < old_stage {
-                                self.logs.push(format!("¡El {} de {} bajó!", stat_name, attacker_name));
-                            }
+                    if !apply_to_user && (hits_substitute || covert_cloak_active) {
+                        continue;
+                    }
+                    // Clear Amulet: el defensor no sufre bajadas de stats de otros Pokémon
+                    if !apply_to_user && stat_change.change < 0 && ItemProcessor::prevents_stat_loss(self.defender) {
+                        if !stat_loss_prevented {
+                            stat_loss_prevented = true;
+                            self.events.push(BattleEvent::message("battle.item.stat_loss_prevented", [
+                                ("pokemon", defender_name.clone()),
+                                ("item", self.defender.held_item.as_deref().map(item_display_name).unwrap_or_default()),
+                            ]));
                         }
+                        continue;
+                    }
+                    let (target, target_name) = if apply_to_user {
+                        (&mut *self.attacker, &attacker_name)
                     } else {
-                        if self.defender.battle_stages.is_none() {
-                            self.defender.init_battle_stages();
-                        }
-                        if let Some(ref mut stages) = self.defender.battle_stages {
-                            let old_stage = match stat_change.stat.as_str() {
-                                "attack" => stages.attack,
-                                "defense" => stages.defense,
-                                "special_attack" => stages.special_attack,
-                                "special_defense" => stages.special_defense,
-                                "speed" => stages.speed,
-                                "accuracy" => stages.accuracy,
-                                "evasion" => stages.evasion,
-                                _ => continue,
-                            };
-                            stages.apply_change(&stat_change.stat, stat_change.change);
-                            let new_stage = match stat_change.stat.as_str() {
-                                "attack" => stages.attack,
-                                "defense" => stages.defense,
-                                "special_attack" => stages.special_attack,
-                                "special_defense" => stages.special_defense,
-                                "speed" => stages.speed,
-                                "accuracy" => stages.accuracy,
-                                "evasion" => stages.evasion,
-                                _ => continue,
-                            };
-                            let stat_name = match stat_change.stat.as_str() {
-                                "attack" => "ataque",
-                                "defense" => "defensa",
-                                "special_attack" => "ataque especial",
-                                "special_defense" => "defensa especial",
-                                "speed" => "velocidad",
-                                "accuracy" => "precisión",
-                                "evasion" => "evasión",
-                                _ => continue,
-                            };
-                            if new_stage > old_stage {
-                                self.logs.push(format!("¡El {} de {} subió!", stat_name, defender_name));
-                            } else if new_stage < old_stage {
-                                self.logs.push(format!("¡El {} de {} bajó!", stat_name, defender_name));
-                            }
+                        (&mut *self.defender, &defender_name)
+                    };
+
+                    if target.battle_stages.is_none() {
+                        target.init_battle_stages();
+                    }
+                    if let Some(ref mut stages) = target.battle_stages {
+                        let applied = stages.apply_change(&stat_change.stat, stat_change.change);
+                        if applied != 0 {
+                            self.events.push(BattleEvent::StatStageChanged {
+                                target: target_name.clone(),
+                                stat: stat_change.stat.clone(),
+                                stages: applied,
+                            });
                         }
                     }
                 }
@@ -408,16 +438,14 @@ impl<'a> BattleContext<'a> {
 
         // Aplicar estados alterados (ailments)
         // Sheer Force elimina ailments
-        if self.move_data.meta.ailment != "none" && !sheer_force_active {
+        if self.move_data.meta.ailment != "none" && !sheer_force_active && !hits_substitute && !covert_cloak_active {
             // Verificar efectos de terreno antes de aplicar estados
             let terrain_blocks_ailment = if let Some(terrain_state) = self.terrain {
                 match terrain_state.terrain_type {
                     TerrainType::Electric => {
                         // Electric Terrain: Bloquea Sleep si el defensor está grounded
                         if is_grounded(self.defender) && self.move_data.meta.ailment.as_str() == "sleep" {
-                            self.logs.push(format!(
-                                "¡El Campo Eléctrico evita el sueño!",
-                            ));
+                            self.events.push(BattleEvent::message("battle.terrain.electric_prevents_sleep", []));
                             true
                         } else {
                             false
@@ -426,10 +454,9 @@ impl<'a> BattleContext<'a> {
                     TerrainType::Misty => {
                         // Misty Terrain: Bloquea CUALQUIER estado si el defensor está grounded
                         if is_grounded(self.defender) {
-                            self.logs.push(format!(
-                                "¡El Campo de Niebla protege a {}!",
-                                self.defender_name
-                            ));
+                            self.events.push(BattleEvent::message("battle.terrain.misty_protects", [
+                                ("pokemon", self.defender_name.clone()),
+                            ]));
                             true
                         } else {
                             false
@@ -453,7 +480,16 @@ impl<'a> BattleContext<'a> {
                 self.rng,
             );
 
-            if ailment_success && self.defender.status_condition.is_none() {
+            if self.move_data.meta.ailment == "confusion" {
+                // La confusión es un estado volátil: se acumula con los estados de salud
+                if ailment_success && self.defender.current_hp > 0 {
+                    self.apply_confusion();
+                }
+            } else if self.move_data.meta.ailment == "infatuation" {
+                if ailment_success && self.defender.current_hp > 0 {
+                    self.apply_infatuation();
+                }
+            } else if ailment_success && self.defender.status_condition.is_none() {
                 // Verificar inmunidades por tipo
                 let is_immune = match self.move_data.meta.ailment.as_str() {
                     "burn" => {
@@ -486,19 +522,10 @@ impl<'a> BattleContext<'a> {
 
                     if let Some(status_condition) = status {
                         self.defender.status_condition = Some(status_condition);
-                        let status_msg = match status_condition {
-                            StatusCondition::Burn => "quemó",
-                            StatusCondition::Paralysis => "paralizó",
-                            StatusCondition::Poison => "envenenó",
-                            StatusCondition::BadPoison => "envenenó gravemente",
-                            StatusCondition::Sleep => "durmió",
-                            StatusCondition::Freeze => "congeló",
-                        };
-                        self.logs.push(format!(
-                            "¡{} se ha {}!",
-                            self.defender.species.display_name,
-                            status_msg
-                        ));
+                        self.events.push(BattleEvent::StatusApplied {
+                            target: self.defender.species.display_name.clone(),
+                            status: status_condition,
+                        });
 
                         // Procesar Lum Berry (cura inmediatamente el status aplicado)
                         let status_name = match status_condition {
@@ -513,7 +540,7 @@ impl<'a> BattleContext<'a> {
                             &mut self.defender,
                             status_name
                         );
-                        self.logs.extend(item_result.logs);
+                        self.events.extend(item_result.events);
                     }
                 }
             }
@@ -521,7 +548,7 @@ impl<'a> BattleContext<'a> {
 
         // Aplicar Flinch
         // Sheer Force elimina flinch
-        if self.move_data.meta.flinch_chance > 0 && !sheer_force_active {
+        if self.move_data.meta.flinch_chance > 0 && !sheer_force_active && !hits_substitute && !covert_cloak_active {
             let roll = self.rng.gen_range(0..=100);
             if roll <= self.move_data.meta.flinch_chance as u32 {
                 if self.defender.volatile_status.is_none() {
@@ -529,26 +556,24 @@ impl<'a> BattleContext<'a> {
                 }
                 if let Some(ref mut volatile) = self.defender.volatile_status {
                     volatile.flinched = true;
-                    self.logs.push(format!(
-                        "¡{} retrocedió!",
-                        self.defender.species.display_name
-                    ));
+                    self.events.push(BattleEvent::message("battle.flinched", [
+                        ("pokemon", self.defender.species.display_name.clone()),
+                    ]));
                 }
             }
         }
 
         // Aplicar Switch Forzado (Dragon Tail, Roar, etc.)
         // Solo se aplica si el movimiento golpeó y causó daño
-        if self.move_data.meta.forces_switch && damage_dealt > 0 && self.defender.current_hp > 0 {
+        if self.move_data.meta.forces_switch && damage_dealt > 0 && self.defender.current_hp > 0 && !hits_substitute {
             if self.defender.volatile_status.is_none() {
                 self.defender.init_battle_stages();
             }
             if let Some(ref mut volatile) = self.defender.volatile_status {
                 volatile.forced_switch = true;
-                self.logs.push(format!(
-                    "¡{} será forzado a cambiar!",
-                    self.defender.species.display_name
-                ));
+                self.events.push(BattleEvent::message("battle.forced_switch", [
+                    ("pokemon", self.defender.species.display_name.clone()),
+                ]));
             }
         }
 
@@ -558,21 +583,7 @@ impl<'a> BattleContext<'a> {
             // Struggle siempre causa daño de retroceso de 1/4 del HP máximo
             let max_hp = self.attacker.base_computed_stats.hp;
             let recoil_damage = max_hp / 4;
-            
-            if recoil_damage >= self.attacker.current_hp {
-                self.attacker.current_hp = 0;
-                self.logs.push(format!(
-                    "¡{} se debilitó por el daño de retroceso!",
-                    self.attacker_name
-                ));
-            } else {
-                self.attacker.current_hp -= recoil_damage;
-                self.logs.push(format!(
-                    "¡{} recibió {} de daño de retroceso!",
-                    self.attacker_name,
-                    recoil_damage
-                ));
-            }
+            self.apply_recoil(recoil_damage);
         } else if self.move_data.meta.drain != 0 && damage_dealt > 0 {
             let drain_percent = self.move_data.meta.drain as f32 / 100.0;
             let drain_amount = (damage_dealt as f32 * drain_percent.abs()) as u16;
@@ -584,28 +595,16 @@ impl<'a> BattleContext<'a> {
                 let healed = new_hp - self.attacker.current_hp;
                 self.attacker.current_hp = new_hp;
                 if healed > 0 {
-                    self.logs.push(format!(
-                        "¡{} recuperó {} PS!",
-                        self.attacker_name,
-                        healed
-                    ));
+                    self.events.push(BattleEvent::Heal {
+                        target: self.attacker_name.clone(),
+                        amount: healed,
+                        remaining_hp: self.attacker.current_hp,
+                        cause: HealCause::Move,
+                    });
                 }
             } else {
                 // Recoil: daña al atacante
-                if drain_amount >= self.attacker.current_hp {
-                    self.attacker.current_hp = 0;
-                    self.logs.push(format!(
-                        "¡{} se debilitó por el daño de retroceso!",
-                        self.attacker_name
-                    ));
-                } else {
-                    self.attacker.current_hp -= drain_amount;
-                    self.logs.push(format!(
-                        "¡{} recibió {} de daño de retroceso!",
-                        self.attacker_name,
-                        drain_amount
-                    ));
-                }
+                self.apply_recoil(drain_amount);
             }
         }
 
@@ -618,26 +617,23 @@ impl<'a> BattleContext<'a> {
             let healed = new_hp - self.attacker.current_hp;
             self.attacker.current_hp = new_hp;
             if healed > 0 {
-                self.logs.push(format!(
-                    "¡{} recuperó {} PS!",
-                    self.attacker.species.display_name,
-                    healed
-                ));
+                self.events.push(BattleEvent::Heal {
+                    target: self.attacker.species.display_name.clone(),
+                    amount: healed,
+                    remaining_hp: self.attacker.current_hp,
+                    cause: HealCause::Move,
+                });
             }
         }
         
-        // Aplicar efectos especiales de movimientos (Protección, Recarga, Carga, Clima)
-        // Clima: Detectar movimientos que cambian el clima (se establecerá en execute_turn)
-        if self.move_data.id == "sunny-day" {
-            self.logs.push("¡El sol se intensificó!".to_string());
-        } else if self.move_data.id == "rain-dance" {
-            self.logs.push("¡Comenzó a llover!".to_string());
-        } else if self.move_data.id == "sandstorm" {
-            self.logs.push("¡Se desató una tormenta de arena!".to_string());
-        } else if self.move_data.id == "hail" {
-            self.logs.push("¡Comenzó a granizar!".to_string());
+        // Substitute
+        if self.move_data.id == "substitute" {
+            self.apply_substitute();
         }
-        
+
+        // Aplicar efectos especiales de movimientos (Protección, Recarga, Carga)
+        // Los movimientos de clima y terreno se resuelven en el pipeline, que es
+        // quien modifica el estado del campo
         if let Some(ref mut volatile) = self.attacker.volatile_status {
             // Protección (Protect/Detect)
             if self.move_data.id == "protect" || self.move_data.id == "detect" {
@@ -649,17 +645,14 @@ impl<'a> BattleContext<'a> {
                     // Protección exitosa
                     volatile.protected = true;
                     volatile.protect_counter += 1;
-                    self.logs.push(format!(
-                        "¡{} se protegió!",
-                        self.attacker_name
-                    ));
+                    self.events.push(BattleEvent::message("battle.protected", [
+                        ("pokemon", self.attacker_name.clone()),
+                    ]));
                 } else {
                     // Protección falló
                     volatile.protected = false;
                     volatile.protect_counter = 0;
-                    self.logs.push(format!(
-                        "¡Pero falló!",
-                    ));
+                    self.events.push(BattleEvent::message("battle.move_failed", []));
                 }
             }
             
@@ -674,16 +667,17 @@ impl<'a> BattleContext<'a> {
             
             // Carga (Solar Beam y similares)
             // Si el movimiento tiene min_turns y max_turns, requiere carga
+            // (en los movimientos con ailment esos turnos son la duración del estado)
+            let is_charge_move = self.move_data.meta.ailment == "none";
             if let (Some(min_turns), Some(max_turns)) = (self.move_data.meta.min_turns, self.move_data.meta.max_turns) {
-                if min_turns > 1 || max_turns > 1 {
+                if is_charge_move && (min_turns > 1 || max_turns > 1) {
                     // Movimiento de carga
                     if volatile.charging_move.is_none() {
                         // Primera fase: cargar
                         volatile.charging_move = Some(self.move_data.id.clone());
-                        self.logs.push(format!(
-                            "¡{} está cargando energía!",
-                            self.attacker_name
-                        ));
+                        self.events.push(BattleEvent::message("battle.charging", [
+                            ("pokemon", self.attacker_name.clone()),
+                        ]));
                     } else {
                         // Segunda fase: ya estaba cargando, ahora se ejecuta
                         volatile.charging_move = None;
@@ -702,20 +696,152 @@ impl<'a> BattleContext<'a> {
         // Hook: Aplicar habilidades OnContact del defensor (Rough Skin, Static, Iron Barbs, etc.)
         // Y items de contacto (Rocky Helmet)
         // Solo se activan si el movimiento hizo contacto y causó daño
-        if self.move_data.meta.makes_contact && damage_dealt > 0 && self.attacker.current_hp > 0 {
+        if self.move_data.meta.makes_contact && damage_dealt > 0 && self.attacker.current_hp > 0 && !hits_substitute {
             self.apply_on_contact_abilities();
             self.apply_on_contact_items();
         }
     }
     
+    /// Confunde al defensor durante 2-5 turnos (Confuse Ray, Swagger, Hurricane...)
+    ///
+    /// Own Tempo lo impide y Persim/Lum Berry lo curan en cuanto se aplica.
+    /// Los fallos solo se anuncian en movimientos de estado; como efecto
+    /// secundario de un ataque simplemente no ocurre.
+    fn apply_confusion(&mut self) {
+        let is_status_move = self.move_data.power.is_none();
+        let name = self.defender_name.clone();
+
+        if self.defender.volatile_status.as_ref().is_some_and(|v| v.confused) {
+            if is_status_move {
+                self.events.push(BattleEvent::message("battle.status.already_confused", [
+                    ("pokemon", name),
+                ]));
+            }
+            return;
+        }
+
+        if prevents_confusion(self.defender) {
+            if is_status_move {
+                self.events.push(BattleEvent::AbilityActivated {
+                    pokemon: name.clone(),
+                    ability: self.defender.ability.clone(),
+                });
+                self.events.push(BattleEvent::message("battle.status.confusion_prevented", [
+                    ("pokemon", name),
+                ]));
+            }
+            return;
+        }
+
+        let min_turns = self.move_data.meta.min_turns.unwrap_or(CONFUSION_MIN_TURNS);
+        let max_turns = self.move_data.meta.max_turns.unwrap_or(CONFUSION_MAX_TURNS).max(min_turns);
+        let turns = self.rng.gen_range(min_turns..=max_turns);
+
+        let volatile = self.defender.volatile_status.get_or_insert_with(VolatileStatus::new);
+        volatile.confused = true;
+        volatile.confusion_turns = turns;
+        self.events.push(BattleEvent::message("battle.status.confused", [
+            ("pokemon", name),
+        ]));
+
+        // Persim Berry / Lum Berry curan la confusión en el acto
+        let item_result = ItemProcessor::process_on_status_applied(self.defender, "confusion");
+        self.events.extend(item_result.events);
+    }
+
+    /// Enamora al defensor del atacante (Attract)
+    ///
+    /// Solo funciona entre géneros opuestos y Oblivious lo impide.
+    fn apply_infatuation(&mut self) {
+        let name = self.defender_name.clone();
+        match infatuate(self.defender, self.attacker) {
+            Ok(()) => {
+                self.events.push(BattleEvent::message("battle.status.infatuated", [
+                    ("pokemon", name),
+                ]));
+                self.reflect_infatuation(true);
+                self.cure_infatuation_with_item(true);
+            }
+            Err(InfatuationFailure::Ability) => {
+                self.events.push(BattleEvent::AbilityActivated {
+                    pokemon: name.clone(),
+                    ability: self.defender.ability.clone(),
+                });
+                self.events.push(BattleEvent::message("battle.status.infatuation_prevented", [
+                    ("pokemon", name),
+                ]));
+            }
+            Err(_) => self.events.push(BattleEvent::message("battle.move_failed", [])),
+        }
+    }
+
+    /// Destiny Knot: si el recién enamorado lo lleva, quien lo enamoró también se enamora
+    fn reflect_infatuation(&mut self, holder_is_defender: bool) {
+        let (holder, other, holder_name, other_name) = if holder_is_defender {
+            (&*self.defender, &mut *self.attacker, &self.defender_name, &self.attacker_name)
+        } else {
+            (&*self.attacker, &mut *self.defender, &self.attacker_name, &self.defender_name)
+        };
+        if !reflects_infatuation(holder) || infatuate(other, holder).is_err() {
+            return;
+        }
+
+        self.events.push(BattleEvent::ItemActivated {
+            pokemon: holder_name.clone(),
+            item: "destiny-knot".to_string(),
+        });
+        self.events.push(BattleEvent::message("battle.status.infatuated", [
+            ("pokemon", other_name.clone()),
+        ]));
+        self.cure_infatuation_with_item(!holder_is_defender);
+    }
+
+    /// Mental Herb: el recién enamorado se cura en el acto
+    fn cure_infatuation_with_item(&mut self, on_defender: bool) {
+        let pokemon = if on_defender { &mut *self.defender } else { &mut *self.attacker };
+        let item_result = ItemProcessor::process_on_status_applied(pokemon, "infatuation");
+        self.events.extend(item_result.events);
+    }
+
+    /// Crea un sustituto con el 25% del HP máximo del usuario
+    ///
+    /// Falla si ya tiene uno o si no le queda HP suficiente para pagarlo.
+    fn apply_substitute(&mut self) {
+        let name = self.attacker_name.clone();
+        if has_substitute(self.attacker) {
+            self.events.push(BattleEvent::message("battle.substitute.already", [("pokemon", name)]));
+        } else if create_substitute(self.attacker) {
+            self.events.push(BattleEvent::SubstituteCreated { pokemon: name });
+        } else {
+            self.events.push(BattleEvent::message("battle.substitute.too_weak", [("pokemon", name)]));
+        }
+    }
+
+    /// Aplica daño de retroceso al atacante (Forcejeo, Double-Edge, etc.)
+    fn apply_recoil(&mut self, recoil_damage: u16) {
+        let actual_damage = recoil_damage.min(self.attacker.current_hp);
+        self.attacker.current_hp -= actual_damage;
+        self.events.push(BattleEvent::Damage {
+            target: self.attacker_name.clone(),
+            amount: actual_damage,
+            remaining_hp: self.attacker.current_hp,
+            cause: DamageCause::Recoil,
+        });
+        if self.attacker.current_hp == 0 {
+            self.events.push(BattleEvent::Fainted {
+                target: self.attacker_name.clone(),
+            });
+        }
+    }
+
     /// Aplica efectos de clima al final del turno
-    /// Retorna (daño total, logs)
-    pub fn apply_weather_effects(&mut self, weather: &mut Option<WeatherState>) -> (u16, Vec<String>) {
-        let mut logs = Vec::new();
+    /// Retorna (daño total, eventos)
+    pub fn apply_weather_effects(&mut self, weather: &mut Option<WeatherState>) -> (u16, Vec<BattleEvent>) {
+        let mut events = Vec::new();
         let mut total_damage = 0u16;
         
         let Some(ref mut weather_state) = weather else {
-            return (0, logs);
+            return (0, events);
         };
         
         // Decrementar turnos restantes
@@ -725,13 +851,13 @@ impl<'a> BattleContext<'a> {
         
         // Si el clima expiró, resetearlo
         if weather_state.turns_remaining == 0 {
-            logs.push("¡El clima volvió a la normalidad!".to_string());
+            events.push(BattleEvent::WeatherSet { weather: WeatherType::None });
             *weather = None;
-            return (0, logs);
+            return (0, events);
         }
         
         // Aplicar daño residual según el tipo de clima
-        use crate::models::WeatherType;
+        let weather_type = weather_state.weather_type;
         match weather_state.weather_type {
             WeatherType::Sandstorm => {
                 // Daña 1/16 HP a todos EXCEPTO tipos Rock, Ground, Steel
@@ -748,10 +874,12 @@ impl<'a> BattleContext<'a> {
                     let actual_damage = damage.min(self.defender.current_hp);
                     self.defender.current_hp = self.defender.current_hp.saturating_sub(damage);
                     total_damage = actual_damage;
-                    logs.push(format!(
-                        "¡La tormenta de arena golpea a {}!",
-                        self.defender_name
-                    ));
+                    events.push(BattleEvent::Damage {
+                        target: self.defender_name.clone(),
+                        amount: actual_damage,
+                        remaining_hp: self.defender.current_hp,
+                        cause: DamageCause::Weather { weather: weather_type },
+                    });
                 }
             }
             WeatherType::Hail => {
@@ -765,10 +893,12 @@ impl<'a> BattleContext<'a> {
                     let actual_damage = damage.min(self.defender.current_hp);
                     self.defender.current_hp = self.defender.current_hp.saturating_sub(damage);
                     total_damage = actual_damage;
-                    logs.push(format!(
-                        "¡El granizo golpea a {}!",
-                        self.defender_name
-                    ));
+                    events.push(BattleEvent::Damage {
+                        target: self.defender_name.clone(),
+                        amount: actual_damage,
+                        remaining_hp: self.defender.current_hp,
+                        cause: DamageCause::Weather { weather: weather_type },
+                    });
                 }
             }
             _ => {
@@ -776,7 +906,7 @@ impl<'a> BattleContext<'a> {
             }
         }
         
-        (total_damage, logs)
+        (total_damage, events)
     }
 
     /// Aplica habilidades del defensor que se activan al recibir contacto
@@ -794,12 +924,15 @@ impl<'a> BattleContext<'a> {
 
                     if actual_damage > 0 {
                         self.attacker.current_hp = self.attacker.current_hp.saturating_sub(actual_damage);
-                        self.logs.push(format!(
-                            "¡{} fue herido por {} de {}!",
-                            self.attacker_name,
-                            ability_id,
-                            self.defender_name
-                        ));
+                        self.events.push(BattleEvent::Damage {
+                            target: self.attacker_name.clone(),
+                            amount: actual_damage,
+                            remaining_hp: self.attacker.current_hp,
+                            cause: DamageCause::Ability {
+                                ability: ability_id.clone(),
+                                holder: self.defender_name.clone(),
+                            },
+                        });
                     }
                 },
 
@@ -820,22 +953,37 @@ impl<'a> BattleContext<'a> {
                     let status_condition = *status;
                     self.attacker.status_condition = Some(status_condition);
 
-                    let status_name = match status_condition {
-                        StatusCondition::Paralysis => "paralizado",
-                        StatusCondition::Burn => "quemado",
-                        StatusCondition::Poison => "envenenado",
-                        StatusCondition::BadPoison => "gravemente envenenado",
-                        StatusCondition::Sleep => "dormido",
-                        StatusCondition::Freeze => "congelado",
-                    };
+                    self.events.push(BattleEvent::AbilityActivated {
+                        pokemon: self.defender_name.clone(),
+                        ability: ability_id.clone(),
+                    });
+                    self.events.push(BattleEvent::StatusApplied {
+                        target: self.attacker_name.clone(),
+                        status: status_condition,
+                    });
+                },
+
+                // Habilidades que enamoran al atacante (Cute Charm)
+                AbilityEffect::InfatuateOnContact { chance } => {
+                    if can_infatuate(self.attacker, self.defender).is_err() {
+                        continue;
+                    }
+
+                    let roll: f32 = self.rng.gen();
+                    if roll > *chance {
+                        continue;
+                    }
 
-                    self.logs.push(format!(
-                        "¡{} fue {} por {} de {}!",
-                        self.attacker_name,
-                        status_name,
-                        ability_id,
-                        self.defender_name
-                    ));
+                    let _ = infatuate(self.attacker, self.defender);
+                    self.events.push(BattleEvent::AbilityActivated {
+                        pokemon: self.defender_name.clone(),
+                        ability: ability_id.clone(),
+                    });
+                    self.events.push(BattleEvent::message("battle.status.infatuated", [
+                        ("pokemon", self.attacker_name.clone()),
+                    ]));
+                    self.reflect_infatuation(false);
+                    self.cure_infatuation_with_item(false);
                 },
 
                 _ => {},
@@ -847,18 +995,22 @@ impl<'a> BattleContext<'a> {
     /// (Rocky Helmet)
     fn apply_on_contact_items(&mut self) {
         // Rocky Helmet: 1/6 del HP máximo del atacante como daño
-        if let Some(ref item_id) = self.defender.held_item {
-            if item_id == "rocky-helmet" {
-                let damage = (self.attacker.base_computed_stats.hp as f32 / 6.0) as u16;
+        if let Some(item_id) = self.defender.held_item.clone() {
+            if let Some(percent) = ItemProcessor::contact_damage_percent(self.defender) {
+                let damage = (self.attacker.base_computed_stats.hp as f32 * percent) as u16;
                 let actual_damage = damage.min(self.attacker.current_hp);
 
                 if actual_damage > 0 {
                     self.attacker.current_hp = self.attacker.current_hp.saturating_sub(actual_damage);
-                    self.logs.push(format!(
-                        "¡{} fue herido por el Rocky Helmet de {}!",
-                        self.attacker_name,
-                        self.defender_name
-                    ));
+                    self.events.push(BattleEvent::Damage {
+                        target: self.attacker_name.clone(),
+                        amount: actual_damage,
+                        remaining_hp: self.attacker.current_hp,
+                        cause: DamageCause::Item {
+                            item: item_id,
+                            holder: self.defender_name.clone(),
+                        },
+                    });
                 }
             }
         }
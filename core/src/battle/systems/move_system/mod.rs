@@ -9,6 +9,9 @@
 pub mod executor;
 pub mod targeting;
 
+#[cfg(test)]
+mod tests;
+
 // Re-exportar tipos y funciones principales
 pub use executor::BattleContext;
 pub use targeting::resolve_targets;
@@ -46,13 +46,12 @@ pub fn resolve_targets(
         // PRIORIDAD ABSOLUTA: Un oponente aleatorio (ej: Outrage, Thrash, Petal Dance)
         // IGNORA selected_target, selecciona aleatoriamente
         "random-opponent" => {
-            let mut available_opponents = Vec::new();
-            if is_position_alive(FieldPosition::OpponentLeft, battle_state, player_team, opponent_team) {
-                available_opponents.push(FieldPosition::OpponentLeft);
-            }
-            if is_position_alive(FieldPosition::OpponentRight, battle_state, player_team, opponent_team) {
-                available_opponents.push(FieldPosition::OpponentRight);
-            }
+            let available_opponents = alive_positions(
+                get_opposing_positions(user_pos),
+                battle_state,
+                player_team,
+                opponent_team,
+            );
             
             if available_opponents.is_empty() {
                 vec![]
@@ -67,28 +66,15 @@ pub fn resolve_targets(
         // Todos los oponentes (ej: Rock Slide, Surf)
         // IGNORA selected_target
         "all-opponents" => {
-            let mut targets = Vec::new();
-            // Agregar posiciones de oponentes que estén vivas
-            if is_position_alive(FieldPosition::OpponentLeft, battle_state, player_team, opponent_team) {
-                targets.push(FieldPosition::OpponentLeft);
-            }
-            if is_position_alive(FieldPosition::OpponentRight, battle_state, player_team, opponent_team) {
-                targets.push(FieldPosition::OpponentRight);
-            }
-            targets
+            // Posiciones vivas del lado contrario al usuario
+            alive_positions(get_opposing_positions(user_pos), battle_state, player_team, opponent_team)
         }
         
         // Todos los otros Pokémon (ej: Earthquake, Explosion)
         // IGNORA selected_target
         "all-other-pokemon" => {
-            let mut targets = Vec::new();
-            // Agregar todos los oponentes
-            if is_position_alive(FieldPosition::OpponentLeft, battle_state, player_team, opponent_team) {
-                targets.push(FieldPosition::OpponentLeft);
-            }
-            if is_position_alive(FieldPosition::OpponentRight, battle_state, player_team, opponent_team) {
-                targets.push(FieldPosition::OpponentRight);
-            }
+            // Agregar todos los rivales del usuario
+            let mut targets = alive_positions(get_opposing_positions(user_pos), battle_state, player_team, opponent_team);
             // Agregar el aliado del usuario (si existe y está vivo)
             if let Some(ally_pos) = get_ally_position(user_pos) {
                 if is_position_alive(ally_pos, battle_state, player_team, opponent_team) {
@@ -101,27 +87,13 @@ pub fn resolve_targets(
         // Campo del usuario (ej: Light Screen, Reflect)
         // IGNORA selected_target
         "users-field" => {
-            let mut targets = Vec::new();
-            if is_position_alive(FieldPosition::PlayerLeft, battle_state, player_team, opponent_team) {
-                targets.push(FieldPosition::PlayerLeft);
-            }
-            if is_position_alive(FieldPosition::PlayerRight, battle_state, player_team, opponent_team) {
-                targets.push(FieldPosition::PlayerRight);
-            }
-            targets
+            alive_positions(get_side_positions(user_pos), battle_state, player_team, opponent_team)
         }
         
         // Campo del oponente (ej: Stealth Rock, Spikes)
         // IGNORA selected_target
         "opponents-field" => {
-            let mut targets = Vec::new();
-            if is_position_alive(FieldPosition::OpponentLeft, battle_state, player_team, opponent_team) {
-                targets.push(FieldPosition::OpponentLeft);
-            }
-            if is_position_alive(FieldPosition::OpponentRight, battle_state, player_team, opponent_team) {
-                targets.push(FieldPosition::OpponentRight);
-            }
-            targets
+            alive_positions(get_opposing_positions(user_pos), battle_state, player_team, opponent_team)
         }
         
         // El aliado del usuario (ej: Helping Hand, Follow Me)
@@ -221,6 +193,43 @@ fn get_ally_position(user_pos: FieldPosition) -> Option<FieldPosition> {
     }
 }
 
+/// Posiciones (izquierda, derecha) del lado del campo del usuario
+fn get_side_positions(user_pos: FieldPosition) -> [FieldPosition; 2] {
+    match user_pos {
+        FieldPosition::PlayerLeft | FieldPosition::PlayerRight => {
+            [FieldPosition::PlayerLeft, FieldPosition::PlayerRight]
+        }
+        FieldPosition::OpponentLeft | FieldPosition::OpponentRight => {
+            [FieldPosition::OpponentLeft, FieldPosition::OpponentRight]
+        }
+    }
+}
+
+/// Posiciones (izquierda, derecha) del lado rival al usuario
+fn get_opposing_positions(user_pos: FieldPosition) -> [FieldPosition; 2] {
+    match user_pos {
+        FieldPosition::PlayerLeft | FieldPosition::PlayerRight => {
+            [FieldPosition::OpponentLeft, FieldPosition::OpponentRight]
+        }
+        FieldPosition::OpponentLeft | FieldPosition::OpponentRight => {
+            [FieldPosition::PlayerLeft, FieldPosition::PlayerRight]
+        }
+    }
+}
+
+/// Filtra las posiciones ocupadas por un Pokémon vivo
+fn alive_positions(
+    positions: [FieldPosition; 2],
+    battle_state: &BattleState,
+    player_team: &PlayerTeam,
+    opponent_team: &Vec<PokemonInstance>,
+) -> Vec<FieldPosition> {
+    positions
+        .into_iter()
+        .filter(|&pos| is_position_alive(pos, battle_state, player_team, opponent_team))
+        .collect()
+}
+
 /// Verifica si un Pokémon en una posición específica está vivo
 /// Retorna true si la posición está ocupada por un Pokémon con HP > 0
 fn is_position_alive(
@@ -323,3 +332,102 @@ fn is_position_alive(
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use crate::factory::create_pokemon_instance;
+    use crate::models::{BattleFormat, MoveMeta, PokemonSpecies, PokemonType, Stats};
+
+    fn pokemon(id: &str) -> PokemonInstance {
+        let species = PokemonSpecies {
+            species_id: id.to_string(),
+            display_name: id.to_string(),
+            generation: 1,
+            primary_type: PokemonType::Normal,
+            secondary_type: None,
+            base_stats: Stats {
+                hp: 80,
+                attack: 80,
+                defense: 80,
+                special_attack: 80,
+                special_defense: 80,
+                speed: 80,
+            },
+            move_pool: vec!["tackle".to_string()],
+            possible_abilities: vec!["run-away".to_string()],
+            is_starter_candidate: false,
+            evolutions: Vec::new(),
+            ev_yield: Stats::zero(),
+            gender_rate: 4,
+            mega_forms: Vec::new(),
+        };
+        create_pokemon_instance(&species, 50, 1, false, &[], None)
+    }
+
+    fn spread_move(target: &str) -> MoveData {
+        MoveData {
+            id: "earthquake".to_string(),
+            name: "Earthquake".to_string(),
+            r#type: "Ground".to_string(),
+            power: Some(100),
+            accuracy: Some(100),
+            priority: 0,
+            pp: 10,
+            damage_class: "physical".to_string(),
+            meta: MoveMeta::default(),
+            stat_changes: vec![],
+            target: target.to_string(),
+        }
+    }
+
+    /// Resuelve los objetivos de `target` usado desde `user_pos` en un combate doble completo
+    fn targets_in_doubles(user_pos: FieldPosition, target: &str) -> Vec<FieldPosition> {
+        let mut player_team = PlayerTeam::new();
+        player_team.add_member(pokemon("p1"));
+        player_team.add_member(pokemon("p2"));
+        let opponent_team = vec![pokemon("o1"), pokemon("o2")];
+        let battle_state = BattleState::new(0, opponent_team.clone(), "Rival".to_string(), BattleFormat::Double, true);
+        let move_data = spread_move(target);
+
+        resolve_targets(
+            user_pos,
+            target,
+            None,
+            &battle_state,
+            &player_team,
+            &opponent_team,
+            &opponent_team[0],
+            &move_data,
+            &mut StdRng::seed_from_u64(1),
+        )
+    }
+
+    #[test]
+    fn test_all_opponents_from_opponent_side_hits_player_side() {
+        assert_eq!(
+            targets_in_doubles(FieldPosition::OpponentLeft, "all-opponents"),
+            vec![FieldPosition::PlayerLeft, FieldPosition::PlayerRight]
+        );
+    }
+
+    #[test]
+    fn test_all_other_pokemon_includes_ally_but_not_user() {
+        assert_eq!(
+            targets_in_doubles(FieldPosition::OpponentRight, "all-other-pokemon"),
+            vec![FieldPosition::PlayerLeft, FieldPosition::PlayerRight, FieldPosition::OpponentLeft]
+        );
+        assert_eq!(
+            targets_in_doubles(FieldPosition::PlayerLeft, "all-other-pokemon"),
+            vec![FieldPosition::OpponentLeft, FieldPosition::OpponentRight, FieldPosition::PlayerRight]
+        );
+    }
+
+    #[test]
+    fn test_random_opponent_stays_on_opposing_side() {
+        let targets = targets_in_doubles(FieldPosition::OpponentLeft, "random-opponent");
+        assert_eq!(targets.len(), 1);
+        assert!(matches!(targets[0], FieldPosition::PlayerLeft | FieldPosition::PlayerRight));
+    }
+}
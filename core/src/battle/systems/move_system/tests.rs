@@ -0,0 +1,155 @@
+//! Tests del Move System: efectos de los movimientos en un turno completo
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::battle::events::BattleEvent;
+use crate::battle::orchestration::{finish_turn, resume_turn};
+use crate::battle::test_fixtures::{confusion_move, is_confused, moves, play_turn, play_wild_turn, pokemon, use_move};
+use crate::battle::BattleOutcome;
+use crate::game::{BattleState, PlayerTeam};
+use crate::models::{BattleFormat, MoveData, MoveStatChange};
+
+#[test]
+fn test_confuse_ray_confuses_the_target() {
+    let mut moves = moves();
+    moves.insert("confuse-ray".to_string(), confusion_move("confuse-ray"));
+
+    let (_, battle_state, result) = play_wild_turn("confuse-ray", &moves, |_, _| {});
+
+    assert!(result.events.contains(&BattleEvent::message("battle.status.confused", [
+        ("pokemon", "wild".to_string()),
+    ])));
+    // Los turnos del estado no convierten el movimiento en uno de carga
+    assert!(!result.events.contains(&BattleEvent::message("battle.charging", [
+        ("pokemon", "player".to_string()),
+    ])));
+    let volatile = battle_state.opponent_instance.volatile_status.as_ref().unwrap();
+    assert!(!volatile.confused || (1..=5).contains(&volatile.confusion_turns));
+}
+
+#[test]
+fn test_swagger_boosts_attack_and_confuses() {
+    let mut moves = moves();
+    let mut swagger = confusion_move("swagger");
+    swagger.stat_changes = vec![MoveStatChange { stat: "attack".to_string(), change: 2 }];
+    moves.insert("swagger".to_string(), swagger);
+
+    let (_, _, result) = play_wild_turn("swagger", &moves, |_, _| {});
+
+    assert!(result.events.contains(&BattleEvent::StatStageChanged {
+        target: "wild".to_string(),
+        stat: "attack".to_string(),
+        stages: 2,
+    }));
+    assert!(result.events.contains(&BattleEvent::message("battle.status.confused", [
+        ("pokemon", "wild".to_string()),
+    ])));
+}
+
+#[test]
+fn test_own_tempo_prevents_confusion() {
+    let mut moves = moves();
+    moves.insert("confuse-ray".to_string(), confusion_move("confuse-ray"));
+
+    let (_, battle_state, result) = play_wild_turn("confuse-ray", &moves, |state, _| {
+        state.opponent_instance.ability = "own-tempo".to_string();
+    });
+
+    assert!(result.events.contains(&BattleEvent::message("battle.status.confusion_prevented", [
+        ("pokemon", "wild".to_string()),
+    ])));
+    assert!(!is_confused(&battle_state.opponent_instance));
+}
+
+fn u_turn() -> MoveData {
+    let mut u_turn = moves()["tackle"].clone();
+    u_turn.id = "u-turn".to_string();
+    u_turn.name = "U-turn".to_string();
+    u_turn.r#type = "Bug".to_string();
+    u_turn.power = Some(70);
+    u_turn
+}
+
+#[test]
+fn test_u_turn_waits_for_the_player_to_pick_a_replacement() {
+    let mut moves = moves();
+    moves.insert("u-turn".to_string(), u_turn());
+
+    let (mut team, mut battle_state, result) = play_wild_turn("u-turn", &moves, |_, team| {
+        team.active_members[0].base_computed_stats.speed = 500;
+        team.add_member(pokemon("bench", "run-away", &moves));
+    });
+
+    // El turno queda detenido antes del ataque salvaje y no avanza el contador
+    assert_eq!(result.outcome, BattleOutcome::PlayerMustPivot);
+    assert_eq!(battle_state.pending_pivot.as_ref().unwrap().slots, vec![0]);
+    assert_eq!(battle_state.turn_counter, 2);
+    assert!(battle_state.opponent_instance.current_hp < battle_state.opponent_instance.base_computed_stats.hp);
+    assert_eq!(team.active_members[0].current_hp, team.active_members[0].base_computed_stats.hp);
+
+    // Un índice inválido (el propio saliente) mantiene el relevo pendiente
+    let mut rng = StdRng::seed_from_u64(12);
+    let result = resume_turn(&mut team, &mut battle_state, &mut rng, 0);
+    assert_eq!(result.outcome, BattleOutcome::PlayerMustPivot);
+    assert!(battle_state.pending_pivot.is_some());
+
+    let result = resume_turn(&mut team, &mut battle_state, &mut rng, 1);
+    finish_turn(&mut battle_state, &result);
+
+    // El entrante recibe el ataque que quedaba en la cola
+    assert_eq!(result.outcome, BattleOutcome::Continue);
+    assert!(battle_state.pending_pivot.is_none());
+    assert_eq!(battle_state.turn_counter, 3);
+    assert_eq!(battle_state.player_active_indices, vec![1]);
+    assert_eq!(team.active_members[0].current_hp, team.active_members[0].base_computed_stats.hp);
+    assert!(team.active_members[1].current_hp < team.active_members[1].base_computed_stats.hp);
+}
+
+#[test]
+fn test_u_turn_without_bench_just_attacks() {
+    let mut moves = moves();
+    moves.insert("u-turn".to_string(), u_turn());
+
+    let (_, battle_state, result) = play_wild_turn("u-turn", &moves, |_, _| {});
+
+    assert_eq!(result.outcome, BattleOutcome::Continue);
+    assert!(battle_state.pending_pivot.is_none());
+    assert_eq!(battle_state.turn_counter, 3);
+}
+
+#[test]
+fn test_trainer_ai_pivots_automatically() {
+    let mut moves = moves();
+    moves.insert("u-turn".to_string(), u_turn());
+    let mut team = PlayerTeam::new();
+    team.add_member(pokemon("player", "run-away", &moves));
+
+    // El rival solo puede usar U-turn y es más rápido
+    let mut pivot = pokemon("pivot", "run-away", &moves);
+    for learned in &mut pivot.randomized_profile.learned_moves {
+        learned.current_pp = 0;
+    }
+    pivot.learn_new_move("u-turn".to_string(), 10);
+    pivot.base_computed_stats.speed = 500;
+    let replacement = pokemon("replacement", "run-away", &moves);
+
+    let mut battle_state = BattleState::new(
+        0,
+        vec![pivot, replacement],
+        "Rival".to_string(),
+        BattleFormat::Single,
+        true,
+    );
+    battle_state.turn_counter = 2;
+    battle_state.pending_player_actions = vec![use_move(0, "tackle")];
+
+    let result = play_turn(&mut team, &mut battle_state, &moves, 4);
+
+    // La IA no detiene el turno: su reemplazo entra y recibe el Tackle
+    assert_eq!(result.outcome, BattleOutcome::Continue);
+    assert!(battle_state.pending_pivot.is_none());
+    assert_eq!(battle_state.opponent_active_indices, vec![1]);
+    assert_eq!(battle_state.opponent_team[0].current_hp, battle_state.opponent_team[0].base_computed_stats.hp);
+    assert!(battle_state.opponent_team[1].current_hp < battle_state.opponent_team[1].base_computed_stats.hp);
+}
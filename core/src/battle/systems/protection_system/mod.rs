@@ -5,10 +5,13 @@
 //! - Quick Guard: Protege al equipo de movimientos con prioridad aumentada
 //! - Mat Block: Protege al equipo de movimientos dañinos el primer turno
 //! - Crafty Shield: Protege al equipo de movimientos de estado
+//! - Substitute: Un sustituto recibe los ataques en lugar del Pokémon
 
 pub mod processor;
+pub mod substitute;
 
 #[cfg(test)]
 mod tests;
 
 pub use processor::*;
+pub use substitute::*;
@@ -3,6 +3,7 @@
 //! Funciones para verificar y aplicar protecciones de equipo
 
 use crate::models::{MoveData, PokemonInstance};
+use crate::i18n::LocalizedText;
 
 /// Verifica si un movimiento es bloqueado por Wide Guard
 ///
@@ -105,34 +106,34 @@ fn is_spread_move(move_data: &MoveData) -> bool {
 pub fn check_advanced_protections(
     defender: &PokemonInstance,
     move_data: &MoveData,
-) -> Option<String> {
+) -> Option<LocalizedText> {
     // Verificar en orden de prioridad
     if is_blocked_by_wide_guard(defender, move_data) {
-        return Some(format!(
-            "¡Wide Guard protegió a {}!",
-            defender.species.display_name
-        ));
+        return Some(LocalizedText::new("battle.protection_blocked", [
+            ("protection", "Wide Guard".to_string()),
+            ("pokemon", defender.species.display_name.clone()),
+        ]));
     }
 
     if is_blocked_by_quick_guard(defender, move_data) {
-        return Some(format!(
-            "¡Quick Guard protegió a {}!",
-            defender.species.display_name
-        ));
+        return Some(LocalizedText::new("battle.protection_blocked", [
+            ("protection", "Quick Guard".to_string()),
+            ("pokemon", defender.species.display_name.clone()),
+        ]));
     }
 
     if is_blocked_by_mat_block(defender, move_data) {
-        return Some(format!(
-            "¡Mat Block protegió a {}!",
-            defender.species.display_name
-        ));
+        return Some(LocalizedText::new("battle.protection_blocked", [
+            ("protection", "Mat Block".to_string()),
+            ("pokemon", defender.species.display_name.clone()),
+        ]));
     }
 
     if is_blocked_by_crafty_shield(defender, move_data) {
-        return Some(format!(
-            "¡Crafty Shield protegió a {}!",
-            defender.species.display_name
-        ));
+        return Some(LocalizedText::new("battle.protection_blocked", [
+            ("protection", "Crafty Shield".to_string()),
+            ("pokemon", defender.species.display_name.clone()),
+        ]));
     }
 
     None
@@ -0,0 +1,63 @@
+//! Substitute
+//!
+//! El usuario gasta un 25% de su HP máximo en un sustituto con esa misma
+//! cantidad de HP. Mientras exista, el sustituto recibe el daño de los ataques
+//! rivales y bloquea los movimientos de estado y los efectos secundarios que
+//! apuntan al Pokémon. Los movimientos de sonido y la habilidad Infiltrator lo
+//! atraviesan.
+
+use crate::models::{MoveData, PokemonInstance, VolatileStatus};
+
+/// Movimientos de sonido: atraviesan el sustituto
+const SOUND_MOVES: &[&str] = &[
+    "growl", "roar", "sing", "supersonic", "screech", "snore", "perish-song", "heal-bell",
+    "uproar", "hyper-voice", "metal-sound", "grass-whistle", "bug-buzz", "chatter", "round",
+    "echoed-voice", "relic-song", "snarl", "disarming-voice", "noble-roar", "parting-shot",
+    "boomburst", "confide", "sparkling-aria", "clanging-scales", "clangorous-soul", "overdrive",
+    "eerie-spell", "torch-song", "alluring-voice", "psychic-noise",
+];
+
+/// Si el movimiento es de sonido
+pub fn is_sound_move(move_id: &str) -> bool {
+    SOUND_MOVES.contains(&move_id)
+}
+
+/// Si el Pokémon tiene un sustituto en pie
+pub fn has_substitute(pokemon: &PokemonInstance) -> bool {
+    pokemon.volatile_status.as_ref().is_some_and(|v| v.substitute_hp > 0)
+}
+
+/// Si el ataque atraviesa el sustituto del objetivo (sonido o Infiltrator)
+pub fn bypasses_substitute(attacker: &PokemonInstance, move_data: &MoveData) -> bool {
+    attacker.ability == "infiltrator" || is_sound_move(&move_data.id)
+}
+
+/// HP que cuesta crear un sustituto (y que tendrá el sustituto)
+pub fn substitute_cost(pokemon: &PokemonInstance) -> u16 {
+    (pokemon.base_computed_stats.hp / 4).max(1)
+}
+
+/// Crea un sustituto pagando su coste en HP
+///
+/// Retorna false si no tiene HP suficiente (necesita más que el coste).
+pub fn create_substitute(pokemon: &mut PokemonInstance) -> bool {
+    let cost = substitute_cost(pokemon);
+    if pokemon.current_hp <= cost {
+        return false;
+    }
+
+    pokemon.current_hp -= cost;
+    pokemon.volatile_status.get_or_insert_with(VolatileStatus::new).substitute_hp = cost;
+    true
+}
+
+/// El sustituto recibe el daño de un golpe
+///
+/// Retorna true si el sustituto se rompió. El daño sobrante no pasa al Pokémon.
+pub fn absorb_substitute_damage(pokemon: &mut PokemonInstance, damage: u16) -> bool {
+    let Some(volatile) = pokemon.volatile_status.as_mut() else {
+        return false;
+    };
+    volatile.substitute_hp = volatile.substitute_hp.saturating_sub(damage);
+    volatile.substitute_hp == 0
+}
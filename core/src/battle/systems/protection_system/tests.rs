@@ -8,6 +8,8 @@ use crate::models::{
     StatModifiers, VolatileStatus, StatStages, MoveData, MoveMeta,
 };
 use super::processor::*;
+use super::substitute::*;
+use crate::i18n::Locale;
 
 /// Helper para crear Pokémon de prueba con configuración personalizada
 fn create_test_pokemon() -> PokemonInstance {
@@ -31,6 +33,9 @@ fn create_test_pokemon() -> PokemonInstance {
             possible_abilities: vec!["static".to_string()],
             is_starter_candidate: false,
             evolutions: Vec::new(),
+            ev_yield: Stats::zero(),
+            gender_rate: 4,
+            mega_forms: Vec::new(),
         },
         level: 50,
         current_hp: 100,
@@ -41,6 +46,11 @@ fn create_test_pokemon() -> PokemonInstance {
         volatile_status: Some(VolatileStatus::new()),
         individual_values: Stats::default(),
         effort_values: Stats::default(),
+        nature: Default::default(),
+        gender: Default::default(),
+        tera_type: Default::default(),
+        terastallized: false,
+        mega_evolution: None,
         randomized_profile: RandomizedProfile {
             rolled_primary_type: PokemonType::Electric,
             rolled_secondary_type: None,
@@ -543,7 +553,7 @@ mod advanced_protections {
 
         assert!(result.is_some(), "Debe retornar un mensaje de protección");
         assert!(
-            result.unwrap().contains("Wide Guard"),
+            result.unwrap().resolve(Locale::Es).contains("Wide Guard"),
             "El mensaje debe mencionar Wide Guard"
         );
     }
@@ -557,7 +567,7 @@ mod advanced_protections {
         let result = check_advanced_protections(&pokemon, &quick_attack);
 
         assert!(result.is_some());
-        assert!(result.unwrap().contains("Quick Guard"));
+        assert!(result.unwrap().resolve(Locale::Es).contains("Quick Guard"));
     }
 
     #[test]
@@ -569,7 +579,7 @@ mod advanced_protections {
         let result = check_advanced_protections(&pokemon, &tackle);
 
         assert!(result.is_some());
-        assert!(result.unwrap().contains("Mat Block"));
+        assert!(result.unwrap().resolve(Locale::Es).contains("Mat Block"));
     }
 
     #[test]
@@ -581,7 +591,7 @@ mod advanced_protections {
         let result = check_advanced_protections(&pokemon, &thunder_wave);
 
         assert!(result.is_some());
-        assert!(result.unwrap().contains("Crafty Shield"));
+        assert!(result.unwrap().resolve(Locale::Es).contains("Crafty Shield"));
     }
 
     #[test]
@@ -600,7 +610,7 @@ mod advanced_protections {
         assert!(result.is_some());
         // Wide Guard se verifica primero, debe bloquear
         assert!(
-            result.unwrap().contains("Wide Guard"),
+            result.unwrap().resolve(Locale::Es).contains("Wide Guard"),
             "Wide Guard debe tener prioridad en el orden de verificación"
         );
     }
@@ -731,3 +741,161 @@ mod integration {
         assert!(poke4.volatile_status.is_some());
     }
 }
+
+// ==================== SUBSTITUTE TESTS ====================
+
+#[cfg(test)]
+mod substitute {
+    use super::*;
+
+    #[test]
+    fn test_substitute_costs_a_quarter_of_max_hp() {
+        let mut pokemon = create_test_pokemon();
+        pokemon.current_hp = 200;
+
+        assert!(create_substitute(&mut pokemon));
+        assert_eq!(pokemon.current_hp, 150);
+        assert_eq!(pokemon.volatile_status.as_ref().unwrap().substitute_hp, 50);
+        assert!(has_substitute(&pokemon));
+    }
+
+    #[test]
+    fn test_substitute_fails_without_enough_hp() {
+        let mut pokemon = create_test_pokemon();
+        pokemon.current_hp = 50;
+
+        assert!(!create_substitute(&mut pokemon), "Con exactamente 25% de HP debe fallar");
+        assert_eq!(pokemon.current_hp, 50);
+        assert!(!has_substitute(&pokemon));
+    }
+
+    #[test]
+    fn test_substitute_absorbs_damage_until_it_breaks() {
+        let mut pokemon = create_test_pokemon();
+        pokemon.current_hp = 200;
+        create_substitute(&mut pokemon);
+
+        assert!(!absorb_substitute_damage(&mut pokemon, 30));
+        assert_eq!(pokemon.volatile_status.as_ref().unwrap().substitute_hp, 20);
+        // El daño sobrante no llega al Pokémon
+        assert!(absorb_substitute_damage(&mut pokemon, 80));
+        assert!(!has_substitute(&pokemon));
+        assert_eq!(pokemon.current_hp, 150);
+    }
+
+    #[test]
+    fn test_sound_moves_and_infiltrator_bypass_substitute() {
+        let attacker = create_test_pokemon();
+        let mut hyper_voice = create_single_target_move();
+        hyper_voice.id = "hyper-voice".to_string();
+
+        assert!(bypasses_substitute(&attacker, &hyper_voice));
+        assert!(!bypasses_substitute(&attacker, &create_single_target_move()));
+
+        let mut infiltrator = create_test_pokemon();
+        infiltrator.ability = "infiltrator".to_string();
+        assert!(bypasses_substitute(&infiltrator, &create_single_target_move()));
+    }
+}
+
+// ==================== SUBSTITUTE IN BATTLE TESTS ====================
+
+#[cfg(test)]
+mod substitute_in_battle {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use crate::battle::events::BattleEvent;
+    use crate::battle::orchestration::{finish_turn, resume_turn};
+    use crate::battle::test_fixtures::{confusion_move, is_confused, moves, play_wild_turn, pokemon, status_move};
+    use crate::battle::BattleOutcome;
+    use crate::models::MoveStatChange;
+
+    fn with_substitute(pokemon: &mut PokemonInstance, hp: u16) {
+        pokemon.init_battle_stages();
+        pokemon.volatile_status.as_mut().unwrap().substitute_hp = hp;
+    }
+
+    #[test]
+    fn test_substitute_costs_hp_and_takes_the_hit() {
+        let mut moves = moves();
+        moves.insert("substitute".to_string(), status_move("substitute", "user"));
+
+        let (team, _, result) = play_wild_turn("substitute", &moves, |_, team| {
+            team.active_members[0].base_computed_stats.speed = 500;
+        });
+
+        let user = &team.active_members[0];
+        assert!(result.events.contains(&BattleEvent::SubstituteCreated { pokemon: "player".to_string() }));
+        // El Tackle del rival da en el sustituto, no en el Pokémon
+        assert!(result.events.contains(&BattleEvent::SubstituteDamaged { pokemon: "player".to_string() }));
+        assert_eq!(user.current_hp, user.base_computed_stats.hp - substitute_cost(user));
+        assert!(has_substitute(user));
+    }
+
+    #[test]
+    fn test_substitute_breaks_and_blocks_status_moves() {
+        let mut moves = moves();
+        moves.insert("confuse-ray".to_string(), confusion_move("confuse-ray"));
+
+        let (_, battle_state, result) = play_wild_turn("confuse-ray", &moves, |state, _| {
+            with_substitute(&mut state.opponent_instance, 1);
+        });
+
+        assert!(result.events.contains(&BattleEvent::message("battle.move_failed", [])));
+        assert!(!is_confused(&battle_state.opponent_instance));
+
+        let (_, battle_state, result) = play_wild_turn("tackle", &moves, |state, _| {
+            with_substitute(&mut state.opponent_instance, 1);
+        });
+        assert!(result.events.contains(&BattleEvent::SubstituteBroken { pokemon: "wild".to_string() }));
+        assert_eq!(battle_state.opponent_instance.current_hp, battle_state.opponent_instance.base_computed_stats.hp);
+    }
+
+    #[test]
+    fn test_sound_moves_bypass_substitute() {
+        let mut moves = moves();
+        let mut growl = status_move("growl", "all-opponents");
+        growl.stat_changes = vec![MoveStatChange { stat: "attack".to_string(), change: -1 }];
+        moves.insert("growl".to_string(), growl);
+
+        let (_, battle_state, _) = play_wild_turn("growl", &moves, |state, _| {
+            with_substitute(&mut state.opponent_instance, 50);
+        });
+
+        assert_eq!(battle_state.opponent_instance.battle_stages.as_ref().unwrap().attack, -1);
+    }
+
+    #[test]
+    fn test_baton_pass_hands_over_substitute_and_stages() {
+        let mut moves = moves();
+        moves.insert("baton-pass".to_string(), status_move("baton-pass", "user"));
+
+        let (team, battle_state, result) = play_wild_turn("baton-pass", &moves, |_, team| {
+            let user = &mut team.active_members[0];
+            user.base_computed_stats.speed = 500;
+            with_substitute(user, 500);
+            user.battle_stages.as_mut().unwrap().attack = 2;
+            team.add_member(pokemon("bench", "run-away", &moves));
+        });
+
+        // El jugador elige a quién pasarle el testigo
+        assert_eq!(result.outcome, BattleOutcome::PlayerMustPivot);
+        let (mut team, mut battle_state) = (team, battle_state);
+        let mut rng = StdRng::seed_from_u64(12);
+        let result = resume_turn(&mut team, &mut battle_state, &mut rng, 1);
+        finish_turn(&mut battle_state, &result);
+
+        assert_eq!(battle_state.player_active_indices, vec![1]);
+        assert!(result.events.contains(&BattleEvent::Switched { trainer: None, pokemon: "bench".to_string() }));
+
+        let incoming = &team.active_members[1];
+        assert_eq!(incoming.battle_stages.as_ref().unwrap().attack, 2);
+        // El Tackle del rival le dio al sustituto heredado
+        assert!(has_substitute(incoming));
+        assert_eq!(incoming.current_hp, incoming.base_computed_stats.hp);
+
+        let outgoing = &team.active_members[0];
+        assert!(outgoing.volatile_status.is_none());
+    }
+}
@@ -33,6 +33,9 @@ fn create_test_pokemon(primary_type: PokemonType, secondary_type: Option<Pokemon
             possible_abilities: vec!["static".to_string()],
             is_starter_candidate: false,
             evolutions: Vec::new(),
+            ev_yield: Stats::zero(),
+            gender_rate: 4,
+            mega_forms: Vec::new(),
         },
         level: 50,
         current_hp: 100,
@@ -43,6 +46,11 @@ fn create_test_pokemon(primary_type: PokemonType, secondary_type: Option<Pokemon
         volatile_status: Some(VolatileStatus::new()),
         individual_values: Stats::default(),
         effort_values: Stats::default(),
+        nature: Default::default(),
+        gender: Default::default(),
+        tera_type: Default::default(),
+        terastallized: false,
+        mega_evolution: None,
         randomized_profile: RandomizedProfile {
             rolled_primary_type: primary_type,
             rolled_secondary_type: secondary_type,
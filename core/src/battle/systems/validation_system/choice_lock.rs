@@ -0,0 +1,104 @@
+//! Bloqueo de movimiento de los objetos Choice
+//!
+//! Choice Band, Choice Specs, Choice Scarf y la habilidad Gorilla Tactics
+//! obligan al Pokémon a repetir el primer movimiento que usa tras entrar al
+//! campo. El bloqueo vive en `VolatileStatus::choice_locked_move`, así que se
+//! pierde al cambiar; también deja de aplicar si el Pokémon pierde el objeto
+//! o la habilidad.
+
+use crate::models::PokemonInstance;
+use super::super::item_system::ItemProcessor;
+
+/// Si el Pokémon queda bloqueado en su primer movimiento (objeto Choice o Gorilla Tactics)
+pub fn applies_choice_lock(pokemon: &PokemonInstance) -> bool {
+    ItemProcessor::is_choice_item(pokemon) || pokemon.ability == "gorilla-tactics"
+}
+
+/// Movimiento en el que está bloqueado el Pokémon, si lo está
+pub fn choice_locked_move(pokemon: &PokemonInstance) -> Option<&str> {
+    if !applies_choice_lock(pokemon) {
+        return None;
+    }
+    pokemon.volatile_status.as_ref()?.choice_locked_move.as_deref()
+}
+
+/// Bloquea al Pokémon en `move_id` si aún no lo está (Struggle no bloquea)
+///
+/// Si ya no lleva el objeto ni la habilidad, retira el bloqueo que quedara.
+pub fn lock_choice_move(pokemon: &mut PokemonInstance, move_id: &str) {
+    let applies = applies_choice_lock(pokemon);
+    let Some(ref mut volatile) = pokemon.volatile_status else {
+        return;
+    };
+    if !applies {
+        volatile.choice_locked_move = None;
+    } else if volatile.choice_locked_move.is_none() && move_id != "struggle" {
+        volatile.choice_locked_move = Some(move_id.to_string());
+    }
+}
+
+/// Si el Pokémon puede elegir `move_id` con su bloqueo actual
+pub fn is_move_allowed_by_choice(pokemon: &PokemonInstance, move_id: &str) -> bool {
+    choice_locked_move(pokemon).is_none_or(|locked| locked == move_id)
+}
+
+/// Movimiento que usará realmente un Pokémon que pidió `move_id`
+///
+/// Un Pokémon bloqueado repite su movimiento; si ese movimiento ya no tiene
+/// PP, recurre a Struggle.
+pub fn enforce_choice_lock(pokemon: &PokemonInstance, move_id: String) -> String {
+    let Some(locked) = choice_locked_move(pokemon) else {
+        return move_id;
+    };
+    let has_pp = pokemon
+        .get_active_learned_moves()
+        .iter()
+        .any(|m| m.move_id == locked && m.current_pp > 0);
+    if has_pp {
+        locked.to_string()
+    } else {
+        "struggle".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::test_fixtures::{moves, play_wild_turn};
+
+    #[test]
+    fn test_choice_item_locks_the_first_move_used() {
+        let moves = moves();
+        let (team, _, _) = play_wild_turn("tackle", &moves, |_, team| {
+            team.active_members[0].held_item = Some("choice-band".to_string());
+        });
+
+        let mut user = team.active_members[0].clone();
+        assert_eq!(choice_locked_move(&user), Some("tackle"));
+        assert_eq!(enforce_choice_lock(&user, "growl".to_string()), "tackle");
+
+        // Sin PP en el movimiento bloqueado, recurre a Struggle
+        for learned in &mut user.randomized_profile.learned_moves {
+            learned.current_pp = 0;
+        }
+        assert_eq!(enforce_choice_lock(&user, "growl".to_string()), "struggle");
+
+        // Al salir del campo pierde el bloqueo
+        user.reset_battle_stages();
+        user.init_battle_stages();
+        assert_eq!(enforce_choice_lock(&user, "growl".to_string()), "growl");
+    }
+
+    #[test]
+    fn test_gorilla_tactics_locks_like_choice_band() {
+        let moves = moves();
+        let (team, _, _) = play_wild_turn("tackle", &moves, |_, team| {
+            team.active_members[0].ability = "gorilla-tactics".to_string();
+        });
+        assert_eq!(choice_locked_move(&team.active_members[0]), Some("tackle"));
+
+        // Sin objeto Choice ni Gorilla Tactics no hay bloqueo
+        let (team, _, _) = play_wild_turn("tackle", &moves, |_, _| {});
+        assert_eq!(choice_locked_move(&team.active_members[0]), None);
+    }
+}
@@ -5,9 +5,11 @@
 //! - Validar estados de Pokémon
 //! - Resetear flags de turno
 //! - Gestionar PP de movimientos
+//! - Bloquear el movimiento de los objetos Choice
 
 pub mod state_resetter;
 pub mod pp_manager;
+pub mod choice_lock;
 
 // Re-exportar funciones principales
 pub use state_resetter::reset_turn_flags;
@@ -17,6 +19,13 @@ pub use pp_manager::{
     has_moves_with_pp,
     create_struggle_move,
 };
+pub use choice_lock::{
+    applies_choice_lock,
+    choice_locked_move,
+    lock_choice_move,
+    is_move_allowed_by_choice,
+    enforce_choice_lock,
+};
 
 // NOTA: can_pokemon_move y check_ailment_success permanecen en checks.rs
 // debido a que requieren acceso a StdRng y lógica de batalla específica
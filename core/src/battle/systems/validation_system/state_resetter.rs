@@ -77,6 +77,9 @@ mod tests {
                 possible_abilities: vec!["static".to_string()],
                 is_starter_candidate: false,
                 evolutions: Vec::new(),
+                ev_yield: Stats::zero(),
+                gender_rate: 4,
+                mega_forms: Vec::new(),
             },
             level: 50,
             current_hp: 100,
@@ -87,6 +90,11 @@ mod tests {
             held_item: None,
             individual_values: Stats::default(),
             effort_values: Stats::default(),
+            nature: Default::default(),
+            gender: Default::default(),
+            tera_type: Default::default(),
+            terastallized: false,
+            mega_evolution: None,
             randomized_profile: RandomizedProfile {
                 rolled_primary_type: PokemonType::Electric,
                 rolled_secondary_type: None,
@@ -110,19 +118,16 @@ mod tests {
     fn test_reset_turn_flags_clears_protect() {
         let mut player_team = PlayerTeam {
             active_members: vec![create_test_pokemon_with_protect()],
-            benched_members: Vec::new(),
+            box_members: Vec::new(),
         };
         let mut opponent_team = vec![create_test_pokemon_with_protect()];
-        let battle_state = BattleState {
-            player_active_indices: vec![0],
-            opponent_active_indices: vec![0],
-            is_trainer_battle: false,
-            opponent_instance: create_test_pokemon_with_protect(),
-            weather: None,
-            terrain: None,
-            turn_number: 1,
-            battle_format: crate::models::BattleFormat::Singles,
-        };
+        let battle_state = BattleState::new(
+            0,
+            opponent_team.clone(),
+            String::new(),
+            crate::models::BattleFormat::Single,
+            false,
+        );
 
         reset_turn_flags(&mut player_team, &mut opponent_team, &battle_state);
 
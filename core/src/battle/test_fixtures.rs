@@ -0,0 +1,171 @@
+//! Fixtures compartidos por los tests que juegan turnos completos
+//!
+//! Pokémon y movimientos mínimos (tipo Normal, stats base 80, nivel 50) y
+//! atajos para preparar, ejecutar y cerrar un turno como lo hace el servidor.
+
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::factory::create_pokemon_instance;
+use crate::game::{BattleState, PendingPlayerAction, PlayerTeam};
+use crate::models::{BattleFormat, MegaForm, MoveData, MoveMeta, PokemonInstance, PokemonSpecies, PokemonType, Stats};
+use super::orchestration::{finish_turn, prepare_turn, run_turn};
+use super::TurnResult;
+
+/// Pokémon de tipo Normal con stats base 80 y la habilidad indicada
+pub(crate) fn pokemon(id: &str, ability: &str, moves: &HashMap<String, MoveData>) -> PokemonInstance {
+    let species = PokemonSpecies {
+        species_id: id.to_string(),
+        display_name: id.to_string(),
+        generation: 1,
+        primary_type: PokemonType::Normal,
+        secondary_type: None,
+        base_stats: Stats {
+            hp: 80,
+            attack: 80,
+            defense: 80,
+            special_attack: 80,
+            special_defense: 80,
+            speed: 80,
+        },
+        move_pool: vec!["tackle".to_string()],
+        possible_abilities: vec![ability.to_string()],
+        is_starter_candidate: false,
+        evolutions: Vec::new(),
+        ev_yield: Stats::zero(),
+        gender_rate: 4,
+        mega_forms: Vec::new(),
+    };
+    let mut pokemon = create_pokemon_instance(&species, 50, 1, false, &[], Some(moves));
+    pokemon.randomized_profile.rolled_primary_type = PokemonType::Normal;
+    pokemon.randomized_profile.rolled_secondary_type = None;
+    pokemon
+}
+
+/// Catálogo con solo Tackle (40 de poder, 100% de precisión)
+pub(crate) fn moves() -> HashMap<String, MoveData> {
+    let tackle = MoveData {
+        id: "tackle".to_string(),
+        name: "Tackle".to_string(),
+        r#type: "Normal".to_string(),
+        power: Some(40),
+        accuracy: Some(100),
+        priority: 0,
+        pp: 35,
+        damage_class: "physical".to_string(),
+        meta: MoveMeta::default(),
+        stat_changes: vec![],
+        target: "selected-pokemon".to_string(),
+    };
+    HashMap::from([("tackle".to_string(), tackle)])
+}
+
+/// Movimiento de estado sin precisión ni efectos
+pub(crate) fn status_move(id: &str, target: &str) -> MoveData {
+    MoveData {
+        id: id.to_string(),
+        name: id.to_string(),
+        r#type: "Normal".to_string(),
+        power: None,
+        accuracy: None,
+        priority: 0,
+        pp: 5,
+        damage_class: "status".to_string(),
+        meta: MoveMeta::default(),
+        stat_changes: vec![],
+        target: target.to_string(),
+    }
+}
+
+/// Movimiento de estado que confunde de 2 a 5 turnos
+pub(crate) fn confusion_move(id: &str) -> MoveData {
+    let mut confuse = status_move(id, "selected-pokemon");
+    confuse.meta.ailment = "confusion".to_string();
+    confuse.meta.min_turns = Some(2);
+    confuse.meta.max_turns = Some(5);
+    confuse
+}
+
+pub(crate) fn is_confused(pokemon: &PokemonInstance) -> bool {
+    pokemon.volatile_status.as_ref().is_some_and(|v| v.confused)
+}
+
+/// `moves()` más "finisher", un golpe de 400 de poder que siempre debilita
+pub(crate) fn moves_with_finisher() -> HashMap<String, MoveData> {
+    let mut moves = moves();
+    let mut finisher = moves["tackle"].clone();
+    finisher.id = "finisher".to_string();
+    finisher.power = Some(400);
+    moves.insert("finisher".to_string(), finisher);
+    moves
+}
+
+/// Forma Mega de "player" (Fuego/Dragón con Drought), activada por "playerite"
+pub(crate) fn mega_form() -> MegaForm {
+    MegaForm {
+        form_id: "player-mega".to_string(),
+        display_name: "Mega Player".to_string(),
+        mega_stone: "playerite".to_string(),
+        primary_type: PokemonType::Fire,
+        secondary_type: Some(PokemonType::Dragon),
+        base_stats: Stats {
+            hp: 80,
+            attack: 130,
+            defense: 110,
+            special_attack: 100,
+            special_defense: 100,
+            speed: 100,
+        },
+        ability: "drought".to_string(),
+    }
+}
+
+/// Acción de usar `move_id` con el Pokémon del slot `user_index`
+pub(crate) fn use_move(user_index: usize, move_id: &str) -> PendingPlayerAction {
+    PendingPlayerAction {
+        user_index,
+        move_id: move_id.to_string(),
+        target_position: None,
+        switch_to: None,
+        terastallize: false,
+        mega_evolve: false,
+    }
+}
+
+/// Prepara, ejecuta y cierra un turno con las acciones pendientes del jugador
+pub(crate) fn play_turn(
+    team: &mut PlayerTeam,
+    battle_state: &mut BattleState,
+    moves: &HashMap<String, MoveData>,
+    seed: u64,
+) -> TurnResult {
+    prepare_turn(team, battle_state, moves);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let result = run_turn(team, battle_state, &mut rng, Some(moves));
+    finish_turn(battle_state, &result);
+    result
+}
+
+/// Batalla salvaje de un turno en la que "player" usa `move_id` contra "wild"
+///
+/// `setup` puede ajustar el estado y el equipo antes de jugar el turno.
+pub(crate) fn play_wild_turn(
+    move_id: &str,
+    moves: &HashMap<String, MoveData>,
+    setup: impl FnOnce(&mut BattleState, &mut PlayerTeam),
+) -> (PlayerTeam, BattleState, TurnResult) {
+    let mut team = PlayerTeam::new();
+    team.add_member(pokemon("player", "run-away", moves));
+    team.active_members[0].learn_new_move(move_id.to_string(), 10);
+    let wild = pokemon("wild", "run-away", moves);
+
+    let mut battle_state = BattleState::new(0, vec![wild], "wild".to_string(), BattleFormat::Single, false);
+    battle_state.turn_counter = 2;
+    setup(&mut battle_state, &mut team);
+    battle_state.pending_player_actions = vec![use_move(0, move_id)];
+
+    let result = play_turn(&mut team, &mut battle_state, moves, 11);
+    (team, battle_state, result)
+}
@@ -1,7 +1,14 @@
 use crate::game::GameConfig;
-use crate::models::{PokemonInstance, PokemonSpecies};
+use crate::models::{PokemonInstance, PokemonSpecies, Stat, Stats};
 use crate::factory::compute_stats;
 
+/// Máximo de EVs en una sola stat
+pub const MAX_EVS_PER_STAT: u16 = 252;
+/// Máximo de EVs sumando las seis stats
+pub const MAX_TOTAL_EVS: u16 = 510;
+/// EVs que suma una vitamina o resta una baya reductora
+pub const EV_ITEM_POINTS: u16 = 10;
+
 /// Aplica la subida de nivel por hitos después de una victoria
 /// 
 /// Sistema de Milestone Leveling:
@@ -42,12 +49,13 @@ pub fn apply_victory_level_up(
             1.0
         };
         
-        // Recalcular stats usando los mismos IVs y EVs, pero con el nuevo nivel
+        // Recalcular stats usando los mismos IVs, EVs y naturaleza, pero con el nuevo nivel
         let new_stats = compute_stats(
             &pokemon.species.base_stats,
             &pokemon.individual_values,
             &pokemon.effort_values,
             new_level,
+            pokemon.nature,
         );
         
         // Obtener el nuevo HP máximo
@@ -66,3 +74,68 @@ pub fn apply_victory_level_up(
     }
 }
 
+
+/// Suma EVs a una stat respetando los topes (252 por stat, 510 en total)
+///
+/// No recalcula los stats. Retorna los EVs que se sumaron realmente.
+pub fn add_effort_values(pokemon: &mut PokemonInstance, stat: Stat, amount: u16) -> u16 {
+    let total = pokemon.effort_values.total() as u16;
+    let current = pokemon.effort_values.get(stat);
+    let added = amount
+        .min(MAX_EVS_PER_STAT.saturating_sub(current))
+        .min(MAX_TOTAL_EVS.saturating_sub(total));
+    *pokemon.effort_values.get_mut(stat) += added;
+    added
+}
+
+/// Reparte los EVs de un rival derrotado y recalcula los stats
+///
+/// Retorna true si el Pokémon ganó algún EV.
+pub fn award_effort_values(pokemon: &mut PokemonInstance, ev_yield: &Stats) -> bool {
+    let mut gained = false;
+    for stat in Stat::ALL {
+        gained |= add_effort_values(pokemon, stat, ev_yield.get(stat)) > 0;
+    }
+    if gained {
+        pokemon.recalculate_stats();
+    }
+    gained
+}
+
+/// Stat y cambio de EVs de un objeto: vitaminas (+10) y bayas reductoras (-10)
+pub fn ev_item_effect(item_id: &str) -> Option<(Stat, i16)> {
+    let points = EV_ITEM_POINTS as i16;
+    match item_id {
+        "hp-up" => Some((Stat::Hp, points)),
+        "protein" => Some((Stat::Attack, points)),
+        "iron" => Some((Stat::Defense, points)),
+        "calcium" => Some((Stat::SpecialAttack, points)),
+        "zinc" => Some((Stat::SpecialDefense, points)),
+        "carbos" => Some((Stat::Speed, points)),
+        "pomeg-berry" => Some((Stat::Hp, -points)),
+        "kelpsy-berry" => Some((Stat::Attack, -points)),
+        "qualot-berry" => Some((Stat::Defense, -points)),
+        "hondew-berry" => Some((Stat::SpecialAttack, -points)),
+        "grepa-berry" => Some((Stat::SpecialDefense, -points)),
+        "tamato-berry" => Some((Stat::Speed, -points)),
+        _ => None,
+    }
+}
+
+/// Usa una vitamina o baya reductora sobre el Pokémon y recalcula sus stats
+///
+/// Retorna false si el objeto no cambia EVs. El objeto se gasta aunque el
+/// Pokémon ya esté en el tope (o en 0 EVs, para las bayas).
+pub fn apply_ev_item(pokemon: &mut PokemonInstance, item_id: &str) -> bool {
+    let Some((stat, delta)) = ev_item_effect(item_id) else {
+        return false;
+    };
+    if delta >= 0 {
+        add_effort_values(pokemon, stat, delta as u16);
+    } else {
+        let value = pokemon.effort_values.get_mut(stat);
+        *value = value.saturating_sub(delta.unsigned_abs());
+    }
+    pokemon.recalculate_stats();
+    true
+}
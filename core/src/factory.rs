@@ -2,7 +2,8 @@ use rand::rngs::StdRng;
 use rand::{seq::SliceRandom, Rng, SeedableRng};
 
 use crate::models::{
-    LearnedMove, MoveInstance, PokemonInstance, PokemonSpecies, RandomizedProfile, StatModifiers, Stats,
+    Gender, LearnedMove, MoveInstance, Nature, PokemonInstance, PokemonSpecies, PokemonType, RandomizedProfile,
+    Stat, StatModifiers, Stats,
 };
 
 // NOTA: Se eliminaron las constantes y funciones de randomización de tipos (Vanilla behavior)
@@ -18,6 +19,24 @@ fn roll_ivs(rng: &mut StdRng) -> Stats {
     }
 }
 
+fn roll_nature(rng: &mut StdRng) -> Nature {
+    Nature::ALL[rng.gen_range(0..Nature::ALL.len())]
+}
+
+fn roll_tera_type(rng: &mut StdRng) -> PokemonType {
+    PokemonType::ALL[rng.gen_range(0..PokemonType::ALL.len())]
+}
+
+fn roll_gender(species: &PokemonSpecies, rng: &mut StdRng) -> Gender {
+    if species.gender_rate < 0 {
+        Gender::Genderless
+    } else if rng.gen_range(0..8) < species.gender_rate {
+        Gender::Female
+    } else {
+        Gender::Male
+    }
+}
+
 fn compute_stat(base: u16, iv: u16, ev: u16, level: u8, is_hp: bool) -> u16 {
     // Casteamos a u32 para evitar overflow durante la multiplicación
     let base_term = ((base as u32 * 2) + iv as u32 + (ev as u32 / 4)) * level as u32 / 100;
@@ -31,28 +50,28 @@ fn compute_stat(base: u16, iv: u16, ev: u16, level: u8, is_hp: bool) -> u16 {
     result.min(u16::MAX as u32) as u16
 }
 
-/// Calcula los stats finales basándose en stats base, IVs, EVs y nivel
+/// Calcula los stats finales basándose en stats base, IVs, EVs, nivel y naturaleza
 /// Esta función es pública para que pueda ser usada por otros módulos
-pub fn compute_stats(base: &Stats, ivs: &Stats, evs: &Stats, level: u8) -> Stats {
+pub fn compute_stats(base: &Stats, ivs: &Stats, evs: &Stats, level: u8, nature: Nature) -> Stats {
     Stats {
         hp: compute_stat(base.hp, ivs.hp, evs.hp, level, true),
-        attack: compute_stat(base.attack, ivs.attack, evs.attack, level, false),
-        defense: compute_stat(base.defense, ivs.defense, evs.defense, level, false),
-        special_attack: compute_stat(
-            base.special_attack,
-            ivs.special_attack,
-            evs.special_attack,
-            level,
-            false,
+        attack: nature.apply(
+            Stat::Attack,
+            compute_stat(base.attack, ivs.attack, evs.attack, level, false),
         ),
-        special_defense: compute_stat(
-            base.special_defense,
-            ivs.special_defense,
-            evs.special_defense,
-            level,
-            false,
+        defense: nature.apply(
+            Stat::Defense,
+            compute_stat(base.defense, ivs.defense, evs.defense, level, false),
+        ),
+        special_attack: nature.apply(
+            Stat::SpecialAttack,
+            compute_stat(base.special_attack, ivs.special_attack, evs.special_attack, level, false),
+        ),
+        special_defense: nature.apply(
+            Stat::SpecialDefense,
+            compute_stat(base.special_defense, ivs.special_defense, evs.special_defense, level, false),
         ),
-        speed: compute_stat(base.speed, ivs.speed, evs.speed, level, false),
+        speed: nature.apply(Stat::Speed, compute_stat(base.speed, ivs.speed, evs.speed, level, false)),
     }
 }
 
@@ -145,9 +164,6 @@ pub fn create_pokemon_instance(
     let ivs = roll_ivs(&mut rng);
     let evs = Stats::zero();
 
-    // Stats calculados sobre la base original
-    let base_computed_stats = compute_stats(&species.base_stats, &ivs, &evs, level);
-
     // Seleccionar movimientos según el modo
     let moves = if chaos_mode {
         roll_chaos_moves(&mut rng, global_move_pool)
@@ -174,6 +190,13 @@ pub fn create_pokemon_instance(
     let rolled_ability_id = roll_ability_id(species, &mut rng);
     let ability = rolled_ability_id.clone(); // La habilidad activa es la misma que la rolled
     let id = roll_instance_id(&mut rng);
+    // Naturaleza, género y Tera Type se tiran al final para no alterar el resto de tiradas de la semilla
+    let nature = roll_nature(&mut rng);
+    let gender = roll_gender(species, &mut rng);
+    let tera_type = roll_tera_type(&mut rng);
+
+    // Stats calculados sobre la base original
+    let base_computed_stats = compute_stats(&species.base_stats, &ivs, &evs, level, nature);
 
     // Perfil "Randomizado" coincide con el original
     let randomized_profile = RandomizedProfile {
@@ -197,6 +220,11 @@ pub fn create_pokemon_instance(
         volatile_status: None, // Se inicializa cuando entra en batalla
         individual_values: ivs,
         effort_values: evs,
+        nature,
+        gender,
+        tera_type,
+        terastallized: false,
+        mega_evolution: None,
         base_computed_stats,
         randomized_profile,
     }
@@ -232,6 +260,9 @@ mod tests {
             possible_abilities: vec!["overgrow".into(), "chlorophyll".into()],
             is_starter_candidate: false,
             evolutions: Vec::new(),
+            ev_yield: Stats::zero(),
+            gender_rate: 4,
+            mega_forms: Vec::new(),
         }
     }
 
@@ -313,5 +344,164 @@ mod tests {
         assert_eq!(instance.base_computed_stats.hp, expected_hp);
         assert_eq!(instance.current_hp, expected_hp, "Current HP starts at max HP");
     }
-}
 
+    #[test]
+    fn nature_raises_one_stat_and_lowers_another() {
+        let species = sample_species();
+        let ivs = Stats::zero();
+        let evs = Stats::zero();
+        let neutral = compute_stats(&species.base_stats, &ivs, &evs, 50, Nature::Hardy);
+        let modest = compute_stats(&species.base_stats, &ivs, &evs, 50, Nature::Modest);
+
+        assert_eq!(Nature::Modest.modified_stats(), Some((Stat::SpecialAttack, Stat::Attack)));
+        assert_eq!(modest.special_attack, neutral.special_attack * 110 / 100);
+        assert_eq!(modest.attack, neutral.attack * 90 / 100);
+        assert_eq!(modest.hp, neutral.hp);
+        assert_eq!(modest.speed, neutral.speed);
+
+        // Las naturalezas neutras no cambian nada
+        assert_eq!(Nature::Serious.modified_stats(), None);
+        assert_eq!(compute_stats(&species.base_stats, &ivs, &evs, 50, Nature::Serious), neutral);
+    }
+
+    #[test]
+    fn instance_stats_use_the_rolled_nature() {
+        let species = sample_species();
+        let instance = create_pokemon_instance(&species, 50, 77, false, &[], None);
+        let expected = compute_stats(
+            &species.base_stats,
+            &instance.individual_values,
+            &instance.effort_values,
+            50,
+            instance.nature,
+        );
+        assert_eq!(instance.base_computed_stats, expected);
+    }
+
+    #[test]
+    fn mint_changes_nature_and_recalculates_stats() {
+        let species = sample_species();
+        let mut instance = create_pokemon_instance(&species, 50, 77, false, &[], None);
+
+        assert!(!instance.apply_mint("leftovers"));
+        assert!(instance.apply_mint("timid-mint"));
+        assert_eq!(instance.nature, Nature::Timid);
+        let expected = compute_stats(&species.base_stats, &instance.individual_values, &instance.effort_values, 50, Nature::Timid);
+        assert_eq!(instance.base_computed_stats, expected);
+        assert_eq!(instance.current_hp, expected.hp);
+    }
+
+    #[test]
+    fn ev_yield_falls_back_to_highest_base_stat() {
+        let mut species = sample_species();
+        // Bulbasaur (BST 318): 1 punto en su stat más alta
+        let fallback = species.effective_ev_yield();
+        assert_eq!(fallback.special_attack, 1);
+        assert_eq!(fallback.total(), 1);
+
+        species.ev_yield.special_defense = 2;
+        assert_eq!(species.effective_ev_yield(), species.ev_yield);
+    }
+
+    #[test]
+    fn effort_values_respect_stat_and_total_caps() {
+        use crate::experience::{award_effort_values, MAX_EVS_PER_STAT, MAX_TOTAL_EVS};
+
+        let species = sample_species();
+        let mut instance = create_pokemon_instance(&species, 50, 77, false, &[], None);
+        let hp_yield = Stats { hp: 200, ..Stats::zero() };
+        let attack_yield = Stats { attack: 300, ..Stats::zero() };
+        let speed_yield = Stats { speed: 100, ..Stats::zero() };
+
+        assert!(award_effort_values(&mut instance, &hp_yield));
+        assert!(award_effort_values(&mut instance, &hp_yield));
+        assert_eq!(instance.effort_values.hp, MAX_EVS_PER_STAT);
+        assert!(award_effort_values(&mut instance, &attack_yield));
+        assert_eq!(instance.effort_values.attack, MAX_EVS_PER_STAT);
+        assert!(award_effort_values(&mut instance, &speed_yield));
+        assert_eq!(instance.effort_values.speed, 6);
+        assert_eq!(instance.effort_values.total(), MAX_TOTAL_EVS as u32);
+        // Ya en el tope: no gana nada más
+        assert!(!award_effort_values(&mut instance, &speed_yield));
+
+        let expected = compute_stats(&species.base_stats, &instance.individual_values, &instance.effort_values, 50, instance.nature);
+        assert_eq!(instance.base_computed_stats, expected);
+    }
+
+    #[test]
+    fn vitamins_and_berries_change_effort_values() {
+        use crate::experience::apply_ev_item;
+
+        let species = sample_species();
+        let mut instance = create_pokemon_instance(&species, 50, 77, false, &[], None);
+
+        assert!(!apply_ev_item(&mut instance, "leftovers"));
+        assert!(apply_ev_item(&mut instance, "carbos"));
+        assert!(apply_ev_item(&mut instance, "carbos"));
+        assert_eq!(instance.effort_values.speed, 20);
+        assert!(apply_ev_item(&mut instance, "tamato-berry"));
+        assert_eq!(instance.effort_values.speed, 10);
+        // Las bayas no bajan de 0
+        assert!(apply_ev_item(&mut instance, "pomeg-berry"));
+        assert_eq!(instance.effort_values.hp, 0);
+    }
+
+    #[test]
+    fn recalculating_stats_shifts_current_hp_by_the_max_hp_change() {
+        use crate::experience::apply_ev_item;
+
+        let species = sample_species();
+        let mut instance = create_pokemon_instance(&species, 50, 77, false, &[], None);
+        let max_hp = instance.base_computed_stats.hp;
+
+        // Sin cambio de HP máximo, el HP actual no se toca (ni a 1 HP)
+        instance.current_hp = 1;
+        assert!(apply_ev_item(&mut instance, "carbos"));
+        assert_eq!(instance.current_hp, 1);
+
+        // Con HP Up, el HP actual sube lo mismo que el máximo
+        instance.current_hp = 20;
+        for _ in 0..4 {
+            apply_ev_item(&mut instance, "hp-up");
+        }
+        let gained = instance.base_computed_stats.hp - max_hp;
+        assert!(gained > 0);
+        assert_eq!(instance.current_hp, 20 + gained);
+
+        // Si el máximo baja, un Pokémon en pie se queda con al menos 1 HP
+        instance.current_hp = 1;
+        for _ in 0..4 {
+            apply_ev_item(&mut instance, "pomeg-berry");
+        }
+        assert_eq!(instance.base_computed_stats.hp, max_hp);
+        assert_eq!(instance.current_hp, 1);
+
+        // Un Pokémon debilitado sigue debilitado
+        instance.current_hp = 0;
+        apply_ev_item(&mut instance, "hp-up");
+        assert_eq!(instance.current_hp, 0);
+    }
+
+    #[test]
+    fn gender_follows_species_gender_rate() {
+        let mut species = sample_species();
+
+        species.gender_rate = -1;
+        assert_eq!(create_pokemon_instance(&species, 30, 5, false, &[], None).gender, Gender::Genderless);
+        species.gender_rate = 0;
+        assert_eq!(create_pokemon_instance(&species, 30, 5, false, &[], None).gender, Gender::Male);
+        species.gender_rate = 8;
+        assert_eq!(create_pokemon_instance(&species, 30, 5, false, &[], None).gender, Gender::Female);
+    }
+
+    #[test]
+    fn species_without_gender_rate_is_genderless() {
+        // Pokedex generada antes de existir el campo
+        let mut json = serde_json::to_value(sample_species()).unwrap();
+        json.as_object_mut().unwrap().remove("gender_rate");
+        let species: PokemonSpecies = serde_json::from_value(json).unwrap();
+
+        assert_eq!(species.gender_rate, -1);
+        assert_eq!(create_pokemon_instance(&species, 30, 5, false, &[], None).gender, Gender::Genderless);
+    }
+}
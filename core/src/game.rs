@@ -1,5 +1,11 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use serde::{Deserialize, Serialize};
-use crate::models::{PokemonInstance, WeatherState, TerrainState, BattleFormat, FieldPosition};
+use crate::models::{PokemonInstance, WeatherState, TerrainState, BattleFormat, BattleSide, FieldPosition};
+use crate::i18n::{Locale, LocalizedText};
+use crate::battle::BattleReplay;
+use crate::battle::systems::ai_system::AiDifficulty;
+use crate::battle::systems::action_system::BattleAction;
 
 /// Estado de redirección activo en la batalla
 /// Usado para Follow Me, Rage Powder, Spotlight
@@ -13,6 +19,35 @@ pub struct RedirectionState {
     pub opponent_only: bool,
 }
 
+/// Condiciones de un lado del campo (hazards de entrada, pantallas y Tailwind)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct SideConditions {
+    /// Stealth Rock colocado
+    #[serde(default)]
+    pub stealth_rock: bool,
+    /// Capas de Spikes (0-3)
+    #[serde(default)]
+    pub spikes: u8,
+    /// Capas de Toxic Spikes (0-2)
+    #[serde(default)]
+    pub toxic_spikes: u8,
+    /// Sticky Web colocado
+    #[serde(default)]
+    pub sticky_web: bool,
+    /// Turnos restantes de Reflect (0 = inactivo)
+    #[serde(default)]
+    pub reflect_turns: u8,
+    /// Turnos restantes de Light Screen (0 = inactivo)
+    #[serde(default)]
+    pub light_screen_turns: u8,
+    /// Turnos restantes de Aurora Veil (0 = inactivo)
+    #[serde(default)]
+    pub aurora_veil_turns: u8,
+    /// Turnos restantes de Tailwind (0 = inactivo)
+    #[serde(default)]
+    pub tailwind_turns: u8,
+}
+
 /// Acción pendiente de un Pokémon del jugador
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct PendingPlayerAction {
@@ -22,6 +57,29 @@ pub struct PendingPlayerAction {
     pub move_id: String,
     /// Posición del objetivo (si aplica)
     pub target_position: Option<FieldPosition>,
+    /// Índice en el equipo del Pokémon al que cambiar (si la acción es un cambio)
+    #[serde(default)]
+    pub switch_to: Option<usize>,
+    /// Si el Pokémon se teracristaliza antes de atacar (una vez por batalla)
+    #[serde(default)]
+    pub terastallize: bool,
+    /// Si el Pokémon megaevoluciona antes de atacar (una vez por batalla)
+    #[serde(default)]
+    pub mega_evolve: bool,
+}
+
+impl PendingPlayerAction {
+    /// Acción de cambiar el Pokémon del slot `user_index` por `switch_to`
+    pub fn switch(user_index: usize, switch_to: usize) -> Self {
+        Self {
+            user_index,
+            move_id: String::new(),
+            target_position: None,
+            switch_to: Some(switch_to),
+            terastallize: false,
+            mega_evolve: false,
+        }
+    }
 }
 
 /// Estado actual del juego (pantalla/contexto en el que está el usuario)
@@ -59,6 +117,19 @@ pub struct GameConfig {
     /// Los encuentros salvajes siempre son Single, independientemente de este valor
     #[serde(default)]
     pub preferred_format: BattleFormat,
+    /// Idioma de la narración de batalla y de los mensajes del servidor
+    #[serde(default)]
+    pub locale: Locale,
+    /// Semilla maestra de la partida: todos los RNG de la sesión se derivan de ella
+    #[serde(default)]
+    pub seed: u64,
+    /// Dificultad de la IA de los líderes de gimnasio
+    #[serde(default = "default_gym_ai_difficulty")]
+    pub gym_ai_difficulty: AiDifficulty,
+}
+
+fn default_gym_ai_difficulty() -> AiDifficulty {
+    AiDifficulty::Smart
 }
 
 /// Equipo del jugador
@@ -102,6 +173,14 @@ impl PlayerTeam {
             member.full_restore();
         }
     }
+
+    /// Devuelve a todo el equipo a su forma normal al terminar una batalla
+    /// (deshace Megaevoluciones y Teracristalización)
+    pub fn revert_battle_forms(&mut self) {
+        for member in &mut self.active_members {
+            member.revert_battle_forms();
+        }
+    }
 }
 
 impl Default for PlayerTeam {
@@ -135,6 +214,12 @@ pub struct GameSession {
     pub config: GameConfig,
     /// Número de encuentros ganados
     pub encounters_won: u32,
+    /// Cantidad de RNG derivados de la semilla maestra hasta ahora
+    #[serde(default)]
+    pub rng_draws: u64,
+    /// Grabación de la batalla en curso (o de la última, hasta que empiece otra)
+    #[serde(default)]
+    pub replay: Option<BattleReplay>,
 }
 
 impl GameSession {
@@ -155,8 +240,28 @@ impl GameSession {
             battle: None,
             config,
             encounters_won: 0,
+            rng_draws: 0,
+            replay: None,
         }
     }
+
+    /// Deriva el siguiente RNG de la sesión a partir de la semilla maestra
+    ///
+    /// Cada llamada avanza `rng_draws`, así que la misma semilla con la misma
+    /// secuencia de acciones reproduce la partida exactamente.
+    pub fn next_rng(&mut self) -> StdRng {
+        let seed = derive_seed(self.config.seed, self.rng_draws);
+        self.rng_draws += 1;
+        StdRng::seed_from_u64(seed)
+    }
+}
+
+/// Mezcla la semilla maestra con un índice (SplitMix64) para obtener semillas independientes
+pub fn derive_seed(master: u64, index: u64) -> u64 {
+    let mut z = master.wrapping_add(index.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
 }
 
 impl Default for GameConfig {
@@ -166,6 +271,9 @@ impl Default for GameConfig {
             total_encounters: 20,
             chaos_move_randomizer: false,
             preferred_format: BattleFormat::Single,
+            locale: Locale::default(),
+            seed: 0,
+            gym_ai_difficulty: default_gym_ai_difficulty(),
         }
     }
 }
@@ -227,6 +335,72 @@ pub struct BattleState {
     /// Turnos restantes de Trick Room (máximo 5)
     #[serde(default)]
     pub trick_room_turns_left: u8,
+
+    /// Condiciones del lado del jugador (hazards colocados por el rival)
+    #[serde(default)]
+    pub player_side: SideConditions,
+    /// Condiciones del lado del oponente
+    #[serde(default)]
+    pub opponent_side: SideConditions,
+
+    /// Idioma en el que se renderizan los logs (copiado de `GameConfig::locale`)
+    #[serde(default)]
+    pub locale: Locale,
+
+    /// Semilla propia de la batalla (derivada del RNG de la sesión al crearla)
+    #[serde(default)]
+    pub rng_seed: u64,
+    /// Cantidad de semillas de turno derivadas de `rng_seed` hasta ahora
+    #[serde(default)]
+    pub rng_draws: u64,
+
+    /// Dificultad de la IA del oponente
+    ///
+    /// Los salvajes eligen al azar y los entrenadores maximizan el daño; las
+    /// batallas de gimnasio la sobrescriben con `GameConfig::gym_ai_difficulty`.
+    #[serde(default)]
+    pub ai_difficulty: AiDifficulty,
+
+    /// Turno detenido a la espera de que el jugador elija el relevo de un
+    /// movimiento de cambio (U-turn, Baton Pass...) o de Eject Button/Eject Pack
+    #[serde(default)]
+    pub pending_pivot: Option<PendingPivot>,
+
+    /// Índices del equipo rival debilitados que ya repartieron sus EVs
+    #[serde(default)]
+    pub ev_awarded_opponents: Vec<usize>,
+
+    /// Índice en el equipo del Pokémon del jugador que se teracristalizó
+    /// (la Teracristalización se usa una vez por batalla)
+    #[serde(default)]
+    pub player_terastallized: Option<usize>,
+    /// Índice en el equipo del Pokémon rival que se teracristalizó
+    #[serde(default)]
+    pub opponent_terastallized: Option<usize>,
+
+    /// Índice en el equipo del Pokémon del jugador que megaevolucionó
+    /// (la Megaevolución se usa una vez por batalla)
+    #[serde(default)]
+    pub player_mega_evolved: Option<usize>,
+    /// Índice en el equipo del Pokémon rival que megaevolucionó
+    #[serde(default)]
+    pub opponent_mega_evolved: Option<usize>,
+}
+
+/// Relevo que el jugador debe elegir antes de que el turno continúe
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PendingPivot {
+    /// Slots del campo del jugador que deben cambiar, en orden
+    pub slots: Vec<usize>,
+    /// Si el que entra hereda los cambios de stats y el sustituto (Baton Pass)
+    #[serde(default)]
+    pub baton_pass: bool,
+    /// Acciones del turno que quedan por ejecutar
+    #[serde(default)]
+    pub remaining_actions: Vec<BattleAction>,
+    /// Si al terminar las acciones se aplican los efectos de final de turno
+    #[serde(default)]
+    pub end_of_turn: bool,
 }
 
 impl BattleState {
@@ -299,6 +473,75 @@ impl BattleState {
             pending_player_actions: Vec::new(),
             trick_room_active: false,
             trick_room_turns_left: 0,
+            player_side: SideConditions::default(),
+            opponent_side: SideConditions::default(),
+            locale: Locale::default(),
+            rng_seed: 0,
+            rng_draws: 0,
+            ai_difficulty: if is_trainer_battle { AiDifficulty::Greedy } else { AiDifficulty::Random },
+            pending_pivot: None,
+            ev_awarded_opponents: Vec::new(),
+            player_terastallized: None,
+            opponent_terastallized: None,
+            player_mega_evolved: None,
+            opponent_mega_evolved: None,
+        }
+    }
+
+    /// Deriva la semilla del RNG del siguiente turno a partir de `rng_seed`
+    ///
+    /// Se devuelve la semilla (y no el RNG) para poder grabarla en el replay.
+    pub fn next_turn_seed(&mut self) -> u64 {
+        let seed = derive_seed(self.rng_seed, self.rng_draws);
+        self.rng_draws += 1;
+        seed
+    }
+
+    /// Condiciones de un lado del campo
+    pub fn side(&self, side: BattleSide) -> &SideConditions {
+        match side {
+            BattleSide::Player => &self.player_side,
+            BattleSide::Opponent => &self.opponent_side,
+        }
+    }
+
+    /// Condiciones de un lado del campo (mutable)
+    pub fn side_mut(&mut self, side: BattleSide) -> &mut SideConditions {
+        match side {
+            BattleSide::Player => &mut self.player_side,
+            BattleSide::Opponent => &mut self.opponent_side,
+        }
+    }
+
+    /// Pokémon de un lado que se teracristalizó (mutable)
+    pub fn terastallized_mut(&mut self, side: BattleSide) -> &mut Option<usize> {
+        match side {
+            BattleSide::Player => &mut self.player_terastallized,
+            BattleSide::Opponent => &mut self.opponent_terastallized,
+        }
+    }
+
+    /// Si el lado aún puede teracristalizar a un Pokémon en esta batalla
+    pub fn tera_available(&self, side: BattleSide) -> bool {
+        match side {
+            BattleSide::Player => self.player_terastallized.is_none(),
+            BattleSide::Opponent => self.opponent_terastallized.is_none(),
+        }
+    }
+
+    /// Pokémon de un lado que megaevolucionó (mutable)
+    pub fn mega_evolved_mut(&mut self, side: BattleSide) -> &mut Option<usize> {
+        match side {
+            BattleSide::Player => &mut self.player_mega_evolved,
+            BattleSide::Opponent => &mut self.opponent_mega_evolved,
+        }
+    }
+
+    /// Si el lado aún puede megaevolucionar a un Pokémon en esta batalla
+    pub fn mega_available(&self, side: BattleSide) -> bool {
+        match side {
+            BattleSide::Player => self.player_mega_evolved.is_none(),
+            BattleSide::Opponent => self.opponent_mega_evolved.is_none(),
         }
     }
 
@@ -379,5 +622,56 @@ impl BattleState {
     pub fn add_log(&mut self, message: String) {
         self.log.push(message);
     }
+
+    /// Añade un mensaje del catálogo al log, resuelto en el locale de la batalla
+    pub fn add_localized_log(&mut self, text: LocalizedText) {
+        let message = text.resolve(self.locale);
+        self.log.push(message);
+    }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+    use crate::battle::test_fixtures::{mega_form, moves, pokemon};
+
+    #[test]
+    fn test_next_rng_is_reproducible_from_seed() {
+        let config = GameConfig { seed: 42, ..GameConfig::default() };
+        let mut a = GameSession::with_config("a".to_string(), config.clone());
+        let mut b = GameSession::with_config("b".to_string(), config);
+
+        let rolls_a: Vec<u64> = (0..3).map(|_| a.next_rng().gen()).collect();
+        let rolls_b: Vec<u64> = (0..3).map(|_| b.next_rng().gen()).collect();
+
+        assert_eq!(rolls_a, rolls_b);
+        assert_eq!(a.rng_draws, 3);
+        // Cada RNG derivado es distinto del anterior
+        assert_ne!(rolls_a[0], rolls_a[1]);
+    }
+
+    #[test]
+    fn test_different_seeds_diverge() {
+        assert_ne!(derive_seed(1, 0), derive_seed(2, 0));
+        assert_ne!(derive_seed(1, 0), derive_seed(1, 1));
+    }
+
+    #[test]
+    fn test_mega_evolution_keeps_current_hp() {
+        let moves = moves();
+        let mut pokemon = pokemon("player", "run-away", &moves);
+        pokemon.species.mega_forms = vec![mega_form()];
+        let form = mega_form();
+
+        // La forma Mega conserva el HP base: ningún HP actual debe cambiar
+        for current_hp in 1..=pokemon.base_computed_stats.hp {
+            pokemon.current_hp = current_hp;
+            pokemon.mega_evolve(&form);
+            assert_eq!(pokemon.current_hp, current_hp);
+            pokemon.revert_mega_evolution();
+            assert_eq!(pokemon.current_hp, current_hp);
+        }
+    }
+}
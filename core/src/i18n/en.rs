@@ -0,0 +1,179 @@
+//! English catalog
+
+pub(super) fn template(key: &str) -> Option<&'static str> {
+    let template = match key {
+        // --- Actions ---
+        "battle.move_used" => "{user} used {move}!",
+        "battle.no_target" => "{user} used {move}, but there was no target!",
+        "battle.move_failed" => "But it failed!",
+        "battle.hit_missed" => "Hit {hit} missed!",
+        "battle.critical_hit" => "A critical hit!",
+        "battle.super_effective" => "It's super effective!",
+        "battle.not_very_effective" => "It's not very effective...",
+        "battle.no_effect" => "It doesn't affect {target}...",
+        "battle.fainted" => "{target} fainted!",
+        "battle.switched.player" => "Go, {pokemon}!",
+        "battle.switched.trainer" => "{trainer} sent out {pokemon}!",
+        "battle.withdrew" => "{pokemon}, come back!",
+        "battle.ability_activated" => "{pokemon}'s {ability}!",
+        "battle.item_activated" => "{pokemon}'s {item} activated!",
+
+        // --- Damage ---
+        "battle.damage.move" => "{target} took {amount} damage",
+        "battle.damage.multi_hit" => "Hit {hits} times! {target} took {amount} total damage",
+        "battle.damage.recoil" => "{target} took {amount} recoil damage!",
+        "battle.damage.sandstorm" => "{target} is buffeted by the sandstorm!",
+        "battle.damage.hail" => "{target} is pelted by hail!",
+        "battle.damage.weather" => "{target} is hurt by the weather!",
+        "battle.damage.burn" => "{target} was hurt by its burn!",
+        "battle.damage.poison" => "{target} was hurt by poison!",
+        "battle.damage.bad_poison" => "{target} was badly hurt by poison!",
+        "battle.damage.status" => "{target} is hurt by its status!",
+        "battle.damage.confusion" => "{target} hurt itself in its confusion and lost {amount} HP!",
+        "battle.damage.leech_seed" => "{target} lost {amount} HP to Leech Seed!",
+        "battle.damage.perish_song" => "{target} was knocked out by Perish Song!",
+        "battle.damage.ability" => "{target} was hurt by {holder}'s {ability}!",
+        "battle.damage.own_item" => "{target} lost {amount} HP from its {item}!",
+        "battle.damage.item" => "{target} was hurt by {holder}'s {item}!",
+
+        // --- Healing ---
+        "battle.heal.move" => "{target} restored {amount} HP!",
+        "battle.heal.terrain" => "{target} restored {amount} HP thanks to {terrain}!",
+        "battle.heal.leech_seed" => "{target} drained {amount} HP!",
+        "battle.heal.ability" => "{target} restored {amount} HP thanks to {ability}!",
+        "battle.heal.item" => "{target} restored {amount} HP with its {item}!",
+
+        // --- Stats ---
+        "battle.stat.rose" => "{target}'s {stat} rose!",
+        "battle.stat.rose_sharply" => "{target}'s {stat} rose sharply!",
+        "battle.stat.fell" => "{target}'s {stat} fell!",
+        "battle.stat.fell_harshly" => "{target}'s {stat} harshly fell!",
+        "battle.stat.loss_prevented" => "{pokemon}'s ability prevents its stats from being lowered!",
+        "stat.attack" => "Attack",
+        "stat.defense" => "Defense",
+        "stat.special_attack" => "Sp. Atk",
+        "stat.special_defense" => "Sp. Def",
+        "stat.speed" => "Speed",
+        "stat.accuracy" => "accuracy",
+        "stat.evasion" => "evasiveness",
+
+        // --- Status conditions ---
+        "battle.status.burned" => "{target} was burned!",
+        "battle.status.paralyzed" => "{target} is paralyzed!",
+        "battle.status.poisoned" => "{target} was poisoned!",
+        "battle.status.badly_poisoned" => "{target} was badly poisoned!",
+        "battle.status.asleep" => "{target} fell asleep!",
+        "battle.status.frozen" => "{target} was frozen solid!",
+        "battle.status.woke_up" => "{target} woke up!",
+        "battle.status.thawed" => "{target} thawed out!",
+        "battle.status.paralysis_cured" => "{target} is no longer paralyzed!",
+        "battle.status.burn_cured" => "{target}'s burn was healed!",
+        "battle.status.poison_cured" => "{target} was cured of its poisoning!",
+        "battle.status.is_asleep" => "{pokemon} is fast asleep!",
+        "battle.status.is_frozen" => "{pokemon} is frozen solid!",
+        "battle.status.fully_paralyzed" => "{pokemon} is paralyzed! It can't move!",
+        "battle.status.is_confused" => "{pokemon} is confused!",
+        "battle.status.confusion_resisted" => "{pokemon} pushed through its confusion this turn!",
+        "battle.status.confused" => "{pokemon} became confused!",
+        "battle.status.already_confused" => "{pokemon} is already confused!",
+        "battle.status.confusion_prevented" => "{pokemon} can't be confused!",
+        "battle.status.confusion_ended" => "{pokemon} snapped out of its confusion!",
+        "battle.status.is_infatuated" => "{pokemon} is in love!",
+        "battle.status.immobilized_by_love" => "{pokemon} is immobilized by love!",
+        "battle.status.infatuation_resisted" => "{pokemon} pushed through its infatuation this turn!",
+        "battle.status.infatuated" => "{pokemon} fell in love!",
+        "battle.status.infatuation_prevented" => "{pokemon} can't fall in love!",
+        "battle.status.infatuation_cured" => "{pokemon} got over its infatuation!",
+
+        // --- Volatiles and protection ---
+        "battle.flinched" => "{pokemon} flinched!",
+        "battle.flinched_cant_move" => "{pokemon} flinched and couldn't move!",
+        "battle.must_recharge" => "{pokemon} must recharge!",
+        "battle.charging" => "{pokemon} is charging up!",
+        "battle.charged_move_released" => "{pokemon} unleashed {move}!",
+        "battle.protected" => "{pokemon} protected itself!",
+        "battle.protection_blocked" => "{protection} protected {pokemon}!",
+        "battle.forced_switch" => "{pokemon} will be forced to switch out!",
+        "battle.levitate" => "{pokemon} levitated over the attack!",
+        "battle.perish_count" => "{pokemon}'s perish count fell to {count}!",
+
+        // --- Weather ---
+        "battle.weather.sun" => "The sunlight turned harsh!",
+        "battle.weather.rain" => "It started to rain!",
+        "battle.weather.sandstorm" => "A sandstorm kicked up!",
+        "battle.weather.hail" => "It started to hail!",
+        "battle.weather.ended" => "The weather returned to normal!",
+
+        // --- Terrain ---
+        "battle.terrain.electric" => "An electric current ran across the battlefield!",
+        "battle.terrain.grassy" => "Grass grew to cover the battlefield!",
+        "battle.terrain.misty" => "Mist swirled around the battlefield!",
+        "battle.terrain.psychic" => "The battlefield got weird!",
+        "battle.terrain.ended" => "The terrain returned to normal!",
+        "battle.terrain.electric_boost" => "Electric Terrain boosted {pokemon}'s attack!",
+        "battle.terrain.psychic_boost" => "Psychic Terrain boosted {pokemon}'s attack!",
+        "battle.terrain.grassy_boost" => "Grassy Terrain boosted {pokemon}'s attack!",
+        "battle.terrain.grassy_weakens" => "Grassy Terrain weakened {move}!",
+        "battle.terrain.misty_weakens" => "Misty Terrain weakened {move}!",
+        "battle.terrain.electric_prevents_sleep" => "Electric Terrain prevents sleep!",
+        "battle.terrain.misty_protects" => "Misty Terrain protects {pokemon}!",
+        "battle.terrain.psychic_blocks_priority" => "Psychic Terrain protects {pokemon} from priority moves!",
+
+        // --- Entry hazards ---
+        "side.player" => "your team",
+        "side.opponent" => "the opposing team",
+        "battle.hazard.stealth_rock" => "Pointed stones float in the air around {side}!",
+        "battle.hazard.spikes" => "Spikes were scattered on the ground all around {side}!",
+        "battle.hazard.toxic_spikes" => "Poison spikes were scattered on the ground all around {side}!",
+        "battle.hazard.sticky_web" => "A sticky web has been laid out on the ground around {side}!",
+        "battle.hazard.cleared" => "The hazards around {side} disappeared!",
+        "battle.hazard.toxic_spikes_absorbed" => "{pokemon} absorbed the poison spikes!",
+        "battle.damage.stealth_rock" => "Pointed stones dug into {target}!",
+        "battle.damage.spikes" => "{target} was hurt by the spikes!",
+        "battle.side_condition.reflect" => "Reflect made {side} stronger against physical moves!",
+        "battle.side_condition.light_screen" => "Light Screen made {side} stronger against special moves!",
+        "battle.side_condition.aurora_veil" => "Aurora Veil made {side} stronger against physical and special moves!",
+        "battle.side_condition.tailwind" => "The Tailwind blew from behind {side}!",
+        "battle.side_condition.reflect_ended" => "The Reflect protecting {side} wore off!",
+        "battle.side_condition.light_screen_ended" => "The Light Screen protecting {side} wore off!",
+        "battle.side_condition.aurora_veil_ended" => "The Aurora Veil protecting {side} wore off!",
+        "battle.side_condition.tailwind_ended" => "The Tailwind behind {side} petered out!",
+        "battle.side_condition.screens_broken" => "The screens protecting {side} were shattered!",
+        "battle.substitute.created" => "{pokemon} put in a substitute!",
+        "battle.substitute.damaged" => "The substitute took damage for {pokemon}!",
+        "battle.substitute.broken" => "{pokemon}'s substitute faded!",
+        "battle.substitute.already" => "{pokemon} already has a substitute!",
+        "battle.substitute.too_weak" => "{pokemon} is too weak to make a substitute!",
+        "battle.terastallized" => "{pokemon} terastallized into the {type} type!",
+        "battle.mega_evolved" => "{pokemon} has Mega Evolved into {form}!",
+        "terrain.electric" => "Electric Terrain",
+        "terrain.grassy" => "Grassy Terrain",
+        "terrain.misty" => "Misty Terrain",
+        "terrain.psychic" => "Psychic Terrain",
+
+        // --- Items ---
+        "battle.item.endured" => "{pokemon} hung on using its {item}!",
+        "battle.item.air_balloon_popped" => "{pokemon}'s {item} popped!",
+        "battle.item.damage_weakened" => "The {item} weakened the damage to {pokemon}!",
+        "battle.item.booster_energy" => "{pokemon} used its {item} to activate {ability}!",
+        "battle.item.stat_loss_prevented" => "The effects of {pokemon}'s {item} prevent its stats from being lowered!",
+
+        // --- Trick Room ---
+        "battle.trick_room.ended" => "The twisted dimensions returned to normal!",
+        "battle.trick_room.active" => "Trick Room is active ({turns} turns left)",
+
+        // --- Server ---
+        "server.wild_appeared" => "A wild {pokemon} appeared!",
+        "server.must_switch" => "You need to switch Pokémon!",
+        "server.must_pivot" => "Choose which Pokémon comes in!",
+        "server.battle_won" => "You won the battle!",
+        "server.battle_lost" => "You were defeated...",
+        "server.level_up" => "{pokemon} grew to level {level}!",
+        "server.team_healed" => "Your team was fully restored!",
+        "server.team_learned_moves" => "Your team learned new moves!",
+        "server.captured_learned_moves" => "{pokemon} learned {count} new moves upon capture!",
+        "server.loot_received" => "You received reward items!",
+        _ => return None,
+    };
+    Some(template)
+}
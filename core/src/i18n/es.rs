@@ -0,0 +1,179 @@
+//! Catálogo en español
+
+pub(super) fn template(key: &str) -> Option<&'static str> {
+    let template = match key {
+        // --- Acciones ---
+        "battle.move_used" => "{user} usó {move}",
+        "battle.no_target" => "¡{user} usó {move}, pero no había objetivo!",
+        "battle.move_failed" => "¡Pero falló!",
+        "battle.hit_missed" => "¡Falló el golpe {hit}!",
+        "battle.critical_hit" => "¡Golpe crítico!",
+        "battle.super_effective" => "¡Es súper efectivo!",
+        "battle.not_very_effective" => "No es muy efectivo...",
+        "battle.no_effect" => "No afecta a {target}...",
+        "battle.fainted" => "¡{target} se debilitó!",
+        "battle.switched.player" => "¡Ve, {pokemon}!",
+        "battle.switched.trainer" => "¡{trainer} envió a {pokemon}!",
+        "battle.withdrew" => "¡{pokemon}, vuelve!",
+        "battle.ability_activated" => "¡{ability} de {pokemon}!",
+        "battle.item_activated" => "¡{item} de {pokemon} se activó!",
+
+        // --- Daño ---
+        "battle.damage.move" => "{target} recibió {amount} de daño",
+        "battle.damage.multi_hit" => "¡Golpeó {hits} veces! {target} recibió {amount} de daño total",
+        "battle.damage.recoil" => "¡{target} recibió {amount} de daño de retroceso!",
+        "battle.damage.sandstorm" => "¡La tormenta de arena golpea a {target}!",
+        "battle.damage.hail" => "¡El granizo golpea a {target}!",
+        "battle.damage.weather" => "¡El clima daña a {target}!",
+        "battle.damage.burn" => "¡{target} se lastima por la quemadura!",
+        "battle.damage.poison" => "¡{target} sufre por el veneno!",
+        "battle.damage.bad_poison" => "¡{target} sufre gravemente por el veneno!",
+        "battle.damage.status" => "¡{target} sufre por su estado!",
+        "battle.damage.confusion" => "¡{target} se golpeó a sí mismo en confusión y perdió {amount} HP!",
+        "battle.damage.leech_seed" => "¡{target} pierde {amount} HP por Leech Seed!",
+        "battle.damage.perish_song" => "¡{target} fue debilitado por Perish Song!",
+        "battle.damage.ability" => "¡{target} fue herido por {ability} de {holder}!",
+        "battle.damage.own_item" => "¡{target} perdió {amount} HP por su {item}!",
+        "battle.damage.item" => "¡{target} fue herido por el {item} de {holder}!",
+
+        // --- Curación ---
+        "battle.heal.move" => "¡{target} recuperó {amount} PS!",
+        "battle.heal.terrain" => "¡{target} se curó {amount} HP por {terrain}!",
+        "battle.heal.leech_seed" => "¡{target} absorbe {amount} HP!",
+        "battle.heal.ability" => "¡{target} recuperó {amount} PS gracias a {ability}!",
+        "battle.heal.item" => "¡{target} recuperó {amount} PS con su {item}!",
+
+        // --- Stats ---
+        "battle.stat.rose" => "¡El {stat} de {target} subió!",
+        "battle.stat.rose_sharply" => "¡El {stat} de {target} subió mucho!",
+        "battle.stat.fell" => "¡El {stat} de {target} bajó!",
+        "battle.stat.fell_harshly" => "¡El {stat} de {target} bajó mucho!",
+        "battle.stat.loss_prevented" => "¡La habilidad de {pokemon} evita que bajen sus características!",
+        "stat.attack" => "ataque",
+        "stat.defense" => "defensa",
+        "stat.special_attack" => "ataque especial",
+        "stat.special_defense" => "defensa especial",
+        "stat.speed" => "velocidad",
+        "stat.accuracy" => "precisión",
+        "stat.evasion" => "evasión",
+
+        // --- Estados ---
+        "battle.status.burned" => "¡{target} fue quemado!",
+        "battle.status.paralyzed" => "¡{target} fue paralizado!",
+        "battle.status.poisoned" => "¡{target} fue envenenado!",
+        "battle.status.badly_poisoned" => "¡{target} fue gravemente envenenado!",
+        "battle.status.asleep" => "¡{target} fue dormido!",
+        "battle.status.frozen" => "¡{target} fue congelado!",
+        "battle.status.woke_up" => "¡{target} se despertó!",
+        "battle.status.thawed" => "¡{target} se descongeló!",
+        "battle.status.paralysis_cured" => "¡{target} ya no está paralizado!",
+        "battle.status.burn_cured" => "¡{target} se curó de la quemadura!",
+        "battle.status.poison_cured" => "¡{target} se curó del envenenamiento!",
+        "battle.status.is_asleep" => "¡{pokemon} está dormido!",
+        "battle.status.is_frozen" => "¡{pokemon} está congelado!",
+        "battle.status.fully_paralyzed" => "¡{pokemon} está paralizado y no se puede mover!",
+        "battle.status.is_confused" => "¡{pokemon} está confundido!",
+        "battle.status.confusion_resisted" => "¡{pokemon} superó la confusión este turno!",
+        "battle.status.confused" => "¡{pokemon} se confundió!",
+        "battle.status.already_confused" => "¡{pokemon} ya está confundido!",
+        "battle.status.confusion_prevented" => "¡{pokemon} no puede confundirse!",
+        "battle.status.confusion_ended" => "¡{pokemon} ya no está confundido!",
+        "battle.status.is_infatuated" => "¡{pokemon} está enamorado!",
+        "battle.status.immobilized_by_love" => "¡{pokemon} está inmobilizado por el amor!",
+        "battle.status.infatuation_resisted" => "¡{pokemon} superó la infatuación este turno!",
+        "battle.status.infatuated" => "¡{pokemon} se enamoró!",
+        "battle.status.infatuation_prevented" => "¡{pokemon} no puede enamorarse!",
+        "battle.status.infatuation_cured" => "¡{pokemon} ya no está enamorado!",
+
+        // --- Volátiles y protecciones ---
+        "battle.flinched" => "¡{pokemon} retrocedió!",
+        "battle.flinched_cant_move" => "¡{pokemon} retrocedió y no pudo atacar!",
+        "battle.must_recharge" => "¡{pokemon} debe recargar energía!",
+        "battle.charging" => "¡{pokemon} está cargando energía!",
+        "battle.charged_move_released" => "¡{pokemon} lanzó {move}!",
+        "battle.protected" => "¡{pokemon} se protegió!",
+        "battle.protection_blocked" => "¡{protection} protegió a {pokemon}!",
+        "battle.forced_switch" => "¡{pokemon} será forzado a cambiar!",
+        "battle.levitate" => "¡{pokemon} levitó sobre el ataque!",
+        "battle.perish_count" => "¡El contador de Perish Song de {pokemon} es {count}!",
+
+        // --- Clima ---
+        "battle.weather.sun" => "¡El sol se intensificó!",
+        "battle.weather.rain" => "¡Comenzó a llover!",
+        "battle.weather.sandstorm" => "¡Se desató una tormenta de arena!",
+        "battle.weather.hail" => "¡Comenzó a granizar!",
+        "battle.weather.ended" => "¡El clima volvió a la normalidad!",
+
+        // --- Terreno ---
+        "battle.terrain.electric" => "¡Una corriente eléctrica recorre el campo!",
+        "battle.terrain.grassy" => "¡Hierba crece en el combate!",
+        "battle.terrain.misty" => "¡Una niebla mística envuelve el campo!",
+        "battle.terrain.psychic" => "¡El campo se llena de energía psíquica!",
+        "battle.terrain.ended" => "¡El terreno volvió a la normalidad!",
+        "battle.terrain.electric_boost" => "¡El Campo Eléctrico potencia el ataque de {pokemon}!",
+        "battle.terrain.psychic_boost" => "¡El Campo Psíquico potencia el ataque de {pokemon}!",
+        "battle.terrain.grassy_boost" => "¡El Campo de Hierba potencia el ataque de {pokemon}!",
+        "battle.terrain.grassy_weakens" => "¡El Campo de Hierba reduce el daño de {move}!",
+        "battle.terrain.misty_weakens" => "¡El Campo de Niebla reduce el daño de {move}!",
+        "battle.terrain.electric_prevents_sleep" => "¡El Campo Eléctrico evita el sueño!",
+        "battle.terrain.misty_protects" => "¡El Campo de Niebla protege a {pokemon}!",
+        "battle.terrain.psychic_blocks_priority" => "¡El Campo Psíquico protege a {pokemon} de ataques rápidos!",
+
+        // --- Hazards de entrada ---
+        "side.player" => "tu equipo",
+        "side.opponent" => "los rivales",
+        "battle.hazard.stealth_rock" => "¡Piedras puntiagudas flotan alrededor de {side}!",
+        "battle.hazard.spikes" => "¡Se esparcieron púas alrededor de {side}!",
+        "battle.hazard.toxic_spikes" => "¡Se esparcieron púas tóxicas alrededor de {side}!",
+        "battle.hazard.sticky_web" => "¡Una red pegajosa se extendió bajo {side}!",
+        "battle.hazard.cleared" => "¡Desaparecieron las trampas alrededor de {side}!",
+        "battle.hazard.toxic_spikes_absorbed" => "¡{pokemon} absorbió las púas tóxicas!",
+        "battle.damage.stealth_rock" => "¡Piedras puntiagudas hieren a {target}!",
+        "battle.damage.spikes" => "¡{target} se hirió con las púas!",
+        "battle.side_condition.reflect" => "¡Reflejo reforzó a {side} contra los ataques físicos!",
+        "battle.side_condition.light_screen" => "¡Pantalla de Luz reforzó a {side} contra los ataques especiales!",
+        "battle.side_condition.aurora_veil" => "¡Velo Aurora reforzó a {side} contra los ataques físicos y especiales!",
+        "battle.side_condition.tailwind" => "¡Sopla un viento afín a favor de {side}!",
+        "battle.side_condition.reflect_ended" => "¡El Reflejo que protegía a {side} se disipó!",
+        "battle.side_condition.light_screen_ended" => "¡La Pantalla de Luz que protegía a {side} se disipó!",
+        "battle.side_condition.aurora_veil_ended" => "¡El Velo Aurora que protegía a {side} se disipó!",
+        "battle.side_condition.tailwind_ended" => "¡El viento afín de {side} dejó de soplar!",
+        "battle.side_condition.screens_broken" => "¡Se rompieron las pantallas que protegían a {side}!",
+        "battle.substitute.created" => "¡{pokemon} creó un sustituto!",
+        "battle.substitute.damaged" => "¡El sustituto recibió el daño en lugar de {pokemon}!",
+        "battle.substitute.broken" => "¡El sustituto de {pokemon} se rompió!",
+        "battle.substitute.already" => "¡{pokemon} ya tiene un sustituto!",
+        "battle.substitute.too_weak" => "¡A {pokemon} no le quedan PS suficientes para crear un sustituto!",
+        "battle.terastallized" => "¡{pokemon} se teracristalizó en tipo {type}!",
+        "battle.mega_evolved" => "¡{pokemon} megaevolucionó en {form}!",
+        "terrain.electric" => "Campo Eléctrico",
+        "terrain.grassy" => "Campo de Hierba",
+        "terrain.misty" => "Campo de Niebla",
+        "terrain.psychic" => "Campo Psíquico",
+
+        // --- Objetos ---
+        "battle.item.endured" => "¡{pokemon} aguantó el golpe gracias a su {item}!",
+        "battle.item.air_balloon_popped" => "¡El {item} de {pokemon} explotó!",
+        "battle.item.damage_weakened" => "¡{item} debilitó el ataque contra {pokemon}!",
+        "battle.item.booster_energy" => "¡{pokemon} usó su {item} para activar {ability}!",
+        "battle.item.stat_loss_prevented" => "¡{item} de {pokemon} evita que bajen sus características!",
+
+        // --- Trick Room ---
+        "battle.trick_room.ended" => "¡Las dimensiones retornaron a la normalidad!",
+        "battle.trick_room.active" => "Trick Room activo ({turns} turnos restantes)",
+
+        // --- Servidor ---
+        "server.wild_appeared" => "¡Un {pokemon} salvaje apareció!",
+        "server.must_switch" => "¡Necesitas cambiar de Pokémon!",
+        "server.must_pivot" => "¡Elige qué Pokémon entra al campo!",
+        "server.battle_won" => "¡Has ganado la batalla!",
+        "server.battle_lost" => "Has sido derrotado...",
+        "server.level_up" => "¡{pokemon} subió al nivel {level}!",
+        "server.team_healed" => "¡El equipo ha recuperado toda su energía!",
+        "server.team_learned_moves" => "¡El equipo aprendió nuevos movimientos!",
+        "server.captured_learned_moves" => "{pokemon} aprendió {count} movimientos nuevos al ser capturado!",
+        "server.loot_received" => "¡Has recibido objetos de recompensa!",
+        _ => return None,
+    };
+    Some(template)
+}
@@ -0,0 +1,138 @@
+//! Localización de la narración (es/en)
+//!
+//! Los mensajes de batalla y del servidor se identifican con una clave
+//! (`"battle.move_used"`) más un conjunto de parámetros con nombre
+//! (`{user}`, `{move}`). Cada locale aporta su catálogo de plantillas y la
+//! resolución ocurre al renderizar, de modo que una misma secuencia de
+//! eventos puede mostrarse en español o en inglés.
+//!
+//! Si una clave no existe en el locale pedido se usa la plantilla en español,
+//! y si tampoco existe ahí se devuelve la clave tal cual.
+
+mod en;
+mod es;
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Idioma de la narración
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    /// Español (idioma original del juego)
+    #[default]
+    Es,
+    /// Inglés
+    En,
+}
+
+/// Mensaje localizable: clave del catálogo más parámetros con nombre
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct LocalizedText {
+    /// Clave del catálogo (ej: "battle.move_failed")
+    pub key: String,
+    /// Valores para los placeholders `{nombre}` de la plantilla
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+}
+
+impl LocalizedText {
+    /// Crea un mensaje a partir de su clave y sus parámetros
+    pub fn new<const N: usize>(key: &str, params: [(&str, String); N]) -> Self {
+        Self {
+            key: key.to_string(),
+            params: params
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        }
+    }
+
+    /// Resuelve el mensaje en el locale indicado
+    pub fn resolve(&self, locale: Locale) -> String {
+        translate(locale, &self.key, &self.params)
+    }
+}
+
+/// Resuelve una clave con sus parámetros en el locale indicado
+pub fn translate(locale: Locale, key: &str, params: &BTreeMap<String, String>) -> String {
+    match template(locale, key).or_else(|| es::template(key)) {
+        Some(template) => fill_template(template, params),
+        None => key.to_string(),
+    }
+}
+
+/// Atajo para resolver una clave sin parámetros
+pub fn tr(locale: Locale, key: &str) -> String {
+    translate(locale, key, &BTreeMap::new())
+}
+
+fn template(locale: Locale, key: &str) -> Option<&'static str> {
+    match locale {
+        Locale::Es => es::template(key),
+        Locale::En => en::template(key),
+    }
+}
+
+/// Sustituye cada `{nombre}` por su parámetro (los desconocidos se dejan intactos)
+fn fill_template(template: &str, params: &BTreeMap<String, String>) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find('}') {
+            Some(end) => {
+                let name = &after[..end];
+                match params.get(name) {
+                    Some(value) => output.push_str(value),
+                    None => {
+                        output.push('{');
+                        output.push_str(name);
+                        output.push('}');
+                    }
+                }
+                rest = &after[end + 1..];
+            }
+            None => {
+                output.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolves_per_locale() {
+        let text = LocalizedText::new("battle.move_used", [
+            ("user", "Pikachu".to_string()),
+            ("move", "Thunderbolt".to_string()),
+        ]);
+        assert_eq!(text.resolve(Locale::Es), "Pikachu usó Thunderbolt");
+        assert_eq!(text.resolve(Locale::En), "Pikachu used Thunderbolt!");
+    }
+
+    #[test]
+    fn test_unknown_key_falls_back_to_key() {
+        assert_eq!(tr(Locale::En, "battle.does_not_exist"), "battle.does_not_exist");
+    }
+
+    #[test]
+    fn test_missing_param_keeps_placeholder() {
+        let text = LocalizedText::new("battle.fainted", []);
+        assert_eq!(text.resolve(Locale::Es), "¡{target} se debilitó!");
+    }
+
+    #[test]
+    fn test_locale_serializes_lowercase() {
+        assert_eq!(serde_json::to_string(&Locale::En).unwrap(), "\"en\"");
+        assert_eq!(serde_json::from_str::<Locale>("\"es\"").unwrap(), Locale::Es);
+    }
+}
@@ -3,6 +3,8 @@ pub mod factory;
 pub mod game;
 pub mod battle;
 pub mod experience;
+pub mod i18n;
+pub mod persistence;
 
 pub use models::*;
 pub use game::*;
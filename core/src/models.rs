@@ -28,6 +28,70 @@ pub enum FieldPosition {
     OpponentRight,
 }
 
+impl FieldPosition {
+    /// Lado del campo al que pertenece la posición
+    pub fn side(self) -> BattleSide {
+        match self {
+            FieldPosition::PlayerLeft | FieldPosition::PlayerRight => BattleSide::Player,
+            FieldPosition::OpponentLeft | FieldPosition::OpponentRight => BattleSide::Opponent,
+        }
+    }
+}
+
+/// Lado del campo de batalla
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BattleSide {
+    /// Lado del jugador
+    Player,
+    /// Lado del oponente
+    Opponent,
+}
+
+impl BattleSide {
+    /// Lado del jugador o del oponente según `is_player`
+    pub fn of(is_player: bool) -> Self {
+        if is_player {
+            BattleSide::Player
+        } else {
+            BattleSide::Opponent
+        }
+    }
+
+    /// El lado contrario
+    pub fn opposite(self) -> Self {
+        match self {
+            BattleSide::Player => BattleSide::Opponent,
+            BattleSide::Opponent => BattleSide::Player,
+        }
+    }
+}
+
+/// Hazard de entrada colocado en un lado del campo
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryHazard {
+    /// Stealth Rock (daño según la efectividad de Roca)
+    StealthRock,
+    /// Spikes (hasta 3 capas, solo Pokémon en el suelo)
+    Spikes,
+    /// Toxic Spikes (hasta 2 capas: veneno / veneno grave)
+    ToxicSpikes,
+    /// Sticky Web (baja la velocidad al entrar)
+    StickyWeb,
+}
+
+/// Condición temporal de un lado del campo
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SideCondition {
+    /// Reflect (reduce el daño físico)
+    Reflect,
+    /// Light Screen (reduce el daño especial)
+    LightScreen,
+    /// Aurora Veil (reduce el daño físico y especial; requiere granizo)
+    AuroraVeil,
+    /// Tailwind (duplica la velocidad)
+    Tailwind,
+}
+
 /// Tipo de clima en batalla
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WeatherType {
@@ -145,6 +209,17 @@ impl Default for PokemonType {
     }
 }
 
+impl PokemonType {
+    /// Los 18 tipos reales (sin `Unknown`)
+    pub const ALL: [PokemonType; 18] = [
+        PokemonType::Normal, PokemonType::Fire, PokemonType::Water, PokemonType::Grass,
+        PokemonType::Electric, PokemonType::Ice, PokemonType::Fighting, PokemonType::Poison,
+        PokemonType::Ground, PokemonType::Flying, PokemonType::Psychic, PokemonType::Bug,
+        PokemonType::Rock, PokemonType::Ghost, PokemonType::Dragon, PokemonType::Dark,
+        PokemonType::Steel, PokemonType::Fairy,
+    ];
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Stat {
     Hp,
@@ -155,6 +230,109 @@ pub enum Stat {
     Speed,
 }
 
+impl Stat {
+    /// Las seis stats, en el orden de `Stats`
+    pub const ALL: [Stat; 6] = [
+        Stat::Hp,
+        Stat::Attack,
+        Stat::Defense,
+        Stat::SpecialAttack,
+        Stat::SpecialDefense,
+        Stat::Speed,
+    ];
+}
+
+/// Naturaleza de un Pokémon: sube un 10% una stat y baja un 10% otra
+///
+/// Las cinco naturalezas neutras (Hardy, Docile, Serious, Bashful, Quirky)
+/// suben y bajan la misma stat, así que no cambian nada. Nunca afectan al HP.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Nature {
+    #[default]
+    Hardy,
+    Lonely,
+    Brave,
+    Adamant,
+    Naughty,
+    Bold,
+    Docile,
+    Relaxed,
+    Impish,
+    Lax,
+    Timid,
+    Hasty,
+    Serious,
+    Jolly,
+    Naive,
+    Modest,
+    Mild,
+    Quiet,
+    Bashful,
+    Rash,
+    Calm,
+    Gentle,
+    Sassy,
+    Careful,
+    Quirky,
+}
+
+impl Nature {
+    /// Las 25 naturalezas, en el orden clásico de la tabla
+    pub const ALL: [Nature; 25] = [
+        Nature::Hardy, Nature::Lonely, Nature::Brave, Nature::Adamant, Nature::Naughty,
+        Nature::Bold, Nature::Docile, Nature::Relaxed, Nature::Impish, Nature::Lax,
+        Nature::Timid, Nature::Hasty, Nature::Serious, Nature::Jolly, Nature::Naive,
+        Nature::Modest, Nature::Mild, Nature::Quiet, Nature::Bashful, Nature::Rash,
+        Nature::Calm, Nature::Gentle, Nature::Sassy, Nature::Careful, Nature::Quirky,
+    ];
+
+    /// Stat que sube y stat que baja (None si es neutra)
+    pub fn modified_stats(self) -> Option<(Stat, Stat)> {
+        // Orden de la tabla: fila = stat que sube, columna = stat que baja
+        const ORDER: [Stat; 5] = [Stat::Attack, Stat::Defense, Stat::Speed, Stat::SpecialAttack, Stat::SpecialDefense];
+        let index = Nature::ALL.iter().position(|&n| n == self)?;
+        let (up, down) = (ORDER[index / 5], ORDER[index % 5]);
+        (up != down).then_some((up, down))
+    }
+
+    /// Aplica la naturaleza al valor de una stat (+10% / -10%, redondeando hacia abajo)
+    pub fn apply(self, stat: Stat, value: u16) -> u16 {
+        let percent = match self.modified_stats() {
+            Some((up, _)) if up == stat => 110,
+            Some((_, down)) if down == stat => 90,
+            _ => 100,
+        };
+        (value as u32 * percent / 100) as u16
+    }
+
+    /// ID en minúsculas ("adamant")
+    pub fn id(self) -> String {
+        format!("{:?}", self).to_lowercase()
+    }
+
+    /// Naturaleza que fija una menta ("adamant-mint" -> Adamant)
+    pub fn from_mint(item_id: &str) -> Option<Nature> {
+        let id = item_id.strip_suffix("-mint")?;
+        Nature::ALL.into_iter().find(|n| n.id() == id)
+    }
+}
+
+/// Género de un Pokémon (las especies sin género, como Magnemite, son `Genderless`)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Gender {
+    Male,
+    Female,
+    #[default]
+    Genderless,
+}
+
+impl Gender {
+    /// Si ambos tienen género y es distinto (requisito de Attract y Cute Charm)
+    pub fn is_opposite(self, other: Gender) -> bool {
+        matches!((self, other), (Gender::Male, Gender::Female) | (Gender::Female, Gender::Male))
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
 pub enum MoveCategory {
     Physical,
@@ -183,6 +361,35 @@ impl Stats {
             speed: 0,
         }
     }
+
+    /// Valor de una stat
+    pub fn get(&self, stat: Stat) -> u16 {
+        match stat {
+            Stat::Hp => self.hp,
+            Stat::Attack => self.attack,
+            Stat::Defense => self.defense,
+            Stat::SpecialAttack => self.special_attack,
+            Stat::SpecialDefense => self.special_defense,
+            Stat::Speed => self.speed,
+        }
+    }
+
+    /// Referencia mutable a una stat
+    pub fn get_mut(&mut self, stat: Stat) -> &mut u16 {
+        match stat {
+            Stat::Hp => &mut self.hp,
+            Stat::Attack => &mut self.attack,
+            Stat::Defense => &mut self.defense,
+            Stat::SpecialAttack => &mut self.special_attack,
+            Stat::SpecialDefense => &mut self.special_defense,
+            Stat::Speed => &mut self.speed,
+        }
+    }
+
+    /// Suma de las seis stats
+    pub fn total(&self) -> u32 {
+        Stat::ALL.iter().map(|&stat| self.get(stat) as u32).sum()
+    }
 }
 
 impl Default for Stats {
@@ -274,7 +481,7 @@ pub struct StatStages {
 pub struct VolatileStatus {
     /// Si el Pokémon retrocedió este turno (flinch)
     pub flinched: bool,
-    /// Si el Pokémon está confundido (para futura implementación)
+    /// Si el Pokémon está confundido
     pub confused: bool,
     /// Stage de crítico (para Focus Energy, etc.)
     pub crit_stage: u8,
@@ -339,6 +546,24 @@ pub struct VolatileStatus {
     /// Si este Pokémon fue marcado para switch forzado este turno (Dragon Tail, Roar, etc.)
     #[serde(default)]
     pub forced_switch: bool,
+
+    /// ID del último movimiento usado (la IA lo consulta para no encadenar Protect)
+    #[serde(default)]
+    pub last_move_used: Option<String>,
+
+    /// Turnos de confusión restantes (se descuenta antes de cada movimiento)
+    #[serde(default)]
+    pub confusion_turns: u8,
+
+    /// Movimiento en el que lo bloquea un objeto Choice o Gorilla Tactics
+    /// (se fija al usar el primer movimiento y se pierde al cambiar)
+    #[serde(default)]
+    pub choice_locked_move: Option<String>,
+
+    /// Stat que potencia Protosynthesis o Quark Drive activadas por Booster Energy
+    /// (dura hasta que el Pokémon sale del campo)
+    #[serde(default)]
+    pub paradox_boost: Option<String>,
 }
 
 impl VolatileStatus {
@@ -363,6 +588,10 @@ impl VolatileStatus {
             mat_block_active: false,
             crafty_shield_active: false,
             forced_switch: false,
+            last_move_used: None,
+            confusion_turns: 0,
+            choice_locked_move: None,
+            paradox_boost: None,
         }
     }
 
@@ -381,18 +610,37 @@ impl VolatileStatus {
         // Resetear switch forzado (Fase 2.3)
         self.forced_switch = false;
 
-        // confused, crit_stage, protect_counter, must_recharge y charging_move persisten entre turnos
+        // confused, crit_stage, protect_counter, must_recharge, charging_move y
+        // choice_locked_move persisten entre turnos
+    }
+
+    /// Estados que Baton Pass transfiere al Pokémon que entra: sustituto,
+    /// confusión, Focus Energy, Leech Seed y Perish Song
+    pub fn baton_passed(&self) -> Self {
+        Self {
+            confused: self.confused,
+            confusion_turns: self.confusion_turns,
+            crit_stage: self.crit_stage,
+            leech_seeded: self.leech_seeded,
+            leech_seed_source: self.leech_seed_source.clone(),
+            substitute_hp: self.substitute_hp,
+            perish_count: self.perish_count,
+            ..Self::new()
+        }
     }
 
     /// Resetea completamente todos los estados volátiles
     pub fn reset_all(&mut self) {
         self.flinched = false;
         self.confused = false;
+        self.confusion_turns = 0;
         self.crit_stage = 0;
         self.protected = false;
         self.protect_counter = 0;
         self.must_recharge = false;
         self.charging_move = None;
+        self.last_move_used = None;
+        self.choice_locked_move = None;
     }
 }
 
@@ -411,18 +659,33 @@ impl StatStages {
     }
 
     /// Aplica un cambio de stat, limitando el rango a -6 a +6
-    pub fn apply_change(&mut self, stat: &str, change: i8) {
+    /// Retorna el cambio efectivo (0 si el stage ya estaba al límite o la stat no existe)
+    pub fn apply_change(&mut self, stat: &str, change: i8) -> i8 {
         let clamped_change = change.clamp(-6, 6);
-        match stat {
-            "attack" => self.attack = (self.attack + clamped_change).clamp(-6, 6),
-            "defense" => self.defense = (self.defense + clamped_change).clamp(-6, 6),
-            "special_attack" => self.special_attack = (self.special_attack + clamped_change).clamp(-6, 6),
-            "special_defense" => self.special_defense = (self.special_defense + clamped_change).clamp(-6, 6),
-            "speed" => self.speed = (self.speed + clamped_change).clamp(-6, 6),
-            "accuracy" => self.accuracy = (self.accuracy + clamped_change).clamp(-6, 6),
-            "evasion" => self.evasion = (self.evasion + clamped_change).clamp(-6, 6),
-            _ => {} // Ignorar stats desconocidos
-        }
+        let stage = match stat {
+            "attack" => &mut self.attack,
+            "defense" => &mut self.defense,
+            "special_attack" => &mut self.special_attack,
+            "special_defense" => &mut self.special_defense,
+            "speed" => &mut self.speed,
+            "accuracy" => &mut self.accuracy,
+            "evasion" => &mut self.evasion,
+            _ => return 0, // Ignorar stats desconocidos
+        };
+        let old_stage = *stage;
+        *stage = (old_stage + clamped_change).clamp(-6, 6);
+        *stage - old_stage
+    }
+
+    /// Si alguna stat bajó respecto a `before` (Eject Pack)
+    pub fn lowered_since(&self, before: &StatStages) -> bool {
+        self.attack < before.attack
+            || self.defense < before.defense
+            || self.special_attack < before.special_attack
+            || self.special_defense < before.special_defense
+            || self.speed < before.speed
+            || self.accuracy < before.accuracy
+            || self.evasion < before.evasion
     }
 
     /// Calcula el multiplicador de stat basado en el stage
@@ -526,6 +789,14 @@ fn default_ailment() -> String {
     "none".to_string()
 }
 
+/// Sin género (pokedex generadas antes de existir `gender_rate`)
+///
+/// Sin el dato no se sabe si la especie tiene género, y es preferible no
+/// dárselo a una que no lo tiene.
+fn default_gender_rate() -> i8 {
+    -1
+}
+
 /// Cambio de stat que aplica un movimiento
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct MoveStatChange {
@@ -601,6 +872,34 @@ pub struct EvolutionData {
     pub trigger: String,
 }
 
+/// Forma Mega de una especie, a la que evoluciona en batalla con su megapiedra
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MegaForm {
+    /// ID de la forma en PokeAPI (ej: "charizard-mega-x")
+    pub form_id: String,
+    /// Nombre para mostrar (ej: "Mega Charizard X")
+    pub display_name: String,
+    /// Megapiedra que debe llevar equipada (ej: "charizardite-x")
+    pub mega_stone: String,
+    pub primary_type: PokemonType,
+    pub secondary_type: Option<PokemonType>,
+    pub base_stats: Stats,
+    /// Habilidad única de la forma Mega
+    pub ability: String,
+}
+
+/// Megaevolución activa de un Pokémon, con lo necesario para revertirla
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MegaEvolution {
+    /// `form_id` de la forma Mega adoptada
+    pub form_id: String,
+    /// Habilidad antes de megaevolucionar
+    pub original_ability: String,
+    /// Tipos (del perfil randomizado) antes de megaevolucionar
+    pub original_primary_type: PokemonType,
+    pub original_secondary_type: Option<PokemonType>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct PokemonSpecies {
     pub species_id: String,
@@ -620,9 +919,42 @@ pub struct PokemonSpecies {
     /// Lista de posibles evoluciones de esta especie
     #[serde(default)]
     pub evolutions: Vec<EvolutionData>,
+    /// EVs que reparte al ser derrotada (vacío en pokedex generadas antes de existir)
+    #[serde(default)]
+    pub ev_yield: Stats,
+    /// Probabilidad de ser hembra, en octavos (-1 = sin género), como en PokeAPI
+    #[serde(default = "default_gender_rate")]
+    pub gender_rate: i8,
+    /// Formas Mega de la especie (vacío si no tiene)
+    #[serde(default)]
+    pub mega_forms: Vec<MegaForm>,
 }
 
 impl PokemonSpecies {
+    /// EVs que reparte la especie al ser derrotada
+    ///
+    /// Usa el `ev_yield` de la pokedex. Solo como respaldo para pokedex
+    /// generadas antes de existir el campo, lo estima: entre 1 y 3 puntos,
+    /// según el BST, en su stat base más alta.
+    pub fn effective_ev_yield(&self) -> Stats {
+        if self.ev_yield.total() > 0 {
+            return self.ev_yield.clone();
+        }
+
+        // Respaldo: estimación por BST (no coincide con los juegos en todas las especies)
+
+        let bst = self.bst();
+        let points = if bst < 350 { 1 } else if bst < 480 { 2 } else { 3 };
+        let best = Stat::ALL
+            .into_iter()
+            .rev()
+            .max_by_key(|&stat| self.base_stats.get(stat))
+            .unwrap_or(Stat::Hp);
+        let mut ev_yield = Stats::zero();
+        *ev_yield.get_mut(best) = points;
+        ev_yield
+    }
+
     /// Calcula el Base Stat Total (BST) de la especie
     /// Retorna la suma de todos los stats base
     pub fn bst(&self) -> u32 {
@@ -693,8 +1025,23 @@ pub struct PokemonInstance {
     
     pub individual_values: Stats,
     pub effort_values: Stats,
+    /// Naturaleza (las partidas guardadas antes de existir son neutras)
+    #[serde(default)]
+    pub nature: Nature,
+    /// Género (las partidas guardadas antes de existir no tienen)
+    #[serde(default)]
+    pub gender: Gender,
+    /// Tipo que adopta al teracristalizarse (`Unknown` en partidas guardadas antes de existir)
+    #[serde(default)]
+    pub tera_type: PokemonType,
+    /// Si está teracristalizado en la batalla actual (lo sincroniza `prepare_turn`)
+    #[serde(default)]
+    pub terastallized: bool,
+    /// Megaevolución activa (solo durante la batalla en que ocurrió)
+    #[serde(default)]
+    pub mega_evolution: Option<MegaEvolution>,
     
-    /// Stats calculados (Base + IV + EV + Nivel) = VIDA MÁXIMA
+    /// Stats calculados (Base + IV + EV + Nivel + Naturaleza) = VIDA MÁXIMA
     pub base_computed_stats: Stats,
     
     pub randomized_profile: RandomizedProfile,
@@ -715,6 +1062,68 @@ impl PokemonInstance {
         }
     }
 
+    /// Tipos originales (los del perfil randomizado)
+    pub fn original_types(&self) -> Vec<PokemonType> {
+        let mut types = vec![self.randomized_profile.rolled_primary_type];
+        types.extend(self.randomized_profile.rolled_secondary_type);
+        types
+    }
+
+    /// Tipos actuales: solo el Tera Type si está teracristalizado
+    pub fn current_types(&self) -> Vec<PokemonType> {
+        if self.terastallized {
+            vec![self.tera_type]
+        } else {
+            self.original_types()
+        }
+    }
+
+    /// Si puede teracristalizarse (tiene Tera Type y aún no lo hizo)
+    pub fn can_terastallize(&self) -> bool {
+        self.tera_type != PokemonType::Unknown && !self.terastallized && self.current_hp > 0
+    }
+
+    /// Stats base actuales: los de la forma Mega si está megaevolucionado
+    pub fn current_base_stats(&self) -> &Stats {
+        self.mega_evolution
+            .as_ref()
+            .and_then(|mega| self.species.mega_forms.iter().find(|form| form.form_id == mega.form_id))
+            .map_or(&self.species.base_stats, |form| &form.base_stats)
+    }
+
+    /// Megaevoluciona a `form`: adopta su habilidad, sus tipos y sus stats
+    ///
+    /// Quién puede megaevolucionar lo decide el item system (megapiedras).
+    pub fn mega_evolve(&mut self, form: &MegaForm) {
+        self.mega_evolution = Some(MegaEvolution {
+            form_id: form.form_id.clone(),
+            original_ability: std::mem::replace(&mut self.ability, form.ability.clone()),
+            original_primary_type: self.randomized_profile.rolled_primary_type,
+            original_secondary_type: self.randomized_profile.rolled_secondary_type,
+        });
+        self.randomized_profile.rolled_primary_type = form.primary_type;
+        self.randomized_profile.rolled_secondary_type = form.secondary_type;
+        self.recalculate_stats();
+    }
+
+    /// Deshace la megaevolución (al terminar la batalla)
+    pub fn revert_mega_evolution(&mut self) {
+        let Some(mega) = self.mega_evolution.take() else {
+            return;
+        };
+        self.ability = mega.original_ability;
+        self.randomized_profile.rolled_primary_type = mega.original_primary_type;
+        self.randomized_profile.rolled_secondary_type = mega.original_secondary_type;
+        self.recalculate_stats();
+    }
+
+    /// Vuelve a la forma normal al terminar una batalla (deshace la
+    /// Megaevolución y la Teracristalización)
+    pub fn revert_battle_forms(&mut self) {
+        self.revert_mega_evolution();
+        self.terastallized = false;
+    }
+
     /// Inicializa los battle_stages y volatile_status al entrar en batalla
     /// Debe llamarse cuando el Pokémon entra en combate
     pub fn init_battle_stages(&mut self) {
@@ -743,18 +1152,54 @@ impl PokemonInstance {
         use crate::factory::compute_stats;
         
         self.level = new_level;
-        // Recalcular stats con el nuevo nivel, manteniendo IVs y EVs
+        // Recalcular stats con el nuevo nivel, manteniendo IVs, EVs y naturaleza
         self.base_computed_stats = compute_stats(
-            &self.species.base_stats,
+            self.current_base_stats(),
             &self.individual_values,
             &self.effort_values,
             new_level,
+            self.nature,
         );
         // Ajustar HP actual proporcionalmente si es necesario
         // Por ahora, simplemente lo ponemos al máximo
         self.current_hp = self.base_computed_stats.hp;
     }
 
+    /// Cambia la naturaleza con una menta y recalcula los stats
+    ///
+    /// Retorna false si el objeto no es una menta.
+    pub fn apply_mint(&mut self, item_id: &str) -> bool {
+        let Some(nature) = Nature::from_mint(item_id) else {
+            return false;
+        };
+        self.nature = nature;
+        self.recalculate_stats();
+        true
+    }
+
+    /// Recalcula los stats con el nivel, IVs, EVs y naturaleza actuales
+    ///
+    /// El HP actual cambia lo mismo que el máximo: si el máximo no cambia, el
+    /// HP tampoco. Un Pokémon en pie se queda con al menos 1 HP y uno
+    /// debilitado sigue a 0.
+    pub fn recalculate_stats(&mut self) {
+        use crate::factory::compute_stats;
+
+        let old_max_hp = self.base_computed_stats.hp;
+        self.base_computed_stats = compute_stats(
+            self.current_base_stats(),
+            &self.individual_values,
+            &self.effort_values,
+            self.level,
+            self.nature,
+        );
+        if self.current_hp > 0 {
+            let new_max_hp = self.base_computed_stats.hp;
+            let hp = (self.current_hp + new_max_hp).saturating_sub(old_max_hp);
+            self.current_hp = hp.clamp(1, new_max_hp.max(1));
+        }
+    }
+
     /// Aprende un nuevo movimiento y lo añade a la bolsa de movimientos
     /// La bolsa puede tener cualquier tamaño, pero solo los primeros 4 están activos en batalla
     /// Requiere el max_pp del movimiento para inicializar correctamente
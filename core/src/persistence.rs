@@ -0,0 +1,194 @@
+//! Formato persistido de las sesiones de juego
+//!
+//! Las sesiones se guardan como un sobre JSON `{ schema_version, session }`
+//! donde `session` es el `GameSession` serializado con sus derives de serde.
+//!
+//! Los campos nuevos de `VolatileStatus`, `BattleState`, etc. se añaden con
+//! `#[serde(default)]`, así que un documento antiguo sigue deserializando.
+//! Cuando el valor por defecto no es el correcto para datos viejos, se sube
+//! `SESSION_SCHEMA_VERSION` y se registra un paso en `MIGRATIONS` que reescribe
+//! el JSON antes de deserializarlo.
+
+use serde_json::Value;
+
+use crate::game::GameSession;
+
+/// Versión actual del esquema de sesiones persistidas
+pub const SESSION_SCHEMA_VERSION: u32 = 2;
+
+/// Pasos de migración: `MIGRATIONS[n]` lleva un documento de la versión `n` a la `n + 1`
+const MIGRATIONS: [fn(&mut Value); SESSION_SCHEMA_VERSION as usize] = [migrate_v0_to_v1, migrate_v1_to_v2];
+
+/// Error al decodificar una sesión persistida
+#[derive(Debug)]
+pub enum SessionDecodeError {
+    /// El documento fue escrito por una versión más nueva del servidor
+    UnsupportedVersion(u32),
+    /// El JSON no corresponde a un `GameSession` válido
+    Json(serde_json::Error),
+}
+
+impl std::fmt::Display for SessionDecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionDecodeError::UnsupportedVersion(version) => write!(
+                f,
+                "session schema version {} is newer than supported version {}",
+                version, SESSION_SCHEMA_VERSION
+            ),
+            SessionDecodeError::Json(e) => write!(f, "invalid session JSON: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for SessionDecodeError {}
+
+impl From<serde_json::Error> for SessionDecodeError {
+    fn from(e: serde_json::Error) -> Self {
+        SessionDecodeError::Json(e)
+    }
+}
+
+/// Serializa una sesión dentro del sobre versionado
+pub fn encode_session(session: &GameSession) -> serde_json::Result<Value> {
+    Ok(serde_json::json!({
+        "schema_version": SESSION_SCHEMA_VERSION,
+        "session": serde_json::to_value(session)?,
+    }))
+}
+
+/// Deserializa una sesión persistida aplicando las migraciones pendientes
+///
+/// Acepta también el formato previo al sobre (un `GameSession` plano), que se
+/// trata como versión 0.
+pub fn decode_session(document: Value) -> Result<GameSession, SessionDecodeError> {
+    let (version, mut session) = split_envelope(document);
+
+    if version > SESSION_SCHEMA_VERSION {
+        return Err(SessionDecodeError::UnsupportedVersion(version));
+    }
+
+    for migration in &MIGRATIONS[version as usize..] {
+        migration(&mut session);
+    }
+
+    Ok(serde_json::from_value(session)?)
+}
+
+/// Separa la versión y el cuerpo de la sesión de un documento persistido
+fn split_envelope(document: Value) -> (u32, Value) {
+    match document {
+        Value::Object(mut map) if map.contains_key("schema_version") => {
+            let version = map
+                .get("schema_version")
+                .and_then(Value::as_u64)
+                .unwrap_or(0) as u32;
+            let session = map.remove("session").unwrap_or(Value::Null);
+            (version, session)
+        }
+        other => (0, other),
+    }
+}
+
+/// v0 → v1: las batallas guardadas antes de que `BattleState` tuviera locale
+/// propio narran en el idioma configurado para la partida
+fn migrate_v0_to_v1(session: &mut Value) {
+    let locale = session
+        .pointer("/config/locale")
+        .cloned()
+        .unwrap_or(Value::Null);
+    if locale.is_null() {
+        return;
+    }
+
+    if let Some(Value::Object(battle)) = session.get_mut("battle") {
+        battle.entry("locale").or_insert(locale);
+    }
+}
+
+/// v1 → v2: las partidas creadas antes de la semilla maestra recibirían todas
+/// la semilla 0; se deriva una propia a partir del id de la sesión
+fn migrate_v1_to_v2(session: &mut Value) {
+    let seed = session
+        .get("id")
+        .and_then(Value::as_str)
+        .map(fnv1a)
+        .unwrap_or(0);
+
+    if let Some(Value::Object(config)) = session.get_mut("config") {
+        config.entry("seed").or_insert(Value::from(seed));
+    }
+}
+
+/// Hash FNV-1a de 64 bits (estable entre versiones, a diferencia de `DefaultHasher`)
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Locale;
+
+    #[test]
+    fn test_round_trip_current_version() {
+        let mut session = GameSession::new("abc".to_string());
+        session.encounters_won = 3;
+
+        let document = encode_session(&session).unwrap();
+        assert_eq!(document["schema_version"], SESSION_SCHEMA_VERSION);
+
+        let decoded = decode_session(document).unwrap();
+        assert_eq!(decoded, session);
+    }
+
+    #[test]
+    fn test_legacy_document_without_envelope() {
+        let session = GameSession::new("legacy".to_string());
+        let mut legacy = serde_json::to_value(&session).unwrap();
+        // Un documento viejo no conoce los campos añadidos con #[serde(default)]
+        legacy.as_object_mut().unwrap().remove("loot_options");
+        legacy["config"].as_object_mut().unwrap().remove("locale");
+
+        let decoded = decode_session(legacy).unwrap();
+        assert_eq!(decoded.id, "legacy");
+        assert_eq!(decoded.loot_options, None);
+        assert_eq!(decoded.config.locale, Locale::Es);
+    }
+
+    #[test]
+    fn test_v0_battle_inherits_config_locale() {
+        let mut session = serde_json::json!({
+            "config": { "locale": "en" },
+            "battle": { "turn_counter": 1 },
+        });
+        migrate_v0_to_v1(&mut session);
+        assert_eq!(session["battle"]["locale"], "en");
+    }
+
+    #[test]
+    fn test_v1_session_gets_seed_from_id() {
+        let session = GameSession::new("legacy".to_string());
+        let mut document = encode_session(&session).unwrap();
+        document["schema_version"] = Value::from(1);
+        document["session"]["config"].as_object_mut().unwrap().remove("seed");
+
+        let decoded = decode_session(document).unwrap();
+        assert_eq!(decoded.config.seed, fnv1a("legacy"));
+        assert_ne!(decoded.config.seed, 0);
+    }
+
+    #[test]
+    fn test_rejects_newer_schema() {
+        let document = serde_json::json!({
+            "schema_version": SESSION_SCHEMA_VERSION + 1,
+            "session": {},
+        });
+        assert!(matches!(
+            decode_session(document),
+            Err(SessionDecodeError::UnsupportedVersion(_))
+        ));
+    }
+}
@@ -3,6 +3,7 @@ import fs from 'fs/promises';
 import * as path from 'path';
 
 // Lista de IDs de objetos competitivos
+// Los items que ya están en items.json se refrescan siempre, aunque no estén aquí
 const ITEM_IDS = [
   'leftovers',
   'life-orb',
@@ -14,10 +15,27 @@ const ITEM_IDS = [
   'focus-sash',
 ];
 
+// Metadatos de mecánica que no vienen de la PokéAPI: se conservan de items.json
+// (los edita a mano quien diseña el item)
+interface ItemMechanics {
+  category: string;
+  trigger: unknown;
+  effects: unknown[];
+  consumable: boolean;
+}
+
+const DEFAULT_MECHANICS: ItemMechanics = {
+  category: 'HeldItem',
+  trigger: 'Passive',
+  effects: [],
+  consumable: false,
+};
+
 // Tipo para la respuesta de la PokéAPI
 interface PokeApiItem {
   id: number;
   name: string;
+  fling_power: number | null;
   sprites: {
     default: string; // URL del sprite
   };
@@ -31,20 +49,24 @@ interface PokeApiItem {
 }
 
 // Tipo de salida para nuestro JSON
-interface ItemDataOutput {
+interface ItemDataOutput extends ItemMechanics {
   id: string;
   name: string;
   sprite_url: string;
   effect_text: string;
+  fling_power: number | null;
 }
 
 // Función para capitalizar la primera letra
-function capitalize(str: string): string {
-  return str.charAt(0).toUpperCase() + str.slice(1);
+function titleCase(str: string): string {
+  return str
+    .split(' ')
+    .map((word) => word.charAt(0).toUpperCase() + word.slice(1))
+    .join(' ');
 }
 
 // Función para obtener datos de un objeto
-async function fetchItemData(itemId: string): Promise<ItemDataOutput | null> {
+async function fetchItemData(itemId: string, mechanics: ItemMechanics): Promise<ItemDataOutput | null> {
   try {
     const response = await axios.get<PokeApiItem>(
       `https://pokeapi.co/api/v2/item/${itemId}`,
@@ -70,9 +92,14 @@ async function fetchItemData(itemId: string): Promise<ItemDataOutput | null> {
 
     const output: ItemDataOutput = {
       id: item.name, // El ID es el nombre en kebab-case
-      name: capitalize(item.name.replace(/-/g, ' ')), // Capitalizar y reemplazar guiones
+      name: titleCase(item.name.replace(/-/g, ' ')), // "weakness-policy" -> "Weakness Policy"
       sprite_url: spriteUrl,
       effect_text: effectText,
+      category: mechanics.category,
+      trigger: mechanics.trigger,
+      effects: mechanics.effects,
+      consumable: mechanics.consumable,
+      fling_power: item.fling_power,
     };
 
     return output;
@@ -86,6 +113,25 @@ async function fetchItemData(itemId: string): Promise<ItemDataOutput | null> {
   }
 }
 
+// Lee la mecánica de los items ya definidos en items.json
+async function loadExistingMechanics(outputPath: string): Promise<Map<string, ItemMechanics>> {
+  const mechanics = new Map<string, ItemMechanics>();
+  try {
+    const existing: ItemDataOutput[] = JSON.parse(await fs.readFile(outputPath, 'utf-8'));
+    for (const item of existing) {
+      mechanics.set(item.id, {
+        category: item.category ?? DEFAULT_MECHANICS.category,
+        trigger: item.trigger ?? DEFAULT_MECHANICS.trigger,
+        effects: item.effects ?? DEFAULT_MECHANICS.effects,
+        consumable: item.consumable ?? DEFAULT_MECHANICS.consumable,
+      });
+    }
+  } catch {
+    console.log('⚠️  No hay items.json previo: los items nuevos usan la mecánica por defecto');
+  }
+  return mechanics;
+}
+
 // Función para hacer sleep (rate limiting)
 function sleep(ms: number): Promise<void> {
   return new Promise((resolve) => setTimeout(resolve, ms));
@@ -98,15 +144,19 @@ async function main() {
   const allItems: ItemDataOutput[] = [];
   const outputPath = path.join(process.cwd(), '..', 'server', 'data', 'items.json');
 
+  const existingMechanics = await loadExistingMechanics(outputPath);
+  const itemIds = [...new Set([...ITEM_IDS, ...existingMechanics.keys()])];
+
   let successCount = 0;
   let errorCount = 0;
 
   // Procesar cada item
-  for (let i = 0; i < ITEM_IDS.length; i++) {
-    const itemId = ITEM_IDS[i];
+  for (let i = 0; i < itemIds.length; i++) {
+    const itemId = itemIds[i];
     console.log(`📦 Procesando ${itemId}...`);
 
-    const itemData = await fetchItemData(itemId);
+    const mechanics = existingMechanics.get(itemId) ?? DEFAULT_MECHANICS;
+    const itemData = await fetchItemData(itemId, mechanics);
 
     if (itemData) {
       allItems.push(itemData);
@@ -118,7 +168,7 @@ async function main() {
     }
 
     // Rate limiting: esperar 100ms entre peticiones
-    if (i < ITEM_IDS.length - 1) {
+    if (i < itemIds.length - 1) {
       await sleep(100);
     }
   }
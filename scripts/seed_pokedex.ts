@@ -13,6 +13,7 @@ interface PokeApiGeneration {
 interface PokeApiPokemonSpecies {
   id: number;
   name: string;
+  gender_rate: number;
   evolution_chain: {
     url: string;
   };
@@ -63,6 +64,7 @@ interface PokeApiPokemon {
   }>;
   stats: Array<{
     base_stat: number;
+    effort: number;
     stat: {
       name: string;
     };
@@ -121,8 +123,74 @@ interface PokemonSpeciesOutput {
   possible_abilities: string[];
   is_starter_candidate: boolean;
   evolutions: EvolutionDataOutput[];
+  ev_yield: PokemonSpeciesOutput['base_stats'];
+  gender_rate: number;
+  mega_forms: MegaFormOutput[];
 }
 
+// Forma Mega de una especie (compatible con MegaForm de Rust)
+interface MegaFormOutput {
+  form_id: string;
+  display_name: string;
+  mega_stone: string;
+  primary_type: string;
+  secondary_type: string | null;
+  base_stats: PokemonSpeciesOutput['base_stats'];
+  ability: string;
+}
+
+// Megapiedra de cada forma Mega (la PokéAPI no relaciona formas y piedras).
+// Mega Rayquaza no necesita piedra y queda fuera.
+const MEGA_STONES: Record<string, string> = {
+  'venusaur-mega': 'venusaurite',
+  'charizard-mega-x': 'charizardite-x',
+  'charizard-mega-y': 'charizardite-y',
+  'blastoise-mega': 'blastoisinite',
+  'beedrill-mega': 'beedrillite',
+  'pidgeot-mega': 'pidgeotite',
+  'alakazam-mega': 'alakazite',
+  'slowbro-mega': 'slowbronite',
+  'gengar-mega': 'gengarite',
+  'kangaskhan-mega': 'kangaskhanite',
+  'pinsir-mega': 'pinsirite',
+  'gyarados-mega': 'gyaradosite',
+  'aerodactyl-mega': 'aerodactylite',
+  'mewtwo-mega-x': 'mewtwonite-x',
+  'mewtwo-mega-y': 'mewtwonite-y',
+  'ampharos-mega': 'ampharosite',
+  'steelix-mega': 'steelixite',
+  'scizor-mega': 'scizorite',
+  'heracross-mega': 'heracronite',
+  'houndoom-mega': 'houndoominite',
+  'tyranitar-mega': 'tyranitarite',
+  'sceptile-mega': 'sceptilite',
+  'blaziken-mega': 'blazikenite',
+  'swampert-mega': 'swampertite',
+  'gardevoir-mega': 'gardevoirite',
+  'sableye-mega': 'sablenite',
+  'mawile-mega': 'mawilite',
+  'aggron-mega': 'aggronite',
+  'medicham-mega': 'medichamite',
+  'manectric-mega': 'manectite',
+  'sharpedo-mega': 'sharpedonite',
+  'camerupt-mega': 'cameruptite',
+  'altaria-mega': 'altarianite',
+  'banette-mega': 'banettite',
+  'absol-mega': 'absolite',
+  'glalie-mega': 'glalitite',
+  'salamence-mega': 'salamencite',
+  'metagross-mega': 'metagrossite',
+  'latias-mega': 'latiasite',
+  'latios-mega': 'latiosite',
+  'lopunny-mega': 'lopunnite',
+  'garchomp-mega': 'garchompite',
+  'lucario-mega': 'lucarionite',
+  'abomasnow-mega': 'abomasite',
+  'gallade-mega': 'galladite',
+  'audino-mega': 'audinite',
+  'diancie-mega': 'diancite',
+};
+
 // Mapeo de tipos de la API al enum de Rust
 const TYPE_MAP: Record<string, string> = {
   normal: 'Normal',
@@ -207,6 +275,27 @@ function mapStats(apiStats: PokeApiPokemon['stats']): PokemonSpeciesOutput['base
   return stats;
 }
 
+// Función para extraer los EVs que reparte la especie al ser derrotada
+function mapEvYield(apiStats: PokeApiPokemon['stats']): PokemonSpeciesOutput['ev_yield'] {
+  const evYield: PokemonSpeciesOutput['ev_yield'] = {
+    hp: 0,
+    attack: 0,
+    defense: 0,
+    special_attack: 0,
+    special_defense: 0,
+    speed: 0,
+  };
+
+  for (const stat of apiStats) {
+    const mappedKey = STAT_MAP[stat.stat.name];
+    if (mappedKey) {
+      evYield[mappedKey] = stat.effort;
+    }
+  }
+
+  return evYield;
+}
+
 // Función para extraer movimientos aprendidos por level-up
 function extractLevelUpMoves(moves: PokeApiPokemon['moves']): string[] {
   const levelUpMoves = new Set<string>();
@@ -397,6 +486,46 @@ async function resolveDefaultPokemonNameAndInfo(
   }
 }
 
+// Nombre legible de una forma Mega ("charizard-mega-x" -> "Mega Charizard X")
+function megaDisplayName(formId: string): string {
+  const [species, suffix] = formId.split('-mega');
+  const variant = suffix ? ` ${suffix.replace('-', '').toUpperCase()}` : '';
+  return `Mega ${capitalize(species)}${variant}`;
+}
+
+// Función para obtener las formas Mega de una especie (las variedades "-mega" con piedra conocida)
+async function fetchMegaForms(speciesInfo: PokeApiPokemonSpecies | null): Promise<MegaFormOutput[]> {
+  const megaVarieties = (speciesInfo?.varieties ?? []).filter(v => MEGA_STONES[v.pokemon.name]);
+  const forms: MegaFormOutput[] = [];
+
+  for (const variety of megaVarieties) {
+    try {
+      const response = await axios.get<PokeApiPokemon>(variety.pokemon.url, { timeout: 10000 });
+      const form = response.data;
+      const types = form.types.sort((a, b) => a.slot - b.slot);
+
+      forms.push({
+        form_id: form.name,
+        display_name: megaDisplayName(form.name),
+        mega_stone: MEGA_STONES[form.name],
+        primary_type: mapType(types[0].type.name),
+        secondary_type: types.length > 1 ? mapType(types[1].type.name) : null,
+        base_stats: mapStats(form.stats),
+        // Las formas Mega tienen una única habilidad
+        ability: form.abilities[0]?.ability.name ?? '',
+      });
+    } catch (error) {
+      if (axios.isAxiosError(error)) {
+        console.error(`Error fetching mega form ${variety.pokemon.name}:`, error.message);
+      } else {
+        console.error(`Error fetching mega form ${variety.pokemon.name}:`, error);
+      }
+    }
+  }
+
+  return forms;
+}
+
 // Función para obtener datos de un Pokémon
 async function fetchPokemonData(
   pokemonName: string,
@@ -421,6 +550,7 @@ async function fetchPokemonData(
 
     // Mapear stats
     const baseStats = mapStats(pokemon.stats);
+    const evYield = mapEvYield(pokemon.stats);
 
     // Extraer movimientos
     const movePool = extractLevelUpMoves(pokemon.moves);
@@ -454,6 +584,9 @@ async function fetchPokemonData(
       }
     }
 
+    // Formas Mega (si la especie tiene)
+    const megaForms = await fetchMegaForms(speciesInfo);
+
     // Crear el objeto de salida
     // Usamos speciesName para el display_name (nombre de la especie, no la forma)
     const output: PokemonSpeciesOutput = {
@@ -467,6 +600,10 @@ async function fetchPokemonData(
       possible_abilities: possibleAbilities,
       is_starter_candidate: isStarterCandidate,
       evolutions: evolutions,
+      ev_yield: evYield,
+      // Probabilidad de ser hembra en octavos (-1 = sin género, también si no hay datos de la especie)
+      gender_rate: speciesInfo?.gender_rate ?? -1,
+      mega_forms: megaForms,
     };
 
     return output;
@@ -1,7 +1,8 @@
 use axum::{extract::State, http::StatusCode, response::Json};
-use core::battle::{execute_turn, trigger_on_entry_abilities, TurnResult, initialize_move_pp, has_moves_with_pp, create_struggle_move, BattleContext};
+use core::battle::{prepare_turn, run_turn, resume_turn, finish_turn, replace_fainted, BattleOutcome, TurnResult, has_moves_with_pp, enforce_choice_lock};
 use core::experience::apply_victory_level_up;
-use core::game::{GameSession, GameState, PendingPlayerAction};
+use core::i18n::LocalizedText;
+use core::game::{BattleState, GameSession, GameState, PendingPlayerAction};
 use core::models::FieldPosition;
 use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
@@ -20,6 +21,12 @@ pub struct SubmitMoveRequest {
     pub user_index: usize,
     /// Posición del objetivo (si aplica, para movimientos "selected-pokemon")
     pub target_position: Option<FieldPosition>,
+    /// Si el Pokémon se teracristaliza antes de moverse (una vez por batalla)
+    #[serde(default)]
+    pub terastallize: bool,
+    /// Si el Pokémon megaevoluciona antes de moverse (una vez por batalla)
+    #[serde(default)]
+    pub mega_evolve: bool,
 }
 
 /// Respuesta del endpoint de batalla
@@ -48,87 +55,102 @@ pub async fn submit_move(
     Json(payload): Json<SubmitMoveRequest>,
 ) -> Result<Json<SubmitMoveResponse>, StatusCode> {
     
-    // Buscar la sesión
-    let mut session = match state.sessions.get(&payload.session_id) {
-        Some(s) => {
-            s.clone()
-        }
-        None => {
-            return Err(StatusCode::NOT_FOUND);
+    // Buscar la sesión y modificarla con su lock tomado
+    state.sessions.update(&payload.session_id, |session| {
+        // Validar que esté en estado de batalla (normal o gimnasio)
+        if session.state != GameState::Battle && session.state != GameState::GymBattle {
+            return Err(StatusCode::BAD_REQUEST);
         }
-    };
-    
 
-    // Validar que esté en estado de batalla (normal o gimnasio)
-    if session.state != GameState::Battle && session.state != GameState::GymBattle {
-        return Err(StatusCode::BAD_REQUEST);
-    }
+        // Obtener el estado de batalla (clonar para poder modificarlo)
+        let mut battle_state = match session.battle.clone() {
+            Some(bs) => {
+                bs
+            }
+            None => {
+                return Err(StatusCode::BAD_REQUEST);
+            }
+        };
 
-    // Obtener el estado de batalla (clonar para poder modificarlo)
-    let mut battle_state = match session.battle.clone() {
-        Some(bs) => {
-            bs
-        }
-        None => {
+        // Con un relevo pendiente solo se acepta la elección del reemplazo (switch)
+        if battle_state.pending_pivot.is_some() {
             return Err(StatusCode::BAD_REQUEST);
         }
-    };
-    
 
-    // Validar que user_index corresponde a un Pokémon activo
-    if payload.user_index >= battle_state.player_active_indices.len() {
-        return Err(StatusCode::BAD_REQUEST);
-    }
+        // Validar que user_index corresponde a un Pokémon activo
+        if payload.user_index >= battle_state.player_active_indices.len() {
+            return Err(StatusCode::BAD_REQUEST);
+        }
 
-    let player_active_index = battle_state.player_active_indices[payload.user_index];
+        let player_active_index = battle_state.player_active_indices[payload.user_index];
 
-    if player_active_index >= session.team.active_members.len() {
-        return Err(StatusCode::BAD_REQUEST);
-    }
+        if player_active_index >= session.team.active_members.len() {
+            return Err(StatusCode::BAD_REQUEST);
+        }
 
-    // Validación: Verificar que el Pokémon activo no esté debilitado
-    let player_hp = session.team.active_members[player_active_index].current_hp;
-    if player_hp == 0 {
-        return Err(StatusCode::BAD_REQUEST);
-    }
+        // Validación: Verificar que el Pokémon activo no esté debilitado
+        let player_hp = session.team.active_members[player_active_index].current_hp;
+        if player_hp == 0 {
+            return Err(StatusCode::BAD_REQUEST);
+        }
 
-    let player_mon = &session.team.active_members[player_active_index];
+        let player_mon = &session.team.active_members[player_active_index];
     
-    // Verificar si el movimiento existe y tiene PP disponible
-    let player_has_pp = has_moves_with_pp(player_mon);
-    let move_id = if !player_has_pp {
-        "struggle".to_string()
-    } else {
-        // Verificar que el movimiento existe en los movimientos aprendidos
-        let active_learned_moves = player_mon.get_active_learned_moves();
-        let found_move = active_learned_moves.iter()
-            .find(|m| m.move_id == payload.move_id);
+        // Verificar si el movimiento existe y tiene PP disponible
+        let player_has_pp = has_moves_with_pp(player_mon);
+        let move_id = if !player_has_pp {
+            "struggle".to_string()
+        } else {
+            // Verificar que el movimiento existe en los movimientos aprendidos
+            let active_learned_moves = player_mon.get_active_learned_moves();
+            let found_move = active_learned_moves.iter()
+                .find(|m| m.move_id == payload.move_id);
         
-        if let Some(learned_move) = found_move {
-            // Verificar PP
-            if learned_move.current_pp == 0 {
-                "struggle".to_string()
+            if let Some(learned_move) = found_move {
+                // Verificar PP
+                if learned_move.current_pp == 0 {
+                    "struggle".to_string()
+                } else {
+                    payload.move_id.clone()
+                }
             } else {
-                payload.move_id.clone()
+                // Movimiento no encontrado, usar Struggle
+                "struggle".to_string()
             }
-        } else {
-            // Movimiento no encontrado, usar Struggle
-            "struggle".to_string()
-        }
-    };
+        };
+        // Un Pokémon bloqueado por un objeto Choice repite su movimiento
+        let move_id = enforce_choice_lock(player_mon, move_id);
+
+        // Crear la acción pendiente
+        let action = PendingPlayerAction {
+            user_index: payload.user_index,
+            move_id: move_id.clone(),
+            target_position: payload.target_position,
+            switch_to: None,
+            terastallize: payload.terastallize,
+            mega_evolve: payload.mega_evolve,
+        };
 
-    // Crear la acción pendiente
-    let action = PendingPlayerAction {
-        user_index: payload.user_index,
-        move_id: move_id.clone(),
-        target_position: payload.target_position,
-    };
+        // Agregar o reemplazar la acción para este Pokémon
+        // Si ya existe una acción para este user_index, reemplazarla
+        battle_state.pending_player_actions.retain(|a| a.user_index != payload.user_index);
+        battle_state.pending_player_actions.push(action);
 
-    // Agregar o reemplazar la acción para este Pokémon
-    // Si ya existe una acción para este user_index, reemplazarla
-    battle_state.pending_player_actions.retain(|a| a.user_index != payload.user_index);
-    battle_state.pending_player_actions.push(action);
+        resolve_pending_turn(&state, session, battle_state, payload.user_index).map(Json)
+    })
+}
 
+/// Ejecuta el turno si todos los slots del jugador ya tienen acción
+///
+/// Usado por `submit_move` y `switch_pokemon`: mientras falten acciones (en
+/// dobles) solo actualiza la sesión; cuando están todas, corre el pipeline, graba
+/// el turno en el replay y resuelve el resultado de la batalla.
+fn resolve_pending_turn(
+    state: &AppState,
+    session: &mut GameSession,
+    mut battle_state: BattleState,
+    user_index: usize,
+) -> Result<SubmitMoveResponse, StatusCode> {
     // Verificar si todas las acciones están listas
     let required_actions = battle_state.player_active_indices.len();
     let pending_count = battle_state.pending_player_actions.len();
@@ -137,128 +159,54 @@ pub async fn submit_move(
     // Si no todas las acciones están listas, retornar sin ejecutar el turno
     if pending_count < required_actions {
         session.battle = Some(battle_state.clone());
-        state.sessions.insert(payload.session_id.clone(), session.clone());
-        return Ok(Json(SubmitMoveResponse {
+        let player_hp = battle_state.player_active_indices.get(user_index)
+            .and_then(|&idx| session.team.active_members.get(idx))
+            .map(|p| p.current_hp)
+            .unwrap_or(0);
+        return Ok(SubmitMoveResponse {
             result: TurnResult::new(),
-            player_hp: player_mon.current_hp,
+            player_hp,
             enemy_hp: battle_state.get_opponent_active().current_hp,
             battle_over: false,
             player_won: None,
             session: None,
             turn_executed: false,
             pending_actions: required_actions - pending_count,
-        }));
+        });
     }
 
     // Todas las acciones están listas, ejecutar el turno
-    let mut rng = StdRng::from_entropy();
-
-    // Inicializar PP para todos los Pokémon activos del jugador (antes de ejecutar el turno)
-    for action in &battle_state.pending_player_actions {
-        let player_index = battle_state.player_active_indices[action.user_index];
-        if player_index < session.team.active_members.len() {
-            let player_mon = &mut session.team.active_members[player_index];
-            if action.move_id != "struggle" {
-                if let Some(move_data) = state.moves.get(&action.move_id) {
-                    initialize_move_pp(player_mon, &action.move_id, move_data);
-                }
-            }
-        }
-    }
+    let player_actions = battle_state.pending_player_actions.clone();
+    let turn_seed = battle_state.next_turn_seed();
+    let mut rng = StdRng::seed_from_u64(turn_seed);
 
-    // Inicializar PP para todos los Pokémon activos del oponente
-    if battle_state.is_trainer_battle {
-        for &idx in &battle_state.opponent_active_indices.clone() {
-            if let Some(opponent_mon) = battle_state.opponent_team.get_mut(idx) {
-                let moves: Vec<String> = opponent_mon.get_active_learned_moves()
-                    .iter()
-                    .map(|m| m.move_id.clone())
-                    .collect();
-                for move_id in moves {
-                    if let Some(move_data) = state.moves.get(&move_id) {
-                        initialize_move_pp(opponent_mon, &move_id, move_data);
-                    }
-                }
-            }
-        }
-    } else {
-        // Batalla salvaje
-        let moves: Vec<String> = battle_state.opponent_instance.get_active_learned_moves()
-            .iter()
-            .map(|m| m.move_id.clone())
-            .collect();
-        for move_id in moves {
-            if let Some(move_data) = state.moves.get(&move_id) {
-                initialize_move_pp(&mut battle_state.opponent_instance, &move_id, move_data);
-            }
-        }
-    }
-
-    // Inicializar battle_stages para todos los Pokémon activos si no están inicializados
-    for &idx in &battle_state.player_active_indices.clone() {
-        if let Some(pokemon) = session.team.active_members.get_mut(idx) {
-            if pokemon.battle_stages.is_none() {
-                pokemon.init_battle_stages();
-            }
-        }
-    }
-
-    if battle_state.is_trainer_battle {
-        for &idx in &battle_state.opponent_active_indices.clone() {
-            if let Some(pokemon) = battle_state.opponent_team.get_mut(idx) {
-                if pokemon.battle_stages.is_none() {
-                    pokemon.init_battle_stages();
-                }
-            }
-        }
-    } else {
-        if battle_state.opponent_instance.battle_stages.is_none() {
-            battle_state.opponent_instance.init_battle_stages();
-        }
-    }
+    // Inicializar PP y battle_stages de los Pokémon activos (antes de ejecutar el turno)
+    prepare_turn(&mut session.team, &mut battle_state, &state.moves);
 
     // Ejecutar el turno usando el nuevo pipeline
-    let turn_result = if battle_state.is_trainer_battle {
-        // Extraer opponent_team temporalmente para evitar problemas de borrow
-        let mut opponent_team = std::mem::take(&mut battle_state.opponent_team);
-        let result = execute_turn(
-            &mut session.team,
-            &mut opponent_team,
-            &mut battle_state,
-            &mut rng,
-            Some(&state.moves),
-        );
-        // Restaurar opponent_team
-        battle_state.opponent_team = opponent_team;
-        result
-    } else {
-        // Para batallas salvajes, crear un Vec temporal con el oponente
-        let mut opponent_vec = vec![battle_state.opponent_instance.clone()];
-        let result = execute_turn(
-            &mut session.team,
-            &mut opponent_vec,
-            &mut battle_state,
-            &mut rng,
-            Some(&state.moves),
-        );
-        // Sincronizar el oponente de vuelta
-        if let Some(opponent) = opponent_vec.first() {
-            battle_state.opponent_instance = opponent.clone();
-            // IMPORTANTE: También sincronizar opponent_team[0] para que get_opponent_active() retorne el valor correcto
-            battle_state.opponent_team[0] = opponent.clone();
-        }
-        result
-    };
+    let turn_result = run_turn(&mut session.team, &mut battle_state, &mut rng, Some(&state.moves));
 
+    // Limpiar las acciones pendientes y añadir los logs al estado de batalla
+    finish_turn(&mut battle_state, &turn_result);
 
-    // Limpiar las acciones pendientes después de ejecutar el turno
-    battle_state.pending_player_actions.clear();
-
-    // Añadir logs al estado de batalla
-    for log in &turn_result.logs {
-        battle_state.add_log(log.clone());
+    // Grabar el turno en el replay de la batalla
+    if let Some(replay) = session.replay.as_mut() {
+        replay.record_turn(turn_seed, player_actions, &turn_result, &session.team, &battle_state);
     }
 
+    resolve_turn_outcome(state, session, battle_state, turn_result)
+}
+
+/// Aplica el resultado de un turno ya ejecutado y grabado
+///
+/// Actualiza la sesión y, si la batalla terminó, reparte experiencia, capturas y
+/// botín (o revive al equipo si el jugador perdió).
+fn resolve_turn_outcome(
+    state: &AppState,
+    session: &mut GameSession,
+    mut battle_state: BattleState,
+    turn_result: TurnResult,
+) -> Result<SubmitMoveResponse, StatusCode> {
     // Obtener HP del primer Pokémon activo para el response
     let player_active_index = battle_state.player_active_indices.first().copied().unwrap_or(0);
     let player_hp = session.team.active_members.get(player_active_index)
@@ -271,9 +219,7 @@ pub async fn submit_move(
         core::battle::BattleOutcome::Continue => {
             // La batalla continúa normalmente
             session.battle = Some(battle_state.clone());
-            // IMPORTANTE: Guardar la sesión antes de retornar
-            state.sessions.insert(payload.session_id.clone(), session.clone());
-            return Ok(Json(SubmitMoveResponse {
+            return Ok(SubmitMoveResponse {
                 result: turn_result,
                 player_hp,
                 enemy_hp,
@@ -282,13 +228,12 @@ pub async fn submit_move(
                 session: None,
                 turn_executed: true,
                 pending_actions: 0,
-            }));
+            });
         }
         core::battle::BattleOutcome::EnemySwitched => {
             // El enemigo cambió de Pokémon, la batalla continúa
             // IMPORTANTE: El battle_state ya tiene el nuevo oponente activo (switch_to_next_opponent ya se ejecutó)
-            // Necesitamos sincronizar opponent_instance para que el frontend lo vea
-            battle_state.sync_opponent_instance();
+            // y finish_turn ya sincronizó opponent_instance para que el frontend lo vea
 
             let next_opponent = battle_state.get_opponent_active().clone();
 
@@ -296,9 +241,7 @@ pub async fn submit_move(
             // (Ya fue manejado en el pipeline, pero lo dejamos por compatibilidad)
 
             session.battle = Some(battle_state.clone());
-            // IMPORTANTE: Guardar la sesión antes de retornar
-            state.sessions.insert(payload.session_id.clone(), session.clone());
-            return Ok(Json(SubmitMoveResponse {
+            return Ok(SubmitMoveResponse {
                 result: turn_result,
                 player_hp,
                 enemy_hp: next_opponent.current_hp,
@@ -307,18 +250,15 @@ pub async fn submit_move(
                 session: None,
                 turn_executed: true,
                 pending_actions: 0,
-            }));
+            });
         }
         core::battle::BattleOutcome::PlayerMustSwitch => {
             // El jugador debe cambiar de Pokémon
             // IMPORTANTE: NO cambiar el estado a Map, mantener Battle/GymBattle
-            battle_state.add_log("¡Necesitas cambiar de Pokémon!".to_string());
+            battle_state.add_localized_log(LocalizedText::new("server.must_switch", []));
             session.battle = Some(battle_state.clone());
 
-            // Guardar la sesión actualizada
-            state.sessions.insert(payload.session_id.clone(), session.clone());
-
-            return Ok(Json(SubmitMoveResponse {
+            return Ok(SubmitMoveResponse {
                 result: turn_result,
                 player_hp, // Será 0
                 enemy_hp,
@@ -327,15 +267,35 @@ pub async fn submit_move(
                 session: None,
                 turn_executed: true,
                 pending_actions: 0,
-            }));
+            });
+        }
+        core::battle::BattleOutcome::PlayerMustPivot => {
+            // Un Pokémon del jugador salió con U-turn o similar: el turno sigue
+            // cuando elija el reemplazo con el endpoint de cambio
+            battle_state.add_localized_log(LocalizedText::new("server.must_pivot", []));
+            session.battle = Some(battle_state.clone());
+
+            return Ok(SubmitMoveResponse {
+                result: turn_result,
+                player_hp,
+                enemy_hp,
+                battle_over: false,
+                player_won: None,
+                session: None,
+                turn_executed: true,
+                pending_actions: 0,
+            });
         }
         core::battle::BattleOutcome::PlayerWon => {
             // El oponente no tiene más Pokémon (o era salvaje) - Jugador ganó la batalla completa
-            battle_state.add_log("¡Has ganado la batalla!".to_string());
+            battle_state.add_localized_log(LocalizedText::new("server.battle_won", []));
 
             // Determinar si fue una batalla de gimnasio
             let is_gym_victory = battle_state.is_trainer_battle;
 
+            // Megaevoluciones y Teracristalización no sobreviven a la batalla
+            session.team.revert_battle_forms();
+
             // EXPERIENCIA GLOBAL (Exp Share): Aplicar subida de nivel a TODO el equipo
             for team_member in &mut session.team.active_members {
                 let species = state.pokedex
@@ -351,17 +311,16 @@ pub async fn submit_move(
                 
                 if levels_gained > 0 {
                     let new_level = team_member.level;
-                    battle_state.add_log(format!(
-                        "¡{} subió al nivel {}!",
-                        team_member.species.display_name,
-                        new_level
-                    ));
+                    battle_state.add_localized_log(LocalizedText::new("server.level_up", [
+                        ("pokemon", team_member.species.display_name.clone()),
+                        ("level", new_level.to_string()),
+                    ]));
                 }
             }
 
             // AUTO-CURACIÓN: Restaurar completamente a todo el equipo después de la victoria
             session.team.heal_all();
-            battle_state.add_log("¡El equipo ha recuperado toda su energía!".to_string());
+            battle_state.add_localized_log(LocalizedText::new("server.team_healed", []));
 
             // PROGRESIÓN DE MOVIMIENTOS: Aprender nuevos movimientos después de la victoria
             // Condición: Es victoria de Gym O cada 2 combates salvajes (encounters_won % 2 == 0)
@@ -369,10 +328,10 @@ pub async fn submit_move(
             
             if should_learn_moves {
                 // Obtener el pool global de movimientos
-                let global_move_pool: Vec<String> = state.moves.keys().cloned().collect();
+                let global_move_pool = state.sorted_move_pool();
                 
                 if !global_move_pool.is_empty() {
-                    let mut rng = StdRng::from_entropy();
+                    let mut rng = session.next_rng();
                     let mut moves_learned = false;
                     
                     // Iterar sobre el equipo activo del jugador
@@ -408,7 +367,7 @@ pub async fn submit_move(
                     }
                     
                     if moves_learned {
-                        battle_state.add_log("¡El equipo aprendió nuevos movimientos!".to_string());
+                        battle_state.add_localized_log(LocalizedText::new("server.team_learned_moves", []));
                     }
                 }
             }
@@ -417,6 +376,7 @@ pub async fn submit_move(
             if !is_gym_victory {
                 // Obtener el Pokémon oponente (ya derrotado, pero lo clonamos para añadirlo)
                 let mut defeated_pokemon = battle_state.get_opponent_active().clone();
+                defeated_pokemon.revert_battle_forms();
                 
                 // Nivelar el Pokémon capturado al nivel del Pokémon activo del jugador
                 let player_active_level = session.team.active_members[player_active_index].level;
@@ -429,10 +389,10 @@ pub async fn submit_move(
                 
                 if current_move_count < target_move_count {
                     // Obtener el pool global de movimientos
-                    let global_move_pool: Vec<String> = state.moves.keys().cloned().collect();
+                    let global_move_pool = state.sorted_move_pool();
                     
                     if !global_move_pool.is_empty() {
-                        let mut rng = StdRng::from_entropy();
+                        let mut rng = session.next_rng();
                         
                         // Añadir movimientos aleatorios hasta igualar la cantidad
                         let moves_to_add = target_move_count - current_move_count;
@@ -469,11 +429,10 @@ pub async fn submit_move(
                         }
                         
                         if added_count > 0 {
-                            battle_state.add_log(format!(
-                                "{} aprendió {} movimientos nuevos al ser capturado!",
-                                defeated_pokemon.species.display_name,
-                                added_count
-                            ));
+                            battle_state.add_localized_log(LocalizedText::new("server.captured_learned_moves", [
+                                ("pokemon", defeated_pokemon.species.display_name.clone()),
+                                ("count", added_count.to_string()),
+                            ]));
                         }
                     }
                 }
@@ -500,9 +459,33 @@ pub async fn submit_move(
                     "sitrus-berry",
                     "rocky-helmet",
                     "focus-sash",
+                    "adamant-mint",
+                    "modest-mint",
+                    "jolly-mint",
+                    "timid-mint",
+                    "hp-up",
+                    "protein",
+                    "iron",
+                    "calcium",
+                    "zinc",
+                    "carbos",
+                    "pomeg-berry",
+                    "kelpsy-berry",
+                    "qualot-berry",
+                    "hondew-berry",
+                    "grepa-berry",
+                    "tamato-berry",
+                    // Megapiedras de las líneas iniciales (solo sirven a su especie)
+                    "venusaurite",
+                    "charizardite-x",
+                    "charizardite-y",
+                    "blastoisinite",
+                    "sceptilite",
+                    "blazikenite",
+                    "swampertite",
                 ];
                 
-                let mut rng = StdRng::from_entropy();
+                let mut rng = session.next_rng();
                 let mut loot = Vec::new();
                 
                 // Seleccionar 3 objetos aleatorios únicos
@@ -518,7 +501,7 @@ pub async fn submit_move(
                 
                 session.loot_options = Some(loot);
                 session.state = GameState::LootSelection;
-                battle_state.add_log("¡Has recibido objetos de recompensa!".to_string());
+                battle_state.add_localized_log(LocalizedText::new("server.loot_received", []));
             } else {
                 // Volver al mapa para encuentros normales
                 session.state = GameState::Map;
@@ -526,71 +509,78 @@ pub async fn submit_move(
             
             // Limpiar el estado de batalla
             session.battle = None;
-            
-            // IMPORTANTE: Guardar la sesión actualizada antes de retornar
-            state.sessions.insert(payload.session_id.clone(), session.clone());
-            
-            return Ok(Json(SubmitMoveResponse {
+
+            return Ok(SubmitMoveResponse {
                 result: turn_result,
                 player_hp,
                 enemy_hp,
                 battle_over: true,
                 player_won: Some(true),
-                session: Some(session),
+                session: Some(session.clone()),
                 turn_executed: true,
                 pending_actions: 0,
-            }));
+            });
         }
         core::battle::BattleOutcome::PlayerLost => {
-            // Todos los Pokémon del jugador están debilitados
-            battle_state.add_log("Has sido derrotado...".to_string());
+            end_lost_battle(session, battle_state);
 
-            // IMPORTANTE: Revivir TODOS los Pokémon del equipo con 1 HP (no solo el activo)
-            // Esto asegura que el jugador pueda continuar jugando
-            for team_member in &mut session.team.active_members {
-                if team_member.current_hp == 0 {
-                    team_member.current_hp = 1;
-                }
-            }
-            
-            // Asegurar que el estado del juego se mantenga (no reiniciar)
-            // El jugador ya eligió su starter, así que NO debe volver a StarterSelection
-            if session.state == GameState::Battle || session.state == GameState::GymBattle {
-                session.state = GameState::Map;
-            }
-            session.battle = None;
-            
-            // IMPORTANTE: Guardar la sesión actualizada antes de retornar
-            state.sessions.insert(payload.session_id.clone(), session.clone());
-            
-            return Ok(Json(SubmitMoveResponse {
+            return Ok(SubmitMoveResponse {
                 result: turn_result,
                 player_hp: 1, // El Pokémon activo ahora tiene 1 HP
                 enemy_hp,
                 battle_over: true,
                 player_won: Some(false),
-                session: Some(session),
+                session: Some(session.clone()),
                 turn_executed: true,
                 pending_actions: 0,
-            }));
+            });
         }
     }
 }
 
+/// Cierra una batalla perdida: todos los Pokémon del jugador están debilitados
+///
+/// Devuelve al equipo a su forma normal, lo revive con 1 HP y vuelve al mapa.
+fn end_lost_battle(
+    session: &mut GameSession,
+    mut battle_state: BattleState,
+) {
+    battle_state.add_localized_log(LocalizedText::new("server.battle_lost", []));
+    session.team.revert_battle_forms();
+
+    // IMPORTANTE: Revivir TODOS los Pokémon del equipo con 1 HP (no solo el activo)
+    // Esto asegura que el jugador pueda continuar jugando
+    for team_member in &mut session.team.active_members {
+        if team_member.current_hp == 0 {
+            team_member.current_hp = 1;
+        }
+    }
+
+    // Asegurar que el estado del juego se mantenga (no reiniciar)
+    // El jugador ya eligió su starter, así que NO debe volver a StarterSelection
+    if session.state == GameState::Battle || session.state == GameState::GymBattle {
+        session.state = GameState::Map;
+    }
+    session.battle = None;
+}
+
 /// Payload para cambiar de Pokémon durante la batalla
 #[derive(Deserialize, Debug)]
 pub struct SwitchPokemonRequest {
     pub session_id: String,
     /// Índice del Pokémon al que cambiar en session.team.active_members
     pub switch_to_index: usize,
+    /// Slot del campo que cambia (0-1 en dobles)
+    #[serde(default)]
+    pub user_index: usize,
 }
 
 /// Respuesta del endpoint de cambio de Pokémon
 #[derive(Serialize, Debug)]
 pub struct SwitchPokemonResponse {
-    /// Resultado del turno (ataque del enemigo)
+    /// Resultado del turno (vacío si el cambio fue forzado o faltan acciones)
     pub result: TurnResult,
-    /// HP del jugador después del cambio y ataque
+    /// HP del Pokémon que entró
     pub player_hp: u16,
     /// HP del enemigo
     pub enemy_hp: u16,
@@ -600,303 +590,230 @@ pub struct SwitchPokemonResponse {
     pub player_won: Option<bool>,
     /// Sesión actualizada
     pub session: GameSession,
+    /// Indica si el turno se ejecutó o si faltan más acciones (en dobles)
+    pub turn_executed: bool,
+    /// Número de acciones pendientes (0 si el turno se ejecutó)
+    pub pending_actions: usize,
 }
 
 /// Handler para cambiar de Pokémon durante la batalla
 /// 
 /// POST /api/game/battle/switch
 /// 
-/// Permite al jugador cambiar su Pokémon activo durante la batalla.
+/// Si el Pokémon del slot está debilitado, el reemplazo entra de inmediato sin
+/// gastar turno. Si no, el cambio es la acción del slot en el turno: se encola
+/// como las de `submit_move` y el pipeline lo ejecuta antes que los movimientos.
 pub async fn switch_pokemon(
     State(state): State<AppState>,
     Json(payload): Json<SwitchPokemonRequest>,
 ) -> Result<Json<SwitchPokemonResponse>, StatusCode> {
-    // Buscar la sesión
-    let mut session = state
-        .sessions
-        .get(&payload.session_id)
-        .ok_or(StatusCode::NOT_FOUND)?
-        .clone();
-    
+    // Buscar la sesión y modificarla con su lock tomado
+    state.sessions.update(&payload.session_id, |session| {
+        // Validar que esté en estado de batalla (normal o gimnasio)
+        if session.state != GameState::Battle && session.state != GameState::GymBattle {
+            return Err(StatusCode::BAD_REQUEST);
+        }
 
-    // Validar que esté en estado de batalla (normal o gimnasio)
-    if session.state != GameState::Battle && session.state != GameState::GymBattle {
-        return Err(StatusCode::BAD_REQUEST);
-    }
+        // Obtener el estado de batalla (clonar para poder modificarlo)
+        let mut battle_state = session
+            .battle
+            .clone()
+            .ok_or(StatusCode::BAD_REQUEST)?;
 
-    // Obtener el estado de batalla (clonar para poder modificarlo)
-    let mut battle_state = session
-        .battle
-        .clone()
-        .ok_or(StatusCode::BAD_REQUEST)?;
+        // Validar que el índice sea válido
+        if payload.switch_to_index >= session.team.active_members.len() {
+            return Err(StatusCode::BAD_REQUEST);
+        }
 
-    // Limpiar acciones pendientes cuando el jugador cambia de Pokémon
-    // (el cambio de Pokémon cancela las acciones del turno)
-    battle_state.pending_player_actions.clear();
+        // Slot que cambia
+        let current_player_index = battle_state
+            .player_active_indices
+            .get(payload.user_index)
+            .copied()
+            .ok_or(StatusCode::BAD_REQUEST)?;
 
-    // Validar que el índice sea válido
-    if payload.switch_to_index >= session.team.active_members.len() {
-        return Err(StatusCode::BAD_REQUEST);
-    }
-
-    // Obtener el primer índice activo (compatibilidad con Single)
-    let current_player_index = battle_state.player_active_indices.first().copied().ok_or(StatusCode::BAD_REQUEST)?;
-    
-    // Validar que no sea el mismo Pokémon que ya está activo
-    if payload.switch_to_index == current_player_index {
-        return Err(StatusCode::BAD_REQUEST);
-    }
+        // Validar que el Pokémon no esté ya en el campo (en cualquier slot)
+        if battle_state.player_active_indices.contains(&payload.switch_to_index) {
+            return Err(StatusCode::BAD_REQUEST);
+        }
 
-    // Validar que el Pokémon al que se quiere cambiar no esté debilitado
-    let target_pokemon = &session.team.active_members[payload.switch_to_index];
-    if target_pokemon.current_hp == 0 {
-        return Err(StatusCode::BAD_REQUEST);
-    }
+        // Validar que el Pokémon al que se quiere cambiar no esté debilitado
+        let target_pokemon = &session.team.active_members[payload.switch_to_index];
+        if target_pokemon.current_hp == 0 {
+            return Err(StatusCode::BAD_REQUEST);
+        }
 
-    // Obtener el Pokémon actual ANTES del cambio para determinar si es forzado
-    let current_active = &session.team.active_members[current_player_index];
-    let is_forced_switch = current_active.current_hp == 0;
+        // Relevo tras U-turn, Baton Pass o un Eject Button/Pack: el reemplazo entra
+        // y el turno detenido continúa
+        if let Some(pivot) = battle_state.pending_pivot.as_ref() {
+            if pivot.slots.first() != Some(&payload.user_index) {
+                return Err(StatusCode::BAD_REQUEST);
+            }
 
-    // Obtener el nombre del Pokémon actual y del nuevo
-    let current_pokemon_name = current_active.species.display_name.clone();
-    let new_pokemon_name = target_pokemon.species.display_name.clone();
+            let turn_seed = battle_state.next_turn_seed();
+            let mut rng = StdRng::seed_from_u64(turn_seed);
+            let turn_result = resume_turn(&mut session.team, &mut battle_state, &mut rng, payload.switch_to_index);
+            finish_turn(&mut battle_state, &turn_result);
 
-    // Cambiar el Pokémon activo (actualizar el primer slot en Single)
-    if let Some(first) = battle_state.player_active_indices.first_mut() {
-        *first = payload.switch_to_index;
-    } else {
-        battle_state.player_active_indices.push(payload.switch_to_index);
-    }
+            if let Some(replay) = session.replay.as_mut() {
+                let action = PendingPlayerAction::switch(payload.user_index, payload.switch_to_index);
+                replay.record_turn(turn_seed, vec![action], &turn_result, &session.team, &battle_state);
+            }
 
-    // Obtener el nuevo Pokémon activo (mutable)
-    let new_active_index = payload.switch_to_index;
-    let mut new_active_pokemon = session.team.active_members[new_active_index].clone();
-    let mut enemy_mon = battle_state.get_opponent_active().clone();
-
-    // Añadir log del cambio
-    if is_forced_switch {
-        // Cambio forzado: solo mostrar el cambio
-        battle_state.add_log(format!(
-            "¡Ve, {}!",
-            new_pokemon_name
-        ));
-    } else {
-        // Cambio táctico: mostrar retiro y entrada
-        battle_state.add_log(format!(
-            "¡Jugador retiró a {}!",
-            current_pokemon_name
-        ));
-        battle_state.add_log(format!(
-            "¡Ve, {}!",
-            new_pokemon_name
-        ));
-    }
+            let response = resolve_turn_outcome(&state, session, battle_state, turn_result)?;
+            let player_hp = session.team.active_members
+                .get(payload.switch_to_index)
+                .map(|p| p.current_hp)
+                .unwrap_or(0);
 
-    // Lógica condicional de ataque
-    let turn_result = if is_forced_switch {
-        // Cambio forzado: El enemigo NO ataca, espera
-        // El jugador no gasta su turno cambiando
-        TurnResult::new()
-    } else {
-        // Cambio táctico: El enemigo SÍ ataca (el jugador gasta su turno cambiando)
-        // Seleccionar un movimiento aleatorio del enemigo
-        let mut rng = StdRng::from_entropy();
-        
-        // Inicializar PP de todos los movimientos del enemigo
-        let enemy_learned_moves = enemy_mon.get_active_learned_moves();
-        let enemy_move_ids: Vec<String> = enemy_learned_moves
-            .iter()
-            .map(|m| m.move_id.clone())
-            .collect();
-        
-        for move_id in &enemy_move_ids {
-            if let Some(move_data) = state.moves.get(move_id) {
-                initialize_move_pp(&mut enemy_mon, move_id, move_data);
-            }
+            return Ok(Json(SwitchPokemonResponse {
+                result: response.result,
+                player_hp,
+                enemy_hp: response.enemy_hp,
+                battle_over: response.battle_over,
+                player_won: response.player_won,
+                session: session.clone(),
+                turn_executed: response.turn_executed,
+                pending_actions: response.pending_actions,
+            }));
         }
-        
-        // Verificar si el enemigo tiene movimientos con PP disponible
-        let enemy_has_pp = has_moves_with_pp(&enemy_mon);
-        
-        // Determinar el movimiento del enemigo
-        let (enemy_move, _enemy_move_template_id) = if !enemy_has_pp {
-            // Sin PP disponible: usar Struggle
-            (create_struggle_move(), "struggle".to_string())
-        } else {
-            // Filtrar movimientos con PP disponible
-            let enemy_learned_moves_after = enemy_mon.get_active_learned_moves();
-            let moves_with_pp: Vec<String> = enemy_learned_moves_after
-                .iter()
-                .filter(|m| m.current_pp > 0)
-                .map(|m| m.move_id.clone())
-                .collect();
-            
-            if moves_with_pp.is_empty() {
-                // No hay movimientos con PP, usar Struggle
-                (create_struggle_move(), "struggle".to_string())
-            } else {
-                // Seleccionar un movimiento aleatorio de los que tienen PP
-                let move_id = moves_with_pp.choose(&mut rng)
-                    .ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
-                let move_data = state
-                    .moves
-                    .get(move_id)
-                    .ok_or(StatusCode::INTERNAL_SERVER_ERROR)?
-                    .clone();
-                (move_data, move_id.clone())
-            }
-        };
 
-        // Inicializar battle_stages si no están inicializados
-        if new_active_pokemon.battle_stages.is_none() {
-            new_active_pokemon.init_battle_stages();
-        }
-        if enemy_mon.battle_stages.is_none() {
-            enemy_mon.init_battle_stages();
+        let is_forced_switch = session.team.active_members[current_player_index].current_hp == 0;
+
+        if !is_forced_switch {
+            // Cambio táctico: es la acción del slot en este turno
+            battle_state.pending_player_actions.retain(|a| a.user_index != payload.user_index);
+            battle_state.pending_player_actions.push(PendingPlayerAction::switch(
+                payload.user_index,
+                payload.switch_to_index,
+            ));
+
+            let response = resolve_pending_turn(
+                &state,
+                session,
+                battle_state,
+                payload.user_index,
+            )?;
+            let player_hp = session.team.active_members
+                .get(payload.switch_to_index)
+                .map(|p| p.current_hp)
+                .unwrap_or(0);
+
+            return Ok(Json(SwitchPokemonResponse {
+                result: response.result,
+                player_hp,
+                enemy_hp: response.enemy_hp,
+                battle_over: response.battle_over,
+                player_won: response.player_won,
+                session: session.clone(),
+                turn_executed: response.turn_executed,
+                pending_actions: response.pending_actions,
+            }));
         }
 
-        // Hook: Activar habilidades de entrada del nuevo Pokémon
-        let mut entry_logs = Vec::new();
-        let (weather_to_set, terrain_to_set) = trigger_on_entry_abilities(&new_active_pokemon, &mut enemy_mon, &mut entry_logs);
-        // Establecer el weather si la habilidad lo activó
-        if let Some(weather) = weather_to_set {
-            battle_state.weather = Some(weather);
-        }
-        // Establecer el terrain si la habilidad lo activó
-        if let Some(terrain) = terrain_to_set {
-            battle_state.terrain = Some(terrain);
-        }
-        // Añadir logs de habilidades de entrada al battle_state
-        for log in &entry_logs {
-            battle_state.add_log(log.clone());
-        }
+        // Cambio forzado: el reemplazo entra sin que el enemigo ataque, pero sí
+        // recibe los hazards de su lado y activa sus habilidades de entrada
+        let result = replace_fainted(&mut session.team, &mut battle_state, payload.user_index, payload.switch_to_index);
 
-        // Ejecutar el ataque del enemigo usando BattleContext
-        // Clonar los nombres antes de prestar mutablemente
-        let attacker_name = enemy_mon.species.display_name.clone();
-        let defender_name = new_active_pokemon.species.display_name.clone();
-
-        let mut ctx = BattleContext::new(
-            &mut enemy_mon,
-            &mut new_active_pokemon,
-            &enemy_move,
-            attacker_name,
-            defender_name,
-            &mut rng,
-            battle_state.weather.as_ref(),
-            battle_state.terrain.as_ref(),
-        );
-
-        // Ejecutar el movimiento
-        let mut result = TurnResult::new();
-        if !ctx.can_execute_move() {
-            result.logs = ctx.logs;
-            result
-        } else {
-            let damage = ctx.calculate_damage();
-            ctx.apply_move_effects(damage);
-            result.logs = ctx.logs;
-            result.enemy_damage_dealt = damage;
-            result
+        if let Some(replay) = session.replay.as_mut() {
+            replay.record_replacement(payload.user_index, payload.switch_to_index, &result, &session.team, &battle_state);
         }
-    };
 
-    // Actualizar los Pokémon en la sesión
-    session.team.active_members[new_active_index] = new_active_pokemon.clone();
-    
-    // Actualizar el oponente en el battle_state (solo si hubo ataque)
-    if !is_forced_switch {
-        if battle_state.is_trainer_battle {
-            // Actualizar el primer slot del oponente (en Single solo hay uno)
-            if let Some(first) = battle_state.opponent_active_indices.first() {
-                battle_state.opponent_team[*first] = enemy_mon.clone();
-            }
-        } else {
-            battle_state.opponent_instance = enemy_mon.clone();
+        // Los hazards pueden haber debilitado al reemplazo
+        if result.outcome == BattleOutcome::PlayerLost {
+            end_lost_battle(session, battle_state);
+            return Ok(Json(SwitchPokemonResponse {
+                result,
+                player_hp: 1,
+                enemy_hp: 0,
+                battle_over: true,
+                player_won: Some(false),
+                session: session.clone(),
+                turn_executed: false,
+                pending_actions: 0,
+            }));
         }
-        battle_state.turn_counter += 1;
-    }
 
-    // Añadir logs al estado de batalla (solo si hubo ataque)
-    if !is_forced_switch {
-        for log in &turn_result.logs {
-            battle_state.add_log(log.clone());
-        }
-    }
+        let player_hp = session.team.active_members[payload.switch_to_index].current_hp;
+        let enemy_hp = battle_state.get_opponent_active().current_hp;
+        session.battle = Some(battle_state);
 
-    // Manejar el resultado del ataque del enemigo (solo si hubo ataque)
-    let mut player_won = None;
-    let battle_over = if is_forced_switch {
-        false // Cambio forzado: la batalla continúa
-    } else {
-        matches!(
-            turn_result.outcome,
-            core::battle::BattleOutcome::PlayerWon | core::battle::BattleOutcome::PlayerLost
-        )
-    };
-
-    if !is_forced_switch {
-        match turn_result.outcome {
-            core::battle::BattleOutcome::PlayerLost => {
-                // Jugador perdió (todos los Pokémon debilitados)
-                player_won = Some(false);
-                battle_state.add_log("Has sido derrotado...".to_string());
-
-                // IMPORTANTE: Revivir TODOS los Pokémon del equipo con 1 HP (no solo el activo)
-                for team_member in &mut session.team.active_members {
-                    if team_member.current_hp == 0 {
-                        team_member.current_hp = 1;
-                    }
-                }
-                
-                // Asegurar que el estado del juego se mantenga (no reiniciar)
-                if session.state == GameState::Battle || session.state == GameState::GymBattle {
-                    session.state = GameState::Map;
-                }
-                session.battle = None;
-            }
-            core::battle::BattleOutcome::PlayerMustSwitch => {
-                // El nuevo Pokémon se debilitó, pero el jugador tiene más disponibles
-                battle_state.add_log("¡Necesitas cambiar de Pokémon!".to_string());
-            }
-            _ => {
-                // La batalla continúa normalmente
-            }
+        Ok(Json(SwitchPokemonResponse {
+            result,
+            player_hp,
+            enemy_hp,
+            battle_over: false,
+            player_won: None,
+            session: session.clone(),
+            turn_executed: false,
+            pending_actions: 0,
+        }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::factory::create_pokemon_instance;
+    use core::models::{BattleFormat, MegaForm, PokemonSpecies, PokemonType, Stats};
+
+    fn species() -> PokemonSpecies {
+        let stats = Stats {
+            hp: 80,
+            attack: 80,
+            defense: 80,
+            special_attack: 80,
+            special_defense: 80,
+            speed: 80,
+        };
+        PokemonSpecies {
+            species_id: "player".to_string(),
+            display_name: "Player".to_string(),
+            generation: 1,
+            primary_type: PokemonType::Normal,
+            secondary_type: None,
+            base_stats: stats.clone(),
+            move_pool: Vec::new(),
+            possible_abilities: vec!["run-away".to_string()],
+            is_starter_candidate: false,
+            evolutions: Vec::new(),
+            ev_yield: Stats::zero(),
+            gender_rate: 4,
+            mega_forms: vec![MegaForm {
+                form_id: "player-mega".to_string(),
+                display_name: "Mega Player".to_string(),
+                mega_stone: "playerite".to_string(),
+                primary_type: PokemonType::Fire,
+                secondary_type: None,
+                base_stats: Stats { attack: 130, ..stats },
+                ability: "drought".to_string(),
+            }],
         }
     }
 
-    // Sincronizar opponent_instance
-    battle_state.sync_opponent_instance();
-
-    // Actualizar el battle_state en la sesión
-    if battle_over && player_won == Some(false) {
-        // Si el jugador perdió, limpiar el estado de batalla
-        session.battle = None;
-    } else {
-        // Actualizar el estado de batalla
-        session.battle = Some(battle_state);
+    #[test]
+    fn test_lost_battle_reverts_battle_forms_and_revives() {
+        let species = species();
+        let mut pokemon = create_pokemon_instance(&species, 50, 1, false, &[], None);
+        let normal_ability = pokemon.ability.clone();
+        pokemon.mega_evolve(&species.mega_forms[0]);
+        pokemon.terastallized = true;
+        pokemon.current_hp = 0;
+
+        let mut session = GameSession::new("lost".to_string());
+        session.team.add_member(pokemon);
+        session.state = GameState::Battle;
+        let wild = create_pokemon_instance(&species, 50, 2, false, &[], None);
+        let battle_state = BattleState::new(0, vec![wild], "wild".to_string(), BattleFormat::Single, false);
+
+        end_lost_battle(&mut session, battle_state);
+
+        let member = &session.team.active_members[0];
+        assert_eq!(member.mega_evolution, None);
+        assert_eq!(member.ability, normal_ability);
+        assert!(!member.terastallized);
+        assert_eq!(member.current_hp, 1);
+        assert_eq!(session.state, GameState::Map);
+        assert!(session.battle.is_none());
     }
-
-    // Guardar la sesión actualizada
-    state.sessions.insert(payload.session_id.clone(), session.clone());
-
-    // Obtener el HP del enemigo (puede haber cambiado si hubo ataque)
-    let enemy_hp = if is_forced_switch {
-        // Si es cambio forzado, obtener el HP del enemigo desde la sesión actualizada
-        session.battle.as_ref()
-            .map(|b| b.get_opponent_active().current_hp)
-            .unwrap_or(enemy_mon.current_hp)
-    } else {
-        enemy_mon.current_hp
-    };
-
-    Ok(Json(SwitchPokemonResponse {
-        result: turn_result,
-        player_hp: new_active_pokemon.current_hp,
-        enemy_hp,
-        battle_over,
-        player_won,
-        session,
-    }))
 }
-
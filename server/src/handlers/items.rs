@@ -0,0 +1,26 @@
+use axum::{extract::State, http::StatusCode, response::Json};
+use core::battle::systems::item_system::ItemDefinition;
+use serde::Serialize;
+
+use crate::state::AppState;
+
+/// Respuesta del endpoint de items
+#[derive(Serialize, Debug)]
+pub struct ItemsResponse {
+    pub items: Vec<ItemDefinition>,
+}
+
+/// Handler para obtener el catálogo de items
+/// 
+/// GET /api/items
+/// 
+/// Devuelve todas las definiciones de items, ordenadas por ID.
+pub async fn get_all_items(
+    State(state): State<AppState>,
+) -> Result<Json<ItemsResponse>, StatusCode> {
+    let mut items: Vec<ItemDefinition> = state.items.values().cloned().collect();
+    items.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(Json(ItemsResponse {
+        items,
+    }))
+}
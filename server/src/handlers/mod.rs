@@ -2,4 +2,5 @@ pub mod game;
 pub mod battle;
 pub mod management;
 pub mod moves;
+pub mod items;
 
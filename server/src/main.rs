@@ -1,9 +1,12 @@
 mod handlers;
 mod state;
+mod store;
 
 use axum::{routing::{get, post}, Router};
-use handlers::{game, battle, management, moves};
-use state::{load_pokedex, load_moves, AppState};
+use handlers::{game, battle, management, moves, items};
+use core::battle::systems::ability_system::register_ability_definitions;
+use core::battle::systems::item_system::register_item_definitions;
+use state::{load_pokedex, load_moves, load_items, load_abilities, open_session_store, AppState};
 use tower_http::cors::{CorsLayer, Any};
 use tower_http::trace::TraceLayer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -33,13 +36,35 @@ async fn main() {
     
     tracing::info!("✅ Movimientos cargados: {} movimientos en memoria", moves.len());
 
+    // Cargar el catálogo de items (el core lo usa para resolver sus efectos)
+    tracing::info!("🎒 Cargando items...");
+    let items = load_items()
+        .expect("Failed to load items - this is a critical error");
+    register_item_definitions(items.clone());
+
+    tracing::info!("✅ Items cargados: {} items en memoria", items.len());
+
+    // Cargar las habilidades (opcional: sin archivo se usan las del core)
+    tracing::info!("✨ Cargando habilidades...");
+    match load_abilities() {
+        Ok(abilities) => {
+            tracing::info!("✅ Habilidades cargadas: {} habilidades en memoria", abilities.len());
+            register_ability_definitions(abilities);
+        }
+        Err(e) => tracing::warn!("⚠️ {} - se usan las habilidades del core", e),
+    }
+
+    // Abrir el almacén de sesiones
+    let sessions = open_session_store()
+        .expect("Failed to open session store - this is a critical error");
+
     // Crear el estado de la aplicación
-    let state = AppState::new(pokedex, moves);
+    let state = AppState::new(pokedex, moves, items, sessions);
 
     // Configurar CORS
     let cors = CorsLayer::new()
         .allow_origin(Any) // Permite cualquier origen (o usar "http://localhost:5173".parse().unwrap() para específico)
-        .allow_methods([axum::http::Method::GET, axum::http::Method::POST])
+        .allow_methods([axum::http::Method::GET, axum::http::Method::POST, axum::http::Method::DELETE])
         .allow_headers([axum::http::header::CONTENT_TYPE]);
 
     // Configurar logging de requests
@@ -68,7 +93,8 @@ async fn main() {
         .route("/api/game/choose-starter", post(game::choose_starter))
         .route("/api/game/explore", post(game::explore))
         .route("/api/game/select-encounter", post(game::select_encounter))
-        .route("/api/game/:session_id", get(game::get_game_state))
+        .route("/api/game/:session_id", get(game::get_game_state).delete(game::delete_game))
+        .route("/api/game/:session_id/replay", get(game::get_battle_replay))
         .route("/api/game/battle/move", post(battle::submit_move))
         .route("/api/game/battle/switch", post(battle::switch_pokemon))
         .route("/api/game/team/reorder", post(management::reorder_team))
@@ -76,6 +102,7 @@ async fn main() {
         .route("/api/game/pokemon/evolve", post(management::evolve_pokemon))
         .route("/api/game/select-loot", post(game::select_loot))
         .route("/api/moves", get(moves::get_all_moves))
+        .route("/api/items", get(items::get_all_items))
         .layer(trace_layer)
         .layer(cors)
         .with_state(state);
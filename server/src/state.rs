@@ -1,8 +1,10 @@
 use std::collections::HashMap;
 use std::sync::Arc;
-use dashmap::DashMap;
+use core::battle::systems::ability_system::{parse_ability_definitions, AbilityDefinition};
+use core::battle::systems::item_system::{parse_item_definitions, ItemDefinition};
 use core::models::{PokemonSpecies, MoveData};
-use core::game::GameSession;
+
+use crate::store::{FileSessionStore, InMemorySessionStore, SessionStore};
 
 /// Estado global de la aplicación compartido entre todos los handlers
 #[derive(Clone)]
@@ -11,17 +13,25 @@ pub struct AppState {
     pub pokedex: Arc<HashMap<String, PokemonSpecies>>,
     /// Base de datos de movimientos indexada por move_id para acceso O(1)
     pub moves: Arc<HashMap<String, MoveData>>,
-    /// Sesiones de juego activas indexadas por session_id
-    pub sessions: Arc<DashMap<String, GameSession>>,
+    /// Catálogo de items indexado por item_id
+    pub items: Arc<HashMap<String, ItemDefinition>>,
+    /// Sesiones de juego indexadas por session_id (en memoria o en disco)
+    pub sessions: Arc<dyn SessionStore>,
 }
 
 impl AppState {
-    /// Crea un nuevo AppState con un pokedex y movimientos vacíos
-    pub fn new(pokedex: HashMap<String, PokemonSpecies>, moves: HashMap<String, MoveData>) -> Self {
+    /// Crea un nuevo AppState con el pokedex, los movimientos, los items y el almacén de sesiones
+    pub fn new(
+        pokedex: HashMap<String, PokemonSpecies>,
+        moves: HashMap<String, MoveData>,
+        items: HashMap<String, ItemDefinition>,
+        sessions: Arc<dyn SessionStore>,
+    ) -> Self {
         Self {
             pokedex: Arc::new(pokedex),
             moves: Arc::new(moves),
-            sessions: Arc::new(DashMap::new()),
+            items: Arc::new(items),
+            sessions,
         }
     }
 
@@ -29,6 +39,23 @@ impl AppState {
     pub fn count(&self) -> usize {
         self.pokedex.len()
     }
+
+    /// Especies del pokedex ordenadas por species_id
+    ///
+    /// El orden de iteración de un `HashMap` cambia entre procesos; para que una
+    /// semilla reproduzca la misma partida los sorteos parten de una lista ordenada.
+    pub fn sorted_species(&self) -> Vec<&PokemonSpecies> {
+        let mut species: Vec<_> = self.pokedex.values().collect();
+        species.sort_by(|a, b| a.species_id.cmp(&b.species_id));
+        species
+    }
+
+    /// IDs de todos los movimientos, ordenados (pool global del modo Chaos)
+    pub fn sorted_move_pool(&self) -> Vec<String> {
+        let mut pool: Vec<String> = self.moves.keys().cloned().collect();
+        pool.sort();
+        pool
+    }
 }
 
 /// Carga el pokedex desde el archivo JSON
@@ -87,3 +114,62 @@ pub fn load_moves() -> Result<HashMap<String, MoveData>, Box<dyn std::error::Err
     Ok(moves)
 }
 
+/// Carga el catálogo de items desde el archivo JSON
+///
+/// # Errors
+///
+/// Retorna un error si:
+/// - No se puede leer el archivo `./data/items.json`
+/// - El JSON no puede ser deserializado a `Vec<ItemDefinition>`
+pub fn load_items() -> Result<HashMap<String, ItemDefinition>, Box<dyn std::error::Error>> {
+    let file_path = "./data/items.json";
+    let contents = std::fs::read_to_string(file_path)
+        .map_err(|e| format!("Failed to read items file at {}: {}", file_path, e))?;
+
+    let items = parse_item_definitions(&contents)
+        .map_err(|e| format!("Failed to parse items JSON: {}", e))?;
+
+    Ok(items)
+}
+
+/// Carga las definiciones de habilidades desde el archivo JSON
+///
+/// El archivo es opcional: sin él, las habilidades usan los hooks escritos en
+/// el core y los nombres derivados de su ID.
+///
+/// # Errors
+///
+/// Retorna un error si:
+/// - No se puede leer el archivo `./data/abilities.json`
+/// - El JSON no puede ser deserializado a `Vec<AbilityDefinition>`
+pub fn load_abilities() -> Result<HashMap<String, AbilityDefinition>, Box<dyn std::error::Error>> {
+    let file_path = "./data/abilities.json";
+    let contents = std::fs::read_to_string(file_path)
+        .map_err(|e| format!("Failed to read abilities file at {}: {}", file_path, e))?;
+
+    let abilities = parse_ability_definitions(&contents)
+        .map_err(|e| format!("Failed to parse abilities JSON: {}", e))?;
+
+    Ok(abilities)
+}
+
+
+/// Crea el almacén de sesiones según las variables de entorno
+///
+/// - `SESSION_STORE=memory`: sesiones en memoria (se pierden al reiniciar)
+/// - en otro caso: archivos JSON en `SESSION_DIR` (por defecto `./data/sessions`)
+///
+/// # Errors
+///
+/// Retorna un error si no se puede crear el directorio de sesiones
+pub fn open_session_store() -> Result<Arc<dyn SessionStore>, Box<dyn std::error::Error>> {
+    let backend = std::env::var("SESSION_STORE").unwrap_or_else(|_| "file".to_string());
+    if backend == "memory" {
+        return Ok(Arc::new(InMemorySessionStore::new()));
+    }
+
+    let dir = std::env::var("SESSION_DIR").unwrap_or_else(|_| "./data/sessions".to_string());
+    let store = FileSessionStore::open(&dir)
+        .map_err(|e| format!("Failed to open session directory {}: {}", dir, e))?;
+    Ok(Arc::new(store))
+}
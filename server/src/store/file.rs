@@ -0,0 +1,338 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use dashmap::DashMap;
+use core::game::GameSession;
+use core::persistence::{decode_session, encode_session};
+
+use super::{lock_session, SessionStore, StoreError};
+
+/// Sesiones que se mantienen en caché por defecto
+const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+/// Sesiones guardadas como JSON (un archivo por sesión) dentro de un directorio
+///
+/// La caché en memoria es la fuente de verdad mientras una sesión está
+/// cargada: cada cambio se aplica ahí y se encola para que un hilo escritor lo
+/// lleve a disco, así que ninguna petición hace I/O de escritura con un lock
+/// tomado. Los archivos usan el sobre versionado de `core::persistence` y se
+/// migran al leerlos.
+///
+/// Las sesiones que llevan más tiempo sin usarse salen de la caché cuando
+/// supera su capacidad, pero solo si no tienen escrituras pendientes (el disco
+/// ya está al día). Al soltar el almacén se espera a que terminen las
+/// escrituras encoladas.
+pub struct FileSessionStore {
+    dir: PathBuf,
+    cache: DashMap<String, Arc<CachedSession>>,
+    capacity: usize,
+    /// Reloj lógico para saber qué sesiones llevan más tiempo sin usarse
+    clock: AtomicU64,
+    writes: Option<Sender<WriteOp>>,
+    writer: Option<JoinHandle<()>>,
+}
+
+/// Sesión en caché
+///
+/// `None` marca una sesión eliminada cuyo archivo puede no haberse borrado
+/// todavía: así una lectura no la recupera del disco entretanto.
+struct CachedSession {
+    session: Mutex<Option<GameSession>>,
+    last_used: AtomicU64,
+}
+
+/// Operación pendiente del hilo escritor
+///
+/// Lleva una referencia a su entrada de la caché para que no se desaloje
+/// antes de llegar a disco.
+enum WriteOp {
+    Save {
+        path: PathBuf,
+        contents: Vec<u8>,
+        _entry: Arc<CachedSession>,
+    },
+    Delete {
+        path: PathBuf,
+        _entry: Arc<CachedSession>,
+    },
+}
+
+impl FileSessionStore {
+    /// Abre (o crea) el directorio de sesiones
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self, StoreError> {
+        Self::open_with_capacity(dir, DEFAULT_CACHE_CAPACITY)
+    }
+
+    /// Abre el directorio de sesiones con una caché de `capacity` sesiones
+    pub fn open_with_capacity(dir: impl Into<PathBuf>, capacity: usize) -> Result<Self, StoreError> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+
+        let (writes, queue) = mpsc::channel();
+        let writer = std::thread::Builder::new()
+            .name("session-writer".to_string())
+            .spawn(move || Self::run_writer(queue))?;
+
+        Ok(Self {
+            dir,
+            cache: DashMap::new(),
+            capacity,
+            clock: AtomicU64::new(0),
+            writes: Some(writes),
+            writer: Some(writer),
+        })
+    }
+
+    /// Ruta del archivo de una sesión
+    ///
+    /// El session_id llega desde el cliente, así que solo se aceptan
+    /// caracteres que no permitan salir del directorio.
+    fn path_for(&self, session_id: &str) -> Result<PathBuf, StoreError> {
+        let valid = !session_id.is_empty()
+            && session_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(StoreError::InvalidId(session_id.to_string()));
+        }
+        Ok(self.dir.join(format!("{}.json", session_id)))
+    }
+
+    fn read_file(path: &Path) -> Result<Option<GameSession>, StoreError> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let document = serde_json::from_str(&contents)
+            .map_err(|e| StoreError::Decode(e.into()))?;
+        decode_session(document).map(Some).map_err(StoreError::Decode)
+    }
+
+    fn encode(session: &GameSession) -> Result<Vec<u8>, StoreError> {
+        let document = encode_session(session).map_err(StoreError::Encode)?;
+        serde_json::to_vec(&document).map_err(StoreError::Encode)
+    }
+
+    /// Escribe a un archivo temporal y lo renombra para no dejar JSON a medias
+    fn write_file(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, contents)?;
+        std::fs::rename(&tmp_path, path)
+    }
+
+    fn delete_file(path: &Path) -> std::io::Result<()> {
+        match std::fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    /// Bucle del hilo escritor: aplica las operaciones en el orden en que se encolaron
+    fn run_writer(queue: Receiver<WriteOp>) {
+        for op in queue {
+            let (path, result) = match op {
+                WriteOp::Save { path, contents, .. } => {
+                    let result = Self::write_file(&path, &contents);
+                    (path, result)
+                }
+                WriteOp::Delete { path, .. } => {
+                    let result = Self::delete_file(&path);
+                    (path, result)
+                }
+            };
+            if let Err(e) = result {
+                tracing::error!("❌ No se pudo actualizar {}: {}", path.display(), e);
+            }
+        }
+    }
+
+    /// Encola una operación para el hilo escritor
+    ///
+    /// Se llama con el lock de la sesión tomado, así que las operaciones de
+    /// una misma sesión llegan a disco en el mismo orden que a la caché.
+    fn enqueue(&self, op: WriteOp) {
+        let sent = self.writes.as_ref().map(|writes| writes.send(op));
+        if !matches!(sent, Some(Ok(()))) {
+            tracing::error!("❌ El hilo escritor de sesiones no está disponible");
+        }
+    }
+
+    fn touch(&self, entry: &CachedSession) {
+        let now = self.clock.fetch_add(1, Ordering::Relaxed);
+        entry.last_used.store(now, Ordering::Relaxed);
+    }
+
+    /// Entrada de la sesión, cargándola desde disco si aún no está en caché
+    fn slot(&self, session_id: &str) -> Result<Option<Arc<CachedSession>>, StoreError> {
+        let cached = self.cache.get(session_id).map(|entry| Arc::clone(&entry));
+        if let Some(entry) = cached {
+            self.touch(&entry);
+            self.evict();
+            return Ok(Some(entry));
+        }
+
+        let path = match self.path_for(session_id) {
+            Ok(path) => path,
+            // Un id inválido nunca pudo haberse guardado
+            Err(StoreError::InvalidId(_)) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        // Una sesión fuera de caché no tiene escrituras pendientes: el disco está al día
+        let Some(session) = Self::read_file(&path)? else {
+            return Ok(None);
+        };
+        // Si otra petición la cargó mientras tanto, se usa su entrada
+        let entry = Arc::clone(
+            &self
+                .cache
+                .entry(session_id.to_string())
+                .or_insert_with(|| Arc::new(CachedSession::new(Some(session)))),
+        );
+        self.touch(&entry);
+        self.evict();
+        Ok(Some(entry))
+    }
+
+    /// Saca de la caché las sesiones menos usadas mientras supere su capacidad
+    ///
+    /// Solo se desalojan las entradas que nadie más referencia: ni una
+    /// petición en curso ni una escritura pendiente. Se llama en cada acceso,
+    /// así que las que tenían escrituras pendientes salen en uno posterior.
+    fn evict(&self) {
+        let surplus = self.cache.len().saturating_sub(self.capacity);
+        if surplus == 0 {
+            return;
+        }
+
+        let mut idle: Vec<(u64, String)> = self
+            .cache
+            .iter()
+            .filter(|entry| Arc::strong_count(entry.value()) == 1)
+            .map(|entry| (entry.last_used.load(Ordering::Relaxed), entry.key().clone()))
+            .collect();
+        idle.sort_unstable();
+
+        let mut evicted = 0;
+        for (_, session_id) in idle {
+            if evicted == surplus {
+                break;
+            }
+            // Se vuelve a comprobar con el shard bloqueado por si alguien la tomó entretanto
+            if self
+                .cache
+                .remove_if(&session_id, |_, entry| Arc::strong_count(entry) == 1)
+                .is_some()
+            {
+                evicted += 1;
+            }
+        }
+    }
+
+    #[cfg(test)]
+    pub(super) fn cached_len(&self) -> usize {
+        self.cache.len()
+    }
+}
+
+impl CachedSession {
+    fn new(session: Option<GameSession>) -> Self {
+        Self {
+            session: Mutex::new(session),
+            last_used: AtomicU64::new(0),
+        }
+    }
+}
+
+impl Drop for FileSessionStore {
+    /// Cierra la cola y espera a que el hilo escritor termine lo pendiente
+    fn drop(&mut self) {
+        drop(self.writes.take());
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.join();
+        }
+    }
+}
+
+impl SessionStore for FileSessionStore {
+    fn get(&self, session_id: &str) -> Result<Option<GameSession>, StoreError> {
+        Ok(self
+            .slot(session_id)?
+            .and_then(|entry| lock_session(&entry.session).clone()))
+    }
+
+    fn insert(&self, session_id: String, session: GameSession) -> Result<(), StoreError> {
+        let path = self.path_for(&session_id)?;
+        let contents = Self::encode(&session)?;
+
+        // Se clona el `Arc` para no retener el shard del mapa con el lock de la sesión
+        let entry = Arc::clone(
+            &self
+                .cache
+                .entry(session_id)
+                .or_insert_with(|| Arc::new(CachedSession::new(None))),
+        );
+        self.touch(&entry);
+
+        let mut current = lock_session(&entry.session);
+        self.enqueue(WriteOp::Save {
+            path,
+            contents,
+            _entry: Arc::clone(&entry),
+        });
+        *current = Some(session);
+        drop(current);
+        drop(entry);
+
+        self.evict();
+        Ok(())
+    }
+
+    fn modify(
+        &self,
+        session_id: &str,
+        apply: &mut dyn FnMut(&mut GameSession) -> bool,
+    ) -> Result<bool, StoreError> {
+        let Some(entry) = self.slot(session_id)? else {
+            return Ok(false);
+        };
+        let path = self.path_for(session_id)?;
+
+        let mut current = lock_session(&entry.session);
+        let Some(session) = current.as_mut() else {
+            return Ok(false);
+        };
+        let mut updated = session.clone();
+        if apply(&mut updated) {
+            let contents = Self::encode(&updated)?;
+            self.enqueue(WriteOp::Save {
+                path,
+                contents,
+                _entry: Arc::clone(&entry),
+            });
+            *session = updated;
+        }
+        Ok(true)
+    }
+
+    fn remove(&self, session_id: &str) -> Result<bool, StoreError> {
+        let Some(entry) = self.slot(session_id)? else {
+            return Ok(false);
+        };
+        let path = self.path_for(session_id)?;
+
+        let mut current = lock_session(&entry.session);
+        if current.take().is_none() {
+            return Ok(false);
+        }
+        self.enqueue(WriteOp::Delete {
+            path,
+            _entry: Arc::clone(&entry),
+        });
+        Ok(true)
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::{Arc, Mutex};
+
+use dashmap::DashMap;
+use core::game::GameSession;
+
+use super::{lock_session, SessionStore, StoreError};
+
+/// Sesiones en memoria: se pierden al reiniciar el servidor
+#[derive(Default)]
+pub struct InMemorySessionStore {
+    sessions: DashMap<String, Arc<Mutex<GameSession>>>,
+}
+
+impl InMemorySessionStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lock de la sesión (se clona el `Arc` para no retener el shard del mapa)
+    fn slot(&self, session_id: &str) -> Option<Arc<Mutex<GameSession>>> {
+        self.sessions.get(session_id).map(|slot| Arc::clone(&slot))
+    }
+}
+
+impl SessionStore for InMemorySessionStore {
+    fn get(&self, session_id: &str) -> Result<Option<GameSession>, StoreError> {
+        Ok(self.slot(session_id).map(|slot| lock_session(&slot).clone()))
+    }
+
+    fn insert(&self, session_id: String, session: GameSession) -> Result<(), StoreError> {
+        match self.slot(&session_id) {
+            Some(slot) => *lock_session(&slot) = session,
+            None => {
+                self.sessions.insert(session_id, Arc::new(Mutex::new(session)));
+            }
+        }
+        Ok(())
+    }
+
+    fn modify(
+        &self,
+        session_id: &str,
+        apply: &mut dyn FnMut(&mut GameSession) -> bool,
+    ) -> Result<bool, StoreError> {
+        let Some(slot) = self.slot(session_id) else {
+            return Ok(false);
+        };
+
+        let mut session = lock_session(&slot);
+        let mut updated = session.clone();
+        if apply(&mut updated) {
+            *session = updated;
+        }
+        Ok(true)
+    }
+
+    fn remove(&self, session_id: &str) -> Result<bool, StoreError> {
+        Ok(self.sessions.remove(session_id).is_some())
+    }
+}
@@ -0,0 +1,125 @@
+//! Persistencia de las sesiones de juego
+//!
+//! Los handlers acceden a las sesiones a través del trait `SessionStore`, de
+//! modo que el backend (memoria o disco) se elige al arrancar el servidor.
+//!
+//! Cada sesión vive tras su propio `Mutex`: las peticiones que la modifican
+//! pasan por `update`, que lee, modifica y guarda sin que otra petición sobre
+//! la misma sesión se cuele en medio (y sin perder sus cambios ni repetir
+//! semillas del RNG).
+
+mod file;
+mod memory;
+#[cfg(test)]
+mod tests;
+
+pub use file::FileSessionStore;
+pub use memory::InMemorySessionStore;
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use axum::http::StatusCode;
+use core::game::GameSession;
+use core::persistence::SessionDecodeError;
+
+/// Almacén de sesiones de juego indexadas por session_id
+pub trait SessionStore: Send + Sync {
+    /// Obtiene una copia de la sesión, o `None` si no existe
+    fn get(&self, session_id: &str) -> Result<Option<GameSession>, StoreError>;
+
+    /// Guarda (o reemplaza) la sesión
+    fn insert(&self, session_id: String, session: GameSession) -> Result<(), StoreError>;
+
+    /// Modifica la sesión con el lock de esa sesión tomado
+    ///
+    /// `apply` recibe una copia de la sesión; si devuelve `true` la copia se
+    /// guarda y reemplaza a la anterior, si no se descarta. Retorna `false` si
+    /// la sesión no existe. Usar `update` en lugar de llamarlo directamente.
+    fn modify(
+        &self,
+        session_id: &str,
+        apply: &mut dyn FnMut(&mut GameSession) -> bool,
+    ) -> Result<bool, StoreError>;
+
+    /// Elimina la sesión; retorna `false` si no existía
+    fn remove(&self, session_id: &str) -> Result<bool, StoreError>;
+}
+
+impl dyn SessionStore {
+    /// Lee, modifica y guarda una sesión de forma atómica
+    ///
+    /// Los cambios de `f` solo se guardan si retorna `Ok`; con un error la
+    /// sesión queda como estaba. Retorna `NOT_FOUND` si la sesión no existe.
+    pub fn update<T>(
+        &self,
+        session_id: &str,
+        f: impl FnOnce(&mut GameSession) -> Result<T, StatusCode>,
+    ) -> Result<T, StatusCode> {
+        let mut f = Some(f);
+        let mut result = None;
+        let found = self.modify(session_id, &mut |session| {
+            let Some(f) = f.take() else {
+                return false;
+            };
+            let outcome = f(session);
+            let commit = outcome.is_ok();
+            result = Some(outcome);
+            commit
+        })?;
+
+        match result {
+            Some(outcome) if found => outcome,
+            _ => Err(StatusCode::NOT_FOUND),
+        }
+    }
+}
+
+/// Toma el lock de una sesión
+///
+/// Un pánico dentro de `modify` ocurre antes de reemplazar la sesión, así que
+/// el valor guardado sigue siendo válido aunque el `Mutex` quede envenenado.
+fn lock_session<T>(slot: &Mutex<T>) -> MutexGuard<'_, T> {
+    slot.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Error de un backend de sesiones
+#[derive(Debug)]
+pub enum StoreError {
+    /// El session_id no es válido para este backend
+    InvalidId(String),
+    /// Fallo de lectura/escritura en disco
+    Io(std::io::Error),
+    /// No se pudo serializar la sesión
+    Encode(serde_json::Error),
+    /// La sesión guardada no se pudo decodificar ni migrar
+    Decode(SessionDecodeError),
+}
+
+impl std::fmt::Display for StoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StoreError::InvalidId(id) => write!(f, "invalid session id '{}'", id),
+            StoreError::Io(e) => write!(f, "session storage I/O error: {}", e),
+            StoreError::Encode(e) => write!(f, "failed to encode session: {}", e),
+            StoreError::Decode(e) => write!(f, "failed to decode session: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+impl From<std::io::Error> for StoreError {
+    fn from(e: std::io::Error) -> Self {
+        StoreError::Io(e)
+    }
+}
+
+impl From<StoreError> for StatusCode {
+    fn from(e: StoreError) -> Self {
+        tracing::error!("❌ Error en el almacén de sesiones: {}", e);
+        match e {
+            StoreError::InvalidId(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
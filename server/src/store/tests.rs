@@ -0,0 +1,217 @@
+//! Tests de los backends de sesiones
+//!
+//! Los comportamientos comunes se comprueban contra ambos backends; la
+//! recarga tras reinicio y el desalojo de la caché solo aplican al de disco.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::http::StatusCode;
+use core::game::GameSession;
+use uuid::Uuid;
+
+use super::{FileSessionStore, InMemorySessionStore, SessionStore, StoreError};
+
+/// Directorio temporal que se borra al salir del test
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new() -> Self {
+        Self(std::env::temp_dir().join(format!("sessions-{}", Uuid::new_v4())))
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Un almacén de cada backend (el directorio debe vivir lo que dure el test)
+fn stores(dir: &TempDir) -> Vec<(&'static str, Arc<dyn SessionStore>)> {
+    vec![
+        ("memory", Arc::new(InMemorySessionStore::new())),
+        ("file", Arc::new(FileSessionStore::open(dir.path()).unwrap())),
+    ]
+}
+
+fn session(id: &str, encounters_won: u32) -> GameSession {
+    let mut session = GameSession::new(id.to_string());
+    session.encounters_won = encounters_won;
+    session
+}
+
+fn as_json(session: &GameSession) -> serde_json::Value {
+    serde_json::to_value(session).unwrap()
+}
+
+#[test]
+fn test_insert_and_get_round_trip() {
+    let dir = TempDir::new();
+    for (backend, store) in stores(&dir) {
+        let original = session("round-trip", 3);
+        store.insert("round-trip".to_string(), original.clone()).unwrap();
+
+        let loaded = store.get("round-trip").unwrap().expect(backend);
+        assert_eq!(as_json(&loaded), as_json(&original), "{}", backend);
+
+        // Insertar de nuevo reemplaza la sesión
+        store.insert("round-trip".to_string(), session("round-trip", 4)).unwrap();
+        assert_eq!(store.get("round-trip").unwrap().unwrap().encounters_won, 4, "{}", backend);
+    }
+}
+
+#[test]
+fn test_missing_session() {
+    let dir = TempDir::new();
+    for (backend, store) in stores(&dir) {
+        assert!(store.get("missing").unwrap().is_none(), "{}", backend);
+        assert!(!store.remove("missing").unwrap(), "{}", backend);
+
+        let result = store.update("missing", |session| {
+            session.encounters_won += 1;
+            Ok(())
+        });
+        assert_eq!(result, Err(StatusCode::NOT_FOUND), "{}", backend);
+    }
+}
+
+#[test]
+fn test_update_only_commits_on_ok() {
+    let dir = TempDir::new();
+    for (backend, store) in stores(&dir) {
+        store.insert("commit".to_string(), session("commit", 0)).unwrap();
+
+        let failed: Result<(), StatusCode> = store.update("commit", |session| {
+            session.encounters_won = 99;
+            Err(StatusCode::BAD_REQUEST)
+        });
+        assert_eq!(failed, Err(StatusCode::BAD_REQUEST), "{}", backend);
+        assert_eq!(store.get("commit").unwrap().unwrap().encounters_won, 0, "{}", backend);
+
+        let won = store.update("commit", |session| {
+            session.encounters_won += 1;
+            Ok(session.encounters_won)
+        });
+        assert_eq!(won, Ok(1), "{}", backend);
+        assert_eq!(store.get("commit").unwrap().unwrap().encounters_won, 1, "{}", backend);
+    }
+}
+
+#[test]
+fn test_concurrent_updates_are_not_lost() {
+    const THREADS: u32 = 8;
+    const UPDATES: u32 = 25;
+
+    let dir = TempDir::new();
+    for (backend, store) in stores(&dir) {
+        store.insert("shared".to_string(), session("shared", 0)).unwrap();
+
+        let workers: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let store = Arc::clone(&store);
+                std::thread::spawn(move || {
+                    for _ in 0..UPDATES {
+                        let before = store.update("shared", |session| {
+                            let before = session.encounters_won;
+                            // Da tiempo a que otro hilo intente colarse entre la lectura y la escritura
+                            std::thread::yield_now();
+                            session.encounters_won = before + 1;
+                            Ok(before)
+                        });
+                        assert!(before.is_ok());
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        let total = store.get("shared").unwrap().unwrap().encounters_won;
+        assert_eq!(total, THREADS * UPDATES, "{}", backend);
+    }
+}
+
+#[test]
+fn test_remove_session() {
+    let dir = TempDir::new();
+    for (backend, store) in stores(&dir) {
+        store.insert("removed".to_string(), session("removed", 1)).unwrap();
+
+        assert!(store.remove("removed").unwrap(), "{}", backend);
+        assert!(store.get("removed").unwrap().is_none(), "{}", backend);
+        assert!(!store.remove("removed").unwrap(), "{}", backend);
+        assert_eq!(store.update("removed", |_| Ok(())), Err(StatusCode::NOT_FOUND), "{}", backend);
+    }
+}
+
+#[test]
+fn test_file_store_reloads_after_restart() {
+    let dir = TempDir::new();
+    {
+        let store: Arc<dyn SessionStore> = Arc::new(FileSessionStore::open(dir.path()).unwrap());
+        store.insert("kept".to_string(), session("kept", 0)).unwrap();
+        store.insert("removed".to_string(), session("removed", 0)).unwrap();
+        store
+            .update("kept", |session| {
+                session.encounters_won = 7;
+                Ok(())
+            })
+            .unwrap();
+        assert!(store.remove("removed").unwrap());
+        // Al soltar el almacén se esperan las escrituras pendientes
+    }
+
+    assert!(dir.path().join("kept.json").exists());
+    assert!(!dir.path().join("removed.json").exists());
+
+    let store = FileSessionStore::open(dir.path()).unwrap();
+    assert_eq!(store.get("kept").unwrap().unwrap().encounters_won, 7);
+    assert!(store.get("removed").unwrap().is_none());
+}
+
+#[test]
+fn test_file_store_rejects_unsafe_ids() {
+    let dir = TempDir::new();
+    let store = FileSessionStore::open(dir.path()).unwrap();
+
+    let result = store.insert("../escape".to_string(), session("escape", 0));
+    assert!(matches!(result, Err(StoreError::InvalidId(_))));
+    assert!(store.get("../escape").unwrap().is_none());
+}
+
+#[test]
+fn test_file_store_evicts_idle_sessions() {
+    const CAPACITY: usize = 2;
+
+    let dir = TempDir::new();
+    let store = FileSessionStore::open_with_capacity(dir.path(), CAPACITY).unwrap();
+    let ids: Vec<String> = (0..5).map(|i| format!("session-{}", i)).collect();
+    for (i, id) in ids.iter().enumerate() {
+        store.insert(id.clone(), session(id, i as u32)).unwrap();
+    }
+
+    // Las sesiones con escrituras pendientes no se desalojan: se recorren
+    // hasta que el hilo escritor se pone al día y la caché vuelve al límite
+    let mut attempts = 0;
+    while store.cached_len() > CAPACITY {
+        assert!(attempts < 200, "la caché nunca bajó de {} sesiones", store.cached_len());
+        for (i, id) in ids.iter().enumerate() {
+            assert_eq!(store.get(id).unwrap().unwrap().encounters_won, i as u32);
+        }
+        std::thread::sleep(Duration::from_millis(10));
+        attempts += 1;
+    }
+
+    // Las sesiones desalojadas se recargan desde disco sin perder datos
+    for (i, id) in ids.iter().enumerate() {
+        assert_eq!(store.get(id).unwrap().unwrap().encounters_won, i as u32);
+    }
+    assert!(store.cached_len() <= CAPACITY);
+}
@@ -184,7 +184,13 @@ function App() {
   };
 
   // Enviar movimiento en batalla
-  const handleMoveSelect = async (moveId: string, userIndex: number, targetPosition?: FieldPosition | null) => {
+  const handleMoveSelect = async (
+    moveId: string,
+    userIndex: number,
+    targetPosition?: FieldPosition | null,
+    terastallize = false,
+    megaEvolve = false,
+  ) => {
     if (!sessionId || !gameState) return;
 
     setLoading(true);
@@ -195,6 +201,8 @@ function App() {
         move_id: moveId,
         user_index: userIndex,
         target_position: targetPosition ?? null,
+        terastallize,
+        mega_evolve: megaEvolve,
       });
 
       // Si el turno no se ejecutó (faltan más acciones en dobles), mostrar mensaje
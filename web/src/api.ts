@@ -4,6 +4,7 @@ import {
   TurnResult,
   FieldPosition,
   Move,
+  ItemDefinition,
 } from './types';
 
 // El proxy de Vite redirige /api a http://localhost:3000/api
@@ -13,6 +14,7 @@ const API_BASE_URL = '/api';
 export interface NewGameResponse {
   session_id: string;
   starters: PokemonInstance[];
+  seed: number;
 }
 
 export interface ExploreResponse {
@@ -32,6 +34,8 @@ export const api = {
     total_encounters?: number;
     chaos_move_randomizer?: boolean;
     preferred_format?: 'Single' | 'Double';
+    locale?: 'es' | 'en';
+    seed?: number;
   }): Promise<NewGameResponse> {
     const payload: {
       generations?: number[];
@@ -39,6 +43,8 @@ export const api = {
       total_encounters?: number;
       chaos_move_randomizer?: boolean;
       preferred_format?: 'Single' | 'Double';
+      locale?: 'es' | 'en';
+      seed?: number;
     } = {};
 
     if (config) {
@@ -57,6 +63,12 @@ export const api = {
       if (config.preferred_format !== undefined) {
         payload.preferred_format = config.preferred_format;
       }
+      if (config.locale !== undefined) {
+        payload.locale = config.locale;
+      }
+      if (config.seed !== undefined) {
+        payload.seed = config.seed;
+      }
     }
 
     const response = await fetch(`${API_BASE_URL}/game/new`, {
@@ -165,6 +177,8 @@ export const api = {
       move_id: string;
       user_index: number;
       target_position?: FieldPosition | null;
+      terastallize?: boolean;
+      mega_evolve?: boolean;
     }
   ): Promise<{
     result: TurnResult;
@@ -182,6 +196,8 @@ export const api = {
       move_id: moveInput.move_id,
       user_index: moveInput.user_index,
       target_position: moveInput.target_position ?? null,
+      terastallize: moveInput.terastallize ?? false,
+      mega_evolve: moveInput.mega_evolve ?? false,
     };
     
     console.log('[DEBUG] submitMove: Enviando payload:', payload);
@@ -220,13 +236,15 @@ export const api = {
   /**
    * Cambia de Pokémon durante la batalla
    */
-  async switchPokemon(sessionId: string, index: number): Promise<{
+  async switchPokemon(sessionId: string, index: number, userIndex = 0): Promise<{
     result: TurnResult;
     player_hp: number;
     enemy_hp: number;
     battle_over: boolean;
     player_won: boolean | null;
     session: GameSession;
+    turn_executed: boolean;
+    pending_actions: number;
   }> {
     const response = await fetch(`${API_BASE_URL}/game/battle/switch`, {
       method: 'POST',
@@ -236,6 +254,7 @@ export const api = {
       body: JSON.stringify({
         session_id: sessionId,
         switch_to_index: index,
+        user_index: userIndex,
       }),
     });
 
@@ -340,5 +359,24 @@ export const api = {
     const data = await response.json();
     return data.moves;
   },
+
+  /**
+   * Obtiene el catálogo de items
+   */
+  async getAllItems(): Promise<ItemDefinition[]> {
+    const response = await fetch(`${API_BASE_URL}/items`, {
+      method: 'GET',
+      headers: {
+        'Content-Type': 'application/json',
+      },
+    });
+
+    if (!response.ok) {
+      throw new Error(`Failed to get items: ${response.statusText}`);
+    }
+
+    const data = await response.json();
+    return data.items;
+  },
 };
 
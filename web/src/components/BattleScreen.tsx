@@ -13,7 +13,13 @@ import { api } from '../api';
 
 interface BattleScreenProps {
   session: GameSession;
-  onMoveSelect: (moveId: string, userIndex: number, targetPosition?: FieldPosition | null) => void;
+  onMoveSelect: (
+    moveId: string,
+    userIndex: number,
+    targetPosition?: FieldPosition | null,
+    terastallize?: boolean,
+    megaEvolve?: boolean,
+  ) => void;
   onSwitchPokemon: (index: number) => void;
   isBoss?: boolean;
 }
@@ -34,6 +40,8 @@ export function BattleScreen({ session, onMoveSelect, onSwitchPokemon, isBoss =
     userIndex: number;
   } | null>(null);
   const [movesMap, setMovesMap] = useState<Record<string, Move>>({});
+  const [teraSelected, setTeraSelected] = useState(false);
+  const [megaSelected, setMegaSelected] = useState(false);
 
   // Cargar los movimientos al montar el componente
   useEffect(() => {
@@ -87,12 +95,25 @@ export function BattleScreen({ session, onMoveSelect, onSwitchPokemon, isBoss =
 
   // Detectar si algún Pokémon del jugador está debilitado
   const isPokemonFainted = activePokemon.current_hp === 0;
+  // Relevo pendiente (U-turn, Volt Switch, Eject Button...): hay que elegir quién entra
+  const mustPivot = !!session.battle.pending_pivot;
+  const mustSwitch = isPokemonFainted || mustPivot;
+  const canTerastallize = !mustSwitch
+    && session.battle.player_terastallized == null
+    && !!activePokemon.tera_type
+    && activePokemon.tera_type !== 'Unknown';
+  // Forma Mega que activa la megapiedra equipada (si es la de su especie)
+  const megaForm = activePokemon.species.mega_forms?.find(form => form.mega_stone === activePokemon.held_item);
+  const canMegaEvolve = !mustSwitch
+    && session.battle.player_mega_evolved == null
+    && !activePokemon.mega_evolution
+    && !!megaForm;
   
   useEffect(() => {
-    if (isPokemonFainted) {
+    if (mustSwitch) {
       setShowSwitchModal(true);
     }
-  }, [isPokemonFainted]);
+  }, [mustSwitch]);
 
   // Determinar el color de fondo según el clima
   const getBackgroundColor = () => {
@@ -126,6 +147,13 @@ export function BattleScreen({ session, onMoveSelect, onSwitchPokemon, isBoss =
 
   const terrainGradient = getTerrainGradient();
 
+  // Envía el movimiento con la teracristalización y megaevolución elegidas (solo se usan una vez)
+  const sendMove = (moveId: string, userIndex: number, targetPosition: FieldPosition | null) => {
+    onMoveSelect(moveId, userIndex, targetPosition, teraSelected, megaSelected);
+    setTeraSelected(false);
+    setMegaSelected(false);
+  };
+
   // Manejar selección de movimiento
   const handleMoveClick = (moveId: string, userIndex: number) => {
     console.log('[DEBUG] BattleScreen: handleMoveClick - moveId:', moveId, 'userIndex:', userIndex, 'battleFormat:', battleFormat);
@@ -141,7 +169,7 @@ export function BattleScreen({ session, onMoveSelect, onSwitchPokemon, isBoss =
         // Movimientos que afectan al usuario mismo (Swords Dance, Recover, etc.)
         // NUNCA pedir selección, enviar inmediatamente con target_position: null
         console.log('[DEBUG] BattleScreen: target="user" - Enviando inmediatamente sin selección');
-        onMoveSelect(moveId, userIndex, null);
+        sendMove(moveId, userIndex, null);
         break;
         
       case 'random-opponent':
@@ -151,7 +179,7 @@ export function BattleScreen({ session, onMoveSelect, onSwitchPokemon, isBoss =
       case 'opponents-field':
         // Movimientos de área o automáticos - enviar inmediatamente
         console.log('[DEBUG] BattleScreen: target="' + moveTarget + '" - Enviando inmediatamente sin selección');
-        onMoveSelect(moveId, userIndex, null);
+        sendMove(moveId, userIndex, null);
         break;
         
       case 'ally':
@@ -163,7 +191,7 @@ export function BattleScreen({ session, onMoveSelect, onSwitchPokemon, isBoss =
         } else {
           // En Single, no hay aliado, enviar con null (el backend manejará el error)
           console.log('[DEBUG] BattleScreen: target="ally" en Single - Enviando con null (no hay aliado)');
-          onMoveSelect(moveId, userIndex, null);
+          sendMove(moveId, userIndex, null);
         }
         break;
         
@@ -177,7 +205,7 @@ export function BattleScreen({ session, onMoveSelect, onSwitchPokemon, isBoss =
         } else {
           // En Single: automáticamente asignar OpponentLeft
           console.log('[DEBUG] BattleScreen: target="selected-pokemon" en Single - Asignando automáticamente OpponentLeft');
-          onMoveSelect(moveId, userIndex, 'OpponentLeft');
+          sendMove(moveId, userIndex, 'OpponentLeft');
         }
         break;
     }
@@ -266,12 +294,17 @@ export function BattleScreen({ session, onMoveSelect, onSwitchPokemon, isBoss =
           <div className="flex justify-between items-center mb-1">
             <span className={`font-bold text-sm ${isBoss && !isPlayer ? 'text-red-900' : 'text-black'}`}>
               {pokemon.species.display_name}
+              {pokemon.gender === 'Male' && <span className="text-blue-600"> ♂</span>}
+              {pokemon.gender === 'Female' && <span className="text-pink-600"> ♀</span>}
+              {pokemon.mega_evolution && <span className="text-orange-600"> Mega</span>}
+              {pokemon.terastallized && <span className="text-purple-600"> ✦ {pokemon.tera_type}</span>}
             </span>
             <span className="text-xs text-black">Lv. {pokemon.level}</span>
           </div>
           
           <div className="text-xs text-gray-600 mb-1" title={`ID: ${pokemon.ability}`}>
             {formatAbilityName(pokemon.ability)}
+            {isPlayer && pokemon.nature && ` · ${pokemon.nature}`}
           </div>
           
           <div className="flex items-center gap-2 mb-1">
@@ -426,6 +459,23 @@ export function BattleScreen({ session, onMoveSelect, onSwitchPokemon, isBoss =
             </div>
           )}
 
+          {mustPivot && !isPokemonFainted && (
+            <div
+              style={{
+                backgroundColor: '#3b82f6',
+                color: '#ffffff',
+                padding: '12px',
+                borderRadius: '4px',
+                marginBottom: '8px',
+                textAlign: 'center',
+                fontWeight: 'bold',
+                fontSize: '14px',
+              }}
+            >
+              ¡Tu Pokémon vuelve! Elige quién entra.
+            </div>
+          )}
+
           {/* Grid de Movimientos */}
           <div
             style={{
@@ -447,30 +497,37 @@ export function BattleScreen({ session, onMoveSelect, onSwitchPokemon, isBoss =
                     max_pp: 0,
                   }));
               
+              // Bloqueo de Choice: solo el movimiento bloqueado mientras le queden PP
+              // (sin PP, el servidor usa Forcejeo con cualquier elección)
+              const lockedMove = activePokemon.volatile_status?.choice_locked_move ?? null;
+              const lockedMoveHasPp = movesToShow.some(m => m.move_id === lockedMove && m.current_pp > 0);
+              const isUnavailable = (move: { move_id: string; current_pp: number }) =>
+                mustSwitch || move.current_pp === 0 || (lockedMoveHasPp && move.move_id !== lockedMove);
+
               return movesToShow.map((move, index) => (
                 <button
                   key={index}
                   onClick={() => handleMoveClick(move.move_id, 0)}
-                  disabled={isPokemonFainted || move.current_pp === 0}
+                  disabled={isUnavailable(move)}
                   style={{
-                    backgroundColor: isPokemonFainted || move.current_pp === 0 ? '#9ca3af' : '#4a5568',
+                    backgroundColor: isUnavailable(move) ? '#9ca3af' : '#4a5568',
                     color: '#ffffff',
                     border: '2px solid #000000',
                     borderRadius: '4px',
                     padding: '12px',
                     fontSize: '14px',
                     fontWeight: 'bold',
-                    cursor: isPokemonFainted || move.current_pp === 0 ? 'not-allowed' : 'pointer',
+                    cursor: isUnavailable(move) ? 'not-allowed' : 'pointer',
                     transition: 'background-color 0.2s',
-                    opacity: isPokemonFainted || move.current_pp === 0 ? 0.6 : 1,
+                    opacity: isUnavailable(move) ? 0.6 : 1,
                   }}
                   onMouseEnter={(e) => {
-                    if (!isPokemonFainted && move.current_pp > 0) {
+                    if (!isUnavailable(move)) {
                       e.currentTarget.style.backgroundColor = '#5a6578';
                     }
                   }}
                   onMouseLeave={(e) => {
-                    if (!isPokemonFainted && move.current_pp > 0) {
+                    if (!isUnavailable(move)) {
                       e.currentTarget.style.backgroundColor = '#4a5568';
                     }
                   }}
@@ -487,6 +544,48 @@ export function BattleScreen({ session, onMoveSelect, onSwitchPokemon, isBoss =
             })()}
           </div>
 
+          {/* Botón de Megaevolución (una vez por batalla) */}
+          {canMegaEvolve && megaForm && (
+            <button
+              onClick={() => setMegaSelected(!megaSelected)}
+              style={{
+                backgroundColor: megaSelected ? '#b45309' : '#f59e0b',
+                color: '#ffffff',
+                border: '2px solid #000000',
+                borderRadius: '4px',
+                padding: '12px',
+                fontSize: '14px',
+                fontWeight: 'bold',
+                cursor: 'pointer',
+                marginTop: '4px',
+              }}
+              title="Megaevoluciona antes de usar el próximo movimiento"
+            >
+              {megaSelected ? `${megaForm.display_name} activada` : `Megaevolucionar (${megaForm.display_name})`}
+            </button>
+          )}
+
+          {/* Botón de Teracristalización (una vez por batalla) */}
+          {canTerastallize && (
+            <button
+              onClick={() => setTeraSelected(!teraSelected)}
+              style={{
+                backgroundColor: teraSelected ? '#7c3aed' : '#a78bfa',
+                color: '#ffffff',
+                border: '2px solid #000000',
+                borderRadius: '4px',
+                padding: '12px',
+                fontSize: '14px',
+                fontWeight: 'bold',
+                cursor: 'pointer',
+                marginTop: '4px',
+              }}
+              title="Se teracristaliza antes de usar el próximo movimiento"
+            >
+              {teraSelected ? `✦ Tera ${activePokemon.tera_type} activada` : `Teracristalizar (${activePokemon.tera_type})`}
+            </button>
+          )}
+
           {/* Botón de Cambiar Pokémon */}
           <button
             onClick={() => setShowSwitchModal(true)}
@@ -518,12 +617,12 @@ export function BattleScreen({ session, onMoveSelect, onSwitchPokemon, isBoss =
       <PokemonSwitchModal
         session={session}
         isOpen={showSwitchModal}
-        onClose={isPokemonFainted ? undefined : () => setShowSwitchModal(false)}
+        onClose={mustSwitch ? undefined : () => setShowSwitchModal(false)}
         onSelect={(index) => {
           onSwitchPokemon(index);
           setShowSwitchModal(false);
         }}
-        forceSwitch={isPokemonFainted}
+        forceSwitch={mustSwitch}
       />
 
       {/* Modal de Selección de Objetivo */}
@@ -533,7 +632,7 @@ export function BattleScreen({ session, onMoveSelect, onSwitchPokemon, isBoss =
         availableTargets={pendingMove ? getAvailableTargets(pendingMove.moveId, pendingMove.userIndex) : []}
         onSelect={(target) => {
           if (pendingMove) {
-            onMoveSelect(pendingMove.moveId, pendingMove.userIndex, target);
+            sendMove(pendingMove.moveId, pendingMove.userIndex, target);
             setPendingMove(null);
           }
         }}
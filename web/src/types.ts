@@ -10,6 +10,21 @@ export interface Move {
   target?: string; // Tipo de objetivo del movimiento (ej: "selected-pokemon", "user", "all-opponents")
 }
 
+// Definición de un item del catálogo (GET /api/items)
+export type ItemCategory = 'HeldItem' | 'Berry' | 'MegaStone' | 'Mint' | 'Vitamin' | 'EvReducingBerry';
+
+export interface ItemDefinition {
+  id: string;
+  name: string;
+  sprite_url: string;
+  effect_text: string;
+  category: ItemCategory;
+  trigger: string | Record<string, unknown>; // Ej: "EndOfTurn" o { OnHPThreshold: { threshold: 0.5 } }
+  effects: Array<{ type: string; [param: string]: unknown }>;
+  consumable: boolean;
+  fling_power: number | null;
+}
+
 // Especie de Pokémon
 export interface PokemonSpecies {
   species_id: string;
@@ -32,6 +47,34 @@ export interface PokemonSpecies {
     min_level: number | null;
     trigger: string;
   }>;
+  ev_yield?: {
+    hp: number;
+    attack: number;
+    defense: number;
+    special_attack: number;
+    special_defense: number;
+    speed: number;
+  }; // EVs que reparte al ser derrotada (vacío en pokedex antiguas)
+  gender_rate?: number; // Probabilidad de ser hembra en octavos (-1 = sin género)
+  mega_forms?: MegaForm[]; // Formas Mega de la especie (vacío si no tiene)
+}
+
+// Forma Mega de una especie, que se activa en batalla con su megapiedra
+export interface MegaForm {
+  form_id: string;
+  display_name: string;
+  mega_stone: string;
+  primary_type: string;
+  secondary_type: string | null;
+  base_stats: {
+    hp: number;
+    attack: number;
+    defense: number;
+    special_attack: number;
+    special_defense: number;
+    speed: number;
+  };
+  ability: string;
 }
 
 // Stages de stats en batalla (cambios temporales de -6 a +6)
@@ -45,6 +88,17 @@ export interface StatStages {
   evasion: number;
 }
 
+// Naturalezas
+export type Nature =
+  | 'Hardy' | 'Lonely' | 'Brave' | 'Adamant' | 'Naughty'
+  | 'Bold' | 'Docile' | 'Relaxed' | 'Impish' | 'Lax'
+  | 'Timid' | 'Hasty' | 'Serious' | 'Jolly' | 'Naive'
+  | 'Modest' | 'Mild' | 'Quiet' | 'Bashful' | 'Rash'
+  | 'Calm' | 'Gentle' | 'Sassy' | 'Careful' | 'Quirky';
+
+// Género
+export type Gender = 'Male' | 'Female' | 'Genderless';
+
 // Instancia de Pokémon
 export interface PokemonInstance {
   id: string;
@@ -55,6 +109,9 @@ export interface PokemonInstance {
   ability: string; // ID de la habilidad activa (ej: "blaze", "intimidate", "levitate")
   held_item: string | null; // ID del objeto equipado (ej: "leftovers", "life-orb")
   battle_stages: StatStages | null; // Puede ser null si el pokemon no ha entrado en combate
+  volatile_status?: {
+    choice_locked_move?: string | null; // Movimiento bloqueado por un objeto Choice o Gorilla Tactics
+  } | null;
   individual_values: {
     hp: number;
     attack: number;
@@ -71,6 +128,16 @@ export interface PokemonInstance {
     special_defense: number;
     speed: number;
   };
+  nature?: Nature; // Naturaleza: +10% a una stat y -10% a otra (las mentas la cambian)
+  gender?: Gender;
+  tera_type?: string; // Tipo al teracristalizarse
+  terastallized?: boolean;
+  mega_evolution?: {
+    form_id: string;
+    original_ability: string;
+    original_primary_type: string;
+    original_secondary_type: string | null;
+  } | null; // Megaevolución activa (solo durante la batalla)
   base_computed_stats: {
     hp: number;
     attack: number;
@@ -125,6 +192,24 @@ export type BattleFormat = 'Single' | 'Double';
 // Posición en el campo de batalla
 export type FieldPosition = 'PlayerLeft' | 'PlayerRight' | 'OpponentLeft' | 'OpponentRight';
 
+// Condiciones de un lado del campo (hazards, pantallas y Tailwind)
+export interface SideConditions {
+  stealth_rock: boolean;
+  spikes: number; // Capas de Spikes (0-3)
+  toxic_spikes: number; // Capas de Toxic Spikes (0-2)
+  sticky_web: boolean;
+  reflect_turns: number; // Turnos restantes (0 = inactivo)
+  light_screen_turns: number;
+  aurora_veil_turns: number;
+  tailwind_turns: number;
+}
+
+// Relevo pendiente: el turno sigue cuando el jugador elige quién entra
+export interface PendingPivot {
+  slots: number[]; // Slots del campo que deben cambiar, en orden
+  baton_pass: boolean; // Si el que entra hereda stats y sustituto
+}
+
 // Estado de batalla
 export interface BattleState {
   format: BattleFormat; // Formato de batalla (Single o Double)
@@ -138,6 +223,13 @@ export interface BattleState {
   log: string[]; // Historial de lo que pasó en el turno
   weather: WeatherState | null; // Clima activo en la batalla
   terrain: TerrainState | null; // Terreno activo en la batalla
+  player_side: SideConditions; // Condiciones del lado del jugador
+  opponent_side: SideConditions; // Condiciones del lado del oponente
+  pending_pivot?: PendingPivot | null; // Relevo que el jugador debe elegir (U-turn, Eject Button...)
+  player_terastallized?: number | null; // Índice del Pokémon del jugador teracristalizado (una vez por batalla)
+  opponent_terastallized?: number | null;
+  player_mega_evolved?: number | null; // Índice del Pokémon del jugador megaevolucionado (una vez por batalla)
+  opponent_mega_evolved?: number | null;
   // Campos de compatibilidad (deprecated, usar player_active_indices y opponent_active_indices)
   player_active_index?: number;
   opponent_active_index?: number;
@@ -166,6 +258,7 @@ export type GameState =
 export interface GameConfig {
   gym_interval: number;
   total_encounters: number;
+  seed: number;
 }
 
 // Sesión de juego